@@ -502,39 +502,60 @@ struct TmpLayout<FieldIdx: Idx, VariantIdx: Idx> {
         let count =
             (niche_variants.end().index() as u128 - niche_variants.start().index() as u128) + 1;
 
-        // Find the field with the largest niche
-        let (field_index, niche, (niche_start, niche_scalar)) = variants[largest_variant_index]
+        // The largest niche is not always the best one: it may be in the middle of the largest
+        // variant, leaving too little room on either side for the other variants, while a smaller
+        // niche in another field is close enough to an edge. So try the niches of all the fields,
+        // largest first, and use the first one around which all the other variants fit. Among
+        // equally large niches, the one in the last field is preferred.
+        //
+        // The tag is still encoded in that single niche: `TagEncoding::Niche` cannot spread it
+        // over the niches or padding of several fields.
+        let mut niche_candidates: Vec<_> = variants[largest_variant_index]
             .iter()
             .enumerate()
             .filter_map(|(j, field)| Some((j, field.largest_niche?)))
-            .max_by_key(|(_, niche)| niche.available(dl))
-            .and_then(|(j, niche)| Some((j, niche, niche.reserve(dl, count)?)))?;
-        let niche_offset =
-            niche.offset + variant_layouts[largest_variant_index].fields.offset(field_index);
-        let niche_size = niche.value.size(dl);
+            .collect();
+        niche_candidates.sort_by_key(|&(j, niche)| cmp::Reverse((niche.available(dl), j)));
+
         let size = variant_layouts[largest_variant_index].size.align_to(align.abi);
 
-        let all_variants_fit = variant_layouts.iter_enumerated_mut().all(|(i, layout)| {
-            if i == largest_variant_index {
-                return true;
-            }
+        // For the first niche that works, the offset at which each variant is placed: zero for the
+        // ones that fit before the niche, and the first aligned offset after it for the others.
+        let (niche_offset, niche_start, niche_scalar, variant_offsets) =
+            niche_candidates.into_iter().find_map(|(field_index, niche)| {
+                let (niche_start, niche_scalar) = niche.reserve(dl, count)?;
+                let niche_offset = niche.offset
+                    + variant_layouts[largest_variant_index].fields.offset(field_index);
+                let niche_end = niche_offset + niche.value.size(dl);
+
+                let variant_offsets = variant_layouts
+                    .iter_enumerated()
+                    .map(|(i, layout)| {
+                        if i == largest_variant_index || layout.size <= niche_offset {
+                            return Some(Size::ZERO);
+                        }
+                        let this_offset = niche_end.align_to(layout.align.abi);
+                        (this_offset + layout.size <= size).then_some(this_offset)
+                    })
+                    .collect::<Option<IndexVec<VariantIdx, _>>>()?;
 
-            layout.largest_niche = None;
+                Some((niche_offset, niche_start, niche_scalar, variant_offsets))
+            })?;
 
-            if layout.size <= niche_offset {
-                // This variant will fit before the niche.
-                return true;
+        for (i, layout) in variant_layouts.iter_enumerated_mut() {
+            if i == largest_variant_index {
+                continue;
             }
 
-            // Determine if it'll fit after the niche.
-            let this_align = layout.align.abi;
-            let this_offset = (niche_offset + niche_size).align_to(this_align);
+            layout.largest_niche = None;
 
-            if this_offset + layout.size > size {
-                return false;
+            let this_offset = variant_offsets[i];
+            if this_offset == Size::ZERO {
+                // This variant fits before the niche.
+                continue;
             }
 
-            // It'll fit, but we need to make some adjustments.
+            // It fits after the niche, but we need to make some adjustments.
             match layout.fields {
                 FieldsShape::Arbitrary { ref mut offsets, .. } => {
                     for offset in offsets.iter_mut() {
@@ -551,12 +572,6 @@ struct TmpLayout<FieldIdx: Idx, VariantIdx: Idx> {
                 layout.abi = Abi::Aggregate { sized: true };
             }
             layout.size += this_offset;
-
-            true
-        });
-
-        if !all_variants_fit {
-            return None;
         }
 
         let largest_niche = Niche::from_scalar(dl, niche_offset, niche_scalar);
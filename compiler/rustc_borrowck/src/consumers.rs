@@ -2,7 +2,7 @@
 
 use rustc_hir::def_id::LocalDefId;
 use rustc_index::{IndexSlice, IndexVec};
-use rustc_middle::mir::{Body, Promoted};
+use rustc_middle::mir::{Body, BorrowCheckResult, Promoted};
 use rustc_middle::ty::TyCtxt;
 use std::rc::Rc;
 
@@ -108,3 +108,21 @@ pub fn get_body_with_borrowck_facts(
     let promoted: &IndexSlice<_, _> = &promoted.borrow();
     *super::do_mir_borrowck(tcx, input_body, promoted, Some(options)).1.unwrap()
 }
+
+/// Like [`get_body_with_borrowck_facts`], but also returns the [`BorrowCheckResult`] of the body,
+/// so that an override of the `mir_borrowck` query can return it instead of borrow checking the
+/// body a second time.
+///
+/// Note: unlike the `mir_borrowck` query, this function does not skip the bodies that are
+/// injected or tainted by errors.
+pub fn get_result_and_body_with_borrowck_facts(
+    tcx: TyCtxt<'_>,
+    def: LocalDefId,
+    options: ConsumerOptions,
+) -> (BorrowCheckResult<'_>, BodyWithBorrowckFacts<'_>) {
+    let (input_body, promoted) = tcx.mir_promoted(def);
+    let input_body: &Body<'_> = &input_body.borrow();
+    let promoted: &IndexSlice<_, _> = &promoted.borrow();
+    let (result, facts) = super::do_mir_borrowck(tcx, input_body, promoted, Some(options));
+    (result, *facts.unwrap())
+}
@@ -34,6 +34,7 @@
 use rustc_span::hygiene::DesugaringKind;
 use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::{BytePos, Span, Symbol};
+use rustc_trait_selection::error_reporting::on_type::on_type_note;
 use rustc_trait_selection::error_reporting::traits::suggestions::TypeErrCtxtExt;
 use rustc_trait_selection::error_reporting::traits::FindExprBySpan;
 use rustc_trait_selection::infer::InferCtxtExt;
@@ -231,16 +232,6 @@ pub(crate) fn report_use_of_moved_or_uninitialized(
 
             use_spans.var_path_only_subdiag(&mut err, desired_action);
 
-            if !is_loop_move {
-                err.span_label(
-                    span,
-                    format!(
-                        "value {} here after {partial_str}move",
-                        desired_action.as_verb_in_past_tense(),
-                    ),
-                );
-            }
-
             let ty = used_place.ty(self.body, self.infcx.tcx).ty;
             let needs_note = match ty.kind() {
                 ty::Closure(id, _) => {
@@ -253,6 +244,30 @@ pub(crate) fn report_use_of_moved_or_uninitialized(
             let place = &self.move_data.move_paths[mpi].place;
             let ty = place.ty(self.body, self.infcx.tcx).ty;
 
+            // `#[diagnostic::on_move]` on the type of the moved value.
+            let mut long_ty_file = None;
+            let on_move_note = on_type_note(self.infcx.tcx, ty, sym::on_move, &mut long_ty_file)
+                .unwrap_or_default();
+            if let Some(message) = on_move_note.message {
+                err.primary_message(message);
+            }
+
+            if !is_loop_move {
+                let label = on_move_note.label.unwrap_or_else(|| {
+                    format!(
+                        "value {} here after {partial_str}move",
+                        desired_action.as_verb_in_past_tense(),
+                    )
+                });
+                err.span_label(span, label);
+            }
+            for note in on_move_note.notes {
+                err.note(note);
+            }
+            if let Some(path) = long_ty_file {
+                err.note(format!("the full type name has been written to '{}'", path.display()));
+            }
+
             // If we're in pattern, we do nothing in favor of the previous suggestion (#80913).
             // Same for if we're in a loop, see #101119.
             if is_loop_move & !in_pattern && !matches!(use_spans, UseSpans::ClosureUse { .. }) {
@@ -434,6 +434,12 @@ pub(crate) fn explain_why_borrow_contains_point(
         let borrow_region_vid = borrow.region;
         debug!(?borrow_region_vid);
 
+        if tcx.sess.opts.unstable_opts.borrowck_explain.is_some()
+            && let Some(borrow_index) = self.borrow_set.get_index_of(&borrow.reserve_location)
+        {
+            self.record_explained_borrow(borrow_index, location);
+        }
+
         let mut region_sub = self.regioncx.find_sub_region_live_at(borrow_region_vid, location);
         debug!(?region_sub);
 
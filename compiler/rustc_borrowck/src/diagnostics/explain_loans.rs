@@ -0,0 +1,288 @@
+//! `-Z borrowck-explain`: a timeline of the loans of a function, explaining
+//! why each of them is still live where it causes a borrow error.
+//!
+//! For every loan, the dump lists the borrowed place, the region of the loan
+//! and the points that region contains. For every borrow error involving the
+//! loan, it then lists the path of outlives constraints from the loan region
+//! to a region that is live at the error point, and the use of a local that
+//! makes that region live. The same information is written as text, JSON and
+//! graphviz files to `-Z dump-mir-dir`.
+
+use std::fmt::{self, Write as _};
+use std::io::{self, Write as _};
+
+use rustc_middle::mir::pretty::create_dump_file;
+use rustc_middle::mir::{BorrowKind, Location};
+use rustc_middle::ty::{self, RegionVid};
+use rustc_span::Span;
+use serde::{Serialize, Serializer};
+
+use super::find_use;
+use crate::borrow_set::BorrowData;
+use crate::dataflow::BorrowIndex;
+use crate::region_infer::Cause;
+use crate::MirBorrowckCtxt;
+
+#[derive(Serialize)]
+struct LoanExplanation {
+    #[serde(serialize_with = "serialize_index")]
+    index: BorrowIndex,
+    kind: &'static str,
+    place: String,
+    #[serde(serialize_with = "serialize_debug")]
+    location: Location,
+    span: String,
+    #[serde(serialize_with = "serialize_debug")]
+    region: RegionVid,
+    live_at: String,
+    errors: Vec<ErrorExplanation>,
+}
+
+/// Why a loan is live at the point of a borrow error.
+#[derive(Serialize)]
+struct ErrorExplanation {
+    #[serde(serialize_with = "serialize_debug")]
+    location: Location,
+    span: String,
+    /// The outlives constraints from the loan region to `live_region`.
+    constraints: Vec<ConstraintStep>,
+    /// A region outlived by the loan region that is live at `location`.
+    #[serde(serialize_with = "serialize_debug")]
+    live_region: RegionVid,
+    /// The use that makes `live_region` live, if one was found.
+    cause: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ConstraintStep {
+    #[serde(serialize_with = "serialize_debug")]
+    sup: RegionVid,
+    #[serde(serialize_with = "serialize_debug")]
+    sub: RegionVid,
+    category: String,
+    span: String,
+}
+
+impl<'tcx> MirBorrowckCtxt<'_, '_, '_, 'tcx> {
+    /// Records that a borrow error explains why `borrow_index` is live at
+    /// `location`, for `-Z borrowck-explain`.
+    pub(crate) fn record_explained_borrow(&self, borrow_index: BorrowIndex, location: Location) {
+        let mut explained_borrows = self.explained_borrows.borrow_mut();
+        if !explained_borrows.contains(&(borrow_index, location)) {
+            explained_borrows.push((borrow_index, location));
+        }
+    }
+
+    /// Writes the loan timeline of this body, if `-Z borrowck-explain` names it.
+    pub(crate) fn dump_loan_explanations(&self) {
+        let tcx = self.infcx.tcx;
+        let Some(filter) = &tcx.sess.opts.unstable_opts.borrowck_explain else {
+            return;
+        };
+        let path = ty::print::with_forced_impl_filename_line!(tcx.def_path_str(self.mir_def_id()));
+        if path != *filter && !path.ends_with(&format!("::{filter}")) {
+            return;
+        }
+
+        let loans: Vec<_> = self
+            .borrow_set
+            .iter_enumerated()
+            .map(|(index, borrow)| self.explain_loan(index, borrow))
+            .collect();
+
+        let _: io::Result<()> = try {
+            let mut file = create_dump_file(tcx, "loans.txt", false, "borrowck", &0, self.body)?;
+            file.write_all(loans_text(&path, &loans).as_bytes())?;
+        };
+        let _: io::Result<()> = try {
+            let mut file = create_dump_file(tcx, "loans.json", false, "borrowck", &0, self.body)?;
+            file.write_all(loans_json(&path, &loans).as_bytes())?;
+        };
+        let _: io::Result<()> = try {
+            let mut file = create_dump_file(tcx, "loans.dot", false, "borrowck", &0, self.body)?;
+            file.write_all(loans_dot(&path, &loans).as_bytes())?;
+        };
+    }
+
+    fn explain_loan(&self, index: BorrowIndex, borrow: &BorrowData<'tcx>) -> LoanExplanation {
+        let kind = match borrow.kind {
+            BorrowKind::Shared => "shared",
+            BorrowKind::Fake(_) => "fake",
+            BorrowKind::Mut { .. } => "mutable",
+        };
+        let mut errors: Vec<_> = self
+            .explained_borrows
+            .borrow()
+            .iter()
+            .filter(|&&(borrow_index, _)| borrow_index == index)
+            .map(|&(_, location)| self.explain_error(borrow.region, location))
+            .collect();
+        errors.sort_by_key(|error| error.location);
+
+        LoanExplanation {
+            index,
+            kind,
+            place: self.describe_any_place(borrow.borrowed_place.as_ref()),
+            location: borrow.reserve_location,
+            span: self.span_str(self.body.source_info(borrow.reserve_location).span),
+            region: borrow.region,
+            live_at: self.regioncx.region_value_str(borrow.region),
+            errors,
+        }
+    }
+
+    fn explain_error(&self, region: RegionVid, location: Location) -> ErrorExplanation {
+        let live_region = self.regioncx.find_sub_region_live_at(region, location);
+        let constraints = self
+            .regioncx
+            .find_constraint_paths_between_regions(region, |r| r == live_region)
+            .map(|(path, _)| path)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|constraint| ConstraintStep {
+                sup: constraint.sup,
+                sub: constraint.sub,
+                category: format!("{:?}", constraint.category),
+                span: self.span_str(constraint.span),
+            })
+            .collect();
+        let tcx = self.infcx.tcx;
+        let cause = find_use::find(self.body, &self.regioncx, tcx, live_region, location);
+
+        ErrorExplanation {
+            location,
+            span: self.span_str(self.body.source_info(location).span),
+            constraints,
+            live_region,
+            cause: cause.map(|cause| self.describe_cause(cause)),
+        }
+    }
+
+    fn describe_cause(&self, cause: Cause) -> String {
+        let (local, location, what) = match cause {
+            Cause::LiveVar(local, location) => (local, location, "used"),
+            Cause::DropVar(local, location) => (local, location, "dropped"),
+        };
+        format!(
+            "{} is {what} at {location:?} ({})",
+            self.describe_any_place(local.into()),
+            self.span_str(self.body.source_info(location).span),
+        )
+    }
+
+    fn span_str(&self, span: Span) -> String {
+        self.infcx.tcx.sess.source_map().span_to_embeddable_string(span)
+    }
+}
+
+fn loans_text(path: &str, loans: &[LoanExplanation]) -> String {
+    let mut text = format!("loans of `{path}`\n");
+    for loan in loans {
+        let LoanExplanation { index, kind, place, location, span, region, live_at, errors } = loan;
+        writeln!(text, "\n{index:?}: {kind} borrow of {place} at {location:?} ({span})").unwrap();
+        writeln!(text, "    region: {region:?}").unwrap();
+        writeln!(text, "    live at: {live_at}").unwrap();
+        for error in errors {
+            writeln!(text, "    error at {:?} ({}):", error.location, error.span).unwrap();
+            for step in &error.constraints {
+                writeln!(
+                    text,
+                    "        {:?}: {:?} due to {} at {}",
+                    step.sup, step.sub, step.category, step.span
+                )
+                .unwrap();
+            }
+            match &error.cause {
+                Some(cause) => writeln!(
+                    text,
+                    "        {:?} is live at {:?} because {cause}",
+                    error.live_region, error.location
+                ),
+                None => writeln!(
+                    text,
+                    "        {:?} is live at {:?}",
+                    error.live_region, error.location
+                ),
+            }
+            .unwrap();
+        }
+    }
+    text
+}
+
+#[derive(Serialize)]
+struct LoansJson<'a> {
+    function: &'a str,
+    loans: &'a [LoanExplanation],
+}
+
+fn loans_json(path: &str, loans: &[LoanExplanation]) -> String {
+    let mut json = serde_json::to_string(&LoansJson { function: path, loans }).unwrap();
+    json.push('\n');
+    json
+}
+
+fn serialize_index<S: Serializer>(index: &BorrowIndex, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(index.as_u32().into())
+}
+
+/// Serializes `value` as a string of its `Debug` output, e.g. `'?3` for a region.
+fn serialize_debug<T: fmt::Debug, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&format_args!("{value:?}"))
+}
+
+/// A graph with a node for every loan, pointing to its region, the outlives
+/// constraints leading to the regions live at the borrow errors, and a node for
+/// every error point.
+fn loans_dot(path: &str, loans: &[LoanExplanation]) -> String {
+    let title = dot_string(&format!("loans of `{path}`"));
+    let mut dot = format!("digraph loans {{\n    label={title};\n");
+    for loan in loans {
+        let index = loan.index.index();
+        writeln!(
+            dot,
+            "    loan{index} [shape=box, label={}];",
+            dot_string(&format!(
+                "{:?}: {} borrow of {}\\nat {:?}\\nlive at {}",
+                loan.index, loan.kind, loan.place, loan.location, loan.live_at
+            ))
+        )
+        .unwrap();
+        writeln!(dot, "    loan{index} -> \"{:?}\";", loan.region).unwrap();
+        for (i, error) in loan.errors.iter().enumerate() {
+            for step in &error.constraints {
+                writeln!(
+                    dot,
+                    "    \"{:?}\" -> \"{:?}\" [label={}];",
+                    step.sup,
+                    step.sub,
+                    dot_string(&step.category)
+                )
+                .unwrap();
+            }
+            writeln!(
+                dot,
+                "    error{index}_{i} [shape=octagon, label={}];",
+                dot_string(&format!("error at {:?}\\n{}", error.location, error.span))
+            )
+            .unwrap();
+            writeln!(
+                dot,
+                "    \"{:?}\" -> error{index}_{i} [style=dashed, label={}];",
+                error.live_region,
+                dot_string(error.cause.as_deref().unwrap_or("live"))
+            )
+            .unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Quotes `s` for a graphviz attribute. `\n` escapes are kept as line breaks.
+fn dot_string(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
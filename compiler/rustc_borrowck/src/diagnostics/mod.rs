@@ -47,6 +47,7 @@
 mod bound_region_errors;
 mod conflict_errors;
 mod explain_borrow;
+mod explain_loans;
 mod move_errors;
 mod mutability_errors;
 mod region_errors;
@@ -303,6 +303,7 @@ fn do_mir_borrowck<'tcx>(
             next_region_name: RefCell::new(1),
             polonius_output: None,
             move_errors: Vec::new(),
+            explained_borrows: RefCell::default(),
             diags,
         };
         MoveVisitor { ctxt: &mut promoted_mbcx }.visit_body(promoted_body);
@@ -344,6 +345,7 @@ fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
         next_region_name: RefCell::new(1),
         polonius_output,
         move_errors: Vec::new(),
+        explained_borrows: RefCell::default(),
         diags,
     };
 
@@ -413,6 +415,8 @@ fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
         tcx.emit_node_span_lint(UNUSED_MUT, lint_root, span, VarNeedNotMut { span: mut_span })
     }
 
+    mbcx.dump_loan_explanations();
+
     let tainted_by_errors = mbcx.emit_errors();
 
     let result = BorrowCheckResult {
@@ -597,6 +601,10 @@ struct MirBorrowckCtxt<'a, 'mir, 'infcx, 'tcx> {
 
     diags: diags::BorrowckDiags<'infcx, 'tcx>,
     move_errors: Vec<MoveError<'tcx>>,
+
+    /// The loans whose liveness was explained by a borrow error, with the
+    /// location of the error, for `-Z borrowck-explain`.
+    explained_borrows: RefCell<Vec<(BorrowIndex, Location)>>,
 }
 
 // Check that:
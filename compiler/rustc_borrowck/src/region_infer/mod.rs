@@ -571,6 +571,17 @@ pub fn outlives_constraints(&self) -> impl Iterator<Item = OutlivesConstraint<'t
         self.constraints.outlives().iter().copied()
     }
 
+    /// Returns whether `r` is a universal region, i.e., a region that is in
+    /// scope for the whole body, such as a lifetime parameter or `'static`.
+    pub fn is_universal_region(&self, r: RegionVid) -> bool {
+        self.universal_regions.is_universal_region(r)
+    }
+
+    /// Returns the locations of the body contained in the inferred value of `r`.
+    pub fn region_locations(&self, r: RegionVid) -> impl Iterator<Item = Location> + '_ {
+        self.scc_values.locations_outlived_by(self.constraint_sccs.scc(r))
+    }
+
     /// Adds annotations for `#[rustc_regions]`; see `UniversalRegions::annotate`.
     pub(crate) fn annotate(&self, tcx: TyCtxt<'tcx>, err: &mut Diag<'_, ()>) {
         self.universal_regions.annotate(tcx, err)
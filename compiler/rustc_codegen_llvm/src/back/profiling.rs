@@ -1,17 +1,39 @@
 use measureme::{event_id::SEPARATOR_BYTE, EventId, StringComponent, StringId};
+use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::profiling::{SelfProfiler, TimingGuard};
+use rustc_middle::util::item_costs::BackendCosts;
 use std::ffi::{c_void, CStr};
 use std::os::raw::c_char;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Splits the name of the IR a pass runs on into the names of the functions it belongs to.
+///
+/// The names of loops are `loop %<header> in function <function>`, and LazyCallGraph::SCC is a
+/// comma separated list of functions within parentheses. Modules and functions are named by a
+/// single name.
+fn ir_name_functions(ir_name: &str) -> (Option<&str>, Vec<&str>) {
+    if let Some(loop_name) = ir_name.strip_prefix("loop %")
+        && let Some((header, function)) = loop_name.split_once(" in function ")
+    {
+        return (Some(header), vec![function]);
+    }
+    match ir_name.strip_prefix('(').and_then(|scc| scc.strip_suffix(')')) {
+        Some(scc) => (None, scc.split(", ").collect()),
+        None => (None, vec![ir_name]),
+    }
+}
 
 fn llvm_args_to_string_id(profiler: &SelfProfiler, pass_name: &str, ir_name: &str) -> EventId {
     let pass_name = profiler.get_or_alloc_cached_string(pass_name);
     let mut components = vec![StringComponent::Ref(pass_name)];
-    // handle that LazyCallGraph::SCC is a comma separated list within parentheses
-    let parentheses: &[_] = &['(', ')'];
-    let trimmed = ir_name.trim_matches(parentheses);
-    for part in trimmed.split(", ") {
-        let demangled_ir_name = rustc_demangle::demangle(part).to_string();
+    let (loop_header, functions) = ir_name_functions(ir_name);
+    for function in functions {
+        let demangled = rustc_demangle::demangle(function);
+        let demangled_ir_name = match loop_header {
+            Some(header) => format!("loop %{header} in function {demangled}"),
+            None => demangled.to_string(),
+        };
         let ir_name = profiler.get_or_alloc_cached_string(demangled_ir_name);
         components.push(StringComponent::Value(SEPARATOR_BYTE));
         components.push(StringComponent::Ref(ir_name));
@@ -47,12 +69,95 @@ fn after_pass_callback(&mut self) {
     ir_name: *const c_char,
 ) {
     let llvm_self_profiler = &mut *(llvm_self_profiler as *mut LlvmSelfProfiler<'_>);
-    let pass_name = CStr::from_ptr(pass_name).to_str().expect("valid UTF-8");
-    let ir_name = CStr::from_ptr(ir_name).to_str().expect("valid UTF-8");
-    llvm_self_profiler.before_pass_callback(pass_name, ir_name);
+    // Unwinding out of this callback would abort, so don't insist on valid UTF-8.
+    let pass_name = CStr::from_ptr(pass_name).to_string_lossy();
+    let ir_name = CStr::from_ptr(ir_name).to_string_lossy();
+    llvm_self_profiler.before_pass_callback(&pass_name, &ir_name);
 }
 
 pub unsafe extern "C" fn selfprofile_after_pass_callback(llvm_self_profiler: *mut c_void) {
     let llvm_self_profiler = &mut *(llvm_self_profiler as *mut LlvmSelfProfiler<'_>);
     llvm_self_profiler.after_pass_callback();
 }
+
+/// A pass that is currently running, as seen by `LlvmPassTimer`.
+struct RunningPass {
+    /// The functions the pass operates on.
+    functions: Vec<String>,
+    start: Instant,
+    /// Time spent in passes nested in this one.
+    nested: Duration,
+}
+
+/// Measures how long LLVM passes spend on each function for
+/// `-Z item-cost-report`. Pass events are forwarded to an `LlvmSelfProfiler`
+/// as well, if LLVM self-profiling is enabled.
+pub struct LlvmPassTimer<'a> {
+    /// The `LlvmSelfProfiler` to forward pass events to, or null.
+    llvm_self_profiler: *mut c_void,
+    backend_costs: &'a BackendCosts,
+    stack: Vec<RunningPass>,
+    function_times: FxHashMap<String, Duration>,
+}
+
+impl<'a> LlvmPassTimer<'a> {
+    pub fn new(llvm_self_profiler: *mut c_void, backend_costs: &'a BackendCosts) -> Self {
+        Self {
+            llvm_self_profiler,
+            backend_costs,
+            stack: Vec::new(),
+            function_times: FxHashMap::default(),
+        }
+    }
+
+    fn before_pass_callback(&mut self, ir_name: &str) {
+        let (_, functions) = ir_name_functions(ir_name);
+        let functions = functions.into_iter().map(str::to_owned).collect();
+        self.stack.push(RunningPass { functions, start: Instant::now(), nested: Duration::ZERO });
+    }
+
+    fn after_pass_callback(&mut self) {
+        let Some(pass) = self.stack.pop() else { return };
+        let elapsed = pass.start.elapsed();
+        if let Some(parent) = self.stack.last_mut() {
+            parent.nested += elapsed;
+        }
+
+        // Passes over a whole module are recorded under the module name,
+        // which never matches a function.
+        let self_time = elapsed.saturating_sub(pass.nested) / pass.functions.len() as u32;
+        for function in pass.functions {
+            *self.function_times.entry(function).or_default() += self_time;
+        }
+    }
+}
+
+impl Drop for LlvmPassTimer<'_> {
+    fn drop(&mut self) {
+        #[allow(rustc::potential_query_instability)]
+        for (function, time) in self.function_times.drain() {
+            self.backend_costs.record_optimization_time(&function, time);
+        }
+    }
+}
+
+pub unsafe extern "C" fn pass_timer_before_pass_callback(
+    llvm_pass_timer: *mut c_void,
+    pass_name: *const c_char,
+    ir_name: *const c_char,
+) {
+    let llvm_pass_timer = &mut *(llvm_pass_timer as *mut LlvmPassTimer<'_>);
+    if !llvm_pass_timer.llvm_self_profiler.is_null() {
+        selfprofile_before_pass_callback(llvm_pass_timer.llvm_self_profiler, pass_name, ir_name);
+    }
+    let ir_name = CStr::from_ptr(ir_name).to_string_lossy();
+    llvm_pass_timer.before_pass_callback(&ir_name);
+}
+
+pub unsafe extern "C" fn pass_timer_after_pass_callback(llvm_pass_timer: *mut c_void) {
+    let llvm_pass_timer = &mut *(llvm_pass_timer as *mut LlvmPassTimer<'_>);
+    llvm_pass_timer.after_pass_callback();
+    if !llvm_pass_timer.llvm_self_profiler.is_null() {
+        selfprofile_after_pass_callback(llvm_pass_timer.llvm_self_profiler);
+    }
+}
@@ -1,7 +1,9 @@
 use crate::back::lto::ThinBuffer;
 use crate::back::owned_target_machine::OwnedTargetMachine;
 use crate::back::profiling::{
-    selfprofile_after_pass_callback, selfprofile_before_pass_callback, LlvmSelfProfiler,
+    pass_timer_after_pass_callback, pass_timer_before_pass_callback,
+    selfprofile_after_pass_callback, selfprofile_before_pass_callback, LlvmPassTimer,
+    LlvmSelfProfiler,
 };
 use crate::base;
 use crate::common;
@@ -29,6 +31,7 @@
 use rustc_errors::{DiagCtxtHandle, FatalError, Level};
 use rustc_fs_util::{link_or_copy, path_to_c_string};
 use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::item_costs::BackendCosts;
 use rustc_session::config::{self, Lto, OutputType, Passes};
 use rustc_session::config::{RemapPathScopeComponents, SplitDwarfKind, SwitchWithOptPath};
 use rustc_session::Session;
@@ -560,6 +563,25 @@ pub(crate) unsafe fn llvm_optimize(
     let llvm_selfprofiler =
         llvm_profiler.as_mut().map(|s| s as *mut _ as *mut c_void).unwrap_or(std::ptr::null_mut());
 
+    // The pass timer forwards to the self-profiler, so only one of them is
+    // registered with LLVM.
+    let mut llvm_pass_timer =
+        cgcx.backend_costs.as_ref().map(|costs| LlvmPassTimer::new(llvm_selfprofiler, costs));
+    let (pass_callback_data, before_pass_callback, after_pass_callback): (
+        _,
+        llvm::SelfProfileBeforePassCallback,
+        llvm::SelfProfileAfterPassCallback,
+    ) = match llvm_pass_timer.as_mut() {
+        Some(timer) => (
+            timer as *mut _ as *mut c_void,
+            pass_timer_before_pass_callback,
+            pass_timer_after_pass_callback,
+        ),
+        None => {
+            (llvm_selfprofiler, selfprofile_before_pass_callback, selfprofile_after_pass_callback)
+        }
+    };
+
     let extra_passes = if !is_lto { config.passes.join(",") } else { "".to_string() };
 
     let llvm_plugins = config.llvm_plugins.join(",");
@@ -587,9 +609,9 @@ pub(crate) unsafe fn llvm_optimize(
         config.instrument_gcov,
         pgo_sample_use_path.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()),
         config.debug_info_for_profiling,
-        llvm_selfprofiler,
-        selfprofile_before_pass_callback,
-        selfprofile_after_pass_callback,
+        pass_callback_data,
+        before_pass_callback,
+        after_pass_callback,
         extra_passes.as_ptr().cast(),
         extra_passes.len(),
         llvm_plugins.as_ptr().cast(),
@@ -620,6 +642,10 @@ pub(crate) unsafe fn optimize(
         llvm::LLVMWriteBitcodeToFile(llmod, out.as_ptr());
     }
 
+    if let Some(backend_costs) = &cgcx.backend_costs {
+        record_ir_instructions(backend_costs, llmod);
+    }
+
     if let Some(opt_level) = config.opt_level {
         let opt_stage = match cgcx.lto {
             Lto::Fat => llvm::OptStage::PreLinkFatLTO,
@@ -632,6 +658,17 @@ pub(crate) unsafe fn optimize(
     Ok(())
 }
 
+/// Records the size of the functions defined in `llmod` for `-Z item-cost-report`.
+fn record_ir_instructions(backend_costs: &BackendCosts, llmod: &llvm::Module) {
+    for llfn in base::iter_functions(llmod) {
+        if unsafe { llvm::LLVMIsDeclaration(llfn) } != 0 {
+            continue;
+        }
+        let name = String::from_utf8_lossy(llvm::get_value_name(llfn));
+        backend_costs.record_ir_instructions(&name, base::instruction_count(llfn));
+    }
+}
+
 pub(crate) fn link(
     cgcx: &CodegenContext<LlvmCodegenBackend>,
     dcx: DiagCtxtHandle<'_>,
@@ -55,6 +55,28 @@ pub fn iter_globals(llmod: &llvm::Module) -> ValueIter<'_> {
     unsafe { ValueIter { cur: llvm::LLVMGetFirstGlobal(llmod), step: llvm::LLVMGetNextGlobal } }
 }
 
+pub fn iter_functions(llmod: &llvm::Module) -> ValueIter<'_> {
+    unsafe { ValueIter { cur: llvm::LLVMGetFirstFunction(llmod), step: llvm::LLVMGetNextFunction } }
+}
+
+/// Counts the instructions in the body of the function `llfn`, which must not
+/// be a declaration.
+pub fn instruction_count(llfn: &Value) -> usize {
+    let mut count = 0;
+    unsafe {
+        let mut llbb = Some(llvm::LLVMGetFirstBasicBlock(llfn));
+        while let Some(bb) = llbb {
+            let instructions = ValueIter {
+                cur: llvm::LLVMGetFirstInstruction(bb),
+                step: llvm::LLVMGetNextInstruction,
+            };
+            count += instructions.count();
+            llbb = llvm::LLVMGetNextBasicBlock(bb);
+        }
+    }
+    count
+}
+
 pub fn compile_codegen_unit(tcx: TyCtxt<'_>, cgu_name: Symbol) -> (ModuleCodegen<ModuleLlvm>, u64) {
     let start_time = Instant::now();
 
@@ -1002,6 +1002,8 @@ pub fn LLVMCreateStringAttribute(
     ) -> &Attribute;
 
     // Operations on functions
+    pub fn LLVMGetFirstFunction(M: &Module) -> Option<&Value>;
+    pub fn LLVMGetNextFunction(Fn: &Value) -> Option<&Value>;
     pub fn LLVMSetFunctionCallConv(Fn: &Value, CC: c_uint);
 
     // Operations on parameters
@@ -1011,6 +1013,9 @@ pub fn LLVMCreateStringAttribute(
 
     // Operations on basic blocks
     pub fn LLVMGetBasicBlockParent(BB: &BasicBlock) -> &Value;
+    pub fn LLVMGetNextBasicBlock(BB: &BasicBlock) -> Option<&BasicBlock>;
+    pub fn LLVMGetFirstInstruction(BB: &BasicBlock) -> Option<&Value>;
+    pub fn LLVMGetNextInstruction(Inst: &Value) -> Option<&Value>;
     pub fn LLVMAppendBasicBlockInContext<'a>(
         C: &'a Context,
         Fn: &'a Value,
@@ -30,6 +30,8 @@
 use rustc_middle::dep_graph::{WorkProduct, WorkProductId};
 use rustc_middle::middle::exported_symbols::SymbolExportInfo;
 use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::item_costs::BackendCosts;
+use rustc_monomorphize::item_costs::ItemCostReport;
 use rustc_session::config::{self, CrateType, Lto, OutFileName, OutputFilenames, OutputType};
 use rustc_session::config::{Passes, SwitchWithOptPath};
 use rustc_session::Session;
@@ -384,6 +386,8 @@ pub struct CodegenContext<B: WriteBackendMethods> {
     ///
     /// Depends on [`CodegenBackend::supports_parallel()`] and `-Zno_parallel_backend`.
     pub parallel: bool,
+    /// Where to record per-function costs, if `-Z item-cost-report` is enabled.
+    pub backend_costs: Option<BackendCosts>,
 }
 
 impl<B: WriteBackendMethods> CodegenContext<B> {
@@ -487,6 +491,7 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
 
     let (shared_emitter, shared_emitter_main) = SharedEmitter::new();
     let (codegen_worker_send, codegen_worker_receive) = channel();
+    let backend_costs = sess.opts.unstable_opts.item_cost_report.map(|_| BackendCosts::default());
 
     let coordinator_thread = start_executing_work(
         backend.clone(),
@@ -500,6 +505,7 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
         Arc::new(metadata_config),
         Arc::new(allocator_config),
         coordinator_send.clone(),
+        backend_costs.clone(),
     );
 
     OngoingCodegen {
@@ -516,6 +522,8 @@ pub fn start_async_codegen<B: ExtraBackendMethods>(
             phantom: PhantomData,
         },
         output_filenames: tcx.output_filenames(()).clone(),
+        backend_costs,
+        item_cost_report: None,
     }
 }
 
@@ -1116,6 +1124,7 @@ fn start_executing_work<B: ExtraBackendMethods>(
     metadata_config: Arc<ModuleConfig>,
     allocator_config: Arc<ModuleConfig>,
     tx_to_llvm_workers: Sender<Box<dyn Any + Send>>,
+    backend_costs: Option<BackendCosts>,
 ) -> thread::JoinHandle<Result<CompiledModules, ()>> {
     let coordinator_send = tx_to_llvm_workers;
     let sess = tcx.sess;
@@ -1216,6 +1225,7 @@ fn start_executing_work<B: ExtraBackendMethods>(
         split_debuginfo: tcx.sess.split_debuginfo(),
         split_dwarf_kind: tcx.sess.opts.unstable_opts.split_dwarf_kind,
         parallel: backend.supports_parallel() && !sess.opts.unstable_opts.no_parallel_backend,
+        backend_costs,
     };
 
     // This is the "main loop" of parallel work happening for parallel codegen.
@@ -2040,6 +2050,8 @@ pub struct OngoingCodegen<B: ExtraBackendMethods> {
     pub shared_emitter_main: SharedEmitterMain,
     pub output_filenames: Arc<OutputFilenames>,
     pub coordinator: Coordinator<B>,
+    pub backend_costs: Option<BackendCosts>,
+    pub item_cost_report: Option<ItemCostReport>,
 }
 
 impl<B: ExtraBackendMethods> OngoingCodegen<B> {
@@ -2074,6 +2086,13 @@ pub fn join(self, sess: &Session) -> (CodegenResults, FxIndexMap<WorkProductId,
             self.backend.print_statistics()
         }
 
+        if let Some(mut report) = self.item_cost_report {
+            if let Some(backend_costs) = &self.backend_costs {
+                report.add_backend_costs(&backend_costs.take());
+            }
+            report.write(sess, &self.output_filenames);
+        }
+
         (
             CodegenResults {
                 metadata: self.metadata,
@@ -34,6 +34,7 @@
 use rustc_middle::query::Providers;
 use rustc_middle::ty::layout::{HasTyCtxt, LayoutOf, TyAndLayout};
 use rustc_middle::ty::{self, Instance, Ty, TyCtxt};
+use rustc_monomorphize::item_costs::ItemCostReport;
 use rustc_session::config::{self, CrateType, EntryFnType, OptLevel, OutputType};
 use rustc_session::Session;
 use rustc_span::symbol::sym;
@@ -633,7 +634,7 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
         })
     });
 
-    let ongoing_codegen =
+    let mut ongoing_codegen =
         start_async_codegen(backend.clone(), tcx, target_cpu, metadata, metadata_module);
 
     // Codegen an allocator shim, if necessary.
@@ -802,6 +803,9 @@ pub fn codegen_crate<B: ExtraBackendMethods>(
         );
     }
 
+    // Only now have all the queries needed for codegen been run.
+    ongoing_codegen.item_cost_report = ItemCostReport::new(tcx);
+
     ongoing_codegen.check_for_errors(tcx.sess);
     ongoing_codegen
 }
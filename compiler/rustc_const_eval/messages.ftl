@@ -213,6 +213,9 @@ const_eval_modified_global =
 const_eval_mut_deref =
     mutation through a reference is not allowed in {const_eval_const_context}s
 
+const_eval_mutable_heap_ptr_in_final = encountered mutable pointer to heap memory in final value of {const_eval_intern_kind}
+    .note = heap memory allocated during const-evaluation becomes read-only static memory, so mutable pointers to it may only be stored in immutable memory of a `static`, or behind a reference
+
 const_eval_mutable_ptr_in_final = encountered mutable pointer in final value of {const_eval_intern_kind}
 
 const_eval_nested_static_in_thread_local = #[thread_local] does not support implicit nested statics, please create explicit static items and refer to them instead
@@ -3,6 +3,7 @@
 use either::{Left, Right};
 use tracing::{debug, instrument, trace};
 
+use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def::DefKind;
 use rustc_middle::bug;
 use rustc_middle::mir::interpret::{AllocId, ErrorHandled, InterpErrorInfo};
@@ -109,6 +110,13 @@ fn eval_body_using_ecx<'tcx, R: InterpretationResult<'tcx>>(
                 .emit_err(DanglingPtrInFinal { span: ecx.tcx.span, kind: intern_kind })
                 .into());
         }
+        Err(InternResult::FoundMutableHeapPointer) => {
+            return Err(ecx
+                .tcx
+                .dcx()
+                .emit_err(errors::MutableHeapPtrInFinal { span: ecx.tcx.span, kind: intern_kind })
+                .into());
+        }
         Err(InternResult::FoundBadMutablePointer) => {
             // only report mutable pointers if there were no dangling pointers
             let err_diag = errors::MutablePtrInFinal { span: ecx.tcx.span, kind: intern_kind };
@@ -385,9 +393,43 @@ fn eval_in_interpreter<'tcx, R: InterpretationResult<'tcx>>(
         // so we have to reject reading mutable global memory.
         CompileTimeMachine::new(CanAccessMutGlobal::from(is_static), CheckAlignment::Error),
     );
+    if tcx.const_eval_profile.is_some() {
+        ecx.machine.profile = Some(Default::default());
+    }
     let res = ecx.load_mir(cid.instance.def, cid.promoted);
-    res.and_then(|body| eval_body_using_ecx(&mut ecx, cid, body))
-        .map_err(|error| report_eval_error(&ecx, cid, error))
+    let res = res
+        .and_then(|body| eval_body_using_ecx(&mut ecx, cid, body))
+        .map_err(|error| report_eval_error(&ecx, cid, error));
+    if let Some(profile) = ecx.machine.profile.take() {
+        record_profile(tcx, cid, profile);
+    }
+    res
+}
+
+/// Adds the steps taken by the evaluation of `cid` to the `-Z const-eval-profile` of `tcx`.
+#[inline(never)]
+fn record_profile<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    cid: GlobalId<'tcx>,
+    profile: FxHashMap<Vec<(ty::Instance<'tcx>, Span)>, u64>,
+) {
+    let source_map = tcx.sess.source_map();
+    let frame_name = |(instance, span): &(ty::Instance<'tcx>, Span)| {
+        let loc = source_map.lookup_char_pos(span.lo());
+        with_no_trimmed_paths!(format!(
+            "{instance} at {}:{}:{}",
+            loc.file.name.prefer_remapped_unconditionaly(),
+            loc.line,
+            loc.col.0 + 1,
+        ))
+    };
+    // Frames with distinct spans can have the same name, e.g., if they come from a macro.
+    let mut stacks = FxHashMap::default();
+    #[allow(rustc::potential_query_instability)]
+    for (frames, steps) in profile {
+        *stacks.entry(frames.iter().map(frame_name).collect()).or_default() += steps;
+    }
+    tcx.const_eval_profile.as_ref().unwrap().record(cid.display(tcx), stacks);
 }
 
 #[inline(always)]
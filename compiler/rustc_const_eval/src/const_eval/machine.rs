@@ -4,8 +4,8 @@
 use std::ops::ControlFlow;
 
 use rustc_ast::Mutability;
-use rustc_data_structures::fx::FxIndexMap;
 use rustc_data_structures::fx::IndexEntry;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
 use rustc_hir::def_id::DefId;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::LangItem;
@@ -67,6 +67,15 @@ pub struct CompileTimeMachine<'tcx> {
     /// storing the result in the given `AllocId`.
     /// Used to prevent reads from a static's base allocation, as that may allow for self-initialization loops.
     pub(crate) static_root_ids: Option<(AllocId, LocalDefId)>,
+
+    /// The heap allocations of this evaluation that were interned as part of its final value.
+    /// They are read-only, which validation needs to know since they are usually only
+    /// reachable through raw pointers.
+    pub(crate) interned_heap_allocs: FxHashSet<AllocId>,
+
+    /// If `-Z const-eval-profile` is enabled, the number of steps taken in each stack of
+    /// frames, identified by their instance and current span.
+    pub(super) profile: Option<FxHashMap<Vec<(ty::Instance<'tcx>, Span)>, u64>>,
 }
 
 #[derive(Copy, Clone)]
@@ -101,6 +110,8 @@ pub(crate) fn new(
             can_access_mut_global,
             check_alignment,
             static_root_ids: None,
+            interned_heap_allocs: FxHashSet::default(),
+            profile: None,
         }
     }
 }
@@ -392,6 +403,11 @@ fn enforce_alignment(ecx: &InterpCx<'tcx, Self>) -> bool {
         matches!(ecx.machine.check_alignment, CheckAlignment::Error)
     }
 
+    #[inline(always)]
+    fn is_interned_heap_alloc(ecx: &InterpCx<'tcx, Self>, alloc_id: AllocId) -> bool {
+        ecx.machine.interned_heap_allocs.contains(&alloc_id)
+    }
+
     #[inline(always)]
     fn enforce_validity(ecx: &InterpCx<'tcx, Self>, layout: TyAndLayout<'tcx>) -> bool {
         ecx.tcx.sess.opts.unstable_opts.extra_const_ub_checks || layout.abi.is_uninhabited()
@@ -593,6 +609,12 @@ fn binary_ptr_op(
     }
 
     fn increment_const_eval_counter(ecx: &mut InterpCx<'tcx, Self>) -> InterpResult<'tcx> {
+        if let Some(profile) = &mut ecx.machine.profile {
+            let stack = ecx.machine.stack.iter();
+            let stack = stack.map(|frame| (frame.instance, frame.current_span())).collect();
+            *profile.entry(stack).or_default() += 1;
+        }
+
         // The step limit has already been hit in a previous call to `increment_const_eval_counter`.
 
         if let Some(new_steps) = ecx.machine.num_evaluated_steps.checked_add(1) {
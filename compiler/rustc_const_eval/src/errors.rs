@@ -26,6 +26,15 @@ pub(crate) struct DanglingPtrInFinal {
     pub kind: InternKind,
 }
 
+#[derive(Diagnostic)]
+#[diag(const_eval_mutable_heap_ptr_in_final)]
+#[note]
+pub(crate) struct MutableHeapPtrInFinal {
+    #[primary_span]
+    pub span: Span,
+    pub kind: InternKind,
+}
+
 #[derive(Diagnostic)]
 #[diag(const_eval_nested_static_in_thread_local)]
 pub(crate) struct NestedStaticInThreadLocal {
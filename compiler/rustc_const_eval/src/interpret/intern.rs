@@ -12,6 +12,12 @@
 //! so all inner allocations are marked mutable. Some of them could potentially be made immutable,
 //! but that would require relying on type information, and given how many ways Rust has to lie
 //! about type information, we want to avoid doing that.
+//!
+//! Heap allocations made during const-evaluation (with the `const_allocate` intrinsic) that are
+//! still alive at the end of evaluation become static memory as well, and are always immutable.
+//! Unlike other inner allocations, they are usually reached through mutable raw pointers (such as
+//! the buffer of a `Vec`), which we accept as long as the pointer itself lives in immutable
+//! memory: otherwise the runtime could free or reallocate that static memory.
 
 use hir::def::DefKind;
 use rustc_ast::Mutability;
@@ -51,6 +57,18 @@ fn static_def_id(&self) -> Option<LocalDefId> {
     }
 }
 
+pub trait HasInternedHeapAllocs {
+    /// Records that the heap allocation `alloc_id` was interned as part of the final value.
+    /// Used by validation, which then knows that this memory is read-only.
+    fn note_interned_heap_alloc(&mut self, alloc_id: AllocId);
+}
+
+impl HasInternedHeapAllocs for const_eval::CompileTimeMachine<'_> {
+    fn note_interned_heap_alloc(&mut self, alloc_id: AllocId) {
+        self.interned_heap_allocs.insert(alloc_id);
+    }
+}
+
 /// Intern an allocation. Returns `Err` if the allocation does not exist in the local memory.
 ///
 /// `mutability` can be used to force immutable interning: if it is `Mutability::Not`, the
@@ -135,6 +153,8 @@ pub enum InternKind {
 #[derive(Debug)]
 pub enum InternResult {
     FoundBadMutablePointer,
+    /// A mutable pointer to a heap allocation was found outside of immutable static memory.
+    FoundMutableHeapPointer,
     FoundDanglingPointer,
 }
 
@@ -145,7 +165,10 @@ pub enum InternResult {
 ///
 /// For `InternKind::Static` the root allocation will not be interned, but must be handled by the caller.
 #[instrument(level = "debug", skip(ecx))]
-pub fn intern_const_alloc_recursive<'tcx, M: CompileTimeMachine<'tcx, const_eval::MemoryKind>>(
+pub fn intern_const_alloc_recursive<
+    'tcx,
+    M: CompileTimeMachine<'tcx, const_eval::MemoryKind> + HasInternedHeapAllocs,
+>(
     ecx: &mut InterpCx<'tcx, M>,
     intern_kind: InternKind,
     ret: &MPlaceTy<'tcx>,
@@ -186,21 +209,31 @@ pub fn intern_const_alloc_recursive<'tcx, M: CompileTimeMachine<'tcx, const_eval
     // First we intern the base allocation, as it requires a different mutability.
     // This gives us the initial set of nested allocations, which will then all be processed
     // recursively in the loop below.
+    // Along with each pointer, we track whether it is stored in memory that is immutable for the
+    // entire execution of the program, which is what makes mutable pointers to heap allocations
+    // okay. The root allocation of a `const` does not qualify, as it is copied to wherever the
+    // constant is used.
     let mut todo: Vec<_> = if is_static {
         // Do not steal the root allocation, we need it later to create the return value of `eval_static_initializer`.
         // But still change its mutability to match the requested one.
         let alloc = ecx.memory.alloc_map.get_mut(&base_alloc_id).unwrap();
         alloc.1.mutability = base_mutability;
-        alloc.1.provenance().ptrs().iter().map(|&(_, prov)| prov).collect()
+        let in_static_memory = base_mutability == Mutability::Not;
+        alloc.1.provenance().ptrs().iter().map(|&(_, prov)| (prov, in_static_memory)).collect()
     } else {
-        intern_shallow(ecx, base_alloc_id, base_mutability).unwrap().collect()
+        intern_shallow(ecx, base_alloc_id, base_mutability)
+            .unwrap()
+            .map(|prov| (prov, false))
+            .collect()
     };
     // We need to distinguish "has just been interned" from "was already in `tcx`",
     // so we track this in a separate set.
     let mut just_interned: FxHashSet<_> = std::iter::once(base_alloc_id).collect();
+    // The heap allocations among them.
+    let mut just_interned_heap = FxHashSet::default();
     // Whether we encountered a bad mutable pointer.
-    // We want to first report "dangling" and then "mutable", so we need to delay reporting these
-    // errors.
+    // We want to first report "dangling", then "mutable heap", and then "mutable", so we need to
+    // delay reporting these errors.
     let mut result = Ok(());
 
     // Keep interning as long as there are things to intern.
@@ -211,9 +244,14 @@ pub fn intern_const_alloc_recursive<'tcx, M: CompileTimeMachine<'tcx, const_eval
     // raw pointers, so we cannot rely on validation to catch them -- and since interning runs
     // before validation, and interning doesn't know the type of anything, this means we can't show
     // better errors. Maybe we should consider doing validation before interning in the future.
-    while let Some(prov) = todo.pop() {
-        trace!(?prov);
+    while let Some((prov, in_static_memory)) = todo.pop() {
+        trace!(?prov, ?in_static_memory);
         let alloc_id = prov.alloc_id();
+        let is_heap = just_interned_heap.contains(&alloc_id)
+            || matches!(
+                ecx.memory.alloc_map.get(&alloc_id),
+                Some((MemoryKind::Machine(const_eval::MemoryKind::Heap), _))
+            );
 
         if base_alloc_id == alloc_id && is_static {
             // This is a pointer to the static itself. It's ok for a static to refer to itself,
@@ -230,7 +268,19 @@ pub fn intern_const_alloc_recursive<'tcx, M: CompileTimeMachine<'tcx, const_eval
         // allocation.
         // (It is likely not possible to actually have multiple pointers to the same allocation,
         // so alternatively we could also check that and ICE if there are multiple such pointers.)
-        if intern_kind != InternKind::Promoted
+        if is_heap && !prov.immutable() {
+            // A mutable pointer to a heap allocation, like the buffer of a `Vec`. Since heap
+            // allocations are always interned immutably, this is only fine if the pointer can
+            // never be used for mutation, or to deallocate or reallocate the memory: the pointer
+            // itself must be in immutable static memory. Shared pointers can only be used for
+            // reading, so for them the regular rules apply.
+            if !in_static_memory {
+                trace!("found mutable heap pointer outside of immutable static memory");
+                if !matches!(result, Err(InternResult::FoundDanglingPointer)) {
+                    result = Err(InternResult::FoundMutableHeapPointer);
+                }
+            }
+        } else if intern_kind != InternKind::Promoted
             && inner_mutability == Mutability::Not
             && !prov.immutable()
         {
@@ -262,6 +312,13 @@ pub fn intern_const_alloc_recursive<'tcx, M: CompileTimeMachine<'tcx, const_eval
             continue;
         }
         just_interned.insert(alloc_id);
+        if is_heap {
+            just_interned_heap.insert(alloc_id);
+            ecx.machine.note_interned_heap_alloc(alloc_id);
+            let nested = intern_shallow(ecx, alloc_id, Mutability::Not).unwrap();
+            todo.extend(nested.map(|prov| (prov, true)));
+            continue;
+        }
         // We always intern with `inner_mutability`, and furthermore we ensured above that if
         // that is "immutable", then there are *no* mutable pointers anywhere in the newly
         // interned memory -- justifying that we can indeed intern immutably. However this also
@@ -273,7 +330,10 @@ pub fn intern_const_alloc_recursive<'tcx, M: CompileTimeMachine<'tcx, const_eval
         // okay with losing some potential for immutability here. This can anyway only affect
         // `static mut`.
         match intern_shallow(ecx, alloc_id, inner_mutability) {
-            Ok(nested) => todo.extend(nested),
+            Ok(nested) => {
+                let in_static_memory = inner_mutability == Mutability::Not;
+                todo.extend(nested.map(|prov| (prov, in_static_memory)))
+            }
             Err(()) => {
                 ecx.tcx.dcx().delayed_bug("found dangling pointer during const interning");
                 result = Err(InternResult::FoundDanglingPointer);
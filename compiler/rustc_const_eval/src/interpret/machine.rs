@@ -167,6 +167,13 @@ fn alignment_check(
     /// Whether to enforce the validity invariant for a specific layout.
     fn enforce_validity(ecx: &InterpCx<'tcx, Self>, layout: TyAndLayout<'tcx>) -> bool;
 
+    /// Whether `alloc_id` was allocated on the heap during const-evaluation and then interned
+    /// (read-only) as part of the final value.
+    #[inline(always)]
+    fn is_interned_heap_alloc(_ecx: &InterpCx<'tcx, Self>, _alloc_id: AllocId) -> bool {
+        false
+    }
+
     /// Whether function calls should be [ABI](CallAbi)-checked.
     fn enforce_abi(_ecx: &InterpCx<'tcx, Self>) -> bool {
         true
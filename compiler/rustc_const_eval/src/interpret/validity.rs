@@ -589,6 +589,18 @@ fn try_visit_primitive(
                 if place.layout.is_unsized() {
                     self.check_wide_ptr_meta(place.meta(), place.layout)?;
                 }
+                // Heap memory of the final value of a const-evaluation is read-only, but unlike
+                // other read-only memory it is usually only reachable through raw pointers, which
+                // we do not recursively validate. So we at least make sure that the type of the
+                // pointer does not allow mutation through a shared reference to its pointee.
+                if self.ctfe_mode.is_some()
+                    && let Ok((alloc_id, _offset, _prov)) =
+                        self.ecx.ptr_try_get_alloc_id(place.ptr())
+                    && M::is_interned_heap_alloc(self.ecx, alloc_id)
+                    && !place.layout.ty.is_freeze(*self.ecx.tcx, self.ecx.param_env)
+                {
+                    throw_validation_failure!(self.path, UnsafeCellInImmutable);
+                }
                 Ok(true)
             }
             ty::Ref(_, _ty, mutbl) => {
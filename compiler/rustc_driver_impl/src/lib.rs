@@ -32,15 +32,18 @@
 use rustc_interface::util::{self, get_codegen_backend};
 use rustc_interface::{interface, passes, Linker, Queries};
 use rustc_lint::unerased_lint_store;
-use rustc_metadata::creader::MetadataLoader;
+use rustc_metadata::creader::{load_symbol_from_dylib, DylibError, MetadataLoader};
 use rustc_metadata::locator;
+use rustc_middle::ty::TyCtxt;
 use rustc_parse::{new_parser_from_file, new_parser_from_source_str, unwrap_or_emit_fatal};
 use rustc_session::config::{nightly_options, CG_OPTIONS, Z_OPTIONS};
-use rustc_session::config::{ErrorOutputType, Input, OutFileName, OutputType};
+use rustc_session::config::{ErrorOutputType, Input, OutFileName, OutputType, StableMirDumpFormat};
 use rustc_session::getopts::{self, Matches};
 use rustc_session::lint::{Lint, LintId};
 use rustc_session::output::collect_crate_types;
 use rustc_session::{config, filesearch, EarlyDiagCtxt, Session};
+use rustc_smir::rustc_internal::dump::write_smir_dump;
+use rustc_span::def_id::LOCAL_CRATE;
 use rustc_span::source_map::FileLoader;
 use rustc_span::symbol::sym;
 use rustc_span::FileName;
@@ -52,7 +55,7 @@
 use std::ffi::OsString;
 use std::fmt::Write as _;
 use std::fs::{self, File};
-use std::io::{self, IsTerminal, Read, Write};
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
 use std::panic::{self, catch_unwind, PanicHookInfo};
 use std::path::PathBuf;
 use std::process::{self, Command, Stdio};
@@ -97,8 +100,9 @@ pub(super) fn install() {}
 }
 
 use crate::session_diagnostics::{
-    RLinkEmptyVersionNumber, RLinkEncodingVersionMismatch, RLinkRustcVersionMismatch,
-    RLinkWrongFileType, RlinkCorruptFile, RlinkNotAFile, RlinkUnableToRead,
+    CouldntDumpStableMir, RLinkEmptyVersionNumber, RLinkEncodingVersionMismatch,
+    RLinkRustcVersionMismatch, RLinkWrongFileType, RlinkCorruptFile, RlinkNotAFile,
+    RlinkUnableToRead,
 };
 
 rustc_fluent_macro::fluent_messages! { "../messages.ftl" }
@@ -323,6 +327,7 @@ fn run_compiler(
         override_queries: None,
         make_codegen_backend,
         registry: diagnostics_registry(),
+        sarif_log: default_early_dcx.sarif_log().clone(),
         using_internal_features,
         expanded_args: args,
     };
@@ -343,11 +348,9 @@ fn run_compiler(
         },
     };
 
-    drop(default_early_dcx);
-
     callbacks.config(&mut config);
 
-    interface::run_compiler(config, |compiler| {
+    let result = interface::run_compiler(config, |compiler| {
         let sess = &compiler.sess;
         let codegen_backend = &*compiler.codegen_backend;
 
@@ -447,6 +450,10 @@ fn run_compiler(
                 return early_exit();
             }
 
+            if let Some(format) = sess.opts.unstable_opts.dump_stable_mir {
+                queries.global_ctxt()?.enter(|tcx| dump_stable_mir(tcx, format));
+            }
+
             queries.global_ctxt()?.enter(|tcx| {
                 Ok(Some(Linker::codegen_and_build_linker(tcx, &*compiler.codegen_backend)?))
             })
@@ -468,7 +475,13 @@ fn run_compiler(
         }
 
         Ok(())
-    })
+    });
+
+    // Only drop `default_early_dcx` once the session is gone: with `--error-format=sarif`, the
+    // log is written when the last emitter contributing to it is dropped, and it must contain
+    // both the early diagnostics and the session's.
+    drop(default_early_dcx);
+    result
 }
 
 // Extract output directory and file from matches.
@@ -660,6 +673,21 @@ fn process_rlink(sess: &Session, compiler: &interface::Compiler) {
     }
 }
 
+/// Writes the `-Z dump-stable-mir` dump to `{crate_name}.stable-mir.{ext}` in the output
+/// directory.
+fn dump_stable_mir(tcx: TyCtxt<'_>, format: StableMirDumpFormat) {
+    let filename = format!("{}.stable-mir.{}", tcx.crate_name(LOCAL_CRATE), format.extension());
+    let path = tcx.output_filenames(()).out_directory.join(filename);
+    let result = File::create(&path).and_then(|file| {
+        let mut file = BufWriter::new(file);
+        write_smir_dump(tcx, format, &mut file)?;
+        file.flush()
+    });
+    if let Err(error) = result {
+        tcx.dcx().emit_fatal(CouldntDumpStableMir { path: &path, error });
+    }
+}
+
 fn list_metadata(sess: &Session, metadata_loader: &dyn MetadataLoader) {
     match sess.io.input {
         Input::File(ref ifile) => {
@@ -871,6 +899,38 @@ fn print_crate_info(
                     sess.dcx().fatal("only Apple targets currently support deployment version info")
                 }
             }
+            LintLevels => {
+                use rustc_middle::lint::LintLevelSource;
+
+                let mut levels = rustc_lint::crate_wide_lint_levels(sess);
+                levels.sort_by_key(|&(lint, _)| lint.name_lower());
+                for (lint, (level, src)) in levels {
+                    let src = match src {
+                        LintLevelSource::Default => "default",
+                        LintLevelSource::Node { .. } => "attribute",
+                        LintLevelSource::CommandLine(..) => "command-line",
+                        LintLevelSource::LintConfig { .. } => "lint-config",
+                    };
+                    println_info!("{} {} {src}", lint.name_lower(), level.as_str());
+                }
+
+                if let Some(config) = &sess.opts.lint_config {
+                    let lint_store = unerased_lint_store(sess);
+                    for (glob, levels) in &config.paths {
+                        for (lint_name, level) in levels {
+                            for id in lint_store.find_lints(lint_name).unwrap_or_default() {
+                                let (name, level) = (id.lint.name_lower(), level.as_str());
+                                println_info!("{name} {level} lint-config:\"{glob}\"");
+                            }
+                        }
+                    }
+                    for (lint_name, params) in &config.params {
+                        for (param, value) in params {
+                            println_info!("{lint_name} param {param}={value}");
+                        }
+                    }
+                }
+            }
         }
 
         req.out.overwrite(&crate_info, sess);
@@ -1548,7 +1608,28 @@ pub fn install_ctrlc_handler() {
     .expect("Unable to install ctrlc handler");
 }
 
+/// Runs the proc macro server and exits if this process was spawned as one by
+/// `-Z proc-macro-execution-strategy=out-of-process`.
+pub fn maybe_run_proc_macro_server() {
+    if env::var_os(rustc_expand::proc_macro::SERVER_ENV).is_none() {
+        return;
+    }
+    let result = rustc_expand::proc_macro::serve(|path, sym_name| {
+        // SAFETY: the symbol is the one `rustc_metadata` loaded the declarations from.
+        unsafe { load_symbol_from_dylib::<*const &[_]>(path, sym_name) }
+            .map(|decls| unsafe { *decls })
+            .map_err(|err| match err {
+                DylibError::DlOpen(path, err) | DylibError::DlSym(path, err) => {
+                    format!("{path}: {err}")
+                }
+            })
+    });
+    process::exit(if result.is_ok() { 0 } else { 1 })
+}
+
 pub fn main() -> ! {
+    maybe_run_proc_macro_server();
+
     let start_time = Instant::now();
     let start_rss = get_resident_set_size();
 
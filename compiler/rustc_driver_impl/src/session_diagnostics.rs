@@ -38,6 +38,13 @@ pub(crate) struct RlinkCorruptFile<'a> {
     pub file: &'a std::path::Path,
 }
 
+#[derive(Diagnostic)]
+#[diag(driver_impl_couldnt_dump_stable_mir)]
+pub(crate) struct CouldntDumpStableMir<'a> {
+    pub path: &'a std::path::Path,
+    pub error: std::io::Error,
+}
+
 #[derive(Diagnostic)]
 #[diag(driver_impl_ice)]
 pub(crate) struct Ice;
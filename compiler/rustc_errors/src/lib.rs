@@ -86,6 +86,7 @@
 mod lock;
 pub mod markdown;
 pub mod registry;
+pub mod sarif;
 mod snippet;
 mod styled_buffer;
 #[cfg(test)]
@@ -919,6 +920,11 @@ pub fn err_count(&self) -> usize {
             + inner.stashed_diagnostics.values().filter(|(_diag, guar)| guar.is_some()).count()
     }
 
+    /// The number of warnings emitted so far, not counting duplicates.
+    pub fn warn_count(&self) -> usize {
+        self.inner.borrow().deduplicated_warn_count
+    }
+
     /// This excludes lint errors and delayed bugs. Unless absolutely
     /// necessary, prefer `has_errors` to this method.
     pub fn has_errors_excluding_lint_errors(&self) -> Option<ErrorGuaranteed> {
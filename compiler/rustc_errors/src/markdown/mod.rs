@@ -23,6 +23,44 @@ pub fn parse_str(s: &str) -> MdStream<'_> {
     pub fn write_termcolor_buf(&self, buf: &mut Buffer) -> io::Result<()> {
         term::entrypoint(self, buf)
     }
+
+    /// Render as plain text, without styles, link targets or line wrapping
+    pub fn to_plain_text(&self) -> String {
+        let mut out = String::new();
+        write_plain_text(self, &mut out);
+        out
+    }
+}
+
+fn write_plain_text(MdStream(stream): &MdStream<'_>, out: &mut String) {
+    for tt in stream {
+        match tt {
+            MdTree::CodeBlock { txt, lang: _ } => out.push_str(txt),
+            MdTree::CodeInline(txt)
+            | MdTree::Strong(txt)
+            | MdTree::Emphasis(txt)
+            | MdTree::Strikethrough(txt)
+            | MdTree::PlainText(txt)
+            | MdTree::Link { disp: txt, link: _ } => out.push_str(txt),
+            MdTree::ParagraphBreak => out.push_str("\n\n"),
+            MdTree::LineBreak => out.push('\n'),
+            MdTree::HorizontalRule => out.push_str("---"),
+            MdTree::Heading(_, stream) => {
+                write_plain_text(stream, out);
+                out.push('\n');
+            }
+            MdTree::OrderedListItem(n, stream) => {
+                out.push_str(&format!("{n}. "));
+                write_plain_text(stream, out);
+            }
+            MdTree::UnorderedListItem(stream) => {
+                out.push_str("* ");
+                write_plain_text(stream, out);
+            }
+            // Removed when parsing
+            MdTree::Comment(_) | MdTree::LinkDef { .. } | MdTree::RefLink { .. } => unreachable!(),
+        }
+    }
 }
 
 /// Create a termcolor buffer with the `Always` color choice
@@ -0,0 +1,510 @@
+//! A SARIF emitter for errors.
+//!
+//! [SARIF] (Static Analysis Results Interchange Format) is the format consumed by most code
+//! scanning tools. Unlike the JSON emitter, which streams one object per diagnostic, a SARIF log
+//! is a single document, so this emitter collects all diagnostics and writes the log when it is
+//! dropped, i.e. when the compilation session ends.
+//!
+//! A compilation uses several emitters: the ones for early errors, which exist before and while
+//! the session is being created, and the session's own. Emitters that
+//! [join](SarifEmitter::join_log) the same [`SarifLog`] add their results to it, and it is written
+//! once, when the last of them is dropped.
+//!
+//! The mapping is as follows:
+//!
+//! - each error code or lint name becomes a rule; error codes link to their explanation, which is
+//!   included both as markdown and rendered as plain text,
+//! - each diagnostic becomes a result, with its primary spans as locations and its secondary
+//!   labels and spanned sub-diagnostics as related locations,
+//! - each structured suggestion becomes a fix.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::emitter::Emitter;
+use crate::markdown::MdStream;
+use crate::registry::Registry;
+use crate::translation::{to_fluent_args, Translate};
+use crate::{
+    diagnostic::IsLint, CodeSuggestion, DiagInner, FluentBundle, LazyFallbackBundle, Level,
+    MultiSpan, SpanLabel,
+};
+use derive_setters::Setters;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_data_structures::sync::{IntoDynSyncSend, Lrc};
+use rustc_error_messages::FluentArgs;
+use rustc_lint_defs::Applicability;
+use rustc_span::source_map::SourceMap;
+use rustc_span::{FileName, Span};
+use serde::Serialize;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+#[cfg(test)]
+mod tests;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const ERROR_CODE_URI: &str = "https://doc.rust-lang.org/error_codes/";
+
+#[derive(Setters)]
+pub struct SarifEmitter {
+    #[setters(skip)]
+    dst: IntoDynSyncSend<Box<dyn Write + Send>>,
+    registry: Option<Registry>,
+    #[setters(skip)]
+    sm: Lrc<SourceMap>,
+    fluent_bundle: Option<Lrc<FluentBundle>>,
+    #[setters(skip)]
+    fallback_bundle: LazyFallbackBundle,
+    #[setters(skip)]
+    pretty: bool,
+    /// The version of the compiler, reported as the version of the tool.
+    #[setters(into)]
+    tool_version: Option<String>,
+    /// Whether to write a log without any results. Emitters that only exist for part of the
+    /// session, like the one for early errors, should not write a log unless they have to.
+    emit_empty_log: bool,
+    #[setters(skip)]
+    log: SarifLog,
+}
+
+/// A SARIF log that several emitters contribute to, e.g. the emitters for early errors and the
+/// session's emitter. Cloning it gives another handle to the same log.
+#[derive(Clone, Default)]
+pub struct SarifLog(Arc<Mutex<LogData>>);
+
+/// The results collected by the emitters that contribute to one SARIF log.
+#[derive(Default)]
+struct LogData {
+    /// The number of live emitters that contribute to this log. It is written when the last of
+    /// them is dropped.
+    emitters: usize,
+    tool_version: Option<String>,
+    emit_empty_log: bool,
+    rules: FxIndexMap<String, Rule>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifEmitter {
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        sm: Lrc<SourceMap>,
+        fallback_bundle: LazyFallbackBundle,
+        pretty: bool,
+    ) -> SarifEmitter {
+        SarifEmitter {
+            dst: IntoDynSyncSend(dst),
+            registry: None,
+            sm,
+            fluent_bundle: None,
+            fallback_bundle,
+            pretty,
+            tool_version: None,
+            emit_empty_log: false,
+            log: SarifLog(Arc::new(Mutex::new(LogData { emitters: 1, ..Default::default() }))),
+        }
+    }
+
+    /// Makes this emitter add its results to `log` instead of writing a log of its own, so that
+    /// the early error emitters and the session's emitter produce a single document.
+    pub fn join_log(mut self, log: &SarifLog) -> SarifEmitter {
+        log.0.lock().unwrap().emitters += 1;
+        self.log = log.clone();
+        self
+    }
+
+    fn write_log(&mut self, data: &LogData) -> io::Result<()> {
+        let log = Log {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: [Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "rustc",
+                        information_uri: "https://www.rust-lang.org/",
+                        version: data.tool_version.as_deref(),
+                        rules: data.rules.values().collect(),
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: &data.results,
+            }],
+        };
+        if self.pretty {
+            serde_json::to_writer_pretty(&mut *self.dst, &log)?
+        } else {
+            serde_json::to_writer(&mut *self.dst, &log)?
+        };
+        self.dst.write_all(b"\n")?;
+        self.dst.flush()
+    }
+
+    /// Returns the index of the rule for the error code or lint of `diag`, registering the rule
+    /// if this is its first use.
+    fn rule_for(&self, log: &mut LogData, diag: &DiagInner) -> Option<(String, usize)> {
+        let rule = if let Some(code) = diag.code {
+            let explanation =
+                self.registry.as_ref().and_then(|r| r.try_find_description(code).ok());
+            Rule {
+                id: code.to_string(),
+                help: explanation.map(|markdown| Help {
+                    text: MdStream::parse_str(markdown).to_plain_text(),
+                    markdown,
+                }),
+                help_uri: explanation.map(|_| format!("{ERROR_CODE_URI}{code}.html")),
+            }
+        } else if let Some(IsLint { name, .. }) = &diag.is_lint {
+            Rule { id: name.clone(), help: None, help_uri: None }
+        } else {
+            return None;
+        };
+        let id = rule.id.clone();
+        let (index, _) = log.rules.insert_full(id.clone(), rule);
+        Some((id, index))
+    }
+
+    fn location(&self, span: Span, message: Option<String>) -> Option<Location> {
+        if span.is_dummy() {
+            return None;
+        }
+        Some(Location {
+            physical_location: PhysicalLocation {
+                artifact_location: self.artifact_location(span),
+                region: self.region(span),
+            },
+            message: message.map(|text| Message { text }),
+        })
+    }
+
+    fn artifact_location(&self, span: Span) -> ArtifactLocation {
+        let file = self.sm.lookup_source_file(span.lo());
+        ArtifactLocation { uri: file_uri(&self.sm, &file.name) }
+    }
+
+    fn region(&self, span: Span) -> Region {
+        let start = self.sm.lookup_char_pos(span.lo());
+        let end = self.sm.lookup_char_pos(span.hi());
+        let byte_offset = start.file.original_relative_byte_pos(span.lo()).0;
+        let byte_end = start.file.original_relative_byte_pos(span.hi()).0;
+        Region {
+            start_line: start.line,
+            start_column: start.col.0 + 1,
+            end_line: end.line,
+            end_column: end.col.0 + 1,
+            byte_offset,
+            byte_length: byte_end.saturating_sub(byte_offset),
+        }
+    }
+
+    fn label(&self, span_label: &SpanLabel, args: &FluentArgs<'_>) -> Option<String> {
+        span_label
+            .label
+            .as_ref()
+            .map(|label| self.translate_message(label, args).unwrap().into_owned())
+    }
+
+    fn locations(
+        &self,
+        msp: &MultiSpan,
+        args: &FluentArgs<'_>,
+        locations: &mut Vec<Location>,
+        related_locations: &mut Vec<Location>,
+    ) {
+        for span_label in msp.span_labels() {
+            let label = self.label(&span_label, args);
+            let location = self.location(span_label.span, label);
+            if span_label.is_primary {
+                locations.extend(location);
+            } else {
+                related_locations.extend(location);
+            }
+        }
+    }
+
+    fn fixes(&self, suggestion: &CodeSuggestion, args: &FluentArgs<'_>) -> Vec<Fix> {
+        let description = self.translate_message(&suggestion.msg, args).unwrap().into_owned();
+        suggestion
+            .substitutions
+            .iter()
+            .map(|substitution| {
+                let mut changes: FxIndexMap<String, Vec<Replacement>> = FxIndexMap::default();
+                for part in &substitution.parts {
+                    let artifact = self.artifact_location(part.span);
+                    changes.entry(artifact.uri).or_default().push(Replacement {
+                        deleted_region: self.region(part.span),
+                        inserted_content: Content { text: part.snippet.clone() },
+                    });
+                }
+                Fix {
+                    description: Message { text: description.clone() },
+                    artifact_changes: changes
+                        .into_iter()
+                        .map(|(uri, replacements)| ArtifactChange {
+                            artifact_location: ArtifactLocation { uri },
+                            replacements,
+                        })
+                        .collect(),
+                    properties: FixProperties { applicability: suggestion.applicability },
+                }
+            })
+            .collect()
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        let log = self.log.clone();
+        let mut log = log.0.lock().unwrap_or_else(|e| e.into_inner());
+        log.emitters -= 1;
+        log.emit_empty_log |= self.emit_empty_log;
+        if log.tool_version.is_none() {
+            log.tool_version = self.tool_version.take();
+        }
+        if log.emitters > 0 {
+            return;
+        }
+
+        // Start over if another emitter joins the log later on.
+        let data = std::mem::take(&mut *log);
+        if data.results.is_empty() && !data.emit_empty_log {
+            return;
+        }
+        // Avoid turning an ICE into an abort if the log can't be written.
+        if let Err(e) = self.write_log(&data)
+            && !std::thread::panicking()
+        {
+            panic!("failed to print diagnostics: {e:?}");
+        }
+    }
+}
+
+impl Translate for SarifEmitter {
+    fn fluent_bundle(&self) -> Option<&Lrc<FluentBundle>> {
+        self.fluent_bundle.as_ref()
+    }
+
+    fn fallback_fluent_bundle(&self) -> &FluentBundle {
+        &self.fallback_bundle
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, diag: DiagInner) {
+        let level = match diag.level {
+            Level::Bug | Level::Fatal | Level::Error | Level::DelayedBug => "error",
+            Level::ForceWarning(_) | Level::Warning => "warning",
+            Level::Note | Level::OnceNote | Level::Help | Level::OnceHelp => "note",
+            // "aborting due to previous error" and similar summaries are not results.
+            Level::FailureNote => return,
+            Level::Allow | Level::Expect(_) => unreachable!(),
+        };
+        let args = to_fluent_args(diag.args.iter());
+
+        let mut text = self.translate_messages(&diag.messages, &args).into_owned();
+        let mut locations = vec![];
+        let mut related_locations = vec![];
+        self.locations(&diag.span, &args, &mut locations, &mut related_locations);
+
+        // Sub-diagnostics that point somewhere become related locations, the others are
+        // appended to the message, as they would be in the human readable output.
+        for child in &diag.children {
+            let message = self.translate_messages(&child.messages, &args).into_owned();
+            let primary_spans = child.span.primary_spans();
+            if primary_spans.is_empty() {
+                text.push_str(&format!("\n{}: {message}", child.level.to_str()));
+            } else {
+                for &span in primary_spans {
+                    let message = format!("{}: {message}", child.level.to_str());
+                    related_locations.extend(self.location(span, Some(message)));
+                }
+            }
+        }
+
+        let fixes = diag.suggestions.iter().flatten().flat_map(|s| self.fixes(s, &args)).collect();
+        let mut log = self.log.0.lock().unwrap();
+        let (rule_id, rule_index) = self.rule_for(&mut log, &diag).unzip();
+
+        log.results.push(SarifResult {
+            rule_id,
+            rule_index,
+            level,
+            message: Message { text },
+            locations,
+            related_locations,
+            fixes,
+        });
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        false
+    }
+}
+
+/// Converts a file name to the URI reference SARIF uses to identify artifacts.
+///
+/// Relative paths stay relative, so that consumers resolve them against the directory the
+/// compiler was run from.
+fn file_uri(sm: &SourceMap, name: &FileName) -> String {
+    let path = sm.filename_for_diagnostics(name).to_string();
+    let mut uri = String::with_capacity(path.len());
+    if path.starts_with('/') {
+        uri.push_str("file://");
+    } else if path.as_bytes().get(1) == Some(&b':') {
+        // Windows path with a drive letter
+        uri.push_str("file:///");
+    }
+    for c in path.chars() {
+        match c {
+            '\\' => uri.push('/'),
+            ' ' => uri.push_str("%20"),
+            '%' => uri.push_str("%25"),
+            '#' => uri.push_str("%23"),
+            '?' => uri.push_str("%3F"),
+            _ => uri.push(c),
+        }
+    }
+    uri
+}
+
+// The following data types are provided just for serialisation.
+
+#[derive(Serialize)]
+struct Log<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run<'a> {
+    tool: Tool<'a>,
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: Driver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'a str>,
+    rules: Vec<&'a Rule>,
+}
+
+/// A SARIF `reportingDescriptor`, describing an error code or a lint.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    /// The error code (e.g. "E0308") or the lint name.
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help: Option<Help>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+/// A SARIF `multiformatMessageString`: the plain text and the markdown versions of a message.
+#[derive(Serialize)]
+struct Help {
+    text: String,
+    markdown: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    /// "error", "warning" or "note".
+    level: &'static str,
+    message: Message,
+    locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<Fix>,
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location {
+    physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    /// 1-based.
+    start_line: usize,
+    /// 1-based, in Unicode code points.
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Fix {
+    description: Message,
+    artifact_changes: Vec<ArtifactChange>,
+    properties: FixProperties,
+}
+
+#[derive(Serialize)]
+struct FixProperties {
+    /// How confident rustc is that the fix is correct, as in the JSON output.
+    applicability: Applicability,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactChange {
+    artifact_location: ArtifactLocation,
+    replacements: Vec<Replacement>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Replacement {
+    deleted_region: Region,
+    inserted_content: Content,
+}
+
+#[derive(Serialize)]
+struct Content {
+    text: String,
+}
@@ -0,0 +1,163 @@
+use super::*;
+
+use crate::codes::E0308;
+use crate::DiagCtxt;
+use rustc_span::source_map::FilePathMapping;
+use rustc_span::BytePos;
+
+use serde_json::Value;
+use std::path::Path;
+use std::str;
+use std::sync::{Arc, Mutex};
+
+struct Shared<T> {
+    data: Arc<Mutex<T>>,
+}
+
+impl<T: Write> Write for Shared<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.data.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.data.lock().unwrap().flush()
+    }
+}
+
+const EXPLANATION: &str = "Mismatched `types`.\n\n```\nlet x: u8 = \"\";\n```";
+
+/// Emits the diagnostics built by `f` for `code` and returns the resulting SARIF log.
+fn sarif_log(code: &str, f: impl FnOnce(&DiagCtxt)) -> Value {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("src/test file.rs").to_owned().into(), code.to_owned());
+        let fallback_bundle =
+            crate::fallback_fluent_bundle(vec![crate::DEFAULT_LOCALE_RESOURCE], false);
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let se = SarifEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            sm,
+            fallback_bundle,
+            false,
+        )
+        .registry(Some(Registry::new(&[(E0308, EXPLANATION)])))
+        .emit_empty_log(true);
+
+        let dcx = DiagCtxt::new(Box::new(se));
+        f(&dcx);
+        drop(dcx);
+
+        let bytes = output.lock().unwrap();
+        serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap()
+    })
+}
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::with_root_ctxt(BytePos(lo), BytePos(hi))
+}
+
+#[test]
+fn empty_log() {
+    let log = sarif_log("", |_| {});
+    assert_eq!(log["version"], "2.1.0");
+    assert_eq!(log["runs"][0]["tool"]["driver"]["name"], "rustc");
+    assert_eq!(log["runs"][0]["results"], Value::Array(vec![]));
+}
+
+#[test]
+fn error_with_code_and_suggestion() {
+    let log = sarif_log("fn f() {\n    let x: u8 = \"é\";\n}\n", |dcx| {
+        dcx.handle()
+            .struct_span_err(span(25, 29), "mismatched types")
+            .with_code(E0308)
+            .with_span_label(span(20, 22), "expected due to this")
+            .with_span_suggestion(span(25, 29), "use a byte", "b'e'", Applicability::MaybeIncorrect)
+            .emit();
+    });
+    let run = &log["runs"][0];
+
+    let rule = &run["tool"]["driver"]["rules"][0];
+    assert_eq!(rule["id"], "E0308");
+    assert_eq!(rule["helpUri"], "https://doc.rust-lang.org/error_codes/E0308.html");
+    assert_eq!(rule["help"]["markdown"], EXPLANATION);
+    assert_eq!(rule["help"]["text"], "Mismatched types.\n\nlet x: u8 = \"\";");
+
+    assert_eq!(run["results"].as_array().unwrap().len(), 1);
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "E0308");
+    assert_eq!(result["ruleIndex"], 0);
+    assert_eq!(result["level"], "error");
+    assert_eq!(result["message"]["text"], "mismatched types");
+
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "src/test%20file.rs");
+    let region = &location["region"];
+    assert_eq!((region["startLine"].as_u64(), region["startColumn"].as_u64()), (Some(2), Some(17)));
+    assert_eq!((region["endLine"].as_u64(), region["endColumn"].as_u64()), (Some(2), Some(20)));
+    assert_eq!((region["byteOffset"].as_u64(), region["byteLength"].as_u64()), (Some(25), Some(4)));
+
+    let related = &result["relatedLocations"][0];
+    assert_eq!(related["message"]["text"], "expected due to this");
+
+    let fix = &result["fixes"][0];
+    assert_eq!(fix["description"]["text"], "use a byte");
+    assert_eq!(fix["properties"]["applicability"], "MaybeIncorrect");
+    let replacement = &fix["artifactChanges"][0]["replacements"][0];
+    assert_eq!(replacement["insertedContent"]["text"], "b'e'");
+    assert_eq!(replacement["deletedRegion"]["byteOffset"], 25);
+}
+
+#[test]
+fn rules_are_shared() {
+    let log = sarif_log("fn f() {}", |dcx| {
+        dcx.handle().struct_span_warn(span(0, 2), "first").with_code(E0308).emit();
+        dcx.handle().struct_span_warn(span(3, 4), "second").with_code(E0308).emit();
+        dcx.handle().struct_span_warn(span(3, 4), "no code").with_note("a note").emit();
+    });
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 1);
+    assert_eq!(run["results"][1]["ruleIndex"], 0);
+    assert_eq!(run["results"][1]["level"], "warning");
+    assert_eq!(run["results"][2].get("ruleId"), None);
+    assert_eq!(run["results"][2]["message"]["text"], "no code\nnote: a note");
+}
+
+#[test]
+fn shared_log_is_written_once() {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("lib.rs").to_owned().into(), "fn f() {}".to_owned());
+        let emitter = |output: &Arc<Mutex<Vec<u8>>>| {
+            let fallback_bundle =
+                crate::fallback_fluent_bundle(vec![crate::DEFAULT_LOCALE_RESOURCE], false);
+            SarifEmitter::new(
+                Box::new(Shared { data: output.clone() }),
+                sm.clone(),
+                fallback_bundle,
+                false,
+            )
+        };
+
+        let log = SarifLog::default();
+        let early_output = Arc::new(Mutex::new(Vec::new()));
+        let early_dcx = DiagCtxt::new(Box::new(emitter(&early_output).join_log(&log)));
+        early_dcx.handle().struct_warn("early").emit();
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let dcx = DiagCtxt::new(Box::new(emitter(&output).emit_empty_log(true).join_log(&log)));
+        dcx.handle().struct_span_err(span(3, 4), "late").emit();
+        drop(dcx);
+        // The early emitter is still alive, so the log hasn't been written yet.
+        assert!(output.lock().unwrap().is_empty());
+
+        drop(early_dcx);
+        assert!(output.lock().unwrap().is_empty());
+        let bytes = early_output.lock().unwrap();
+        let log: Value = serde_json::from_str(str::from_utf8(&bytes).unwrap()).unwrap();
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["message"]["text"], "early");
+        assert_eq!(results[1]["message"]["text"], "late");
+    })
+}
@@ -41,6 +41,9 @@ expand_empty_delegation_mac =
 expand_expected_paren_or_brace =
     expected `(` or `{"{"}`, found `{$token}`
 
+expand_expansion_cache_write =
+    failed to write the macro expansion cache to `{$path}`: {$err}
+
 expand_explain_doc_comment_inner =
     inner doc comments expand to `#![doc = "..."]`, which is what this macro attempted to match
 
@@ -133,6 +136,10 @@ expand_only_one_word =
 expand_proc_macro_back_compat = using an old version of `{$crate_name}`
     .note = older versions of the `{$crate_name}` crate no longer compile; please update to `{$crate_name}` v{$fixed_version}, or switch to one of the `{$crate_name}` alternatives
 
+expand_proc_macro_crashed =
+    proc macro crashed
+    .note = the proc macro server exited with {$status}
+
 expand_proc_macro_derive_panicked =
     proc-macro derive panicked
     .help = message: {$message}
@@ -144,6 +151,10 @@ expand_proc_macro_panicked =
     proc macro panicked
     .help = message: {$message}
 
+expand_proc_macro_timed_out =
+    proc macro did not finish within {$timeout} seconds
+    .help = the limit can be changed with `-Z proc-macro-timeout`
+
 expand_recursion_limit_reached =
     recursion limit reached while expanding `{$descr}`
     .help = consider increasing the recursion limit by adding a `#![recursion_limit = "{$suggested_limit}"]` attribute to your crate (`{$crate_name}`)
@@ -1,6 +1,7 @@
 use crate::base::ast::NestedMetaItem;
 use crate::errors;
 use crate::expand::{self, AstFragment, Invocation};
+use crate::expansion_cache::ExpansionCache;
 use crate::module::DirOwnership;
 
 use rustc_ast::attr::MarkedAttrs;
@@ -1148,6 +1149,10 @@ pub struct ExtCtxt<'a> {
     /// in the AST, but insert it here so that we know
     /// not to expand it again.
     pub(super) expanded_inert_attrs: MarkedAttrs,
+    /// Expansions reused from the previous incremental session, see `-Z cache-expansions`.
+    /// This is only loaded for the expansion of the crate itself, not for the contexts used
+    /// by the harnesses and the standard library imports.
+    pub expansion_cache: ExpansionCache,
 }
 
 impl<'a> ExtCtxt<'a> {
@@ -1177,6 +1182,7 @@ pub fn new(
             expansions: FxIndexMap::default(),
             expanded_inert_attrs: MarkedAttrs::new(),
             buffered_early_lint: vec![],
+            expansion_cache: ExpansionCache::default(),
         }
     }
 
@@ -5,6 +5,7 @@
 use rustc_span::symbol::{Ident, MacroRulesNormalizedIdent};
 use rustc_span::{Span, Symbol};
 use std::borrow::Cow;
+use std::path::PathBuf;
 
 #[derive(Diagnostic)]
 #[diag(expand_expr_repeat_no_syntax_vars)]
@@ -369,6 +370,31 @@ pub(crate) struct ProcMacroPanickedHelp {
     pub message: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(expand_expansion_cache_write)]
+pub(crate) struct ExpansionCacheWrite {
+    pub path: PathBuf,
+    pub err: std::io::Error,
+}
+
+#[derive(Diagnostic)]
+#[diag(expand_proc_macro_crashed)]
+#[note]
+pub(crate) struct ProcMacroCrashed {
+    #[primary_span]
+    pub span: Span,
+    pub status: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(expand_proc_macro_timed_out)]
+#[help]
+pub(crate) struct ProcMacroTimedOut {
+    #[primary_span]
+    pub span: Span,
+    pub timeout: u64,
+}
+
 #[derive(Diagnostic)]
 #[diag(expand_proc_macro_derive_panicked)]
 pub(crate) struct ProcMacroDerivePanicked {
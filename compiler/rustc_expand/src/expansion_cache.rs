@@ -0,0 +1,624 @@
+//! Reuse of macro expansions across incremental compilation sessions, see
+//! `-Z cache-expansions`.
+//!
+//! An expansion is identified by its macro and a hash of the contents of its input token
+//! streams, ignoring their spans. Its output is stored with every span replaced by a reference
+//! to an *anchor*: one of the spans of the input tokens, the call-site, def-site and mixed-site
+//! spans of a proc macro, or one of the spans of a `macro_rules!` macro's right-hand sides,
+//! possibly with the mark of the expansion applied. When the macro is invoked on input with the
+//! same contents in a later session, the output is rebuilt from the spans of the new anchors, so
+//! moving a macro invocation around doesn't invalidate its expansion. Outputs with spans that
+//! aren't anchors are never stored.
+//!
+//! Proc macros are only cached if they declare themselves deterministic through
+//! `proc_macro::expansion_cache::declare_deterministic`. The environment variables and files
+//! they track are recorded with the expansion, and checked before reusing it, and so are the
+//! diagnostics they emit, which are emitted again when the expansion is reused. Expansions that
+//! emitted errors, other warnings, buffered lints or used feature-gated syntax are never stored,
+//! since reusing them would lose those.
+
+use crate::base::ExtCtxt;
+use crate::errors;
+
+use rustc_ast::token::{self, IdentIsRaw, Token, TokenKind};
+use rustc_ast::tokenstream::{DelimSpacing, DelimSpan, Spacing, TokenStream, TokenTree};
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::stable_hasher::StableHasher;
+use rustc_errors::{Diag, DiagCtxtHandle, DiagMessage, Level, MultiSpan};
+use rustc_macros::{Decodable, Encodable};
+use rustc_serialize::opaque::{FileEncoder, MemDecoder};
+use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
+use rustc_session::Session;
+use rustc_span::hygiene::{LocalExpnId, Transparency};
+use rustc_span::{Span, Symbol, SyntaxContext};
+use std::hash::Hash;
+use std::path::PathBuf;
+use std::{env, fs, io};
+
+const FILE_NAME: &str = "expansions.bin";
+const FILE_MAGIC: &[u8] = b"RSEX";
+
+/// A reference to the span of an anchor, see the module docs.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Encodable, Decodable)]
+struct SpanRef {
+    anchor: u32,
+    /// Whether the mark of the expansion is applied to the anchor's span.
+    marked: bool,
+}
+
+#[derive(Encodable, Decodable)]
+enum CachedTokenTree {
+    /// A token, and the span of its ident for `NtIdent` and `NtLifetime` tokens. The spans in
+    /// the `TokenKind` itself are meaningless.
+    Token(TokenKind, SpanRef, Option<SpanRef>, Spacing),
+    Delimited(SpanRef, SpanRef, DelimSpacing, token::Delimiter, Vec<CachedTokenTree>),
+}
+
+#[derive(Encodable, Decodable)]
+struct CachedExpansion {
+    output: Vec<CachedTokenTree>,
+    /// Anchors that had the same span when the expansion was stored and were used by the
+    /// output. They need to still have the same span, as either could have been the one the
+    /// macro used.
+    equal_anchors: Vec<(SpanRef, SpanRef)>,
+    /// A hash of the positions of the anchors, for proc macros that looked at them.
+    positions: Option<Fingerprint>,
+    env_vars: Vec<(Symbol, Option<Symbol>)>,
+    files: Vec<(Symbol, Option<Fingerprint>)>,
+    /// The arm of a `macro_rules!` macro that matched the input.
+    arm: Option<usize>,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+/// A diagnostic emitted by a proc macro, with references to anchors instead of spans.
+#[derive(Encodable, Decodable)]
+struct CachedDiagnostic {
+    level: Level,
+    message: String,
+    spans: Vec<SpanRef>,
+    children: Vec<CachedDiagnostic>,
+}
+
+/// A diagnostic emitted by a proc macro through `proc_macro::Diagnostic`.
+#[derive(Clone)]
+pub(crate) struct ProcMacroDiagnostic {
+    pub level: Level,
+    pub message: String,
+    pub spans: Vec<Span>,
+    pub children: Vec<ProcMacroDiagnostic>,
+}
+
+impl ProcMacroDiagnostic {
+    pub(crate) fn emit(self, dcx: DiagCtxtHandle<'_>) {
+        let message = DiagMessage::from(self.message);
+        let mut diag: Diag<'_, ()> = Diag::new(dcx, self.level, message);
+        diag.span(MultiSpan::from_spans(self.spans));
+        for child in self.children {
+            // This message comes from another diagnostic, and we are just reconstructing the
+            // diagnostic, so there's no need for translation.
+            #[allow(rustc::untranslatable_diagnostic)]
+            diag.sub(child.level, child.message, MultiSpan::from_spans(child.spans));
+        }
+        diag.emit();
+    }
+}
+
+/// What the proc macro that is currently running did that affects whether its output can be
+/// stored. This is filled in by the proc macro server.
+#[derive(Default)]
+pub(crate) struct ProcMacroTracker {
+    pub deterministic: bool,
+    /// The proc macro did something that can't be reproduced, like expanding an expression.
+    pub uncacheable: bool,
+    /// The proc macro looked at the position of spans, so its output is only valid for input
+    /// at the same position.
+    pub observed_positions: bool,
+    pub env_vars: Vec<(Symbol, Option<Symbol>)>,
+    pub paths: Vec<Symbol>,
+    /// The diagnostics to emit again when the output is reused.
+    pub diagnostics: Vec<ProcMacroDiagnostic>,
+}
+
+/// The spans that the output of an expansion can refer to.
+pub(crate) struct Anchors {
+    spans: Vec<Span>,
+    /// The mark `macro_rules!` transcription applies to spans.
+    mark: Option<(LocalExpnId, Transparency)>,
+    marked_ctxts: FxHashMap<SyntaxContext, SyntaxContext>,
+}
+
+impl Anchors {
+    fn new(spans: Vec<Span>, mark: Option<(LocalExpnId, Transparency)>) -> Anchors {
+        Anchors { spans, mark, marked_ctxts: Default::default() }
+    }
+
+    /// Anchors of a proc macro invoked on `inputs`.
+    pub(crate) fn for_proc_macro(ecx: &ExtCtxt<'_>, inputs: &[&TokenStream]) -> Anchors {
+        // These need to be the spans returned by `Span::call_site` etc., see `Rustc::new`.
+        let expn_data = ecx.current_expansion.id.expn_data();
+        let mut spans = vec![
+            ecx.with_def_site_ctxt(expn_data.def_site),
+            ecx.with_call_site_ctxt(expn_data.call_site),
+            ecx.with_mixed_site_ctxt(expn_data.call_site),
+        ];
+        for input in inputs {
+            collect_stream_spans(input, &mut spans);
+        }
+        Anchors::new(spans, None)
+    }
+
+    /// Anchors of a `macro_rules!` macro invoked on `input`, where `def_spans` are the spans of
+    /// the macro's right-hand sides.
+    pub(crate) fn for_macro_rules(
+        input: &TokenStream,
+        def_spans: &[Span],
+        expn_id: LocalExpnId,
+        transparency: Transparency,
+    ) -> Anchors {
+        let mut spans = def_spans.to_vec();
+        collect_stream_spans(input, &mut spans);
+        Anchors::new(spans, Some((expn_id, transparency)))
+    }
+
+    fn resolve(&mut self, span_ref: SpanRef) -> Option<Span> {
+        let span = *self.spans.get(span_ref.anchor as usize)?;
+        if !span_ref.marked {
+            return Some(span);
+        }
+        let (expn_id, transparency) = self.mark?;
+        // This mirrors `Marker` in `mbe::transcribe`.
+        let marked_ctxts = &mut self.marked_ctxts;
+        Some(span.map_ctxt(|ctxt| {
+            *marked_ctxts
+                .entry(ctxt)
+                .or_insert_with(|| ctxt.apply_mark(expn_id.to_expn_id(), transparency))
+        }))
+    }
+
+    /// Maps every span of the anchors to the references that resolve to it.
+    fn references(&mut self) -> FxHashMap<Span, Vec<SpanRef>> {
+        let mut refs = FxHashMap::<Span, Vec<SpanRef>>::default();
+        let marks = if self.mark.is_some() { &[false, true][..] } else { &[false][..] };
+        for anchor in 0..self.spans.len() as u32 {
+            for &marked in marks {
+                let span_ref = SpanRef { anchor, marked };
+                let span = self.resolve(span_ref).unwrap();
+                refs.entry(span).or_default().push(span_ref);
+            }
+        }
+        refs
+    }
+
+    /// Hashes the positions of the anchors.
+    fn positions(&self, sess: &Session) -> Fingerprint {
+        let source_map = sess.source_map();
+        let mut hasher = StableHasher::new();
+        for span in &self.spans {
+            let lo = source_map.lookup_char_pos(span.lo());
+            let hi = source_map.lookup_char_pos(span.hi());
+            lo.file.name.prefer_local().to_string().hash(&mut hasher);
+            (lo.line, lo.col.0, hi.line, hi.col.0).hash(&mut hasher);
+            let lo = source_map.lookup_byte_offset(span.lo()).pos.0;
+            let hi = source_map.lookup_byte_offset(span.hi()).pos.0;
+            (lo, hi).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+fn collect_stream_spans(stream: &TokenStream, spans: &mut Vec<Span>) {
+    for tree in stream.trees() {
+        match tree {
+            TokenTree::Token(token, _) => collect_token_spans(token, spans),
+            TokenTree::Delimited(delim_span, _, _, stream) => {
+                spans.extend([delim_span.open, delim_span.close]);
+                collect_stream_spans(stream, spans);
+            }
+        }
+    }
+}
+
+pub(crate) fn collect_token_spans(token: &Token, spans: &mut Vec<Span>) {
+    spans.push(token.span);
+    if let token::NtIdent(ident, _) | token::NtLifetime(ident) = &token.kind {
+        spans.push(ident.span);
+    }
+}
+
+/// Hashes the contents of `stream`, ignoring its spans. Returns `false` if the stream contains
+/// interpolated tokens, which can't be cached.
+pub(crate) fn hash_stream(
+    stream: &TokenStream,
+    with_editions: bool,
+    hasher: &mut StableHasher,
+) -> bool {
+    for tree in stream.trees() {
+        match tree {
+            TokenTree::Token(token, spacing) => {
+                match &token.kind {
+                    token::NtIdent(ident, is_raw) => {
+                        ("NtIdent", ident.name.as_str(), *is_raw == IdentIsRaw::Yes).hash(hasher)
+                    }
+                    token::NtLifetime(ident) => ("NtLifetime", ident.name.as_str()).hash(hasher),
+                    token::Interpolated(..) => return false,
+                    // Apart from interpolated tokens, the debug output of a token kind only
+                    // contains symbols as strings.
+                    kind => format!("{kind:?}").hash(hasher),
+                }
+                format!("{spacing:?}").hash(hasher);
+                // Whether an identifier is a keyword depends on its edition, which matters for
+                // matching `macro_rules!` macros.
+                if with_editions && token.is_ident() {
+                    token.span.edition().hash(hasher);
+                }
+            }
+            TokenTree::Delimited(_, spacing, delim, stream) => {
+                (format!("{spacing:?}"), *delim).hash(hasher);
+                if !hash_stream(stream, with_editions, hasher) {
+                    return false;
+                }
+                "end".hash(hasher);
+            }
+        }
+    }
+    true
+}
+
+/// Whether diagnostics, lints or feature gates were emitted or recorded, see `snapshot`.
+#[derive(PartialEq)]
+pub(crate) struct DiagnosticsSnapshot {
+    err_count: usize,
+    warn_count: usize,
+    buffered_lints: usize,
+    early_lints: usize,
+    gated_spans: usize,
+}
+
+impl DiagnosticsSnapshot {
+    pub(crate) fn new(ecx: &ExtCtxt<'_>) -> DiagnosticsSnapshot {
+        let psess = &ecx.sess.psess;
+        DiagnosticsSnapshot {
+            err_count: ecx.dcx().err_count(),
+            warn_count: ecx.dcx().warn_count(),
+            buffered_lints: psess.buffered_lints.lock().len(),
+            early_lints: ecx.buffered_early_lint.len(),
+            gated_spans: psess.gated_spans.spans.borrow().values().map(Vec::len).sum(),
+        }
+    }
+}
+
+/// Returns the reference to the anchor with `span`, remembering the spans of several anchors in
+/// `ambiguous`.
+fn span_ref(
+    span: Span,
+    refs: &FxHashMap<Span, Vec<SpanRef>>,
+    ambiguous: &mut Vec<Span>,
+) -> Option<SpanRef> {
+    let candidates = refs.get(&span)?;
+    if candidates.len() > 1 {
+        ambiguous.push(span);
+    }
+    Some(candidates[0])
+}
+
+fn encode_stream(
+    stream: &TokenStream,
+    refs: &FxHashMap<Span, Vec<SpanRef>>,
+    ambiguous: &mut Vec<Span>,
+) -> Option<Vec<CachedTokenTree>> {
+    let mut trees = Vec::new();
+    for tree in stream.trees() {
+        trees.push(match tree {
+            TokenTree::Token(token, spacing) => {
+                let ident_span = match &token.kind {
+                    token::NtIdent(ident, _) | token::NtLifetime(ident) => {
+                        Some(span_ref(ident.span, refs, ambiguous)?)
+                    }
+                    token::Interpolated(..) => return None,
+                    _ => None,
+                };
+                let span = span_ref(token.span, refs, ambiguous)?;
+                CachedTokenTree::Token(token.kind.clone(), span, ident_span, *spacing)
+            }
+            TokenTree::Delimited(delim_span, spacing, delim, stream) => {
+                let open = span_ref(delim_span.open, refs, ambiguous)?;
+                let close = span_ref(delim_span.close, refs, ambiguous)?;
+                let stream = encode_stream(stream, refs, ambiguous)?;
+                CachedTokenTree::Delimited(open, close, *spacing, *delim, stream)
+            }
+        });
+    }
+    Some(trees)
+}
+
+fn encode_diagnostic(
+    diagnostic: &ProcMacroDiagnostic,
+    refs: &FxHashMap<Span, Vec<SpanRef>>,
+    ambiguous: &mut Vec<Span>,
+) -> Option<CachedDiagnostic> {
+    Some(CachedDiagnostic {
+        level: diagnostic.level,
+        message: diagnostic.message.clone(),
+        spans: diagnostic
+            .spans
+            .iter()
+            .map(|&span| span_ref(span, refs, ambiguous))
+            .collect::<Option<_>>()?,
+        children: diagnostic
+            .children
+            .iter()
+            .map(|child| encode_diagnostic(child, refs, ambiguous))
+            .collect::<Option<_>>()?,
+    })
+}
+
+fn decode_diagnostic(
+    diagnostic: &CachedDiagnostic,
+    anchors: &mut Anchors,
+) -> Option<ProcMacroDiagnostic> {
+    Some(ProcMacroDiagnostic {
+        level: diagnostic.level,
+        message: diagnostic.message.clone(),
+        spans: diagnostic.spans.iter().map(|&span| anchors.resolve(span)).collect::<Option<_>>()?,
+        children: diagnostic
+            .children
+            .iter()
+            .map(|child| decode_diagnostic(child, anchors))
+            .collect::<Option<_>>()?,
+    })
+}
+
+fn decode_stream(trees: &[CachedTokenTree], anchors: &mut Anchors) -> Option<TokenStream> {
+    let mut stream = Vec::with_capacity(trees.len());
+    for tree in trees {
+        stream.push(match tree {
+            CachedTokenTree::Token(kind, span, ident_span, spacing) => {
+                let mut kind = kind.clone();
+                if let token::NtIdent(ident, _) | token::NtLifetime(ident) = &mut kind {
+                    ident.span = anchors.resolve((*ident_span)?)?;
+                }
+                TokenTree::Token(Token::new(kind, anchors.resolve(*span)?), *spacing)
+            }
+            CachedTokenTree::Delimited(open, close, spacing, delim, trees) => {
+                let delim_span =
+                    DelimSpan::from_pair(anchors.resolve(*open)?, anchors.resolve(*close)?);
+                TokenTree::Delimited(delim_span, *spacing, *delim, decode_stream(trees, anchors)?)
+            }
+        });
+    }
+    Some(TokenStream::new(stream))
+}
+
+fn hash_file(path: Symbol) -> Option<Fingerprint> {
+    let contents = fs::read(path.as_str()).ok()?;
+    let mut hasher = StableHasher::new();
+    contents.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+fn env_var(sess: &Session, var: Symbol) -> Option<Symbol> {
+    // This mirrors `proc_macro::tracked_env::var`.
+    match sess.opts.logical_env.get(var.as_str()) {
+        Some(value) => Some(Symbol::intern(value)),
+        None => env::var(var.as_str()).ok().map(|value| Symbol::intern(&value)),
+    }
+}
+
+/// Checks that the anchors and dependencies of `expansion` didn't change.
+fn is_valid(expansion: &CachedExpansion, sess: &Session, anchors: &mut Anchors) -> bool {
+    expansion.equal_anchors.iter().all(|&(a, b)| anchors.resolve(a) == anchors.resolve(b))
+        && expansion.positions.map_or(true, |positions| positions == anchors.positions(sess))
+        && expansion.env_vars.iter().all(|&(var, value)| env_var(sess, var) == value)
+        && expansion.files.iter().all(|&(path, hash)| hash_file(path) == hash)
+}
+
+/// The expansions of the previous session, and the ones that are reused or stored in this one.
+#[derive(Default)]
+pub struct ExpansionCache {
+    enabled: bool,
+    previous: FxHashMap<Fingerprint, CachedExpansion>,
+    current: FxHashMap<Fingerprint, CachedExpansion>,
+    /// The proc macros that are running, which can be more than one because of
+    /// `proc_macro::TokenStream::expand_expr`.
+    pub(crate) trackers: Vec<ProcMacroTracker>,
+}
+
+impl ExpansionCache {
+    fn path(sess: &Session) -> Option<PathBuf> {
+        if !sess.opts.unstable_opts.cache_expansions {
+            return None;
+        }
+        Some(sess.incr_comp_session_dir_opt()?.join(FILE_NAME))
+    }
+
+    /// Loads the expansions stored by the previous session.
+    pub fn load(sess: &Session) -> ExpansionCache {
+        let Some(path) = Self::path(sess) else {
+            return ExpansionCache::default();
+        };
+        let previous = fs::read(&path)
+            .ok()
+            .and_then(|data| {
+                let mut decoder = MemDecoder::new(&data, 0).ok()?;
+                if decoder.read_raw_bytes(FILE_MAGIC.len()) != FILE_MAGIC
+                    || String::decode(&mut decoder) != sess.cfg_version
+                {
+                    return None;
+                }
+                Some(Decodable::decode(&mut decoder))
+            })
+            .unwrap_or_default();
+        ExpansionCache { enabled: true, previous, ..Default::default() }
+    }
+
+    /// Stores the expansions reused or stored by this session for the next one.
+    pub fn save(&self, sess: &Session) {
+        let Some(path) = Self::path(sess) else { return };
+        // The file may be a hard link to the one of the previous session, which must not be
+        // modified, so remove it instead of truncating it.
+        let removed = match fs::remove_file(&path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        };
+        let result = removed.and_then(|()| FileEncoder::new(&path)).and_then(|mut encoder| {
+            encoder.emit_raw_bytes(FILE_MAGIC);
+            sess.cfg_version.encode(&mut encoder);
+            self.current.encode(&mut encoder);
+            encoder.finish().map(|_| ()).map_err(|(_, err)| err)
+        });
+        if let Err(err) = result {
+            sess.dcx().emit_warn(errors::ExpansionCacheWrite { path, err });
+        }
+    }
+
+    /// Computes the key of an expansion of the macro identified by `macro_id`, if it can be
+    /// cached.
+    pub(crate) fn key(
+        &self,
+        macro_id: Option<Fingerprint>,
+        inputs: &[&TokenStream],
+        with_editions: bool,
+    ) -> Option<Fingerprint> {
+        if !self.enabled {
+            return None;
+        }
+        let mut hasher = StableHasher::new();
+        macro_id?.hash(&mut hasher);
+        for input in inputs {
+            if !hash_stream(input, with_editions, &mut hasher) {
+                return None;
+            }
+            "input".hash(&mut hasher);
+        }
+        Some(hasher.finish())
+    }
+
+    /// Returns the output and matched `macro_rules!` arm of the expansion with `key`, if it was
+    /// stored and is still valid, and emits the diagnostics the proc macro emitted.
+    pub(crate) fn reuse(
+        &mut self,
+        sess: &Session,
+        key: Fingerprint,
+        anchors: &mut Anchors,
+    ) -> Option<(TokenStream, Option<usize>)> {
+        if !self.current.contains_key(&key) {
+            let previous = self.previous.remove(&key)?;
+            self.current.insert(key, previous);
+        }
+        let expansion = &self.current[&key];
+        let reused = if is_valid(expansion, sess, anchors) {
+            decode_stream(&expansion.output, anchors).zip(
+                expansion
+                    .diagnostics
+                    .iter()
+                    .map(|diagnostic| decode_diagnostic(diagnostic, anchors))
+                    .collect::<Option<Vec<_>>>(),
+            )
+        } else {
+            None
+        };
+        let Some((output, diagnostics)) = reused else {
+            self.current.remove(&key);
+            return None;
+        };
+        for diagnostic in diagnostics {
+            diagnostic.emit(sess.dcx());
+        }
+
+        // Keep the dependencies the proc macro would have recorded.
+        let psess = &sess.psess;
+        psess.env_depinfo.borrow_mut().extend(expansion.env_vars.iter().copied());
+        psess.file_depinfo.borrow_mut().extend(expansion.files.iter().map(|&(path, _)| path));
+        Some((output, expansion.arm))
+    }
+
+    /// Stores the output of the expansion with `key`, if it only refers to `anchors`.
+    pub(crate) fn store(
+        &mut self,
+        sess: &Session,
+        key: Fingerprint,
+        anchors: &mut Anchors,
+        output: &TokenStream,
+        tracker: Option<ProcMacroTracker>,
+        arm: Option<usize>,
+    ) {
+        if self.current.contains_key(&key) {
+            return;
+        }
+        let refs = anchors.references();
+        let mut ambiguous = Vec::new();
+        let Some(output) = encode_stream(output, &refs, &mut ambiguous) else {
+            return;
+        };
+        let tracker = tracker.unwrap_or_default();
+        let Some(diagnostics) = tracker
+            .diagnostics
+            .iter()
+            .map(|diagnostic| encode_diagnostic(diagnostic, &refs, &mut ambiguous))
+            .collect()
+        else {
+            return;
+        };
+        let mut equal_anchors = Vec::new();
+        for span in ambiguous {
+            let candidates = &refs[&span];
+            equal_anchors.extend(candidates[1..].iter().map(|&other| (candidates[0], other)));
+        }
+        equal_anchors.sort_by_key(|&(a, b)| (a.anchor, a.marked, b.anchor, b.marked));
+        equal_anchors.dedup();
+
+        let positions = tracker.observed_positions.then(|| anchors.positions(sess));
+        let files = tracker.paths.iter().map(|&path| (path, hash_file(path))).collect();
+        self.current.insert(
+            key,
+            CachedExpansion {
+                output,
+                equal_anchors,
+                positions,
+                env_vars: tracker.env_vars,
+                files,
+                arm,
+                diagnostics,
+            },
+        );
+    }
+
+    /// Returns the tracker of the proc macro that is currently running, if it may be cached.
+    pub(crate) fn tracker(&mut self) -> Option<&mut ProcMacroTracker> {
+        self.trackers.last_mut()
+    }
+}
+
+/// Runs `expand` for a proc macro identified by `macro_id` and invoked on `inputs`, or reuses
+/// its output from the previous session.
+pub(crate) fn expand_proc_macro<E>(
+    ecx: &mut ExtCtxt<'_>,
+    macro_id: Option<Fingerprint>,
+    inputs: &[&TokenStream],
+    expand: impl FnOnce(&mut ExtCtxt<'_>) -> Result<TokenStream, E>,
+) -> Result<TokenStream, E> {
+    let Some(key) = ecx.expansion_cache.key(macro_id, inputs, false) else {
+        return expand(ecx);
+    };
+    let mut anchors = Anchors::for_proc_macro(ecx, inputs);
+    if let Some((output, _)) = ecx.expansion_cache.reuse(ecx.sess, key, &mut anchors) {
+        return Ok(output);
+    }
+
+    let mut snapshot = DiagnosticsSnapshot::new(ecx);
+    ecx.expansion_cache.trackers.push(ProcMacroTracker::default());
+    let result = expand(ecx);
+    let tracker = ecx.expansion_cache.trackers.pop().unwrap();
+    // The warnings emitted through `proc_macro::Diagnostic` are stored with the output.
+    snapshot.warn_count +=
+        tracker.diagnostics.iter().filter(|diag| diag.level == Level::Warning).count();
+    if let Ok(output) = &result
+        && tracker.deterministic
+        && !tracker.uncacheable
+        && DiagnosticsSnapshot::new(ecx) == snapshot
+    {
+        ecx.expansion_cache.store(ecx.sess, key, &mut anchors, output, Some(tracker), None);
+    }
+    result
+}
@@ -19,12 +19,14 @@
 
 mod build;
 mod errors;
+mod expansion_cache;
 // FIXME(Nilstrieb) Translate macro_rules diagnostics
 #[allow(rustc::untranslatable_diagnostic)]
 mod mbe;
 mod placeholders;
 mod proc_macro_server;
 
+pub use expansion_cache::ExpansionCache;
 pub use mbe::macro_rules::compile_declarative_macro;
 pub mod base;
 pub mod config;
@@ -1,6 +1,7 @@
 use crate::base::{DummyResult, SyntaxExtension, SyntaxExtensionKind};
 use crate::base::{ExpandResult, ExtCtxt, MacResult, MacroExpanderResult, TTMacroExpander};
 use crate::expand::{ensure_complete_parse, parse_ast_fragment, AstFragment, AstFragmentKind};
+use crate::expansion_cache::{self, Anchors, DiagnosticsSnapshot};
 use crate::mbe;
 use crate::mbe::diagnostics::{annotate_doc_comment, parse_failure_msg};
 use crate::mbe::macro_check;
@@ -17,7 +18,9 @@
 use rustc_ast::{NodeId, DUMMY_NODE_ID};
 use rustc_ast_pretty::pprust;
 use rustc_attr::{self as attr, TransparencyError};
+use rustc_data_structures::fingerprint::Fingerprint;
 use rustc_data_structures::fx::{FxHashMap, FxIndexMap};
+use rustc_data_structures::stable_hasher::StableHasher;
 use rustc_errors::{Applicability, ErrorGuaranteed};
 use rustc_feature::Features;
 use rustc_lint_defs::builtin::{
@@ -35,6 +38,7 @@
 
 use std::borrow::Cow;
 use std::collections::hash_map::Entry;
+use std::hash::Hash;
 use std::{mem, slice};
 
 use super::diagnostics;
@@ -105,6 +109,10 @@ struct MacroRulesMacroExpander {
     transparency: Transparency,
     lhses: Vec<Vec<MatcherLoc>>,
     rhses: Vec<mbe::TokenTree>,
+    /// Identifies the macro for `-Z cache-expansions`, if its expansions may be cached.
+    cache_key: Option<Fingerprint>,
+    /// The spans in `rhses`, which cached expansions refer to.
+    def_spans: Vec<Span>,
 }
 
 impl TTMacroExpander for MacroRulesMacroExpander {
@@ -114,17 +122,7 @@ fn expand<'cx>(
         sp: Span,
         input: TokenStream,
     ) -> MacroExpanderResult<'cx> {
-        ExpandResult::Ready(expand_macro(
-            cx,
-            sp,
-            self.span,
-            self.node_id,
-            self.name,
-            self.transparency,
-            input,
-            &self.lhses,
-            &self.rhses,
-        ))
+        ExpandResult::Ready(expand_macro(cx, sp, self, input))
     }
 }
 
@@ -195,15 +193,11 @@ fn description() -> &'static str {
 fn expand_macro<'cx>(
     cx: &'cx mut ExtCtxt<'_>,
     sp: Span,
-    def_span: Span,
-    node_id: NodeId,
-    name: Ident,
-    transparency: Transparency,
+    expander: &MacroRulesMacroExpander,
     arg: TokenStream,
-    lhses: &[Vec<MatcherLoc>],
-    rhses: &[mbe::TokenTree],
 ) -> Box<dyn MacResult + 'cx> {
-    let psess = &cx.sess.psess;
+    let MacroRulesMacroExpander { node_id, name, transparency, ref lhses, ref rhses, .. } =
+        *expander;
     // Macros defined in the current crate have a real node id,
     // whereas macros from an external crate have a dummy id.
     let is_local = node_id != DUMMY_NODE_ID;
@@ -213,7 +207,25 @@ fn expand_macro<'cx>(
         trace_macros_note(&mut cx.expansions, sp, msg);
     }
 
+    // Tracing needs the output, so only reuse it when nobody is looking.
+    let id = cx.current_expansion.id;
+    let mut cached = cx
+        .expansion_cache
+        .key(expander.cache_key, &[&arg], true)
+        .filter(|_| !cx.trace_macros())
+        .map(|key| (key, Anchors::for_macro_rules(&arg, &expander.def_spans, id, transparency)));
+    if let Some((key, anchors)) = &mut cached
+        && let Some((tts, Some(i))) = cx.expansion_cache.reuse(cx.sess, *key, anchors)
+    {
+        if is_local {
+            cx.resolver.record_macro_rule_usage(node_id, i);
+        }
+        return parser_any_macro(cx, sp, name, tts, rhses[i].span(), is_local);
+    }
+    let snapshot = cached.is_some().then(|| DiagnosticsSnapshot::new(cx));
+
     // Track nothing for the best performance.
+    let psess = &cx.sess.psess;
     let try_success_result = try_match_macro(psess, name, &arg, lhses, &mut NoopTracker);
 
     match try_success_result {
@@ -225,7 +237,6 @@ fn expand_macro<'cx>(
             let arm_span = rhses[i].span();
 
             // rhs has holes ( `$id` and `$(...)` that need filled)
-            let id = cx.current_expansion.id;
             let tts = match transcribe(psess, &named_matches, rhs, rhs_span, transparency, id) {
                 Ok(tts) => tts,
                 Err(err) => {
@@ -239,27 +250,17 @@ fn expand_macro<'cx>(
                 trace_macros_note(&mut cx.expansions, sp, msg);
             }
 
-            let p = Parser::new(psess, tts, None);
+            if let Some((key, mut anchors)) = cached
+                && snapshot == Some(DiagnosticsSnapshot::new(cx))
+            {
+                cx.expansion_cache.store(cx.sess, key, &mut anchors, &tts, None, Some(i));
+            }
 
             if is_local {
                 cx.resolver.record_macro_rule_usage(node_id, i);
             }
 
-            // Let the context choose how to interpret the result.
-            // Weird, but useful for X-macros.
-            Box::new(ParserAnyMacro {
-                parser: p,
-
-                // Pass along the original expansion site and the name of the macro
-                // so we can print a useful error message if the parse of the expanded
-                // macro leaves unparsed tokens.
-                site_span: sp,
-                macro_ident: name,
-                lint_node_id: cx.current_expansion.lint_node_id,
-                is_trailing_mac: cx.current_expansion.is_trailing_mac,
-                arm_span,
-                is_local,
-            })
+            parser_any_macro(cx, sp, name, tts, arm_span, is_local)
         }
         Err(CanRetry::No(guar)) => {
             debug!("Will not retry matching as an error was emitted already");
@@ -267,11 +268,36 @@ fn expand_macro<'cx>(
         }
         Err(CanRetry::Yes) => {
             // Retry and emit a better error.
-            diagnostics::failed_to_match_macro(cx, sp, def_span, name, arg, lhses)
+            diagnostics::failed_to_match_macro(cx, sp, expander.span, name, arg, lhses)
         }
     }
 }
 
+fn parser_any_macro<'cx>(
+    cx: &ExtCtxt<'cx>,
+    sp: Span,
+    name: Ident,
+    tts: TokenStream,
+    arm_span: Span,
+    is_local: bool,
+) -> Box<dyn MacResult + 'cx> {
+    // Let the context choose how to interpret the result.
+    // Weird, but useful for X-macros.
+    Box::new(ParserAnyMacro {
+        parser: Parser::new(&cx.sess.psess, tts, None),
+
+        // Pass along the original expansion site and the name of the macro
+        // so we can print a useful error message if the parse of the expanded
+        // macro leaves unparsed tokens.
+        site_span: sp,
+        macro_ident: name,
+        lint_node_id: cx.current_expansion.lint_node_id,
+        is_trailing_mac: cx.current_expansion.is_trailing_mac,
+        arm_span,
+        is_local,
+    })
+}
+
 pub(super) enum CanRetry {
     Yes,
     /// We are not allowed to retry macro expansion as a fatal error has been emitted already.
@@ -597,6 +623,17 @@ pub fn compile_declarative_macro(
         })
         .collect();
 
+    let (cache_key, def_spans) = if sess.opts.unstable_opts.cache_expansions {
+        let mut hasher = StableHasher::new();
+        let cacheable = expansion_cache::hash_stream(&macro_def.body.tokens, true, &mut hasher);
+        (macro_rules, transparency, edition).hash(&mut hasher);
+        let mut def_spans = Vec::new();
+        collect_rhs_spans(&rhses, &mut def_spans);
+        (cacheable.then(|| hasher.finish()), def_spans)
+    } else {
+        (None, Vec::new())
+    };
+
     let expander = Box::new(MacroRulesMacroExpander {
         name: def.ident,
         span: def.span,
@@ -604,10 +641,38 @@ pub fn compile_declarative_macro(
         transparency,
         lhses,
         rhses,
+        cache_key,
+        def_spans,
     });
     (mk_syn_ext(expander), rule_spans)
 }
 
+/// Collects the spans that the transcription of `tts` can produce, see `def_spans`.
+fn collect_rhs_spans(tts: &[mbe::TokenTree], spans: &mut Vec<Span>) {
+    for tt in tts {
+        match tt {
+            mbe::TokenTree::Token(token) => expansion_cache::collect_token_spans(token, spans),
+            mbe::TokenTree::Delimited(dspan, _, delimited) => {
+                spans.extend([dspan.open, dspan.close]);
+                collect_rhs_spans(&delimited.tts, spans);
+            }
+            mbe::TokenTree::Sequence(dspan, seq) => {
+                spans.extend([dspan.open, dspan.close, seq.kleene.span]);
+                if let Some(separator) = &seq.separator {
+                    expansion_cache::collect_token_spans(separator, spans);
+                }
+                collect_rhs_spans(&seq.tts, spans);
+            }
+            mbe::TokenTree::MetaVar(span, ident) | mbe::TokenTree::MetaVarDecl(span, ident, _) => {
+                spans.extend([*span, ident.span]);
+            }
+            mbe::TokenTree::MetaVarExpr(dspan, _) => {
+                spans.extend([dspan.open, dspan.close, dspan.entire()]);
+            }
+        }
+    }
+}
+
 fn check_lhs_nt_follows(
     sess: &Session,
     def: &ast::Item,
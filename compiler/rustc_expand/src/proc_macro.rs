@@ -1,15 +1,26 @@
 use crate::base::{self, *};
 use crate::errors;
+use crate::expansion_cache;
 use crate::proc_macro_server;
 
 use rustc_ast as ast;
 use rustc_ast::ptr::P;
 use rustc_ast::tokenstream::TokenStream;
+use rustc_data_structures::fingerprint::Fingerprint;
+use rustc_data_structures::stable_hasher::StableHasher;
+use rustc_data_structures::svh::Svh;
 use rustc_errors::ErrorGuaranteed;
 use rustc_parse::parser::{ForceCollect, Parser};
 use rustc_session::config::ProcMacroExecutionStrategy;
 use rustc_span::profiling::SpannedEventArgRecorder;
 use rustc_span::Span;
+use std::hash::Hash;
+use std::path::PathBuf;
+
+mod out_of_process;
+
+pub use out_of_process::{serve, SERVER_ENV};
+use out_of_process::{Failure, ProcessConnection};
 
 struct MessagePipe<T> {
     tx: std::sync::mpsc::SyncSender<T>,
@@ -35,12 +46,69 @@ fn recv(&mut self) -> Option<T> {
 fn exec_strategy(ecx: &ExtCtxt<'_>) -> impl pm::bridge::server::ExecutionStrategy {
     pm::bridge::server::MaybeCrossThread::<MessagePipe<_>>::new(
         ecx.sess.opts.unstable_opts.proc_macro_execution_strategy
-            == ProcMacroExecutionStrategy::CrossThread,
+            != ProcMacroExecutionStrategy::SameThread,
     )
 }
 
+/// Returns the strategy to run the proc macro at `location` in another process with, if that
+/// was requested. Proc macros that aren't loaded from a dylib always run in-process.
+fn out_of_process_strategy(
+    ecx: &ExtCtxt<'_>,
+    location: &Option<ProcMacroLocation>,
+) -> Option<pm::bridge::server::OutOfProcess<ProcessConnection>> {
+    let opts = &ecx.sess.opts.unstable_opts;
+    if opts.proc_macro_execution_strategy != ProcMacroExecutionStrategy::OutOfProcess {
+        return None;
+    }
+    let connection = ProcessConnection::new(location.clone()?, opts.proc_macro_timeout);
+    Some(pm::bridge::server::OutOfProcess::new(connection))
+}
+
+/// Reports the failure of the process that ran a proc macro, which would otherwise show up as
+/// the proc macro panicking.
+fn report_process_failure(
+    ecx: &ExtCtxt<'_>,
+    span: Span,
+    strategy: &pm::bridge::server::OutOfProcess<ProcessConnection>,
+) -> Option<ErrorGuaranteed> {
+    Some(match strategy.connection().failure()? {
+        Failure::Crashed(status) => ecx.dcx().emit_err(errors::ProcMacroCrashed {
+            span,
+            status: status.map_or_else(|| "an unknown status".to_owned(), |s| s.to_string()),
+        }),
+        Failure::TimedOut(timeout) => {
+            ecx.dcx().emit_err(errors::ProcMacroTimedOut { span, timeout })
+        }
+    })
+}
+
+/// Where a proc macro was loaded from, so that it can be loaded again in another process.
+#[derive(Clone, Debug)]
+pub struct ProcMacroLocation {
+    /// The proc macro crate's dylib.
+    pub dylib: PathBuf,
+    /// The symbol of the proc macro declarations in the dylib.
+    pub decls_symbol: String,
+    /// The index of the proc macro in its declarations.
+    pub index: usize,
+    /// The hash of the proc macro crate, which changes whenever the proc macro might.
+    pub crate_hash: Svh,
+}
+
+/// Identifies the proc macro at `location` for `-Z cache-expansions`. Proc macros that aren't
+/// loaded from a dylib are never cached.
+fn cache_id(kind: &str, location: &Option<ProcMacroLocation>) -> Option<Fingerprint> {
+    let location = location.as_ref()?;
+    let mut hasher = StableHasher::new();
+    kind.hash(&mut hasher);
+    location.crate_hash.as_u128().hash(&mut hasher);
+    location.index.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
 pub struct BangProcMacro {
     pub client: pm::bridge::client::Client<pm::TokenStream, pm::TokenStream>,
+    pub location: Option<ProcMacroLocation>,
 }
 
 impl base::BangProcMacro for BangProcMacro {
@@ -56,14 +124,28 @@ fn expand<'cx>(
             });
 
         let proc_macro_backtrace = ecx.ecfg.proc_macro_backtrace;
-        let strategy = exec_strategy(ecx);
-        let server = proc_macro_server::Rustc::new(ecx);
-        self.client.run(&strategy, server, input, proc_macro_backtrace).map_err(|e| {
-            ecx.dcx().emit_err(errors::ProcMacroPanicked {
-                span,
-                message: e
-                    .as_str()
-                    .map(|message| errors::ProcMacroPanickedHelp { message: message.into() }),
+        let macro_id = cache_id("bang", &self.location);
+        expansion_cache::expand_proc_macro(ecx, macro_id, &[&input], |ecx| {
+            let input = input.clone();
+            let result = if let Some(strategy) = out_of_process_strategy(ecx, &self.location) {
+                let server = proc_macro_server::Rustc::new(ecx);
+                let result = self.client.run(&strategy, server, input, proc_macro_backtrace);
+                if let Some(guar) = report_process_failure(ecx, span, &strategy) {
+                    return Err(guar);
+                }
+                result
+            } else {
+                let strategy = exec_strategy(ecx);
+                let server = proc_macro_server::Rustc::new(ecx);
+                self.client.run(&strategy, server, input, proc_macro_backtrace)
+            };
+            result.map_err(|e| {
+                ecx.dcx().emit_err(errors::ProcMacroPanicked {
+                    span,
+                    message: e
+                        .as_str()
+                        .map(|message| errors::ProcMacroPanickedHelp { message: message.into() }),
+                })
             })
         })
     }
@@ -71,6 +153,7 @@ fn expand<'cx>(
 
 pub struct AttrProcMacro {
     pub client: pm::bridge::client::Client<(pm::TokenStream, pm::TokenStream), pm::TokenStream>,
+    pub location: Option<ProcMacroLocation>,
 }
 
 impl base::AttrProcMacro for AttrProcMacro {
@@ -87,23 +170,38 @@ fn expand<'cx>(
             });
 
         let proc_macro_backtrace = ecx.ecfg.proc_macro_backtrace;
-        let strategy = exec_strategy(ecx);
-        let server = proc_macro_server::Rustc::new(ecx);
-        self.client.run(&strategy, server, annotation, annotated, proc_macro_backtrace).map_err(
-            |e| {
+        let macro_id = cache_id("attr", &self.location);
+        let inputs = [&annotation, &annotated];
+        expansion_cache::expand_proc_macro(ecx, macro_id, &inputs, |ecx| {
+            let (annotation, annotated) = (annotation.clone(), annotated.clone());
+            let result = if let Some(strategy) = out_of_process_strategy(ecx, &self.location) {
+                let server = proc_macro_server::Rustc::new(ecx);
+                let result =
+                    self.client.run(&strategy, server, annotation, annotated, proc_macro_backtrace);
+                if let Some(guar) = report_process_failure(ecx, span, &strategy) {
+                    return Err(guar);
+                }
+                result
+            } else {
+                let strategy = exec_strategy(ecx);
+                let server = proc_macro_server::Rustc::new(ecx);
+                self.client.run(&strategy, server, annotation, annotated, proc_macro_backtrace)
+            };
+            result.map_err(|e| {
                 ecx.dcx().emit_err(errors::CustomAttributePanicked {
                     span,
                     message: e.as_str().map(|message| errors::CustomAttributePanickedHelp {
                         message: message.into(),
                     }),
                 })
-            },
-        )
+            })
+        })
     }
 }
 
 pub struct DeriveProcMacro {
     pub client: pm::bridge::client::Client<pm::TokenStream, pm::TokenStream>,
+    pub location: Option<ProcMacroLocation>,
 }
 
 impl MultiItemModifier for DeriveProcMacro {
@@ -135,11 +233,22 @@ fn expand(
                     );
                 });
             let proc_macro_backtrace = ecx.ecfg.proc_macro_backtrace;
-            let strategy = exec_strategy(ecx);
-            let server = proc_macro_server::Rustc::new(ecx);
-            match self.client.run(&strategy, server, input, proc_macro_backtrace) {
-                Ok(stream) => stream,
-                Err(e) => {
+            let macro_id = cache_id("derive", &self.location);
+            let result = expansion_cache::expand_proc_macro(ecx, macro_id, &[&input], |ecx| {
+                let input = input.clone();
+                let result = if let Some(strategy) = out_of_process_strategy(ecx, &self.location) {
+                    let server = proc_macro_server::Rustc::new(ecx);
+                    let result = self.client.run(&strategy, server, input, proc_macro_backtrace);
+                    if let Some(guar) = report_process_failure(ecx, span, &strategy) {
+                        return Err(guar);
+                    }
+                    result
+                } else {
+                    let strategy = exec_strategy(ecx);
+                    let server = proc_macro_server::Rustc::new(ecx);
+                    self.client.run(&strategy, server, input, proc_macro_backtrace)
+                };
+                result.map_err(|e| {
                     ecx.dcx().emit_err({
                         errors::ProcMacroDerivePanicked {
                             span,
@@ -147,9 +256,12 @@ fn expand(
                                 errors::ProcMacroDerivePanickedHelp { message: message.into() }
                             }),
                         }
-                    });
-                    return ExpandResult::Ready(vec![]);
-                }
+                    })
+                })
+            });
+            match result {
+                Ok(stream) => stream,
+                Err(_) => return ExpandResult::Ready(vec![]),
             }
         };
 
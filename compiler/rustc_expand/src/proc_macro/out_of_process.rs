@@ -0,0 +1,341 @@
+//! Running proc macros in a separate process, see
+//! `-Z proc-macro-execution-strategy=out-of-process`.
+//!
+//! The server process is another instance of the current executable, started with
+//! [`SERVER_ENV`] set. It connects back to the compiler over a loopback TCP socket, where the
+//! compiler sends it the dylib, declarations symbol and index of the proc macro to run together
+//! with the encoded input, and then answers the requests the proc macro makes until it is done.
+//! Standard output can't be used for this, as proc macros are free to print to it.
+//!
+//! Servers that finished a proc macro are kept around and reused for later expansions. A server
+//! that crashed or was killed because of `-Z proc-macro-timeout` is never reused.
+
+use super::ProcMacroLocation;
+
+use pm::bridge::client::ProcMacro;
+use pm::bridge::server::{ClientConnection, ClientMessage};
+use rustc_data_structures::fx::FxHashMap;
+use std::cell::{Cell, RefCell};
+use std::ffi::OsStr;
+use std::hash::{BuildHasher, RandomState};
+use std::io::{self, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use std::{env, thread};
+
+/// The environment variable telling a process to act as a proc macro server. Its value is the
+/// address of the compiler's socket and the token to authenticate with, separated by a space.
+pub const SERVER_ENV: &str = "RUSTC_PROC_MACRO_SERVER";
+
+/// How long to wait for a newly spawned server to connect back to the compiler.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+// Message tags. Every message is a tag, the length of the payload as a little-endian `u64`,
+// and the payload.
+const RUN: u8 = 0;
+const REQUEST: u8 = 1;
+const REPLY: u8 = 2;
+const DONE: u8 = 3;
+
+fn write_message(stream: &mut TcpStream, tag: u8, payload: &[u8]) -> io::Result<()> {
+    let mut header = [0; 9];
+    header[0] = tag;
+    header[1..].copy_from_slice(&(payload.len() as u64).to_le_bytes());
+    stream.write_all(&header)?;
+    stream.write_all(payload)
+}
+
+/// Reads the next message, or returns `None` if the other side closed the connection.
+fn read_message(stream: &mut TcpStream) -> io::Result<Option<(u8, Vec<u8>)>> {
+    let mut header = [0; 9];
+    match stream.read_exact(&mut header) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let len = u64::from_le_bytes(header[1..].try_into().unwrap());
+    let mut payload = vec![0; len as usize];
+    stream.read_exact(&mut payload)?;
+    Ok(Some((header[0], payload)))
+}
+
+fn put_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    buf.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    buf.extend_from_slice(bytes);
+}
+
+fn take_bytes<'a>(buf: &mut &'a [u8]) -> &'a [u8] {
+    let (len, rest) = buf.split_at(8);
+    let (bytes, rest) = rest.split_at(u64::from_le_bytes(len.try_into().unwrap()) as usize);
+    *buf = rest;
+    bytes
+}
+
+/// A running server process and the connection to it.
+struct Server {
+    child: Child,
+    stream: TcpStream,
+}
+
+/// Servers that are waiting for their next proc macro.
+static IDLE_SERVERS: Mutex<Vec<Server>> = Mutex::new(Vec::new());
+
+impl Server {
+    fn spawn() -> io::Result<Server> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let token = RandomState::new().hash_one(listener.local_addr()?);
+        let mut child = Command::new(env::current_exe()?)
+            .env(SERVER_ENV, format!("{} {token}", listener.local_addr()?))
+            .stdin(Stdio::null())
+            .spawn()?;
+
+        // Don't wait for a connection forever if the server fails to start or hangs.
+        listener.set_nonblocking(true)?;
+        let deadline = Instant::now() + CONNECT_TIMEOUT;
+        let mut stream = loop {
+            match listener.accept() {
+                Ok((stream, _)) => break stream,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    if let Some(status) = child.try_wait()? {
+                        return Err(io::Error::other(format!(
+                            "proc macro server exited with {status} before connecting"
+                        )));
+                    }
+                    if Instant::now() >= deadline {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "proc macro server did not connect in time",
+                        ));
+                    }
+                    thread::sleep(Duration::from_millis(1));
+                }
+                Err(e) => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(e);
+                }
+            }
+        };
+        stream.set_nonblocking(false)?;
+        stream.set_nodelay(true)?;
+
+        let mut client_token = [0; 8];
+        stream.read_exact(&mut client_token)?;
+        if u64::from_le_bytes(client_token) != token {
+            let _ = child.kill();
+            return Err(io::Error::other("proc macro server sent the wrong token"));
+        }
+        Ok(Server { child, stream })
+    }
+
+    /// Kills the server and returns its exit status.
+    fn kill(mut self) -> Option<ExitStatus> {
+        let _ = self.child.kill();
+        self.child.wait().ok()
+    }
+}
+
+/// Why running a proc macro in a server process failed.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Failure {
+    /// The server exited without finishing the proc macro.
+    Crashed(Option<ExitStatus>),
+    /// The server was killed because the proc macro ran for longer than this many seconds.
+    TimedOut(u64),
+}
+
+/// A connection running one proc macro expansion in a server process.
+pub(crate) struct ProcessConnection {
+    location: ProcMacroLocation,
+    timeout: Option<u64>,
+    deadline: Cell<Option<Instant>>,
+    server: RefCell<Option<Server>>,
+    failure: Cell<Option<Failure>>,
+}
+
+impl ProcessConnection {
+    pub(crate) fn new(location: ProcMacroLocation, timeout: Option<u64>) -> Self {
+        ProcessConnection {
+            location,
+            timeout,
+            deadline: Cell::new(None),
+            server: RefCell::new(None),
+            failure: Cell::new(None),
+        }
+    }
+
+    /// Returns how the server failed, if it did.
+    pub(crate) fn failure(&self) -> Option<Failure> {
+        self.failure.get()
+    }
+
+    fn fail(&self, failure: Failure) -> ClientMessage {
+        self.failure.set(Some(failure));
+        ClientMessage::Failed(match failure {
+            Failure::Crashed(_) => "proc macro server crashed".to_owned(),
+            Failure::TimedOut(secs) => format!("proc macro timed out after {secs} seconds"),
+        })
+    }
+
+    fn send_run(
+        &self,
+        server: &mut Server,
+        input: &[u8],
+        force_show_panics: bool,
+    ) -> io::Result<()> {
+        let mut payload = Vec::with_capacity(input.len() + 64);
+        put_bytes(&mut payload, self.location.dylib.as_os_str().as_encoded_bytes());
+        put_bytes(&mut payload, self.location.decls_symbol.as_bytes());
+        payload.extend_from_slice(&(self.location.index as u64).to_le_bytes());
+        payload.push(force_show_panics as u8);
+        payload.extend_from_slice(input);
+        write_message(&mut server.stream, RUN, &payload)
+    }
+}
+
+impl ClientConnection for ProcessConnection {
+    fn start(&self, input: &[u8], force_show_panics: bool) {
+        self.deadline.set(self.timeout.map(|secs| Instant::now() + Duration::from_secs(secs)));
+
+        // A server may have died while it was idle, so try the next one if sending fails.
+        loop {
+            let idle = IDLE_SERVERS.lock().unwrap().pop();
+            let from_pool = idle.is_some();
+            let mut server = match idle {
+                Some(server) => server,
+                None => match Server::spawn() {
+                    Ok(server) => server,
+                    Err(_) => {
+                        self.failure.set(Some(Failure::Crashed(None)));
+                        return;
+                    }
+                },
+            };
+            match self.send_run(&mut server, input, force_show_panics) {
+                Ok(()) => {
+                    *self.server.borrow_mut() = Some(server);
+                    return;
+                }
+                Err(_) => {
+                    let status = server.kill();
+                    if !from_pool {
+                        self.failure.set(Some(Failure::Crashed(status)));
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    fn reply(&self, reply: &[u8]) {
+        let mut server = self.server.borrow_mut();
+        if let Some(server) = &mut *server {
+            // A failure shows up when reading the next message.
+            let _ = write_message(&mut server.stream, REPLY, reply);
+        }
+    }
+
+    fn recv(&self) -> ClientMessage {
+        if let Some(failure) = self.failure.get() {
+            return self.fail(failure);
+        }
+        let mut server = self.server.borrow_mut().take().unwrap();
+        let timeout = self.deadline.get().map(|deadline| {
+            deadline.saturating_duration_since(Instant::now()).max(Duration::from_millis(1))
+        });
+        let message =
+            server.stream.set_read_timeout(timeout).and_then(|()| read_message(&mut server.stream));
+        match message {
+            Ok(Some((REQUEST, request))) => {
+                *self.server.borrow_mut() = Some(server);
+                ClientMessage::Request(request)
+            }
+            Ok(Some((DONE, output))) => {
+                IDLE_SERVERS.lock().unwrap().push(server);
+                ClientMessage::Done(output)
+            }
+            Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => {
+                server.kill();
+                self.fail(Failure::TimedOut(self.timeout.unwrap()))
+            }
+            _ => {
+                let status = server.kill();
+                self.fail(Failure::Crashed(status))
+            }
+        }
+    }
+}
+
+impl Drop for ProcessConnection {
+    fn drop(&mut self) {
+        // The expansion was abandoned in the middle, so the server is in an unknown state.
+        if let Some(server) = self.server.get_mut().take() {
+            server.kill();
+        }
+    }
+}
+
+/// Runs a proc macro server for the compiler that spawned this process, see [`SERVER_ENV`].
+///
+/// `load` loads the proc macro declarations from the given dylib and symbol.
+pub fn serve(
+    mut load: impl FnMut(&Path, &str) -> Result<&'static [ProcMacro], String>,
+) -> io::Result<()> {
+    let var = env::var(SERVER_ENV).map_err(io::Error::other)?;
+    // Processes started by the proc macros, like another compiler, must not become servers too.
+    env::remove_var(SERVER_ENV);
+    let (addr, token) = var
+        .split_once(' ')
+        .and_then(|(addr, token)| Some((addr, token.parse::<u64>().ok()?)))
+        .ok_or_else(|| io::Error::other(format!("malformed {SERVER_ENV}")))?;
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_nodelay(true)?;
+    stream.write_all(&token.to_le_bytes())?;
+
+    let mut loaded = FxHashMap::<(PathBuf, String), &'static [ProcMacro]>::default();
+    while let Some((tag, payload)) = read_message(&mut stream)? {
+        if tag != RUN {
+            return Err(io::Error::other("unexpected message"));
+        }
+        let mut payload = &payload[..];
+        // SAFETY: the bytes were produced by `as_encoded_bytes` in the same executable.
+        let dylib =
+            Path::new(unsafe { OsStr::from_encoded_bytes_unchecked(take_bytes(&mut payload)) });
+        let decls_symbol = String::from_utf8_lossy(take_bytes(&mut payload)).into_owned();
+        let (index, payload) = payload.split_at(8);
+        let index = u64::from_le_bytes(index.try_into().unwrap()) as usize;
+        let (&force_show_panics, input) = payload.split_first().unwrap();
+
+        let key = (dylib.to_owned(), decls_symbol);
+        let decls = match loaded.get(&key) {
+            Some(&decls) => decls,
+            None => {
+                let decls = load(&key.0, &key.1).map_err(io::Error::other)?;
+                loaded.insert(key, decls);
+                decls
+            }
+        };
+
+        let output = decls[index].run_with_dispatch(
+            input.to_vec(),
+            |request| {
+                let reply = write_message(&mut stream, REQUEST, &request)
+                    .and_then(|()| read_message(&mut stream));
+                match reply {
+                    Ok(Some((REPLY, reply))) => reply,
+                    // The compiler is gone, and there is nobody left to report to. This can't
+                    // unwind, since it is called by the proc macro through the bridge.
+                    _ => std::process::exit(1),
+                }
+            },
+            force_show_panics != 0,
+        );
+        write_message(&mut stream, DONE, &output)?;
+    }
+    Ok(())
+}
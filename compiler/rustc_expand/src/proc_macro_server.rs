@@ -1,4 +1,5 @@
 use crate::base::ExtCtxt;
+use crate::expansion_cache::{ProcMacroDiagnostic, ProcMacroTracker};
 use ast::token::IdentIsRaw;
 use pm::bridge::{
     server, DelimSpan, Diagnostic, ExpnGlobals, Group, Ident, LitKind, Literal, Punct, TokenTree,
@@ -11,7 +12,7 @@
 use rustc_ast_pretty::pprust;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sync::Lrc;
-use rustc_errors::{Diag, ErrorGuaranteed, MultiSpan, PResult};
+use rustc_errors::{ErrorGuaranteed, PResult};
 use rustc_parse::lexer::nfc_normalize;
 use rustc_parse::parser::Parser;
 use rustc_parse::{new_parser_from_source_str, source_str_to_stream, unwrap_or_emit_fatal};
@@ -412,6 +413,17 @@ fn to_internal(self) -> rustc_errors::Level {
     }
 }
 
+impl ToInternal<ProcMacroDiagnostic> for Diagnostic<Span> {
+    fn to_internal(self) -> ProcMacroDiagnostic {
+        ProcMacroDiagnostic {
+            level: self.level.to_internal(),
+            message: self.message,
+            spans: self.spans,
+            children: self.children.into_iter().map(ToInternal::to_internal).collect(),
+        }
+    }
+}
+
 pub struct FreeFunctions;
 
 pub(crate) struct Rustc<'a, 'b> {
@@ -439,6 +451,13 @@ pub fn new(ecx: &'a mut ExtCtxt<'b>) -> Self {
     fn psess(&self) -> &ParseSess {
         self.ecx.psess()
     }
+
+    /// Records what the proc macro did, to decide whether its output can be cached.
+    fn track(&mut self, f: impl FnOnce(&mut ProcMacroTracker)) {
+        if let Some(tracker) = self.ecx.expansion_cache.tracker() {
+            f(tracker);
+        }
+    }
 }
 
 impl server::Types for Rustc<'_, '_> {
@@ -455,14 +474,19 @@ fn injected_env_var(&mut self, var: &str) -> Option<String> {
     }
 
     fn track_env_var(&mut self, var: &str, value: Option<&str>) {
-        self.psess()
-            .env_depinfo
-            .borrow_mut()
-            .insert((Symbol::intern(var), value.map(Symbol::intern)));
+        let var = (Symbol::intern(var), value.map(Symbol::intern));
+        self.psess().env_depinfo.borrow_mut().insert(var);
+        self.track(|tracker| tracker.env_vars.push(var));
     }
 
     fn track_path(&mut self, path: &str) {
-        self.psess().file_depinfo.borrow_mut().insert(Symbol::intern(path));
+        let path = Symbol::intern(path);
+        self.psess().file_depinfo.borrow_mut().insert(path);
+        self.track(|tracker| tracker.paths.push(path));
+    }
+
+    fn declare_deterministic(&mut self) {
+        self.track(|tracker| tracker.deterministic = true);
     }
 
     fn literal_from_str(&mut self, s: &str) -> Result<Literal<Self::Span, Self::Symbol>, ()> {
@@ -520,17 +544,10 @@ fn literal_from_str(&mut self, s: &str) -> Result<Literal<Self::Span, Self::Symb
     }
 
     fn emit_diagnostic(&mut self, diagnostic: Diagnostic<Self::Span>) {
-        let message = rustc_errors::DiagMessage::from(diagnostic.message);
-        let mut diag: Diag<'_, ()> =
-            Diag::new(self.psess().dcx(), diagnostic.level.to_internal(), message);
-        diag.span(MultiSpan::from_spans(diagnostic.spans));
-        for child in diagnostic.children {
-            // This message comes from another diagnostic, and we are just reconstructing the
-            // diagnostic, so there's no need for translation.
-            #[allow(rustc::untranslatable_diagnostic)]
-            diag.sub(child.level.to_internal(), child.message, MultiSpan::from_spans(child.spans));
-        }
-        diag.emit();
+        let diagnostic = diagnostic.to_internal();
+        // Errors make the expansion uncacheable anyway, see `DiagnosticsSnapshot`.
+        self.track(|tracker| tracker.diagnostics.push(diagnostic.clone()));
+        diagnostic.emit(self.psess().dcx());
     }
 }
 
@@ -553,6 +570,7 @@ fn to_string(&mut self, stream: &Self::TokenStream) -> String {
     }
 
     fn expand_expr(&mut self, stream: &Self::TokenStream) -> Result<Self::TokenStream, ()> {
+        self.track(|tracker| tracker.uncacheable = true);
         // Parse the expression from our tokenstream.
         let expr: PResult<'_, _> = try {
             let mut p = Parser::new(self.psess(), stream.clone(), Some("proc_macro expand expr"));
@@ -679,6 +697,7 @@ fn is_real(&mut self, file: &Self::SourceFile) -> bool {
 
 impl server::Span for Rustc<'_, '_> {
     fn debug(&mut self, span: Self::Span) -> String {
+        self.track(|tracker| tracker.uncacheable = true);
         if self.ecx.ecfg.span_debug {
             format!("{span:?}")
         } else {
@@ -687,18 +706,22 @@ fn debug(&mut self, span: Self::Span) -> String {
     }
 
     fn source_file(&mut self, span: Self::Span) -> Self::SourceFile {
+        self.track(|tracker| tracker.observed_positions = true);
         self.psess().source_map().lookup_char_pos(span.lo()).file
     }
 
     fn parent(&mut self, span: Self::Span) -> Option<Self::Span> {
+        self.track(|tracker| tracker.observed_positions = true);
         span.parent_callsite()
     }
 
     fn source(&mut self, span: Self::Span) -> Self::Span {
+        self.track(|tracker| tracker.observed_positions = true);
         span.source_callsite()
     }
 
     fn byte_range(&mut self, span: Self::Span) -> Range<usize> {
+        self.track(|tracker| tracker.observed_positions = true);
         let source_map = self.psess().source_map();
 
         let relative_start_pos = source_map.lookup_byte_offset(span.lo()).pos;
@@ -715,16 +738,19 @@ fn end(&mut self, span: Self::Span) -> Self::Span {
     }
 
     fn line(&mut self, span: Self::Span) -> usize {
+        self.track(|tracker| tracker.observed_positions = true);
         let loc = self.psess().source_map().lookup_char_pos(span.lo());
         loc.line
     }
 
     fn column(&mut self, span: Self::Span) -> usize {
+        self.track(|tracker| tracker.observed_positions = true);
         let loc = self.psess().source_map().lookup_char_pos(span.lo());
         loc.col.to_usize() + 1
     }
 
     fn join(&mut self, first: Self::Span, second: Self::Span) -> Option<Self::Span> {
+        self.track(|tracker| tracker.observed_positions = true);
         let self_loc = self.psess().source_map().lookup_char_pos(first.lo());
         let other_loc = self.psess().source_map().lookup_char_pos(second.lo());
 
@@ -741,6 +767,7 @@ fn subspan(
         start: Bound<usize>,
         end: Bound<usize>,
     ) -> Option<Self::Span> {
+        self.track(|tracker| tracker.observed_positions = true);
         let length = span.hi().to_usize() - span.lo().to_usize();
 
         let start = match start {
@@ -776,6 +803,7 @@ fn resolved_at(&mut self, span: Self::Span, at: Self::Span) -> Self::Span {
     }
 
     fn source_text(&mut self, span: Self::Span) -> Option<String> {
+        self.track(|tracker| tracker.observed_positions = true);
         self.psess().source_map().span_to_snippet(span).ok()
     }
 
@@ -804,6 +832,7 @@ fn source_text(&mut self, span: Self::Span) -> Option<String> {
     /// since we've loaded `my_proc_macro` from disk in order to execute it).
     /// In this way, we have obtained a span pointing into `my_proc_macro`
     fn save_span(&mut self, span: Self::Span) -> usize {
+        self.track(|tracker| tracker.uncacheable = true);
         self.psess().save_proc_macro_span(span)
     }
 
@@ -438,6 +438,12 @@ pub fn internal(&self, feature: Symbol) -> bool {
     (incomplete, deref_patterns, "1.79.0", Some(87121)),
     /// Allows deriving `SmartPointer` traits
     (unstable, derive_smart_pointer, "1.79.0", Some(123430)),
+    /// Allows `#[diagnostic::on_missing_method]` to customize method resolution errors.
+    (unstable, diagnostic_on_missing_method, "CURRENT_RUSTC_VERSION", None),
+    /// Allows `#[diagnostic::on_move]` to customize use-after-move errors.
+    (unstable, diagnostic_on_move, "CURRENT_RUSTC_VERSION", None),
+    /// Allows `#[diagnostic::on_type_mismatch]` to customize type mismatch errors.
+    (unstable, diagnostic_on_type_mismatch, "CURRENT_RUSTC_VERSION", None),
     /// Controls errors in trait implementations.
     (unstable, do_not_recommend, "1.67.0", Some(51992)),
     /// Tells rustdoc to automatically generate `#[doc(cfg(...))]`.
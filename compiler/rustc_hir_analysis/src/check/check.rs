@@ -25,6 +25,7 @@
 };
 use rustc_session::lint::builtin::{UNINHABITED_STATIC, UNSUPPORTED_CALLING_CONVENTIONS};
 use rustc_target::abi::FieldIdx;
+use rustc_trait_selection::error_reporting::on_type::{on_type_attr_enabled, OnTypeDirective};
 use rustc_trait_selection::error_reporting::traits::on_unimplemented::OnUnimplementedDirective;
 use rustc_trait_selection::error_reporting::traits::TypeErrCtxtExt as _;
 use rustc_trait_selection::traits;
@@ -678,6 +679,7 @@ pub(crate) fn check_item_type(tcx: TyCtxt<'_>, def_id: LocalDefId) {
         }
         DefKind::Enum => {
             check_enum(tcx, def_id);
+            check_on_type_attrs(tcx, def_id);
         }
         DefKind::Fn => {
             if let Some(i) = tcx.intrinsic(def_id) {
@@ -728,9 +730,11 @@ pub(crate) fn check_item_type(tcx: TyCtxt<'_>, def_id: LocalDefId) {
         }
         DefKind::Struct => {
             check_struct(tcx, def_id);
+            check_on_type_attrs(tcx, def_id);
         }
         DefKind::Union => {
             check_union(tcx, def_id);
+            check_on_type_attrs(tcx, def_id);
         }
         DefKind::OpaqueTy => {
             check_opaque_precise_captures(tcx, def_id);
@@ -834,6 +838,16 @@ pub(super) fn check_on_unimplemented(tcx: TyCtxt<'_>, def_id: LocalDefId) {
     let _ = OnUnimplementedDirective::of_item(tcx, def_id.to_def_id());
 }
 
+/// Lints about malformed `#[diagnostic::on_move]`, `#[diagnostic::on_type_mismatch]`
+/// and `#[diagnostic::on_missing_method]` attributes whose feature is enabled.
+fn check_on_type_attrs(tcx: TyCtxt<'_>, def_id: LocalDefId) {
+    for attr_name in [sym::on_move, sym::on_type_mismatch, sym::on_missing_method] {
+        if on_type_attr_enabled(tcx, attr_name) {
+            let _ = OnTypeDirective::of_item(tcx, def_id.to_def_id(), attr_name);
+        }
+    }
+}
+
 pub(super) fn check_specialization_validity<'tcx>(
     tcx: TyCtxt<'tcx>,
     trait_def: &ty::TraitDef,
@@ -13,6 +13,7 @@
 use rustc_middle::ty::{self, AssocItem, Ty, TypeFoldable, TypeVisitableExt};
 use rustc_span::symbol::sym;
 use rustc_span::{Span, DUMMY_SP};
+use rustc_trait_selection::error_reporting::on_type::on_type_note;
 use rustc_trait_selection::infer::InferCtxtExt;
 use rustc_trait_selection::traits::ObligationCause;
 
@@ -84,6 +85,7 @@ pub fn emit_coerce_suggestions(
             return;
         }
 
+        self.note_on_type_mismatch(err, expr, expr_ty, expected);
         self.annotate_expected_due_to_let_ty(err, expr, error);
         self.annotate_loop_expected_due_to_inference(err, expr, error);
 
@@ -105,6 +107,36 @@ pub fn emit_coerce_suggestions(
         self.note_wrong_return_ty_due_to_generic_arg(err, expr, expr_ty);
     }
 
+    /// Applies the `#[diagnostic::on_type_mismatch]` attribute of the expected
+    /// type, or of the found type if the expected type has none.
+    fn note_on_type_mismatch(
+        &self,
+        err: &mut Diag<'_>,
+        expr: &hir::Expr<'tcx>,
+        expr_ty: Ty<'tcx>,
+        expected: Ty<'tcx>,
+    ) {
+        let mut long_ty_file = None;
+        let Some(note) = [expected, expr_ty].into_iter().find_map(|ty| {
+            let ty = self.resolve_vars_if_possible(ty).peel_refs();
+            on_type_note(self.tcx, ty, sym::on_type_mismatch, &mut long_ty_file)
+        }) else {
+            return;
+        };
+        if let Some(message) = note.message {
+            err.primary_message(message);
+        }
+        if let Some(label) = note.label {
+            err.span_label(expr.span, label);
+        }
+        for note in note.notes {
+            err.note(note);
+        }
+        if let Some(path) = long_ty_file {
+            err.note(format!("the full type name has been written to '{}'", path.display()));
+        }
+    }
+
     /// Really hacky heuristic to remap an `assert_eq!` error to the user
     /// expressions provided to the macro.
     fn adjust_expr_for_assert_eq_macro(
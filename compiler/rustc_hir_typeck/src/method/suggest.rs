@@ -35,6 +35,7 @@
 use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::{edit_distance, ErrorGuaranteed, ExpnKind, FileName, MacroKind, Span};
 use rustc_span::{Symbol, DUMMY_SP};
+use rustc_trait_selection::error_reporting::on_type::on_type_note;
 use rustc_trait_selection::error_reporting::traits::on_unimplemented::OnUnimplementedNote;
 use rustc_trait_selection::error_reporting::traits::on_unimplemented::TypeErrCtxtExt as _;
 use rustc_trait_selection::infer::InferCtxtExt;
@@ -738,6 +739,20 @@ fn report_no_match_method_error(
             ty_str = short_ty_str;
         }
 
+        if let Some(note) =
+            on_type_note(tcx, rcvr_ty.peel_refs(), sym::on_missing_method, &mut ty_file)
+        {
+            if let Some(message) = note.message {
+                err.primary_message(message);
+            }
+            if let Some(label) = note.label {
+                err.span_label(span, label);
+            }
+            for note in note.notes {
+                err.note(note);
+            }
+        }
+
         if let Some(file) = ty_file {
             err.note(format!("the full type name has been written to '{}'", file.display(),));
             err.note("consider using `--verbose` to print the full type name to the console");
@@ -50,6 +50,12 @@
 //! any more and will delete those. It will also delete any finalized session
 //! directories for a given crate except for the most recent one.
 //!
+//! With `-Z incremental-cache-limit` or `-Z incremental-cache-max-age`, the
+//! compiler additionally evicts finalized session directories of *all* crates
+//! in the incremental compilation directory, least recently used first. Since
+//! every session replaces the finalized directory it started from, the
+//! timestamp of a finalized directory is when its crate was last compiled.
+//!
 //! ## Synchronization
 //!
 //! There is some synchronization needed in order for the compiler to be able to
@@ -376,6 +382,7 @@ pub fn finalize_session_directory(sess: &Session, svh: Option<Svh>) {
     }
 
     let _ = garbage_collect_session_directories(sess);
+    let _ = evict_session_directories(sess);
 }
 
 pub(crate) fn delete_all_session_dir_contents(sess: &Session) -> io::Result<()> {
@@ -850,6 +857,132 @@ pub(crate) fn garbage_collect_session_directories(sess: &Session) -> io::Result<
     Ok(())
 }
 
+/// Returns the time before which session directories are older than `max_age_days` days, or
+/// `None` if that is so far back that nothing is ever old enough to be evicted.
+fn expiry_time(now: SystemTime, max_age_days: u64) -> Option<SystemTime> {
+    let secs = max_age_days.checked_mul(24 * 60 * 60)?;
+    now.checked_sub(Duration::from_secs(secs))
+}
+
+/// Evicts finalized session directories of all crates, see `-Z incremental-cache-limit` and
+/// `-Z incremental-cache-max-age`.
+fn evict_session_directories(sess: &Session) -> io::Result<()> {
+    let opts = &sess.opts.unstable_opts;
+    let limit = opts.incremental_cache_limit;
+    let expiry =
+        opts.incremental_cache_max_age.and_then(|days| expiry_time(SystemTime::now(), days));
+    if limit.is_none() && expiry.is_none() {
+        return Ok(());
+    }
+
+    let current_session_dir = sess.incr_comp_session_dir().clone();
+    let incr_dir = current_session_dir.parent().unwrap().parent().unwrap();
+    debug!("evict_session_directories() - incremental directory: {}", incr_dir.display());
+
+    let mut total_size = 0;
+    let mut candidates = Vec::new();
+    for crate_dir in incr_dir.read_dir()?.filter_map(|e| e.ok().map(|e| e.path())) {
+        let Ok(crate_dir_iterator) = crate_dir.read_dir() else {
+            // Not a crate directory, leave it alone
+            continue;
+        };
+        for session_dir in crate_dir_iterator.filter_map(|e| e.ok().map(|e| e.path())) {
+            let Some(directory_name) = session_dir.file_name().unwrap().to_str() else {
+                continue;
+            };
+            if !is_session_directory(directory_name) {
+                continue;
+            }
+            let size = directory_size(&session_dir);
+            total_size += size;
+            // Directories that are still being written to are left to the garbage collection
+            // of their own crate.
+            if session_dir == current_session_dir || !is_finalized(directory_name) {
+                continue;
+            }
+            if let Ok(timestamp) = extract_timestamp_from_session_dir(directory_name) {
+                candidates.push((timestamp, session_dir, size));
+            }
+        }
+    }
+
+    let mut evicted = 0;
+    let remaining_size = evict_least_recently_used(candidates, total_size, limit, expiry, |path| {
+        // Get an exclusive lock, so that nobody is copying from the directory.
+        let Ok(lock) = flock::Lock::new(
+            &lock_file_path(path),
+            false, // don't wait
+            false, // don't create the lock-file
+            true,
+        ) else {
+            debug!("evict_session_directories() - not evicting, still in use");
+            return false;
+        };
+        debug!("evict_session_directories() - deleting `{}`", path.display());
+        let deleted = match safe_remove_dir_all(path) {
+            Ok(()) => {
+                delete_session_dir_lock_file(sess, &lock_file_path(path));
+                evicted += 1;
+                true
+            }
+            Err(err) => {
+                sess.dcx().emit_warn(errors::FinalizedGcFailed { path, err });
+                false
+            }
+        };
+        drop(lock);
+        deleted
+    });
+
+    if sess.opts.unstable_opts.incremental_info {
+        eprintln!(
+            "[incremental] evicted {evicted} session directories, \
+             {remaining_size} bytes remaining"
+        );
+    }
+    Ok(())
+}
+
+/// Calls `evict` on the `(timestamp, path, size)` candidates, oldest first, while they are
+/// older than `expiry` or the total size is above `limit`. `evict` returns whether it deleted
+/// the directory. Returns the remaining total size.
+fn evict_least_recently_used(
+    mut candidates: Vec<(SystemTime, PathBuf, u64)>,
+    mut total_size: u64,
+    limit: Option<u64>,
+    expiry: Option<SystemTime>,
+    mut evict: impl FnMut(&Path) -> bool,
+) -> u64 {
+    candidates.sort();
+    for (timestamp, path, size) in candidates {
+        let expired = expiry.is_some_and(|expiry| timestamp < expiry);
+        let over_limit = limit.is_some_and(|limit| total_size > limit);
+        if !expired && !over_limit {
+            // The remaining candidates are newer, so they haven't expired either.
+            break;
+        }
+        if evict(&path) {
+            total_size -= size;
+        }
+    }
+    total_size
+}
+
+/// Returns the total size of the files in `path`, ignoring errors.
+fn directory_size(path: &Path) -> u64 {
+    let Ok(dir_iterator) = path.read_dir() else {
+        return 0;
+    };
+    dir_iterator
+        .filter_map(|entry| entry.ok())
+        .map(|entry| match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => directory_size(&entry.path()),
+            Ok(metadata) => metadata.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
 fn delete_old(sess: &Session, path: &Path) {
     debug!("garbage_collect_session_directories() - deleting `{}`", path.display());
 
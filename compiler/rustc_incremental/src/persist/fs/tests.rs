@@ -75,3 +75,60 @@ fn test_find_source_directory_in_iter() {
         None
     );
 }
+
+fn evict_all(evicted: &mut Vec<PathBuf>) -> impl FnMut(&Path) -> bool + '_ {
+    move |path: &Path| {
+        evicted.push(path.to_owned());
+        true
+    }
+}
+
+#[test]
+fn test_evict_least_recently_used() {
+    let candidates = || {
+        vec![
+            (UNIX_EPOCH + Duration::new(3, 0), PathBuf::from("3"), 30),
+            (UNIX_EPOCH + Duration::new(1, 0), PathBuf::from("1"), 10),
+            (UNIX_EPOCH + Duration::new(2, 0), PathBuf::from("2"), 20),
+        ]
+    };
+
+    // Oldest first, until the limit is met
+    let mut evicted = vec![];
+    assert_eq!(
+        evict_least_recently_used(candidates(), 100, Some(75), None, evict_all(&mut evicted)),
+        70
+    );
+    assert_eq!(evicted, vec![PathBuf::from("1")]);
+
+    // Everything older than the expiry
+    let mut evicted = vec![];
+    let expiry = Some(UNIX_EPOCH + Duration::new(3, 0));
+    assert_eq!(
+        evict_least_recently_used(candidates(), 100, None, expiry, evict_all(&mut evicted)),
+        70
+    );
+    assert_eq!(evicted, vec![PathBuf::from("1"), PathBuf::from("2")]);
+
+    // Directories that can't be evicted are skipped
+    let mut tried = vec![];
+    let remaining = evict_least_recently_used(candidates(), 100, Some(50), None, |path| {
+        tried.push(path.to_owned());
+        path != Path::new("2")
+    });
+    assert_eq!(remaining, 60);
+    assert_eq!(tried, vec![PathBuf::from("1"), PathBuf::from("2"), PathBuf::from("3")]);
+
+    // Nothing to do without a policy
+    assert_eq!(evict_least_recently_used(candidates(), 100, None, None, |_| unreachable!()), 100);
+}
+
+#[test]
+fn test_expiry_time() {
+    let now = UNIX_EPOCH + Duration::from_secs(10 * 24 * 60 * 60);
+    assert_eq!(expiry_time(now, 0), Some(now));
+    assert_eq!(expiry_time(now, 3), Some(UNIX_EPOCH + Duration::from_secs(7 * 24 * 60 * 60)));
+    // Overflowing the number of seconds means that nothing is old enough to be evicted.
+    assert_eq!(expiry_time(now, u64::MAX), None);
+    assert_eq!(expiry_time(now, u64::MAX / (24 * 60 * 60) + 1), None);
+}
@@ -9,6 +9,7 @@
 use rustc_data_structures::stable_hasher::StableHasher;
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::SarifLog;
 use rustc_errors::{DiagCtxtHandle, ErrorGuaranteed};
 use rustc_lint::LintStore;
 use rustc_middle::ty;
@@ -342,6 +343,10 @@ pub struct Config {
     /// Registry of diagnostics codes.
     pub registry: Registry,
 
+    /// The SARIF log of the driver's early diagnostics, which the compiler's diagnostics are
+    /// added to with `--error-format=sarif`.
+    pub sarif_log: SarifLog,
+
     /// The inner atomic value is set to true when a feature marked as `internal` is
     /// enabled. Makes it so that "please report a bug" is hidden, as ICEs with
     /// internal features are wontfix, and they are usually the cause of the ICEs.
@@ -377,7 +382,8 @@ pub fn run_compiler<R: Send>(config: Config, f: impl FnOnce(&Compiler) -> R + Se
     rustc_data_structures::sync::set_dyn_thread_safe_mode(config.opts.unstable_opts.threads > 1);
 
     // Check jobserver before run_in_thread_pool_with_globals, which call jobserver::acquire_thread
-    let early_dcx = EarlyDiagCtxt::new(config.opts.error_format);
+    let early_dcx =
+        EarlyDiagCtxt::with_sarif_log(config.opts.error_format, config.sarif_log.clone());
     initialize_checked_jobserver(&early_dcx);
 
     crate::callbacks::setup_callbacks();
@@ -396,7 +402,8 @@ pub fn run_compiler<R: Send>(config: Config, f: impl FnOnce(&Compiler) -> R + Se
         |current_gcx| {
             // The previous `early_dcx` can't be reused here because it doesn't
             // impl `Send`. Creating a new one is fine.
-            let early_dcx = EarlyDiagCtxt::new(config.opts.error_format);
+            let early_dcx =
+                EarlyDiagCtxt::with_sarif_log(config.opts.error_format, config.sarif_log);
 
             let codegen_backend = match config.make_codegen_backend {
                 None => util::get_codegen_backend(
@@ -209,8 +209,11 @@ fn configure_and_expand(
         let lint_store = LintStoreExpandImpl(lint_store);
         let mut ecx = ExtCtxt::new(sess, cfg, resolver, Some(&lint_store));
         ecx.num_standard_library_imports = num_standard_library_imports;
+        ecx.expansion_cache =
+            sess.time("load_expansion_cache", || rustc_expand::ExpansionCache::load(sess));
         // Expand macros now!
         let krate = sess.time("expand_crate", || ecx.monotonic_expander().expand_crate(krate));
+        sess.time("save_expansion_cache", || ecx.expansion_cache.save(sess));
 
         // The rest is error reporting
 
@@ -453,6 +456,11 @@ fn write_out_deps(tcx: TyCtxt<'_>, outputs: &OutputFilenames, out_filenames: &[P
             files.push(normalize_path(profile_sample.as_path().to_path_buf()));
         }
 
+        // The lint configuration file
+        if let Some(ref lint_config) = sess.opts.lint_config {
+            files.push(normalize_path(lint_config.path.clone()));
+        }
+
         // Debugger visualizer files
         for debugger_visualizer in tcx.debugger_visualizers(LOCAL_CRATE) {
             files.push(normalize_path(debugger_visualizer.path.clone().unwrap()));
@@ -611,6 +619,22 @@ pub fn write_dep_info(tcx: TyCtxt<'_>) {
     }
 }
 
+/// Writes the steps recorded by `-Z const-eval-profile` to `{crate}.const-eval-profile.folded`
+/// in the output directory.
+pub(crate) fn write_const_eval_profile(tcx: TyCtxt<'_>) {
+    let Some(profile) = &tcx.const_eval_profile else { return };
+    let filename = format!("{}.const-eval-profile.folded", tcx.crate_name(LOCAL_CRATE));
+    let path = tcx.output_filenames(()).out_directory.join(filename);
+    let result = fs::File::create(&path).and_then(|file| {
+        let mut file = BufWriter::new(file);
+        profile.write(&mut file)?;
+        file.flush()
+    });
+    if let Err(error) = result {
+        tcx.dcx().emit_fatal(errors::FailedWritingFile { path: &path, error });
+    }
+}
+
 pub static DEFAULT_QUERY_PROVIDERS: LazyLock<Providers> = LazyLock::new(|| {
     let providers = &mut Providers::default();
     providers.analysis = analysis;
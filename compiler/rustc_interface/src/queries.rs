@@ -130,7 +130,8 @@ pub fn codegen_and_build_linker(
         // This must run after monomorphization so that all generic types
         // have been instantiated.
         if tcx.sess.opts.unstable_opts.print_type_sizes {
-            tcx.sess.code_stats.print_type_sizes();
+            let format = tcx.sess.opts.unstable_opts.print_type_sizes_format;
+            tcx.sess.code_stats.print_type_sizes(format);
         }
 
         if tcx.sess.opts.unstable_opts.print_vtable_sizes {
@@ -212,6 +213,8 @@ pub fn enter<F, T>(&self, f: F) -> T
         // since that likely means there was a parse error.
         if let Some(Ok(gcx)) = &mut *queries.gcx.result.borrow_mut() {
             let gcx = gcx.get_mut();
+            gcx.enter(passes::write_const_eval_profile);
+
             // We assume that no queries are run past here. If there are new queries
             // after this point, they'll show up as "<unknown>" in self-profiling data.
             {
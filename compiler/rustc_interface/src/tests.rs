@@ -9,15 +9,15 @@
 };
 use rustc_session::config::{
     ExternEntry, ExternLocation, Externs, FunctionReturn, InliningThreshold, Input,
-    InstrumentCoverage, InstrumentXRay, LinkSelfContained, LinkerPluginLto,
+    InstrumentCoverage, InstrumentXRay, ItemCostReportFormat, LinkSelfContained, LinkerPluginLto,
 };
 use rustc_session::config::{
-    LocationDetail, LtoCli, NextSolverConfig, OomStrategy, Options, OutFileName, OutputType,
-    OutputTypes, PAuthKey, PacRet, Passes, PatchableFunctionEntry,
+    LintConfig, LocationDetail, LtoCli, NextSolverConfig, OomStrategy, Options, OutFileName,
+    OutputType, OutputTypes, PAuthKey, PacRet, Passes, PatchableFunctionEntry,
 };
 use rustc_session::config::{
-    Polonius, ProcMacroExecutionStrategy, Strip, SwitchWithOptPath, SymbolManglingVersion,
-    WasiExecModel,
+    Polonius, PrintTypeSizesFormat, ProcMacroExecutionStrategy, RemapPathPrefixes,
+    StableMirDumpFormat, Strip, SwitchWithOptPath, SymbolManglingVersion, WasiExecModel,
 };
 use rustc_session::lint::Level;
 use rustc_session::search_paths::SearchPath;
@@ -197,6 +197,20 @@ fn test_output_types_tracking_hash_different_paths() {
     assert_non_crate_hash_different(&v2, &v3);
 }
 
+#[test]
+fn test_remap_path_prefix_tracking_hash_ignores_local_paths() {
+    let mut v1 = Options::default();
+    let mut v2 = Options::default();
+    let mut v3 = Options::default();
+
+    v1.remap_path_prefix = RemapPathPrefixes(vec![("/ci/a".into(), "/src".into())]);
+    v2.remap_path_prefix = RemapPathPrefixes(vec![("/ci/b".into(), "/src".into())]);
+    v3.remap_path_prefix = RemapPathPrefixes(vec![("/ci/a".into(), "/other".into())]);
+
+    assert_same_hash(&v1, &v2);
+    assert_non_crate_hash_different(&v1, &v3);
+}
+
 #[test]
 fn test_output_types_tracking_hash_different_construction_order() {
     let mut v1 = Options::default();
@@ -662,11 +676,18 @@ macro_rules! tracked {
 
     // Make sure that changing a [TRACKED_NO_CRATE_HASH] option leaves the crate hash unchanged but changes the incremental hash.
     // tidy-alphabetical-start
+    tracked!(
+        lint_config,
+        Some(LintConfig::parse("lints.toml".into(), "[lints]\nunused = \"deny\"\n").unwrap())
+    );
     tracked!(
         real_rust_source_base_dir,
         Some("/home/bors/rust/.rustup/toolchains/nightly/lib/rustlib/src/rust".into())
     );
-    tracked!(remap_path_prefix, vec![("/home/bors/rust".into(), "src".into())]);
+    tracked!(
+        remap_path_prefix,
+        RemapPathPrefixes(vec![("/home/bors/rust".into(), "src".into())])
+    );
     // tidy-alphabetical-end
 }
 
@@ -686,6 +707,9 @@ macro_rules! untracked {
     // Make sure that changing an [UNTRACKED] option leaves the hash unchanged.
     // tidy-alphabetical-start
     untracked!(assert_incr_state, Some(String::from("loaded")));
+    untracked!(borrowck_explain, Some(String::from("abc")));
+    untracked!(cache_expansions, true);
+    untracked!(const_eval_profile, true);
     untracked!(deduplicate_diagnostics, false);
     untracked!(dump_dep_graph, true);
     untracked!(dump_mir, Some(String::from("abc")));
@@ -695,14 +719,18 @@ macro_rules! untracked {
     untracked!(dump_mir_graphviz, true);
     untracked!(dump_mono_stats, SwitchWithOptPath::Enabled(Some("mono-items-dir/".into())));
     untracked!(dump_mono_stats_format, DumpMonoStatsFormat::Json);
+    untracked!(dump_stable_mir, Some(StableMirDumpFormat::Json));
     untracked!(dylib_lto, true);
     untracked!(emit_stack_sizes, true);
     untracked!(future_incompat_test, true);
     untracked!(hir_stats, true);
     untracked!(identify_regions, true);
+    untracked!(incremental_cache_limit, Some(1 << 30));
+    untracked!(incremental_cache_max_age, Some(30));
     untracked!(incremental_info, true);
     untracked!(incremental_verify_ich, true);
     untracked!(input_stats, true);
+    untracked!(item_cost_report, Some(ItemCostReportFormat::Json));
     untracked!(link_native_libraries, false);
     untracked!(llvm_time_trace, true);
     untracked!(ls, vec!["all".to_owned()]);
@@ -720,8 +748,10 @@ macro_rules! untracked {
     untracked!(print_llvm_passes, true);
     untracked!(print_mono_items, Some(String::from("abc")));
     untracked!(print_type_sizes, true);
+    untracked!(print_type_sizes_format, PrintTypeSizesFormat::Json);
     untracked!(proc_macro_backtrace, true);
     untracked!(proc_macro_execution_strategy, ProcMacroExecutionStrategy::CrossThread);
+    untracked!(proc_macro_timeout, Some(1));
     untracked!(profile_closures, true);
     untracked!(query_dep_graph, true);
     untracked!(self_profile, SwitchWithOptPath::Enabled(None));
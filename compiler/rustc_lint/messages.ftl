@@ -433,6 +433,8 @@ lint_invalid_reference_casting_note_ty_has_interior_mutability = even for types
 lint_legacy_derive_helpers = derive helper attribute is used before it is introduced
     .label = the attribute is introduced here
 
+lint_lint_config_source = `forbid` lint level was set in the lint configuration file
+
 lint_lintpass_by_hand = implementing `LintPass` by hand
     .help = try using `declare_lint_pass!` or `impl_lint_pass!` instead
 
@@ -700,6 +702,8 @@ lint_renamed_lint = lint `{$name}` has been renamed to `{$replace}`
 
 lint_requested_level = requested on the command line with `{$level} {$lint_name}`
 
+lint_requested_level_lint_config = requested in the lint configuration file `{$path}` with `{$lint_name} = "{$level}"`
+
 lint_reserved_prefix = prefix `{$prefix}` is unknown
     .label = unknown prefix
     .suggestion = insert whitespace here to avoid this being parsed as a prefix in Rust 2021
@@ -85,11 +85,20 @@ fn visit_param(&mut self, param: &'a ast::Param) {
     }
 
     fn visit_item(&mut self, it: &'a ast::Item) {
+        let push = match &it.kind {
+            ast::ItemKind::Mod(_, ast::ModKind::Loaded(_, ast::Inline::No, spans)) => {
+                Some(self.context.builder.push_lint_config_paths(spans.inner_span))
+            }
+            _ => None,
+        };
         self.with_lint_attrs(it.id, &it.attrs, |cx| {
             lint_callback!(cx, check_item, it);
             ast_visit::walk_item(cx, it);
             lint_callback!(cx, check_item_post, it);
-        })
+        });
+        if let Some(push) = push {
+            self.context.builder.pop(push);
+        }
     }
 
     fn visit_foreign_item(&mut self, it: &'a ast::ForeignItem) {
@@ -331,6 +340,9 @@ impl EarlyLintPass for RuntimeCombinedEarlyLintPass<'_> {
 /// This trait generalizes over those nodes.
 pub trait EarlyCheckNode<'a>: Copy {
     fn id(self) -> ast::NodeId;
+    /// The inner span of the node if it is a module whose file may have lint
+    /// levels set by the lint configuration file.
+    fn inner_span(self) -> Option<Span>;
     fn attrs<'b>(self) -> &'b [ast::Attribute]
     where
         'a: 'b;
@@ -343,6 +355,9 @@ impl<'a> EarlyCheckNode<'a> for (&'a ast::Crate, &'a [ast::Attribute]) {
     fn id(self) -> ast::NodeId {
         ast::CRATE_NODE_ID
     }
+    fn inner_span(self) -> Option<Span> {
+        Some(self.0.spans.inner_span)
+    }
     fn attrs<'b>(self) -> &'b [ast::Attribute]
     where
         'a: 'b,
@@ -363,6 +378,11 @@ impl<'a> EarlyCheckNode<'a> for (ast::NodeId, &'a [ast::Attribute], &'a [P<ast::
     fn id(self) -> ast::NodeId {
         self.0
     }
+    fn inner_span(self) -> Option<Span> {
+        // Freshly loaded modules are only checked by pre-expansion lints,
+        // which do not see the levels set for paths.
+        None
+    }
     fn attrs<'b>(self) -> &'b [ast::Attribute]
     where
         'a: 'b,
@@ -420,7 +440,11 @@ pub fn check_ast_node_inner<'a, T: EarlyLintPass>(
 ) {
     let mut cx = EarlyContextAndPass { context, pass };
 
+    let push = check_node.inner_span().map(|span| cx.context.builder.push_lint_config_paths(span));
     cx.with_lint_attrs(check_node.id(), check_node.attrs(), |cx| check_node.check(cx));
+    if let Some(push) = push {
+        cx.context.builder.pop(push);
+    }
 
     // All of the buffered lints should have been emitted at this point.
     // If not, that means that we somehow buffered a lint for a node id
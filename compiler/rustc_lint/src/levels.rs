@@ -39,7 +39,7 @@
 };
 use rustc_session::Session;
 use rustc_span::symbol::{sym, Symbol};
-use rustc_span::{Span, DUMMY_SP};
+use rustc_span::{FileName, Span, DUMMY_SP};
 use tracing::{debug, instrument};
 
 use crate::errors::{
@@ -174,6 +174,9 @@ fn shallow_lint_levels_on(tcx: TyCtxt<'_>, owner: hir::OwnerId) -> ShallowLintLe
     if owner == hir::CRATE_OWNER_ID {
         levels.add_command_line();
     }
+    if let Some(inner_span) = lint_config_module_span(tcx, owner.into()) {
+        levels.add_lint_config_paths(inner_span);
+    }
 
     match attrs.map.range(..) {
         // There is only something to do if there are attributes at all.
@@ -206,6 +209,22 @@ fn shallow_lint_levels_on(tcx: TyCtxt<'_>, owner: hir::OwnerId) -> ShallowLintLe
     specs
 }
 
+/// The inner span of `hir_id` if it is a module and the lint configuration file
+/// sets levels for paths, see `LintLevelsBuilder::add_lint_config_paths`.
+fn lint_config_module_span(tcx: TyCtxt<'_>, hir_id: HirId) -> Option<Span> {
+    if tcx.sess.opts.lint_config.as_ref().map_or(true, |config| config.paths.is_empty()) {
+        return None;
+    }
+    match tcx.hir_node(hir_id) {
+        hir::Node::Crate(module) => Some(module.spans.inner_span),
+        hir::Node::Item(item) => match item.kind {
+            hir::ItemKind::Mod(module) => Some(module.spans.inner_span),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub struct TopDown {
     sets: LintLevelSets,
     cur: LintStackIndex,
@@ -381,6 +400,9 @@ fn add_id(&mut self, hir_id: HirId) {
         // Change both the `HirId` and the associated specs.
         self.provider.cur = hir_id;
         self.provider.specs.specs.clear();
+        if let Some(inner_span) = lint_config_module_span(self.provider.tcx, hir_id) {
+            self.add_lint_config_paths(inner_span);
+        }
         self.add(self.provider.tcx.hir().attrs(hir_id), hir_id == hir::CRATE_HIR_ID, Some(hir_id));
     }
 }
@@ -513,11 +535,24 @@ pub(crate) fn push(
         is_crate_node: bool,
         source_hir_id: Option<HirId>,
     ) -> BuilderPush {
+        self.push_with(|builder| builder.add(attrs, is_crate_node, source_hir_id))
+    }
+
+    /// Pushes the levels the lint configuration file sets for the source file
+    /// of a module, see `add_lint_config_paths`. This has to happen before the
+    /// attributes of the module are pushed.
+    ///
+    /// Don't forget to call `pop`!
+    pub(crate) fn push_lint_config_paths(&mut self, inner_span: Span) -> BuilderPush {
+        self.push_with(|builder| builder.add_lint_config_paths(inner_span))
+    }
+
+    fn push_with(&mut self, add: impl FnOnce(&mut Self)) -> BuilderPush {
         let prev = self.provider.cur;
         self.provider.cur =
             self.provider.sets.list.push(LintSet { specs: FxIndexMap::default(), parent: prev });
 
-        self.add(attrs, is_crate_node, source_hir_id);
+        add(self);
 
         if self.provider.current_specs().is_empty() {
             self.provider.sets.list.pop();
@@ -559,9 +594,31 @@ fn insert(&mut self, id: LintId, lvl: LevelAndSource) {
     }
 
     fn add_command_line(&mut self) {
+        // The crate-wide levels of the lint configuration file come first, so
+        // that the command line can override them.
+        if let Some(config) = &self.sess.opts.lint_config {
+            let path = config.path.display().to_string();
+            let requested = config.levels.iter().chain(config.paths.iter().flat_map(|(_, l)| l));
+            // Levels for paths are only checked here, to report them once.
+            for (lint_name, level) in requested {
+                let level = level.as_str();
+                self.check_requested_lint_name(
+                    lint_name,
+                    RequestedLevel::LintConfig { level, lint_name, path: &path },
+                );
+            }
+            for &(ref lint_name, level) in &config.levels {
+                let src = LintLevelSource::LintConfig {
+                    name: Symbol::intern(lint_name),
+                    level,
+                    path: None,
+                };
+                self.insert_requested_level(lint_name, level, src);
+            }
+        }
+
         for &(ref lint_name, level) in &self.sess.opts.lint_opts {
-            // Checks the validity of lint names derived from the command line.
-            let (tool_name, lint_name_only) = parse_lint_and_tool_name(lint_name);
+            let (_, lint_name_only) = parse_lint_and_tool_name(lint_name);
             if lint_name_only == crate::WARNINGS.name_lower()
                 && matches!(level, Level::ForceWarn(_))
             {
@@ -569,61 +626,110 @@ fn add_command_line(&mut self) {
                     .dcx()
                     .emit_err(UnsupportedGroup { lint_group: crate::WARNINGS.name_lower() });
             }
-            match self.store.check_lint_name(lint_name_only, tool_name, self.registered_tools) {
-                CheckLintNameResult::Renamed(ref replace) => {
-                    let name = lint_name.as_str();
-                    let suggestion = RenamedLintSuggestion::WithoutSpan { replace };
-                    let requested_level = RequestedLevel { level, lint_name };
-                    let lint = RenamedLintFromCommandLine { name, suggestion, requested_level };
-                    self.emit_lint(RENAMED_AND_REMOVED_LINTS, lint);
-                }
-                CheckLintNameResult::Removed(ref reason) => {
-                    let name = lint_name.as_str();
-                    let requested_level = RequestedLevel { level, lint_name };
-                    let lint = RemovedLintFromCommandLine { name, reason, requested_level };
-                    self.emit_lint(RENAMED_AND_REMOVED_LINTS, lint);
-                }
-                CheckLintNameResult::NoLint(suggestion) => {
-                    let name = lint_name.clone();
-                    let suggestion = suggestion.map(|(replace, from_rustc)| {
-                        UnknownLintSuggestion::WithoutSpan { replace, from_rustc }
-                    });
-                    let requested_level = RequestedLevel { level, lint_name };
-                    let lint = UnknownLintFromCommandLine { name, suggestion, requested_level };
-                    self.emit_lint(UNKNOWN_LINTS, lint);
-                }
-                CheckLintNameResult::Tool(_, Some(ref replace)) => {
-                    let name = lint_name.clone();
-                    let requested_level = RequestedLevel { level, lint_name };
-                    let lint = DeprecatedLintNameFromCommandLine { name, replace, requested_level };
-                    self.emit_lint(RENAMED_AND_REMOVED_LINTS, lint);
-                }
-                CheckLintNameResult::NoTool => {
-                    self.sess.dcx().emit_err(CheckNameUnknownTool {
-                        tool_name: tool_name.unwrap(),
-                        sub: RequestedLevel { level, lint_name },
-                    });
-                }
-                _ => {}
-            };
+            self.check_requested_lint_name(
+                lint_name,
+                RequestedLevel::CommandLine { level, lint_name },
+            );
+
+            let src = LintLevelSource::CommandLine(Symbol::intern(lint_name), level);
+            self.insert_requested_level(lint_name, level, src);
+        }
+    }
+
+    /// Checks the validity of a lint name that was requested outside of the
+    /// source code, on the command line or in the lint configuration file.
+    fn check_requested_lint_name(&self, lint_name: &str, requested_level: RequestedLevel<'_>) {
+        let (tool_name, lint_name_only) = parse_lint_and_tool_name(lint_name);
+        match self.store.check_lint_name(lint_name_only, tool_name, self.registered_tools) {
+            CheckLintNameResult::Renamed(ref replace) => {
+                let name = lint_name;
+                let suggestion = RenamedLintSuggestion::WithoutSpan { replace };
+                let lint = RenamedLintFromCommandLine { name, suggestion, requested_level };
+                self.emit_lint(RENAMED_AND_REMOVED_LINTS, lint);
+            }
+            CheckLintNameResult::Removed(ref reason) => {
+                let name = lint_name;
+                let lint = RemovedLintFromCommandLine { name, reason, requested_level };
+                self.emit_lint(RENAMED_AND_REMOVED_LINTS, lint);
+            }
+            CheckLintNameResult::NoLint(suggestion) => {
+                let name = lint_name.to_owned();
+                let suggestion = suggestion.map(|(replace, from_rustc)| {
+                    UnknownLintSuggestion::WithoutSpan { replace, from_rustc }
+                });
+                let lint = UnknownLintFromCommandLine { name, suggestion, requested_level };
+                self.emit_lint(UNKNOWN_LINTS, lint);
+            }
+            CheckLintNameResult::Tool(_, Some(ref replace)) => {
+                let name = lint_name.to_owned();
+                let lint = DeprecatedLintNameFromCommandLine { name, replace, requested_level };
+                self.emit_lint(RENAMED_AND_REMOVED_LINTS, lint);
+            }
+            CheckLintNameResult::NoTool => {
+                self.sess.dcx().emit_err(CheckNameUnknownTool {
+                    tool_name: tool_name.unwrap(),
+                    sub: requested_level,
+                });
+            }
+            _ => {}
+        };
+    }
+
+    /// Sets the level of the lint or lint group `lint_name` requested on the
+    /// command line or in the lint configuration file, for the whole crate.
+    fn insert_requested_level(&mut self, lint_name: &str, level: Level, src: LintLevelSource) {
+        let Ok(ids) = self.store.find_lints(lint_name) else {
+            // errors already handled by `check_requested_lint_name`
+            return;
+        };
+        for id in ids {
+            // ForceWarn and Forbid cannot be overridden
+            if let Some((Level::ForceWarn(_) | Level::Forbid, _)) = self.current_specs().get(&id) {
+                continue;
+            }
 
-            let orig_level = level;
-            let lint_flag_val = Symbol::intern(lint_name);
+            if self.check_gated_lint(id, DUMMY_SP, true) {
+                self.insert(id, (level, src));
+            }
+        }
+    }
+
+    /// Inserts the levels the lint configuration file sets for the source file
+    /// starting at `inner_span`, the inner span of the crate root or of an
+    /// out-of-line module. They apply like inner attributes of the module, so
+    /// the module's own attributes have to be added afterwards.
+    fn add_lint_config_paths(&mut self, inner_span: Span) {
+        let sess = self.sess;
+        let Some(config) = &sess.opts.lint_config else { return };
+        if config.paths.is_empty() {
+            return;
+        }
+        let file = sess.source_map().lookup_source_file(inner_span.lo());
+        // Inline modules share the file of their parent, whose levels already apply.
+        if file.start_pos != inner_span.lo() {
+            return;
+        }
+        let FileName::Real(name) = &file.name else { return };
+        let Some(path) = name.local_path() else { return };
 
+        for (glob, lint_name, level) in config.levels_for_file(path) {
             let Ok(ids) = self.store.find_lints(lint_name) else {
-                // errors already handled above
+                // errors already handled by `add_command_line`
                 continue;
             };
+            let src = LintLevelSource::LintConfig {
+                name: Symbol::intern(lint_name),
+                level,
+                path: Some(Symbol::intern(glob)),
+            };
             for id in ids {
                 // ForceWarn and Forbid cannot be overridden
-                if let Some((Level::ForceWarn(_) | Level::Forbid, _)) =
-                    self.current_specs().get(&id)
-                {
+                let (old_level, _) = self.provider.get_lint_level(id.lint, sess);
+                if let Level::ForceWarn(_) | Level::Forbid = old_level {
                     continue;
                 }
 
                 if self.check_gated_lint(id, DUMMY_SP, true) {
-                    let src = LintLevelSource::CommandLine(lint_flag_val, orig_level);
                     self.insert(id, (level, src));
                 }
             }
@@ -658,6 +764,7 @@ fn insert_spec(&mut self, id: LintId, (mut level, src): LevelAndSource) {
                 LintLevelSource::Default => false,
                 LintLevelSource::Node { name, .. } => self.store.is_lint_group(name),
                 LintLevelSource::CommandLine(symbol, _) => self.store.is_lint_group(symbol),
+                LintLevelSource::LintConfig { name, .. } => self.store.is_lint_group(name),
             };
             debug!(
                 "fcw_warning={:?}, specs.get(&id) = {:?}, old_src={:?}, id_name={:?}",
@@ -674,6 +781,7 @@ fn insert_spec(&mut self, id: LintId, (mut level, src): LevelAndSource) {
                     OverruledAttributeSub::NodeSource { span, reason }
                 }
                 LintLevelSource::CommandLine(_, _) => OverruledAttributeSub::CommandLineSource,
+                LintLevelSource::LintConfig { .. } => OverruledAttributeSub::LintConfigSource,
             };
             if !fcw_warning {
                 self.sess.dcx().emit_err(OverruledAttribute {
@@ -1109,6 +1217,19 @@ pub fn emit_lint(&self, lint: &'static Lint, decorate: impl for<'a> LintDiagnost
     }
 }
 
+/// The level of every lint for the whole crate, as set by the defaults, the
+/// lint configuration file and the command line, without looking at the
+/// source code. This implements `--print lint-levels`.
+pub fn crate_wide_lint_levels(sess: &Session) -> Vec<(&'static Lint, LevelAndSource)> {
+    let store = unerased_lint_store(sess);
+    // The crate attributes are not parsed yet, so the levels requested for
+    // feature-gated lints are ignored.
+    let features = Features::default();
+    let registered_tools = RegisteredTools::default();
+    let builder = LintLevelsBuilder::new(sess, &features, false, store, &registered_tools);
+    store.get_lints().iter().map(|&lint| (lint, builder.lint_level(lint))).collect()
+}
+
 pub(crate) fn provide(providers: &mut Providers) {
     *providers = Providers { shallow_lint_levels_on, lint_expectations, ..*providers };
 }
@@ -640,8 +640,11 @@ std::string LLVMRustwrappedIrGetName(const llvm::Any &WrappedIr) {
     return (*Cast)->getName().str();
   if (const auto *Cast = any_cast<const Function *>(&WrappedIr))
     return (*Cast)->getName().str();
+  // Include the function, so that loop passes can be attributed to it.
   if (const auto *Cast = any_cast<const Loop *>(&WrappedIr))
-    return (*Cast)->getName().str();
+    return ("loop %" + (*Cast)->getName() + " in function " +
+            (*Cast)->getHeader()->getParent()->getName())
+        .str();
   if (const auto *Cast = any_cast<const LazyCallGraph::SCC *>(&WrappedIr))
     return (*Cast)->getName();
   return "<UNKNOWN>";
@@ -13,7 +13,7 @@
 use rustc_errors::DiagCtxtHandle;
 use rustc_expand::base::SyntaxExtension;
 use rustc_fs_util::try_canonicalize;
-use rustc_hir::def_id::{CrateNum, LocalDefId, StableCrateId, LOCAL_CRATE};
+use rustc_hir::def_id::{CrateNum, LocalDefId, LOCAL_CRATE};
 use rustc_hir::definitions::Definitions;
 use rustc_index::IndexVec;
 use rustc_middle::bug;
@@ -433,7 +433,7 @@ fn register_crate(
 
         let cnum_map = self.resolve_crate_deps(root, &crate_root, &metadata, cnum, dep_kind)?;
 
-        let raw_proc_macros = if crate_root.is_proc_macro_crate() {
+        let (raw_proc_macros, proc_macro_dylib) = if crate_root.is_proc_macro_crate() {
             let temp_root;
             let (dlsym_source, dlsym_root) = match &host_lib {
                 Some(host_lib) => (&host_lib.source, {
@@ -443,9 +443,11 @@ fn register_crate(
                 None => (&source, &crate_root),
             };
             let dlsym_dylib = dlsym_source.dylib.as_ref().expect("no dylib for a proc-macro crate");
-            Some(self.dlsym_proc_macros(&dlsym_dylib.0, dlsym_root.stable_crate_id())?)
+            let sym_name = self.sess.generate_proc_macro_decls_symbol(dlsym_root.stable_crate_id());
+            let raw_proc_macros = self.dlsym_proc_macros(&dlsym_dylib.0, &sym_name)?;
+            (Some(raw_proc_macros), Some((dlsym_dylib.0.clone(), sym_name)))
         } else {
-            None
+            (None, None)
         };
 
         let crate_metadata = CrateMetadata::new(
@@ -454,6 +456,7 @@ fn register_crate(
             metadata,
             crate_root,
             raw_proc_macros,
+            proc_macro_dylib,
             cnum,
             cnum_map,
             dep_kind,
@@ -692,10 +695,9 @@ fn resolve_crate_deps(
     fn dlsym_proc_macros(
         &self,
         path: &Path,
-        stable_crate_id: StableCrateId,
+        sym_name: &str,
     ) -> Result<&'static [ProcMacro], CrateError> {
-        let sym_name = self.sess.generate_proc_macro_decls_symbol(stable_crate_id);
-        Ok(unsafe { *load_symbol_from_dylib::<*const &[ProcMacro]>(path, &sym_name)? })
+        Ok(unsafe { *load_symbol_from_dylib::<*const &[ProcMacro]>(path, sym_name)? })
     }
 
     fn inject_panic_runtime(&mut self, krate: &ast::Crate) {
@@ -11,7 +11,7 @@
 use rustc_data_structures::sync::{Lock, Lrc, OnceLock};
 use rustc_data_structures::unhash::UnhashMap;
 use rustc_expand::base::{SyntaxExtension, SyntaxExtensionKind};
-use rustc_expand::proc_macro::{AttrProcMacro, BangProcMacro, DeriveProcMacro};
+use rustc_expand::proc_macro::{AttrProcMacro, BangProcMacro, DeriveProcMacro, ProcMacroLocation};
 use rustc_hir::def::Res;
 use rustc_hir::def_id::{CRATE_DEF_INDEX, LOCAL_CRATE};
 use rustc_hir::definitions::{DefPath, DefPathData};
@@ -32,7 +32,7 @@
 
 use proc_macro::bridge::client::ProcMacro;
 use std::iter::TrustedLen;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{io, iter, mem};
 
 pub(super) use cstore_impl::provide;
@@ -91,6 +91,9 @@ pub(crate) struct CrateMetadata {
     incoherent_impls: FxHashMap<SimplifiedType, LazyArray<DefIndex>>,
     /// Proc macro descriptions for this crate, if it's a proc macro crate.
     raw_proc_macros: Option<&'static [ProcMacro]>,
+    /// The dylib `raw_proc_macros` were loaded from and the symbol of their declarations,
+    /// so that they can be loaded again by a proc macro server in another process.
+    proc_macro_dylib: Option<(PathBuf, String)>,
     /// Source maps for code from the crate.
     source_map_import_info: Lock<Vec<Option<ImportedSourceFile>>>,
     /// For every definition in this crate, maps its `DefPathHash` to its `DefIndex`.
@@ -967,19 +970,21 @@ fn missing(self, descr: &str, id: DefIndex) -> ! {
         bug!("missing `{descr}` for {:?}", self.local_def_id(id))
     }
 
-    fn raw_proc_macro(self, id: DefIndex) -> &'a ProcMacro {
+    fn proc_macro_index(self, id: DefIndex) -> usize {
         // DefIndex's in root.proc_macro_data have a one-to-one correspondence
         // with items in 'raw_proc_macros'.
-        let pos = self
-            .root
+        self.root
             .proc_macro_data
             .as_ref()
             .unwrap()
             .macros
             .decode(self)
             .position(|i| i == id)
-            .unwrap();
-        &self.raw_proc_macros.unwrap()[pos]
+            .unwrap()
+    }
+
+    fn raw_proc_macro(self, id: DefIndex) -> &'a ProcMacro {
+        &self.raw_proc_macros.unwrap()[self.proc_macro_index(id)]
     }
 
     fn opt_item_name(self, item_index: DefIndex) -> Option<Symbol> {
@@ -1037,22 +1042,33 @@ fn get_span(self, index: DefIndex, sess: &Session) -> Span {
     }
 
     fn load_proc_macro(self, id: DefIndex, tcx: TyCtxt<'tcx>) -> SyntaxExtension {
+        let location =
+            self.proc_macro_dylib.as_ref().map(|(dylib, decls_symbol)| ProcMacroLocation {
+                dylib: dylib.clone(),
+                decls_symbol: decls_symbol.clone(),
+                index: self.proc_macro_index(id),
+                crate_hash: self.cdata.hash(),
+            });
         let (name, kind, helper_attrs) = match *self.raw_proc_macro(id) {
             ProcMacro::CustomDerive { trait_name, attributes, client } => {
                 let helper_attrs =
                     attributes.iter().cloned().map(Symbol::intern).collect::<Vec<_>>();
                 (
                     trait_name,
-                    SyntaxExtensionKind::Derive(Box::new(DeriveProcMacro { client })),
+                    SyntaxExtensionKind::Derive(Box::new(DeriveProcMacro { client, location })),
                     helper_attrs,
                 )
             }
-            ProcMacro::Attr { name, client } => {
-                (name, SyntaxExtensionKind::Attr(Box::new(AttrProcMacro { client })), Vec::new())
-            }
-            ProcMacro::Bang { name, client } => {
-                (name, SyntaxExtensionKind::Bang(Box::new(BangProcMacro { client })), Vec::new())
-            }
+            ProcMacro::Attr { name, client } => (
+                name,
+                SyntaxExtensionKind::Attr(Box::new(AttrProcMacro { client, location })),
+                Vec::new(),
+            ),
+            ProcMacro::Bang { name, client } => (
+                name,
+                SyntaxExtensionKind::Bang(Box::new(BangProcMacro { client, location })),
+                Vec::new(),
+            ),
         };
 
         let sess = tcx.sess;
@@ -1628,7 +1644,7 @@ fn filter<'a>(sess: &Session, path: Option<&'a Path>) -> Option<&'a Path> {
                 // Don't translate away `/rustc/$hash` if we're still remapping to it,
                 // since that means we're still building `std`/`rustc` that need it,
                 // and we don't want the real path to leak into codegen/debuginfo.
-                !sess.opts.remap_path_prefix.iter().any(|(_from, to)| to == virtual_dir)
+                !sess.opts.remap_path_prefix.0.iter().any(|(_from, to)| to == virtual_dir)
             })
         }
 
@@ -1837,6 +1853,7 @@ pub(crate) fn new(
         blob: MetadataBlob,
         root: CrateRoot,
         raw_proc_macros: Option<&'static [ProcMacro]>,
+        proc_macro_dylib: Option<(PathBuf, String)>,
         cnum: CrateNum,
         cnum_map: CrateNumMap,
         dep_kind: CrateDepKind,
@@ -1863,6 +1880,7 @@ pub(crate) fn new(
             trait_impls,
             incoherent_impls: Default::default(),
             raw_proc_macros,
+            proc_macro_dylib,
             source_map_import_info: Lock::new(Vec::new()),
             def_path_hash_map,
             expn_hash_map: Default::default(),
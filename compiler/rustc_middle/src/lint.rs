@@ -34,6 +34,11 @@ pub enum LintLevelSource {
     /// The provided `Level` is the level specified on the command line.
     /// (The actual level may be lower due to `--cap-lints`.)
     CommandLine(Symbol, Level),
+
+    /// Lint level was set by the `--lint-config` file, for the whole crate or,
+    /// if `path` is set, for the source files matching that glob.
+    /// The provided `level` is the level specified in the file.
+    LintConfig { name: Symbol, level: Level, path: Option<Symbol> },
 }
 
 impl LintLevelSource {
@@ -42,6 +47,7 @@ pub fn name(&self) -> Symbol {
             LintLevelSource::Default => symbol::kw::Default,
             LintLevelSource::Node { name, .. } => name,
             LintLevelSource::CommandLine(name, _) => name,
+            LintLevelSource::LintConfig { name, .. } => name,
         }
     }
 
@@ -50,6 +56,7 @@ pub fn span(&self) -> Span {
             LintLevelSource::Default => DUMMY_SP,
             LintLevelSource::Node { span, .. } => span,
             LintLevelSource::CommandLine(_, _) => DUMMY_SP,
+            LintLevelSource::LintConfig { .. } => DUMMY_SP,
         }
     }
 }
@@ -235,6 +242,22 @@ pub fn explain_lint_level_source(
                 ));
             }
         }
+        LintLevelSource::LintConfig { name: config_name, level: orig_level, path } => {
+            let setting = format!("{config_name} = \"{}\"", orig_level.as_str());
+            let scope = match path {
+                Some(glob) => format!(" for `{glob}`"),
+                None => String::new(),
+            };
+            if config_name.as_str() == name {
+                err.note_once(format!(
+                    "requested in the lint configuration file with `{setting}`{scope}"
+                ));
+            } else {
+                err.note_once(format!(
+                    "`{name}` implied by `{setting}`{scope} in the lint configuration file"
+                ));
+            }
+        }
         LintLevelSource::Node { name: lint_attr_name, span, reason, .. } => {
             if let Some(rationale) = reason {
                 err.note(rationale.to_string());
@@ -24,7 +24,16 @@ pub fn provide(providers: &mut Providers) {
             tcx.hir().krate_attrs(),
             tcx.sess,
             sym::move_size_limit,
-            tcx.sess.opts.unstable_opts.move_size_limit.unwrap_or(0),
+            tcx.sess
+                .opts
+                .unstable_opts
+                .move_size_limit
+                .or_else(|| {
+                    let config = tcx.sess.opts.lint_config.as_ref()?;
+                    let limit = config.param("large_assignments", "limit")?;
+                    Some(usize::try_from(limit).unwrap_or(usize::MAX))
+                })
+                .unwrap_or(0),
         ),
         type_length_limit: get_limit(
             tcx.hir().krate_attrs(),
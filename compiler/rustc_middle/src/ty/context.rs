@@ -34,6 +34,8 @@
     RegionKind, ReprOptions, TraitObjectVisitor, Ty, TyKind, TyVid, Visibility,
 };
 use crate::ty::{GenericArg, GenericArgs, GenericArgsRef};
+use crate::util::const_eval_profile::ConstEvalProfile;
+use crate::util::item_costs::ItemCosts;
 use rustc_ast::{self as ast, attr};
 use rustc_data_structures::defer;
 use rustc_data_structures::fingerprint::Fingerprint;
@@ -1294,6 +1296,12 @@ pub struct GlobalCtxt<'tcx> {
     /// Stores memory for globals (statics/consts).
     pub(crate) alloc_map: Lock<interpret::AllocMap<'tcx>>,
 
+    /// Compile costs attributed to items, if `-Z item-cost-report` is enabled.
+    pub item_costs: Option<ItemCosts>,
+
+    /// Steps taken by compile-time evaluation, if `-Z const-eval-profile` is enabled.
+    pub const_eval_profile: Option<ConstEvalProfile>,
+
     current_gcx: CurrentGcx,
 }
 
@@ -1523,6 +1531,12 @@ pub fn create_global_ctxt(
             canonical_param_env_cache: Default::default(),
             data_layout,
             alloc_map: Lock::new(interpret::AllocMap::new()),
+            item_costs: s.opts.unstable_opts.item_cost_report.map(|_| ItemCosts::default()),
+            const_eval_profile: s
+                .opts
+                .unstable_opts
+                .const_eval_profile
+                .then(ConstEvalProfile::default),
             current_gcx,
         }
     }
@@ -0,0 +1,70 @@
+//! Bookkeeping for `-Z const-eval-profile`, which attributes the steps taken by compile-time
+//! evaluation to the call stacks being interpreted.
+//!
+//! Steps are the ones counted for the `long_running_const_eval` lint, i.e., function calls and
+//! loop iterations. Each evaluation of a `const`, `static` or promoted samples the stack of the
+//! interpreter at every step, and records the samples in [`ConstEvalProfile`] once it is done.
+//! They are written in the folded stacks format that flame graph tools take as input.
+
+use std::io::{self, Write};
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::Lock;
+
+/// The samples of `-Z const-eval-profile`, see the module documentation.
+#[derive(Default)]
+pub struct ConstEvalProfile {
+    /// For each evaluation, the name of the evaluated global and the number of steps taken in
+    /// each stack of frames, outermost first.
+    evaluations: Lock<Vec<(String, FxHashMap<Vec<String>, u64>)>>,
+}
+
+impl ConstEvalProfile {
+    /// Records the steps taken by an evaluation of the global `name`.
+    pub fn record(&self, name: String, stacks: FxHashMap<Vec<String>, u64>) {
+        if !stacks.is_empty() {
+            self.evaluations.lock().push((name, stacks));
+        }
+    }
+
+    /// Writes the recorded steps as folded stacks, one line per stack. The name of the evaluated
+    /// global is the outermost frame, so that a flame graph shows each global separately.
+    // The lines are sorted before they are written.
+    #[allow(rustc::potential_query_instability)]
+    pub fn write(&self, w: &mut impl Write) -> io::Result<()> {
+        let mut lines: Vec<_> = self
+            .evaluations
+            .lock()
+            .iter()
+            .flat_map(|(name, stacks)| {
+                stacks.iter().map(move |(frames, &steps)| {
+                    let mut line = folded_frame(name);
+                    for frame in frames {
+                        line.push(';');
+                        line.push_str(&folded_frame(frame));
+                    }
+                    (line, steps)
+                })
+            })
+            .collect();
+        // Globals can be evaluated in parallel, and several times with different generic
+        // arguments, so merge the samples and sort them to get a deterministic output.
+        lines.sort();
+        lines.dedup_by(|(line, steps), (prev_line, prev_steps)| {
+            let same = line == prev_line;
+            if same {
+                *prev_steps += *steps;
+            }
+            same
+        });
+        for (line, steps) in lines {
+            writeln!(w, "{line} {steps}")?;
+        }
+        Ok(())
+    }
+}
+
+/// `;` separates the frames of a folded stack, so it cannot appear in the name of a frame.
+fn folded_frame(frame: &str) -> String {
+    frame.replace(';', ",")
+}
@@ -0,0 +1,87 @@
+//! Bookkeeping for `-Z item-cost-report`, which attributes compile time and
+//! code size to the items of the crate being compiled.
+//!
+//! The frontend part lives in [`ItemCosts`]: every query whose key names a
+//! `DefId` has its self time charged to that item. Queries without such a key
+//! are charged to the innermost enclosing query that has one. The backend
+//! reports per-function LLVM IR sizes and optimization times through
+//! [`BackendCosts`], keyed by symbol name, from its worker threads.
+
+use std::cell::Cell;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::Lock;
+use rustc_hir::def_id::DefId;
+
+thread_local! {
+    /// Time spent in nested item-keyed queries by the query currently
+    /// executing on this thread, to be subtracted from its own time.
+    static CHILD_TIME: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+/// The frontend part of `-Z item-cost-report`, see the module documentation.
+#[derive(Default)]
+pub struct ItemCosts {
+    /// Self time of the queries executed for each item, by query name.
+    query_times: Lock<FxHashMap<DefId, FxHashMap<&'static str, Duration>>>,
+}
+
+impl ItemCosts {
+    /// Runs `compute` for the query `query_name` keyed by `def_id`, charging
+    /// the time not spent in nested item-keyed queries to `def_id`.
+    pub fn time_query<R>(
+        &self,
+        def_id: DefId,
+        query_name: &'static str,
+        compute: impl FnOnce() -> R,
+    ) -> R {
+        let parent_child_time = CHILD_TIME.replace(Duration::ZERO);
+        let start = Instant::now();
+        let result = compute();
+        let elapsed = start.elapsed();
+        let self_time = elapsed.saturating_sub(CHILD_TIME.replace(parent_child_time + elapsed));
+
+        *self.query_times.lock().entry(def_id).or_default().entry(query_name).or_default() +=
+            self_time;
+        result
+    }
+
+    /// Takes the query times recorded so far.
+    pub fn take_query_times(&self) -> FxHashMap<DefId, FxHashMap<&'static str, Duration>> {
+        std::mem::take(&mut *self.query_times.lock())
+    }
+}
+
+/// What the codegen backend measured for a single function.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FunctionCosts {
+    /// Number of instructions in the function before optimization.
+    pub ir_instructions: usize,
+    /// Time the optimization passes spent on the function.
+    pub optimization_time: Duration,
+}
+
+/// Per-function costs reported by the codegen backend, keyed by symbol name.
+/// Cloning this shares the underlying map, so that it can be handed to the
+/// backend's worker threads.
+#[derive(Clone, Debug, Default)]
+pub struct BackendCosts(Arc<Mutex<FxHashMap<String, FunctionCosts>>>);
+
+impl BackendCosts {
+    pub fn record_ir_instructions(&self, symbol_name: &str, ir_instructions: usize) {
+        let mut costs = self.0.lock().unwrap();
+        costs.entry(symbol_name.to_owned()).or_default().ir_instructions += ir_instructions;
+    }
+
+    pub fn record_optimization_time(&self, symbol_name: &str, time: Duration) {
+        let mut costs = self.0.lock().unwrap();
+        costs.entry(symbol_name.to_owned()).or_default().optimization_time += time;
+    }
+
+    /// Takes the costs recorded so far.
+    pub fn take(&self) -> FxHashMap<String, FunctionCosts> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
@@ -1,7 +1,9 @@
 pub mod bug;
 pub mod call_kind;
 pub mod common;
+pub mod const_eval_profile;
 pub mod find_self_call;
+pub mod item_costs;
 
 pub use call_kind::{call_kind, CallDesugaringKind, CallKind};
 pub use find_self_call::find_self_call;
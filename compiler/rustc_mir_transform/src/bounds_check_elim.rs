@@ -0,0 +1,470 @@
+//! Bounds-check elimination.
+//!
+//! Indexing `slice[index]` is guarded by `assert(Lt(index, Len(*slice)))`. When the code already
+//! compared the same index with the same length, as in
+//! ```ignore (MIR)
+//! bb0: {
+//!     _3 = PtrMetadata(copy _2);
+//!     _4 = Lt(copy _1, copy _3);
+//!     switchInt(move _4) -> [0: bb2, otherwise: bb1];
+//! }
+//! bb1: {
+//!     _5 = Len((*_2));
+//!     _6 = Lt(copy _1, copy _5);
+//!     assert(move _6, "index out of bounds: ...") -> [success: bb3, unwind continue];
+//! }
+//! ```
+//! the assertion can never fail and is replaced by a `goto`.
+//!
+//! To find those, this pass runs a forward analysis that assigns a symbolic value to each
+//! tracked local, and collects the facts `a < b` between symbolic values that are known to
+//! hold: the ones established by the true edge of a `switchInt` on a comparison, and by the
+//! success edge of an `assert`. At join points, a local keeps its value only if it has the
+//! same value on all incoming edges, and only the facts that hold on all incoming edges are
+//! kept.
+//!
+//! The value of a local that is not known (eg. an argument, or a local that has different
+//! values on different incoming edges) is represented by the location at which it is read.
+//! When that location is executed again, e.g. on the next iteration of a loop, all the facts
+//! that mention the previous value are forgotten. On entry to a loop, the locals that are
+//! assigned in the loop are forgotten, so that their value is read in the loop, and named the
+//! same way on the first iteration and on the following ones.
+//!
+//! Values are also tracked through enum variants and structs with a single field, so that the
+//! index yielded by an inlined `Range::next`, i.e. `((_10 as Some).0: usize)`, is known to be
+//! the value that was compared with the end of the range.
+
+use rustc_data_structures::fx::FxIndexSet;
+use rustc_index::bit_set::{BitSet, GrowableBitSet};
+use rustc_index::newtype_index;
+use rustc_index::IndexVec;
+use rustc_middle::mir::visit::{NonUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+use rustc_mir_dataflow::impls::borrowed_locals;
+use rustc_target::abi::{FieldIdx, VariantIdx, FIRST_VARIANT};
+
+use crate::loops::natural_loops;
+
+// These limits are somewhat random guesses and have not been optimized.
+// If `tcx.sess.mir_opt_level() >= 4`, we ignore them (this can become very expensive).
+const BLOCK_LIMIT: usize = 500;
+const ITERATION_LIMIT: usize = 10;
+
+pub struct BoundsCheckElimination;
+
+impl<'tcx> MirPass<'tcx> for BoundsCheckElimination {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 2
+    }
+
+    #[instrument(level = "trace", skip(self, tcx, body))]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        debug!(def_id = ?body.source.def_id());
+
+        let has_bounds_checks = body.basic_blocks.iter().any(|data| {
+            matches!(
+                data.terminator().kind,
+                TerminatorKind::Assert { msg: box AssertKind::BoundsCheck { .. }, .. }
+            )
+        });
+        if !has_bounds_checks {
+            return;
+        }
+        let unlimited = tcx.sess.mir_opt_level() >= 4;
+        if !unlimited && body.basic_blocks.len() > BLOCK_LIMIT {
+            debug!("aborted bounds check elimination due to too many basic blocks");
+            return;
+        }
+
+        let mut analysis = Analysis {
+            tcx,
+            body,
+            borrowed: borrowed_locals(body),
+            values: FxIndexSet::default(),
+            entry_states: IndexVec::from_elem(None, &body.basic_blocks),
+        };
+        let iteration_limit = if unlimited { None } else { Some(ITERATION_LIMIT) };
+        if !analysis.compute_entry_states(iteration_limit) {
+            debug!("aborted bounds check elimination due to too many iterations");
+            return;
+        }
+
+        let mut redundant = Vec::new();
+        for (bb, data) in body.basic_blocks.iter_enumerated() {
+            let TerminatorKind::Assert {
+                ref cond,
+                expected: true,
+                msg: box AssertKind::BoundsCheck { .. },
+                target,
+                ..
+            } = data.terminator().kind
+            else {
+                continue;
+            };
+            let Some(mut state) = analysis.entry_states[bb].clone() else { continue };
+            analysis.apply_statements(bb, &mut state);
+            let loc = body.terminator_loc(bb);
+            if let Some(fact) = analysis.eval_condition(&mut state, cond, loc)
+                && state.less_than.contains(&fact)
+            {
+                redundant.push((bb, target));
+            }
+        }
+
+        for (bb, target) in redundant {
+            debug!(?bb, "removing bounds check");
+            body.basic_blocks_mut()[bb].terminator_mut().kind = TerminatorKind::Goto { target };
+        }
+    }
+}
+
+newtype_index! {
+    struct ValueIdx {}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Value<'tcx> {
+    /// The unknown value of a local, as read by the last execution of the statement or
+    /// terminator at this location.
+    Read(Location, Local),
+    /// The unknown value computed by the last execution of the assignment at this location.
+    Assigned(Location),
+    /// An integer constant.
+    Constant(Const<'tcx>),
+    /// The metadata of a pointer, i.e. the length of a slice.
+    Metadata(ValueIdx),
+    /// An enum variant or a struct with a single field, like `Some(value)`.
+    Variant(VariantIdx, ValueIdx),
+    /// The result of `Lt(a, b)`.
+    Lt(ValueIdx, ValueIdx),
+}
+
+#[derive(Clone, Debug)]
+struct State {
+    /// The value of each tracked local, if known.
+    locals: IndexVec<Local, Option<ValueIdx>>,
+    /// The pairs `(a, b)` such that `a < b` is known to hold.
+    less_than: FxIndexSet<(ValueIdx, ValueIdx)>,
+    /// A superset of the unknown values mentioned by `locals` and `less_than`.
+    unknowns: GrowableBitSet<ValueIdx>,
+}
+
+impl State {
+    /// Keeps only what holds both in `self` and in `other`. Returns whether `self` changed.
+    fn join(&mut self, other: &State) -> bool {
+        let mut changed = false;
+        for (value, other_value) in self.locals.iter_mut().zip(&other.locals) {
+            if value.is_some() && value != other_value {
+                *value = None;
+                changed = true;
+            }
+        }
+        let len = self.less_than.len();
+        self.less_than.retain(|fact| other.less_than.contains(fact));
+        for value in other.unknowns.iter() {
+            self.unknowns.insert(value);
+        }
+        changed || self.less_than.len() != len
+    }
+}
+
+struct Analysis<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    body: &'a Body<'tcx>,
+    /// Locals that may be modified through a pointer, whose value is not tracked.
+    borrowed: BitSet<Local>,
+    values: FxIndexSet<Value<'tcx>>,
+    entry_states: IndexVec<BasicBlock, Option<State>>,
+}
+
+impl<'a, 'tcx> Analysis<'a, 'tcx> {
+    /// Computes the state on entry to each block. Returns `false` if that takes more than
+    /// `iteration_limit` passes over the body.
+    fn compute_entry_states(&mut self, iteration_limit: Option<usize>) -> bool {
+        self.entry_states[START_BLOCK] = Some(State {
+            locals: IndexVec::from_elem(None, &self.body.local_decls),
+            less_than: FxIndexSet::default(),
+            unknowns: GrowableBitSet::new_empty(),
+        });
+
+        let body = self.body;
+        let mut loop_assigned = IndexVec::from_elem(None, &body.basic_blocks);
+        for lp in natural_loops(&body.basic_blocks) {
+            let mut assigned = AssignedLocals(BitSet::new_empty(body.local_decls.len()));
+            for bb in lp.blocks.iter() {
+                assigned.visit_basic_block_data(bb, &body.basic_blocks[bb]);
+            }
+            loop_assigned[lp.header] = Some(assigned.0);
+        }
+
+        // States only ever lose information when joined, so this terminates.
+        let mut changed = true;
+        let mut iterations = 0;
+        while changed {
+            if iteration_limit.is_some_and(|limit| iterations == limit) {
+                return false;
+            }
+            iterations += 1;
+            changed = false;
+            for &bb in body.basic_blocks.reverse_postorder() {
+                let Some(mut state) = self.entry_states[bb].clone() else { continue };
+                self.apply_statements(bb, &mut state);
+                for (target, fact) in self.apply_terminator(bb, &mut state) {
+                    let mut target_state = state.clone();
+                    if let Some(fact) = fact {
+                        target_state.less_than.insert(fact);
+                    }
+                    if let Some(assigned) = &loop_assigned[target] {
+                        for local in assigned.iter() {
+                            target_state.locals[local] = None;
+                        }
+                    }
+                    match &mut self.entry_states[target] {
+                        Some(entry) => changed |= entry.join(&target_state),
+                        entry => {
+                            *entry = Some(target_state);
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn apply_statements(&mut self, bb: BasicBlock, state: &mut State) {
+        let body = self.body;
+        for (statement_index, stmt) in body.basic_blocks[bb].statements.iter().enumerate() {
+            let loc = Location { block: bb, statement_index };
+            match stmt.kind {
+                StatementKind::Assign(box (place, ref rvalue))
+                    if let Some(local) = place.as_local() =>
+                {
+                    let value = self.eval_rvalue(state, rvalue, loc);
+                    if !self.borrowed.contains(local) {
+                        let value =
+                            value.unwrap_or_else(|| self.fresh(state, Value::Assigned(loc)));
+                        state.locals[local] = Some(value);
+                    }
+                }
+                StatementKind::StorageLive(local) | StatementKind::StorageDead(local) => {
+                    state.locals[local] = None;
+                }
+                _ => ClobberedLocals { state }.visit_statement(stmt, loc),
+            }
+        }
+    }
+
+    /// Returns the successors of `bb`, with the fact that holds when jumping to each of them.
+    fn apply_terminator(
+        &mut self,
+        bb: BasicBlock,
+        state: &mut State,
+    ) -> Vec<(BasicBlock, Option<(ValueIdx, ValueIdx)>)> {
+        let body = self.body;
+        let loc = body.terminator_loc(bb);
+        let terminator = body.basic_blocks[bb].terminator();
+        let successors = match terminator.kind {
+            TerminatorKind::SwitchInt { ref discr, ref targets } => {
+                let fact = self.eval_condition(state, discr, loc);
+                match targets.as_static_if() {
+                    Some((0, false_target, true_target)) if false_target != true_target => {
+                        vec![(false_target, None), (true_target, fact)]
+                    }
+                    Some((1, true_target, false_target)) if false_target != true_target => {
+                        vec![(true_target, fact), (false_target, None)]
+                    }
+                    _ => targets.all_targets().iter().map(|&target| (target, None)).collect(),
+                }
+            }
+            TerminatorKind::Assert { ref cond, expected: true, target, .. } => {
+                let fact = self.eval_condition(state, cond, loc);
+                terminator
+                    .successors()
+                    .map(|succ| (succ, if succ == target { fact } else { None }))
+                    .collect()
+            }
+            _ => terminator.successors().map(|succ| (succ, None)).collect(),
+        };
+        ClobberedLocals { state }.visit_terminator(terminator, loc);
+        successors
+    }
+
+    /// If `cond` evaluates to `Lt(a, b)`, returns the fact `(a, b)` that holds when it is true.
+    fn eval_condition(
+        &mut self,
+        state: &mut State,
+        cond: &Operand<'tcx>,
+        loc: Location,
+    ) -> Option<(ValueIdx, ValueIdx)> {
+        let value = self.eval_operand(state, cond, loc)?;
+        match self.values[value.as_usize()] {
+            Value::Lt(a, b) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    fn eval_rvalue(
+        &mut self,
+        state: &mut State,
+        rvalue: &Rvalue<'tcx>,
+        loc: Location,
+    ) -> Option<ValueIdx> {
+        let value = match *rvalue {
+            Rvalue::Use(ref operand) => return self.eval_operand(state, operand, loc),
+            // A reborrow has the same address and metadata as the original pointer.
+            Rvalue::Ref(_, _, place) | Rvalue::AddressOf(_, place) => {
+                let [PlaceElem::Deref] = place.projection[..] else { return None };
+                return self.read_local(state, place.local, loc);
+            }
+            Rvalue::Len(place) => {
+                let [PlaceElem::Deref] = place.projection[..] else { return None };
+                if !place.ty(self.body, self.tcx).ty.is_slice() {
+                    return None;
+                }
+                Value::Metadata(self.read_local(state, place.local, loc)?)
+            }
+            Rvalue::UnaryOp(UnOp::PtrMetadata, ref operand) => {
+                Value::Metadata(self.eval_operand(state, operand, loc)?)
+            }
+            Rvalue::Aggregate(box AggregateKind::Adt(_, variant, _, _, None), ref fields)
+                if fields.len() == 1 =>
+            {
+                let field = self.eval_operand(state, &fields[FieldIdx::ZERO], loc)?;
+                Value::Variant(variant, field)
+            }
+            Rvalue::BinaryOp(BinOp::Lt, box (ref lhs, ref rhs)) => {
+                let lhs = self.eval_operand(state, lhs, loc)?;
+                let rhs = self.eval_operand(state, rhs, loc)?;
+                Value::Lt(lhs, rhs)
+            }
+            Rvalue::BinaryOp(BinOp::Gt, box (ref lhs, ref rhs)) => {
+                let lhs = self.eval_operand(state, lhs, loc)?;
+                let rhs = self.eval_operand(state, rhs, loc)?;
+                Value::Lt(rhs, lhs)
+            }
+            _ => return None,
+        };
+        Some(self.intern(value))
+    }
+
+    fn eval_operand(
+        &mut self,
+        state: &mut State,
+        operand: &Operand<'tcx>,
+        loc: Location,
+    ) -> Option<ValueIdx> {
+        match *operand {
+            Operand::Copy(place) | Operand::Move(place) => self.eval_place(state, place, loc),
+            // Only integers, for which equal constants have equal values.
+            Operand::Constant(ref constant) if constant.ty().is_integral() => {
+                Some(self.intern(Value::Constant(constant.const_)))
+            }
+            Operand::Constant(_) => None,
+        }
+    }
+
+    /// Evaluates a local, or the field of a local that holds a [`Value::Variant`].
+    fn eval_place(
+        &mut self,
+        state: &mut State,
+        place: Place<'tcx>,
+        loc: Location,
+    ) -> Option<ValueIdx> {
+        let expected_variant = match place.projection[..] {
+            [] => return self.read_local(state, place.local, loc),
+            [PlaceElem::Field(field, _)] if field == FieldIdx::ZERO => FIRST_VARIANT,
+            [PlaceElem::Downcast(_, variant), PlaceElem::Field(field, _)]
+                if field == FieldIdx::ZERO =>
+            {
+                variant
+            }
+            _ => return None,
+        };
+        let value = self.read_local(state, place.local, loc)?;
+        match self.values[value.as_usize()] {
+            Value::Variant(variant, field) if variant == expected_variant => Some(field),
+            _ => None,
+        }
+    }
+
+    fn read_local(&mut self, state: &mut State, local: Local, loc: Location) -> Option<ValueIdx> {
+        if self.borrowed.contains(local) {
+            return None;
+        }
+        if let Some(value) = state.locals[local] {
+            return Some(value);
+        }
+        let value = self.fresh(state, Value::Read(loc, local));
+        state.locals[local] = Some(value);
+        Some(value)
+    }
+
+    fn intern(&mut self, value: Value<'tcx>) -> ValueIdx {
+        let (index, _) = self.values.insert_full(value);
+        ValueIdx::from_usize(index)
+    }
+
+    /// Interns a value that is recomputed by executing its location again, and forgets what was
+    /// known about its previous instance.
+    fn fresh(&mut self, state: &mut State, value: Value<'tcx>) -> ValueIdx {
+        let value = self.intern(value);
+        // Only scan the state if it may mention the previous instance of the value.
+        if state.unknowns.insert(value) {
+            return value;
+        }
+        for local_value in &mut state.locals {
+            if local_value.is_some_and(|v| self.mentions(v, value)) {
+                *local_value = None;
+            }
+        }
+        state.less_than.retain(|&(a, b)| !self.mentions(a, value) && !self.mentions(b, value));
+        value
+    }
+
+    fn mentions(&self, value: ValueIdx, target: ValueIdx) -> bool {
+        value == target
+            || match self.values[value.as_usize()] {
+                Value::Metadata(pointer) => self.mentions(pointer, target),
+                Value::Variant(_, field) => self.mentions(field, target),
+                Value::Lt(lhs, rhs) => self.mentions(lhs, target) || self.mentions(rhs, target),
+                Value::Read(..) | Value::Assigned(_) | Value::Constant(_) => false,
+            }
+    }
+}
+
+/// Forgets the value of every local that may be modified by a statement or terminator.
+struct ClobberedLocals<'a> {
+    state: &'a mut State,
+}
+
+impl<'tcx> Visitor<'tcx> for ClobberedLocals<'_> {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, _: Location) {
+        // Writing through a pointer doesn't change the pointer itself.
+        if context.is_mutating_use() && !place.is_indirect_first_projection() {
+            self.state.locals[place.local] = None;
+        }
+    }
+}
+
+/// Collects the locals that are assigned, or whose storage starts or ends.
+struct AssignedLocals(BitSet<Local>);
+
+impl<'tcx> Visitor<'tcx> for AssignedLocals {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, _: Location) {
+        if context.is_mutating_use() && !place.is_indirect_first_projection() {
+            self.0.insert(place.local);
+        }
+    }
+
+    fn visit_local(&mut self, local: Local, context: PlaceContext, _: Location) {
+        // Only reached for the operands of `StorageLive` and `StorageDead`, as places are not
+        // visited any further.
+        if let PlaceContext::NonUse(NonUseContext::StorageLive | NonUseContext::StorageDead) =
+            context
+        {
+            self.0.insert(local);
+        }
+    }
+}
@@ -22,7 +22,7 @@
 use hir::ConstContext;
 use required_consts::RequiredConstsVisitor;
 use rustc_const_eval::util;
-use rustc_data_structures::fx::FxIndexSet;
+use rustc_data_structures::fx::{FxHashSet, FxIndexSet};
 use rustc_data_structures::steal::Steal;
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
@@ -52,6 +52,7 @@
 mod add_moves_for_packed_drops;
 mod add_retag;
 mod add_subtyping_projections;
+mod bounds_check_elim;
 mod check_alignment;
 mod check_const_item_mutation;
 mod check_packed_ref;
@@ -82,7 +83,9 @@
 mod jump_threading;
 mod known_panics_lint;
 mod large_enums;
+mod licm;
 mod lint;
+mod loops;
 mod lower_intrinsics;
 mod lower_slice_len;
 mod match_branches;
@@ -596,6 +599,10 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             &dead_store_elimination::DeadStoreElimination::Initial,
             &gvn::GVN,
             &simplify::SimplifyLocals::AfterGVN,
+            // Move loop-invariant computations, such as slice lengths, out of loops, so that the
+            // bounds checks that use them can be recognized as redundant.
+            &licm::LoopInvariantCodeMotion,
+            &bounds_check_elim::BoundsCheckElimination,
             &dataflow_const_prop::DataflowConstProp,
             &single_use_consts::SingleUseConsts,
             &o1(simplify_branches::SimplifyConstCondition::AfterConstProp),
@@ -669,6 +676,37 @@ fn inner_optimized_mir(tcx: TyCtxt<'_>, did: LocalDefId) -> Body<'_> {
     body
 }
 
+/// Prepares an optimized MIR body that was rewritten outside of the MIR pipeline, e.g., by a
+/// StableMIR transform, to be used in place of the original one.
+///
+/// The `required_consts` and `mentioned_items` of the original body are kept, as they include
+/// those of inlined callees and of code that was optimized away, and the ones used by the new
+/// body are added. The body is then validated.
+pub fn finish_rewritten_optimized_mir<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    body: &mut Body<'tcx>,
+    when: String,
+) {
+    let mut required_consts = Vec::new();
+    RequiredConstsVisitor::new(&mut required_consts).visit_body(body);
+    for constant in required_consts {
+        if !body.required_consts.contains(&constant) {
+            body.required_consts.push(constant);
+        }
+    }
+
+    let original_items = std::mem::take(&mut body.mentioned_items);
+    mentioned_items::MentionedItems.run_pass(tcx, body);
+    let mut seen: FxHashSet<_> = body.mentioned_items.iter().map(|item| item.node).collect();
+    for item in original_items {
+        if seen.insert(item.node) {
+            body.mentioned_items.push(item);
+        }
+    }
+
+    pm::validate_body(tcx, body, when);
+}
+
 /// Fetch all the promoteds of an item and prepare their MIR bodies to be ready for
 /// constant evaluation once all generic parameters become known.
 fn promoted_mir(tcx: TyCtxt<'_>, def: LocalDefId) -> &IndexVec<Promoted, Body<'_>> {
@@ -0,0 +1,282 @@
+//! Loop-invariant code motion.
+//!
+//! This pass moves assignments whose value does not change between iterations of a loop into
+//! the loop preheader, so they are computed once instead of on every iteration. Only
+//! assignments to SSA locals of side-effect-free rvalues are considered: such an assignment
+//! can be executed speculatively, even if the loop body would not have reached it, and the
+//! single definition keeps dominating all the uses once it is moved before the loop.
+//!
+//! For example, in
+//! ```ignore (MIR)
+//! bb1: {
+//!     _5 = Mul(_1, const 3_u32);
+//!     _6 = Add(_3, _5);
+//!     switchInt(...) -> [0: bb2, otherwise: bb1];
+//! }
+//! ```
+//! `_5` only depends on `_1`, which is defined before the loop, so its assignment is moved to
+//! the block that jumps into `bb1`.
+
+use rustc_index::bit_set::BitSet;
+use rustc_middle::mir::visit::{MutVisitor, PlaceContext, Visitor};
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, TyCtxt};
+
+use crate::loops::{natural_loops, Loop};
+use crate::ssa::SsaLocals;
+
+pub struct LoopInvariantCodeMotion;
+
+impl<'tcx> MirPass<'tcx> for LoopInvariantCodeMotion {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 2
+    }
+
+    #[instrument(level = "trace", skip(self, tcx, body))]
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        debug!(def_id = ?body.source.def_id());
+        if !body.basic_blocks.is_cfg_cyclic() {
+            return;
+        }
+
+        let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+        let ssa = SsaLocals::new(tcx, body, param_env);
+
+        let mut hoisted = BitSet::new_empty(body.local_decls.len());
+        // Outer loops come first, so an assignment that is invariant in several nested loops
+        // is moved out of all of them at once.
+        for lp in natural_loops(&body.basic_blocks) {
+            hoist_loop_invariants(tcx, param_env, body, &ssa, &lp, &mut hoisted);
+        }
+
+        if hoisted.is_empty() {
+            return;
+        }
+
+        StorageRemover { tcx, hoisted }.visit_body_preserves_cfg(body);
+    }
+}
+
+fn hoist_loop_invariants<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    body: &mut Body<'tcx>,
+    ssa: &SsaLocals,
+    lp: &Loop,
+    hoisted: &mut BitSet<Local>,
+) {
+    let data = &body.basic_blocks[lp.header];
+    if lp.header == START_BLOCK || data.is_cleanup {
+        return;
+    }
+
+    // Blocks created for the preheaders of previous loops are never part of this loop.
+    let in_loop = |bb: BasicBlock| bb.index() < lp.blocks.domain_size() && lp.blocks.contains(bb);
+
+    let mut defined = DefinedLocals { in_loop: BitSet::new_empty(body.local_decls.len()) };
+    for bb in lp.blocks.iter() {
+        defined.visit_basic_block_data(bb, &body.basic_blocks[bb]);
+    }
+
+    let mut invariant = Invariant {
+        tcx,
+        param_env,
+        body,
+        ssa,
+        defined: &defined.in_loop,
+        hoisted_here: BitSet::new_empty(body.local_decls.len()),
+    };
+    let mut candidates = Vec::new();
+    // Visit in reverse postorder so that definitions are seen before their uses.
+    for &bb in body.basic_blocks.reverse_postorder() {
+        if !in_loop(bb) {
+            continue;
+        }
+        for (statement_index, stmt) in body.basic_blocks[bb].statements.iter().enumerate() {
+            let StatementKind::Assign(box (place, ref rvalue)) = stmt.kind else { continue };
+            let Some(local) = place.as_local() else { continue };
+            if ssa.is_ssa(local)
+                && !ssa.borrowed_locals().contains(local)
+                && invariant.is_invariant_rvalue(rvalue)
+            {
+                invariant.hoisted_here.insert(local);
+                candidates.push(Location { block: bb, statement_index });
+            }
+        }
+    }
+
+    if candidates.is_empty() {
+        return;
+    }
+    hoisted.union(&invariant.hoisted_here);
+
+    let preheader = preheader(body, lp, in_loop);
+    for loc in candidates {
+        let stmt = body.basic_blocks.as_mut_preserves_cfg()[loc.block].statements
+            [loc.statement_index]
+            .replace_nop();
+        debug!(?loc, ?stmt, ?preheader, "hoisting");
+        body.basic_blocks.as_mut_preserves_cfg()[preheader].statements.push(stmt);
+    }
+}
+
+/// Returns a block whose only successor is the loop header, and which is the only predecessor
+/// of the header outside of the loop. It is created if the loop doesn't already have one.
+fn preheader(body: &mut Body<'_>, lp: &Loop, in_loop: impl Fn(BasicBlock) -> bool) -> BasicBlock {
+    let entries: Vec<BasicBlock> = body.basic_blocks.predecessors()[lp.header]
+        .iter()
+        .copied()
+        .filter(|&bb| !in_loop(bb))
+        .collect();
+
+    if let [entry] = entries[..]
+        && let TerminatorKind::Goto { target } = body.basic_blocks[entry].terminator().kind
+    {
+        debug_assert_eq!(target, lp.header);
+        return entry;
+    }
+
+    let source_info = body.basic_blocks[lp.header].terminator().source_info;
+    let preheader = body.basic_blocks_mut().push(BasicBlockData::new(Some(Terminator {
+        source_info,
+        kind: TerminatorKind::Goto { target: lp.header },
+    })));
+    for entry in entries {
+        for target in body.basic_blocks_mut()[entry].terminator_mut().successors_mut() {
+            if *target == lp.header {
+                *target = preheader;
+            }
+        }
+    }
+    preheader
+}
+
+/// Collects the locals that are written to anywhere inside the loop.
+struct DefinedLocals {
+    in_loop: BitSet<Local>,
+}
+
+impl<'tcx> Visitor<'tcx> for DefinedLocals {
+    fn visit_local(&mut self, local: Local, context: PlaceContext, _: Location) {
+        if context.is_mutating_use() {
+            self.in_loop.insert(local);
+        }
+    }
+}
+
+struct Invariant<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    body: &'a Body<'tcx>,
+    ssa: &'a SsaLocals,
+    defined: &'a BitSet<Local>,
+    /// Locals whose assignment is moved out of this loop.
+    hoisted_here: BitSet<Local>,
+}
+
+impl<'tcx> Invariant<'_, 'tcx> {
+    /// Whether `rvalue` has the same value on every iteration of the loop, and can be evaluated
+    /// before the loop without panicking or having any other side effect.
+    fn is_invariant_rvalue(&self, rvalue: &Rvalue<'tcx>) -> bool {
+        match *rvalue {
+            Rvalue::BinaryOp(op, box (ref lhs, ref rhs)) => {
+                matches!(
+                    op,
+                    BinOp::Add
+                        | BinOp::AddWithOverflow
+                        | BinOp::Sub
+                        | BinOp::SubWithOverflow
+                        | BinOp::Mul
+                        | BinOp::MulWithOverflow
+                        | BinOp::BitAnd
+                        | BinOp::BitOr
+                        | BinOp::BitXor
+                        | BinOp::Eq
+                        | BinOp::Ne
+                        | BinOp::Lt
+                        | BinOp::Le
+                        | BinOp::Gt
+                        | BinOp::Ge
+                ) && self.is_invariant_operand(lhs)
+                    && self.is_invariant_operand(rhs)
+            }
+            Rvalue::UnaryOp(UnOp::Not | UnOp::Neg | UnOp::PtrMetadata, ref operand) => {
+                self.is_invariant_operand(operand)
+            }
+            Rvalue::Cast(
+                CastKind::IntToInt
+                | CastKind::IntToFloat
+                | CastKind::FloatToInt
+                | CastKind::FloatToFloat
+                | CastKind::PtrToPtr,
+                ref operand,
+                _,
+            ) => self.is_invariant_operand(operand),
+            // The length of a slice behind a reference cannot change.
+            Rvalue::Len(place) => {
+                if let [PlaceElem::Deref] = place.projection[..]
+                    && self.body.local_decls[place.local].ty.is_ref()
+                {
+                    self.is_invariant_local(place.local)
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        }
+    }
+
+    fn is_invariant_operand(&self, operand: &Operand<'tcx>) -> bool {
+        match *operand {
+            Operand::Constant(_) => true,
+            Operand::Copy(place) => place.as_local().is_some_and(|l| self.is_invariant_local(l)),
+            // Evaluating the operand once instead of on every iteration is only fine if it
+            // doesn't actually move anything.
+            Operand::Move(place) => place.as_local().is_some_and(|l| {
+                self.is_invariant_local(l)
+                    && self.body.local_decls[l].ty.is_copy_modulo_regions(self.tcx, self.param_env)
+            }),
+        }
+    }
+
+    /// An SSA local that is not assigned inside the loop is defined before it, and its
+    /// definition dominates the loop header.
+    fn is_invariant_local(&self, local: Local) -> bool {
+        self.ssa.is_ssa(local)
+            && (!self.defined.contains(local) || self.hoisted_here.contains(local))
+    }
+}
+
+/// The hoisted locals are now live from the preheader on, which may be outside of their original
+/// storage range, and they are no longer reassigned on every iteration so they must not be moved
+/// out of either.
+struct StorageRemover<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    hoisted: BitSet<Local>,
+}
+
+impl<'tcx> MutVisitor<'tcx> for StorageRemover<'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_operand(&mut self, operand: &mut Operand<'tcx>, _: Location) {
+        if let Operand::Move(place) = *operand
+            && !place.is_indirect_first_projection()
+            && self.hoisted.contains(place.local)
+        {
+            *operand = Operand::Copy(place);
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'tcx>, loc: Location) {
+        match stmt.kind {
+            StatementKind::StorageLive(l) | StatementKind::StorageDead(l)
+                if self.hoisted.contains(l) =>
+            {
+                stmt.make_nop()
+            }
+            _ => self.super_statement(stmt, loc),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Natural loop detection on the MIR control-flow graph.
+//!
+//! A natural loop is identified by a back edge `latch -> header` where `header` dominates
+//! `latch`. The loop body is the header together with every block that can reach a latch
+//! without going through the header. Loops that share a header are merged into one.
+
+use rustc_index::bit_set::BitSet;
+use rustc_middle::mir::{BasicBlock, BasicBlocks};
+
+pub(crate) struct Loop {
+    /// The single entry point of the loop, which dominates every block in `blocks`.
+    pub header: BasicBlock,
+    /// All the blocks in the loop, including `header`.
+    pub blocks: BitSet<BasicBlock>,
+}
+
+/// Computes the natural loops of the body, in reverse postorder of their headers. This means
+/// that an outer loop always comes before the loops nested inside it.
+pub(crate) fn natural_loops(basic_blocks: &BasicBlocks<'_>) -> Vec<Loop> {
+    let dominators = basic_blocks.dominators();
+    let predecessors = basic_blocks.predecessors();
+
+    let mut loops = Vec::new();
+    for &header in basic_blocks.reverse_postorder() {
+        let mut blocks = BitSet::new_empty(basic_blocks.len());
+        let mut stack = Vec::new();
+        for &pred in &predecessors[header] {
+            // `dominates` requires both nodes to be reachable.
+            if dominators.is_reachable(pred) && dominators.dominates(header, pred) {
+                stack.push(pred);
+            }
+        }
+        if stack.is_empty() {
+            continue;
+        }
+
+        blocks.insert(header);
+        while let Some(bb) = stack.pop() {
+            if blocks.insert(bb) {
+                stack.extend(
+                    predecessors[bb].iter().copied().filter(|&p| dominators.is_reachable(p)),
+                );
+            }
+        }
+        loops.push(Loop { header, blocks });
+    }
+    loops
+}
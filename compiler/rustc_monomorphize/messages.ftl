@@ -1,6 +1,9 @@
 monomorphize_couldnt_dump_mono_stats =
     unexpected error occurred while dumping monomorphization stats: {$error}
 
+monomorphize_couldnt_write_item_cost_report =
+    unexpected error occurred while writing the item cost report: {$error}
+
 monomorphize_encountered_error_while_instantiating =
     the above error was encountered while instantiating `{$formatted_item}`
 
@@ -229,7 +229,7 @@
     TypeVisitableExt, VtblEntry,
 };
 use rustc_middle::{bug, span_bug};
-use rustc_session::config::EntryFnType;
+use rustc_session::config::{EntryFnType, PrintTypeSizesFormat};
 use rustc_session::Limit;
 use rustc_span::source_map::{dummy_spanned, respan, Spanned};
 use rustc_span::symbol::{sym, Ident};
@@ -624,6 +624,23 @@ fn monomorphize<T>(&self, value: T) -> T
         )
     }
 
+    /// Records the spans of the locals of the body that hold a value of a type whose layout
+    /// `-Z print-type-sizes-format=json` prints, so that the output can tell where each type
+    /// is instantiated.
+    fn record_type_instantiations(&self) {
+        let source_map = self.tcx.sess.source_map();
+        for decl in &self.body.local_decls {
+            let ty = self.monomorphize(decl.ty);
+            if !matches!(ty.kind(), ty::Adt(..) | ty::Closure(..) | ty::Coroutine(..)) {
+                continue;
+            }
+            self.tcx.sess.code_stats.record_type_instantiation(
+                with_no_trimmed_paths!(ty.to_string()),
+                source_map.span_to_embeddable_string(decl.source_info.span),
+            );
+        }
+    }
+
     /// Evaluates a *not yet monomorphized* constant.
     fn eval_constant(
         &mut self,
@@ -1225,6 +1242,11 @@ fn collect_items_of_instance<'tcx>(
         for (bb, data) in traversal::mono_reachable(body, tcx, instance) {
             collector.visit_basic_block_data(bb, data)
         }
+
+        let opts = &tcx.sess.opts.unstable_opts;
+        if opts.print_type_sizes && opts.print_type_sizes_format == PrintTypeSizesFormat::Json {
+            collector.record_type_instantiations();
+        }
     }
 
     // Always visit all `required_consts`, so that we evaluate them and abort compilation if any of
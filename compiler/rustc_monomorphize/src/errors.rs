@@ -73,6 +73,12 @@ pub struct CouldntDumpMonoStats {
     pub error: String,
 }
 
+#[derive(Diagnostic)]
+#[diag(monomorphize_couldnt_write_item_cost_report)]
+pub struct CouldntWriteItemCostReport {
+    pub error: String,
+}
+
 #[derive(Diagnostic)]
 #[diag(monomorphize_encountered_error_while_instantiating)]
 pub struct EncounteredErrorWhileInstantiating {
@@ -0,0 +1,183 @@
+//! The report written by `-Z item-cost-report`.
+//!
+//! It combines the query times recorded in [`ItemCosts`], the mono items the
+//! partitioning produced for each item, and the per-function costs reported by
+//! the codegen backend. Everything is aggregated per `DefId`, so that all
+//! instantiations of a generic function are accounted to that function, with
+//! the costs of the individual mono items listed for each item.
+//!
+//! [`ItemCosts`]: rustc_middle::util::item_costs::ItemCosts
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufWriter;
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_middle::mir::mono::MonoItem;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::{InstanceKind, TyCtxt};
+use rustc_middle::util::item_costs::FunctionCosts;
+use rustc_session::config::{ItemCostReportFormat, OutputFilenames};
+use rustc_session::Session;
+use rustc_span::symbol::Symbol;
+
+use crate::errors::CouldntWriteItemCostReport;
+
+/// The costs of a single item, summed over all of its instantiations.
+#[derive(serde::Serialize)]
+struct ItemCost {
+    name: String,
+    /// Self time of the queries run for the item.
+    query_time_ns: u64,
+    /// The query times making up `query_time_ns`, by query name.
+    queries: BTreeMap<&'static str, u64>,
+    /// Number of mono items instantiated from the item.
+    instantiations: usize,
+    /// Number of MIR statements codegened for the item.
+    mir_statements: usize,
+    /// Number of LLVM IR instructions emitted for the item, before optimization.
+    llvm_ir_instructions: usize,
+    /// Time spent in LLVM function, loop and CGSCC passes on the item.
+    llvm_optimization_time_ns: u64,
+    /// The costs of each mono item instantiated from the item.
+    mono_items: Vec<MonoItemCost>,
+}
+
+/// The codegen costs of a single mono item.
+#[derive(serde::Serialize)]
+struct MonoItemCost {
+    name: String,
+    symbol_name: String,
+    mir_statements: usize,
+    llvm_ir_instructions: usize,
+    llvm_optimization_time_ns: u64,
+}
+
+pub struct ItemCostReport {
+    crate_name: Symbol,
+    format: ItemCostReportFormat,
+    items: FxIndexMap<DefId, ItemCost>,
+}
+
+impl ItemCostReport {
+    /// Collects the frontend costs of all items. This has to be called after
+    /// all codegen units have been codegened, so that the queries run during
+    /// codegen are accounted for.
+    pub fn new(tcx: TyCtxt<'_>) -> Option<Self> {
+        let format = tcx.sess.opts.unstable_opts.item_cost_report?;
+        let item_costs = tcx.item_costs.as_ref()?;
+        let mut report = ItemCostReport {
+            crate_name: tcx.crate_name(LOCAL_CRATE),
+            format,
+            items: Default::default(),
+        };
+
+        // The items are sorted by `write`.
+        #[allow(rustc::potential_query_instability)]
+        for (def_id, query_times) in item_costs.take_query_times() {
+            let item = report.item(tcx, def_id);
+            #[allow(rustc::potential_query_instability)]
+            for (query_name, time) in query_times {
+                let time = time.as_nanos() as u64;
+                item.query_time_ns += time;
+                *item.queries.entry(query_name).or_default() += time;
+            }
+        }
+
+        // Inlined items may be instantiated in several codegen units, but are
+        // only counted once.
+        let mut seen = FxHashSet::default();
+        let (_, codegen_units) = tcx.collect_and_partition_mono_items(());
+        for cgu in codegen_units {
+            #[allow(rustc::potential_query_instability)]
+            for &mono_item in cgu.items().keys() {
+                if !seen.insert(mono_item) {
+                    continue;
+                }
+                let mir_statements = match mono_item {
+                    MonoItem::Fn(instance)
+                        if matches!(
+                            instance.def,
+                            InstanceKind::Item(..) | InstanceKind::DropGlue(..)
+                        ) =>
+                    {
+                        let body = tcx.instance_mir(instance.def);
+                        body.basic_blocks.iter().map(|bb| bb.statements.len()).sum()
+                    }
+                    _ => 0,
+                };
+                let mono_item_cost = MonoItemCost {
+                    name: with_no_trimmed_paths!(mono_item.to_string()),
+                    symbol_name: mono_item.symbol_name(tcx).name.to_owned(),
+                    mir_statements,
+                    llvm_ir_instructions: 0,
+                    llvm_optimization_time_ns: 0,
+                };
+                let item = report.item(tcx, mono_item.def_id());
+                item.instantiations += 1;
+                item.mir_statements += mir_statements;
+                item.mono_items.push(mono_item_cost);
+            }
+        }
+
+        Some(report)
+    }
+
+    fn item(&mut self, tcx: TyCtxt<'_>, def_id: DefId) -> &mut ItemCost {
+        self.items.entry(def_id).or_insert_with(|| ItemCost {
+            name: with_no_trimmed_paths!(tcx.def_path_str(def_id)),
+            query_time_ns: 0,
+            queries: BTreeMap::new(),
+            instantiations: 0,
+            mir_statements: 0,
+            llvm_ir_instructions: 0,
+            llvm_optimization_time_ns: 0,
+            mono_items: Vec::new(),
+        })
+    }
+
+    /// Adds the costs the codegen backend measured for the symbols of each item.
+    pub fn add_backend_costs(&mut self, costs: &FxHashMap<String, FunctionCosts>) {
+        for item in self.items.values_mut() {
+            for mono_item in &mut item.mono_items {
+                let Some(function_costs) = costs.get(&mono_item.symbol_name) else { continue };
+                let optimization_time_ns = function_costs.optimization_time.as_nanos() as u64;
+                mono_item.llvm_ir_instructions += function_costs.ir_instructions;
+                mono_item.llvm_optimization_time_ns += optimization_time_ns;
+                item.llvm_ir_instructions += function_costs.ir_instructions;
+                item.llvm_optimization_time_ns += optimization_time_ns;
+            }
+        }
+    }
+
+    /// Writes the report to `{crate_name}.item-costs.{ext}` in the output
+    /// directory, with the most expensive items first.
+    pub fn write(self, sess: &Session, outputs: &OutputFilenames) {
+        let mut items: Vec<_> = self.items.into_values().collect();
+        for item in &mut items {
+            item.mono_items.sort_by(|a, b| {
+                let cost = |mono_item: &MonoItemCost| mono_item.llvm_optimization_time_ns;
+                cost(b).cmp(&cost(a)).then_with(|| a.name.cmp(&b.name))
+            });
+        }
+        items.sort_by(|a, b| {
+            let cost = |item: &ItemCost| item.query_time_ns + item.llvm_optimization_time_ns;
+            cost(b).cmp(&cost(a)).then_with(|| a.name.cmp(&b.name))
+        });
+
+        let filename = format!("{}.item-costs.{}", self.crate_name, self.format.extension());
+        let path = outputs.out_directory.join(filename);
+        let result = File::create(&path).map_err(|err| err.to_string()).and_then(|file| {
+            let file = BufWriter::new(file);
+            match self.format {
+                ItemCostReportFormat::Json => {
+                    serde_json::to_writer(file, &items).map_err(|err| err.to_string())
+                }
+            }
+        });
+        if let Err(error) = result {
+            sess.dcx().emit_fatal(CouldntWriteItemCostReport { error });
+        }
+    }
+}
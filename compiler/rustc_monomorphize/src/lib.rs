@@ -17,6 +17,7 @@
 
 mod collector;
 mod errors;
+pub mod item_costs;
 mod partitioning;
 mod polymorphize;
 mod util;
@@ -34,6 +34,7 @@
 use rustc_span::symbol::{kw, sym, Symbol};
 use rustc_span::{BytePos, Span, DUMMY_SP};
 use rustc_target::spec::abi::Abi;
+use rustc_trait_selection::error_reporting::on_type::on_type_attr_enabled;
 use rustc_trait_selection::error_reporting::traits::TypeErrCtxtExt;
 use rustc_trait_selection::infer::{TyCtxtInferExt, ValuePairs};
 use rustc_trait_selection::traits::ObligationCtxt;
@@ -45,6 +46,12 @@
 #[diag(passes_diagnostic_diagnostic_on_unimplemented_only_for_traits)]
 struct DiagnosticOnUnimplementedOnlyForTraits;
 
+#[derive(LintDiagnostic)]
+#[diag(passes_diagnostic_diagnostic_on_type_only_for_adts)]
+struct DiagnosticOnTypeOnlyForAdts {
+    attr_name: Symbol,
+}
+
 fn target_from_impl_item<'tcx>(tcx: TyCtxt<'tcx>, impl_item: &hir::ImplItem<'_>) -> Target {
     match impl_item.kind {
         hir::ImplItemKind::Const(..) => Target::AssocConst,
@@ -121,6 +128,15 @@ fn check_attributes(
                 [sym::diagnostic, sym::on_unimplemented] => {
                     self.check_diagnostic_on_unimplemented(attr.span, hir_id, target)
                 }
+                [sym::diagnostic, sym::on_move] => {
+                    self.check_diagnostic_on_type(sym::on_move, attr.span, hir_id, target)
+                }
+                [sym::diagnostic, sym::on_type_mismatch] => {
+                    self.check_diagnostic_on_type(sym::on_type_mismatch, attr.span, hir_id, target)
+                }
+                [sym::diagnostic, sym::on_missing_method] => {
+                    self.check_diagnostic_on_type(sym::on_missing_method, attr.span, hir_id, target)
+                }
                 [sym::inline] => self.check_inline(hir_id, attr, span, target),
                 [sym::coverage] => self.check_coverage(attr, span, target),
                 [sym::non_exhaustive] => self.check_non_exhaustive(hir_id, attr, span, target),
@@ -326,6 +342,29 @@ fn check_diagnostic_on_unimplemented(
         true
     }
 
+    /// Checks if `#[diagnostic::on_move]`, `#[diagnostic::on_type_mismatch]` or
+    /// `#[diagnostic::on_missing_method]` is applied to a struct, enum or union definition.
+    /// Without its feature gate, the attribute has already been linted as unknown.
+    fn check_diagnostic_on_type(
+        &self,
+        attr_name: Symbol,
+        attr_span: Span,
+        hir_id: HirId,
+        target: Target,
+    ) -> bool {
+        if on_type_attr_enabled(self.tcx, attr_name)
+            && !matches!(target, Target::Struct | Target::Enum | Target::Union)
+        {
+            self.tcx.emit_node_span_lint(
+                UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+                hir_id,
+                attr_span,
+                DiagnosticOnTypeOnlyForAdts { attr_name },
+            );
+        }
+        true
+    }
+
     /// Checks if an `#[inline]` is applied to a function or a closure. Returns `true` if valid.
     fn check_inline(&self, hir_id: HirId, attr: &Attribute, span: Span, target: Target) -> bool {
         match target {
@@ -548,6 +548,23 @@ pub fn __rust_begin_short_backtrace<F, T>(f: F) -> T
     result
 }
 
+/// Charges the time spent computing a query to the item named by its key, if
+/// `-Z item-cost-report` is enabled.
+#[inline(always)]
+pub(crate) fn with_item_cost<'tcx, K: Key, R>(
+    tcx: TyCtxt<'tcx>,
+    query_name: &'static str,
+    key: &K,
+    compute: impl FnOnce() -> R,
+) -> R {
+    if let Some(item_costs) = &tcx.item_costs {
+        if let Some(def_id) = key.key_as_def_id() {
+            return item_costs.time_query(def_id, query_name, compute);
+        }
+    }
+    compute()
+}
+
 // NOTE: `$V` isn't used here, but we still need to match on it so it can be passed to other macros
 // invoked by `rustc_query_append`.
 macro_rules! define_queries {
@@ -615,16 +632,18 @@ pub fn dynamic_query<'tcx>() -> DynamicQuery<'tcx, queries::$name::Storage<'tcx>
                     compute: |tcx, key| {
                         #[cfg(debug_assertions)]
                         let _guard = tracing::span!(tracing::Level::TRACE, stringify!($name), ?key).entered();
-                        __rust_begin_short_backtrace(||
-                            queries::$name::provided_to_erased(
-                                tcx,
-                                {
-                                    let ret = call_provider!([$($modifiers)*][tcx, $name, key]);
-                                    rustc_middle::ty::print::with_reduced_queries!({
-                                        tracing::trace!(?ret);
-                                    });
-                                    ret
-                                }
+                        $crate::plumbing::with_item_cost(tcx, stringify!($name), &key, ||
+                            __rust_begin_short_backtrace(||
+                                queries::$name::provided_to_erased(
+                                    tcx,
+                                    {
+                                        let ret = call_provider!([$($modifiers)*][tcx, $name, key]);
+                                        rustc_middle::ty::print::with_reduced_queries!({
+                                            tracing::trace!(?ret);
+                                        });
+                                        ret
+                                    }
+                                )
                             )
                         )
                     },
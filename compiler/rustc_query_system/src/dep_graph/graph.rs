@@ -962,9 +962,17 @@ pub fn exec_cache_promotions<Tcx: DepContext>(&self, tcx: Tcx) {
 
     pub fn print_incremental_info(&self) {
         if let Some(data) = &self.data {
+            let green_count = data
+                .colors
+                .values
+                .indices()
+                .filter(|&prev_index| data.colors.get(prev_index).is_some_and(|c| c.is_green()))
+                .count();
             data.current.encoder.print_incremental_info(
                 data.current.total_read_count.load(Ordering::Relaxed),
                 data.current.total_duplicate_read_count.load(Ordering::Relaxed),
+                green_count,
+                data.previous.node_count(),
             )
         }
     }
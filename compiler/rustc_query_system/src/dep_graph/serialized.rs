@@ -646,6 +646,8 @@ pub(crate) fn print_incremental_info(
         &self,
         total_read_count: u64,
         total_duplicate_read_count: u64,
+        green_node_count: usize,
+        prev_node_count: usize,
     ) {
         let mut status = self.status.lock();
         let status = status.as_mut().unwrap();
@@ -663,6 +665,14 @@ pub(crate) fn print_incremental_info(
             eprintln!("[incremental]");
             eprintln!("[incremental] Total Node Count: {}", status.total_node_count);
             eprintln!("[incremental] Total Edge Count: {}", status.total_edge_count);
+            if prev_node_count > 0 {
+                // Nodes that were marked green, i.e. reused from the previous session.
+                let reuse_ratio = (100.0 * (green_node_count as f64)) / (prev_node_count as f64);
+                eprintln!(
+                    "[incremental] Reused Node Count: {green_node_count} of {prev_node_count} \
+                     ({reuse_ratio:.1}%)"
+                );
+            }
 
             if cfg!(debug_assertions) {
                 eprintln!("[incremental] Total Edge Reads: {total_read_count}");
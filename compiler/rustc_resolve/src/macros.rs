@@ -672,14 +672,17 @@ fn smart_resolve_macro_path(
         if res == Res::NonMacroAttr(NonMacroAttrKind::Tool)
             && let [namespace, attribute, ..] = &*path.segments
             && namespace.ident.name == sym::diagnostic
-            && !(attribute.ident.name == sym::on_unimplemented
-                || (attribute.ident.name == sym::do_not_recommend
-                    && self.tcx.features().do_not_recommend))
+            && let known_attributes = self.known_diagnostic_attributes()
+            && !known_attributes.contains(&attribute.ident.name)
         {
-            let distance =
-                edit_distance(attribute.ident.name.as_str(), sym::on_unimplemented.as_str(), 5);
-
-            let typo_name = distance.map(|_| sym::on_unimplemented);
+            let typo_name = known_attributes
+                .into_iter()
+                .filter_map(|name| {
+                    edit_distance(attribute.ident.name.as_str(), name.as_str(), 5)
+                        .map(|distance| (distance, name))
+                })
+                .min_by_key(|&(distance, _)| distance)
+                .map(|(_, name)| name);
 
             self.tcx.sess.psess.buffer_lint(
                 UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
@@ -692,6 +695,21 @@ fn smart_resolve_macro_path(
         Ok((ext, res))
     }
 
+    /// The attributes of the `diagnostic` namespace that are available in this crate.
+    fn known_diagnostic_attributes(&self) -> Vec<Symbol> {
+        let features = self.tcx.features();
+        [
+            (sym::on_unimplemented, true),
+            (sym::do_not_recommend, features.do_not_recommend),
+            (sym::on_move, features.diagnostic_on_move),
+            (sym::on_type_mismatch, features.diagnostic_on_type_mismatch),
+            (sym::on_missing_method, features.diagnostic_on_missing_method),
+        ]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect()
+    }
+
     pub(crate) fn resolve_macro_path(
         &mut self,
         path: &ast::Path,
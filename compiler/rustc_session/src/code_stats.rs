@@ -1,8 +1,10 @@
+use crate::config::PrintTypeSizesFormat;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::sync::Lock;
 use rustc_span::def_id::DefId;
 use rustc_span::Symbol;
 use rustc_target::abi::{Align, Size};
+use serde::Serialize;
 use std::cmp;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -44,6 +46,8 @@ pub struct FieldInfo {
     pub offset: u64,
     pub size: u64,
     pub align: u64,
+    /// Type of this field.
+    pub ty: Symbol,
     /// Name of the type of this field.
     /// Present only if the creator thought that this would be important for identifying the field,
     /// typically because the field name is uninformative.
@@ -59,6 +63,33 @@ pub enum DataTypeKind {
     Coroutine,
 }
 
+impl std::fmt::Display for DataTypeKind {
+    fn fmt(&self, w: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataTypeKind::Struct => write!(w, "struct"),
+            DataTypeKind::Union => write!(w, "union"),
+            DataTypeKind::Enum => write!(w, "enum"),
+            DataTypeKind::Closure => write!(w, "closure"),
+            DataTypeKind::Coroutine => write!(w, "coroutine"),
+        }
+    }
+}
+
+/// The largest niche of a type, i.e. the invalid values of one of its scalars
+/// that enclosing enums can use to encode their discriminant.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize)]
+pub struct NicheInfo {
+    pub offset: u64,
+    pub size: u64,
+    /// Start of the valid range of the scalar, inclusive.
+    pub valid_range_start: u128,
+    /// End of the valid range of the scalar, inclusive. This is smaller than
+    /// the start if the range wraps around.
+    pub valid_range_end: u128,
+    /// Number of invalid values.
+    pub available: u128,
+}
+
 #[derive(PartialEq, Eq, Hash, Debug)]
 pub struct TypeSizeInfo {
     pub kind: DataTypeKind,
@@ -67,6 +98,7 @@ pub struct TypeSizeInfo {
     pub overall_size: u64,
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
+    pub niche: Option<NicheInfo>,
     pub variants: Vec<VariantInfo>,
 }
 
@@ -93,6 +125,9 @@ pub struct VTableSizeInfo {
 pub struct CodeStats {
     type_sizes: Lock<FxHashSet<TypeSizeInfo>>,
     vtable_sizes: Lock<FxHashMap<DefId, VTableSizeInfo>>,
+    /// Spans of the locals of each type, keyed by type description, as
+    /// recorded during monomorphization.
+    type_instantiations: Lock<FxHashMap<String, FxHashSet<String>>>,
 }
 
 impl CodeStats {
@@ -104,6 +139,7 @@ pub fn record_type_size<S: ToString>(
         overall_size: Size,
         packed: bool,
         opt_discr_size: Option<Size>,
+        niche: Option<NicheInfo>,
         mut variants: Vec<VariantInfo>,
     ) {
         // Sort variants so the largest ones are shown first. A stable sort is
@@ -121,11 +157,18 @@ pub fn record_type_size<S: ToString>(
             overall_size: overall_size.bytes(),
             packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
+            niche,
             variants,
         };
         self.type_sizes.borrow_mut().insert(info);
     }
 
+    /// Records that a value of the type described by `type_desc` is created
+    /// at `site`, for the JSON output of `-Z print-type-sizes`.
+    pub fn record_type_instantiation(&self, type_desc: String, site: String) {
+        self.type_instantiations.lock().entry(type_desc).or_default().insert(site);
+    }
+
     pub fn record_vtable_size(&self, trait_did: DefId, trait_name: &str, info: VTableSizeInfo) {
         let prev = self.vtable_sizes.lock().insert(trait_did, info);
         assert!(
@@ -134,8 +177,9 @@ pub fn record_vtable_size(&self, trait_did: DefId, trait_name: &str, info: VTabl
         );
     }
 
-    pub fn print_type_sizes(&self) {
+    pub fn print_type_sizes(&self, format: PrintTypeSizesFormat) {
         let type_sizes = self.type_sizes.borrow();
+        let type_instantiations = self.type_instantiations.borrow();
         // We will soon sort, so the initial order does not matter.
         #[allow(rustc::potential_query_instability)]
         let mut sorted: Vec<_> = type_sizes.iter().collect();
@@ -145,6 +189,12 @@ pub fn print_type_sizes(&self) {
         sorted.sort_by_key(|info| (cmp::Reverse(info.overall_size), &info.type_description));
 
         for info in sorted {
+            if let PrintTypeSizesFormat::Json = format {
+                let instantiations = type_instantiations.get(&info.type_description);
+                println!("{}", type_size_json(info, instantiations));
+                continue;
+            }
+
             let TypeSizeInfo { type_description, overall_size, align, kind, variants, .. } = info;
             println!(
                 "print-type-size type: `{type_description}`: {overall_size} bytes, alignment: {align} bytes"
@@ -196,7 +246,7 @@ pub fn print_type_sizes(&self) {
                 fields.sort_by_key(|f| (f.offset, f.size));
 
                 for field in fields {
-                    let FieldInfo { kind, ref name, offset, size, align, type_name } = field;
+                    let FieldInfo { kind, ref name, offset, size, align, ty: _, type_name } = field;
 
                     if offset > min_offset {
                         let pad = offset - min_offset;
@@ -267,3 +317,117 @@ pub fn print_vtable_sizes(&self, crate_name: Symbol) {
         }
     }
 }
+
+#[derive(Serialize)]
+struct TypeSizeJson<'a> {
+    #[serde(rename = "type")]
+    ty: &'a str,
+    kind: String,
+    /// Where values of the type are created, sorted.
+    instantiations: Vec<&'a str>,
+    size: u64,
+    align: u64,
+    packed: bool,
+    discriminant_size: Option<u64>,
+    niche: Option<NicheInfo>,
+    variants: Vec<VariantJson<'a>>,
+    end_padding: u64,
+}
+
+#[derive(Serialize)]
+struct VariantJson<'a> {
+    name: Option<&'a str>,
+    size: u64,
+    align: u64,
+    fields: Vec<FieldJson<'a>>,
+    padding: Vec<PaddingJson>,
+}
+
+#[derive(Serialize)]
+struct FieldJson<'a> {
+    kind: String,
+    name: &'a str,
+    offset: u64,
+    size: u64,
+    align: u64,
+    #[serde(rename = "type")]
+    ty: &'a str,
+}
+
+#[derive(Serialize)]
+struct PaddingJson {
+    offset: u64,
+    size: u64,
+}
+
+/// Describes the layout of a type as a single line of JSON for
+/// `-Z print-type-sizes-format=json`. Unlike the text output, this makes the
+/// padding between fields explicit and includes the niche of the type.
+fn type_size_json(info: &TypeSizeInfo, instantiations: Option<&FxHashSet<String>>) -> String {
+    let discr_size = info.opt_discr_size.unwrap_or(0);
+    let mut max_variant_size = discr_size;
+
+    let variants: Vec<_> = info
+        .variants
+        .iter()
+        .map(|variant| {
+            max_variant_size = cmp::max(max_variant_size, variant.size);
+
+            // As for the text output, fields are listed by increasing offset, with
+            // zero-sized fields first.
+            let mut fields: Vec<_> = variant.fields.iter().collect();
+            fields.sort_by_key(|f| (f.offset, f.size));
+            let mut min_offset = discr_size;
+            let mut padding = Vec::new();
+            for field in &fields {
+                if field.offset > min_offset {
+                    padding
+                        .push(PaddingJson { offset: min_offset, size: field.offset - min_offset });
+                }
+                min_offset = field.offset + field.size;
+            }
+
+            VariantJson {
+                name: variant.name.as_ref().map(|name| name.as_str()),
+                size: variant.size,
+                align: variant.align,
+                fields: fields
+                    .into_iter()
+                    .map(|field| FieldJson {
+                        kind: field.kind.to_string(),
+                        name: field.name.as_str(),
+                        offset: field.offset,
+                        size: field.size,
+                        align: field.align,
+                        ty: field.ty.as_str(),
+                    })
+                    .collect(),
+                padding,
+            }
+        })
+        .collect();
+
+    let end_padding = info.overall_size.checked_sub(max_variant_size).unwrap_or_else(|| {
+        panic!("max_variant_size {max_variant_size} > {} overall_size", info.overall_size)
+    });
+
+    // We sort, so the initial order does not matter.
+    #[allow(rustc::potential_query_instability)]
+    let mut instantiations: Vec<_> =
+        instantiations.into_iter().flatten().map(|site| site.as_str()).collect();
+    instantiations.sort_unstable();
+
+    let json = TypeSizeJson {
+        ty: &info.type_description,
+        kind: info.kind.to_string(),
+        instantiations,
+        size: info.overall_size,
+        align: info.align,
+        packed: info.packed,
+        discriminant_size: info.opt_discr_size,
+        niche: info.niche,
+        variants,
+        end_padding,
+    };
+    serde_json::to_string(&json).unwrap()
+}
@@ -36,9 +36,11 @@
 use tracing::debug;
 
 mod cfg;
+mod lint_config;
 pub mod sigpipe;
 
 pub use cfg::{Cfg, CheckCfg, ExpectedValues};
+pub use lint_config::LintConfig;
 
 /// The different settings that the `-C strip` flag can have.
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
@@ -612,6 +614,8 @@ pub enum ErrorOutputType {
         /// human output.
         json_rendered: HumanReadableErrorType,
     },
+    /// A single SARIF log, written when the session ends, for code scanning tools.
+    Sarif,
 }
 
 impl Default for ErrorOutputType {
@@ -703,6 +707,14 @@ pub fn should_link(&self) -> bool {
     }
 }
 
+/// The `--remap-path-prefix` mappings, as `(from, to)` pairs.
+///
+/// Only the `to` paths are tracked for incremental compilation. Which files get remapped is
+/// already reflected in their remapped names, and tracking the local `from` paths would prevent
+/// reusing an incremental cache from a checkout at a different path.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RemapPathPrefixes(pub Vec<(PathBuf, PathBuf)>);
+
 /// Use tree-based collections to cheaply get a deterministic `Hash` implementation.
 /// *Do not* switch `BTreeMap` or `BTreeSet` out for an unsorted container type! That
 /// would break dependency tracking for command-line arguments.
@@ -813,6 +825,7 @@ pub enum PrintKind {
     LinkArgs,
     SplitDebuginfo,
     DeploymentTarget,
+    LintLevels,
 }
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
@@ -1101,9 +1114,10 @@ pub fn host_triple() -> &'static str {
 }
 
 fn file_path_mapping(
-    remap_path_prefix: Vec<(PathBuf, PathBuf)>,
+    remap_path_prefix: RemapPathPrefixes,
     unstable_opts: &UnstableOptions,
 ) -> FilePathMapping {
+    let RemapPathPrefixes(remap_path_prefix) = remap_path_prefix;
     FilePathMapping::new(
         remap_path_prefix.clone(),
         if unstable_opts.remap_path_scope.contains(RemapPathScopeComponents::DIAGNOSTICS)
@@ -1126,6 +1140,7 @@ fn default() -> Options {
             debuginfo_compression: DebugInfoCompression::None,
             lint_opts: Vec::new(),
             lint_cap: None,
+            lint_config: None,
             describe_lints: false,
             output_types: OutputTypes(BTreeMap::new()),
             search_paths: vec![],
@@ -1149,7 +1164,7 @@ fn default() -> Options {
             trimmed_def_paths: false,
             cli_forced_codegen_units: None,
             cli_forced_local_thinlto_off: false,
-            remap_path_prefix: Vec::new(),
+            remap_path_prefix: RemapPathPrefixes::default(),
             real_rust_source_base_dir: None,
             edition: DEFAULT_EDITION,
             json_artifact_notifications: false,
@@ -1421,7 +1436,7 @@ pub(crate) fn flagmulti_s(a: S, b: S, c: S) -> R {
         stable(longer(a, b), move |opts| opts.optflagmulti(a, b, c))
     }
 
-    fn opt(a: S, b: S, c: S, d: S) -> R {
+    pub(crate) fn opt(a: S, b: S, c: S, d: S) -> R {
         unstable(longer(a, b), move |opts| opts.optopt(a, b, c, d))
     }
     pub(crate) fn multi(a: S, b: S, c: S, d: S) -> R {
@@ -1486,7 +1501,7 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
             "[crate-name|file-names|sysroot|target-libdir|cfg|check-cfg|calling-conventions|\
              target-list|target-cpus|target-features|relocation-models|code-models|\
              tls-models|target-spec-json|all-target-specs-json|native-static-libs|\
-             stack-protector-strategies|link-args|deployment-target]",
+             stack-protector-strategies|link-args|deployment-target|lint-levels]",
         ),
         opt::flagmulti_s("g", "", "Equivalent to -C debuginfo=2"),
         opt::flagmulti_s("O", "", "Equivalent to -C opt-level=2"),
@@ -1570,6 +1585,7 @@ pub fn rustc_optgroups() -> Vec<RustcOptGroup> {
             "FROM=TO",
         ),
         opt::multi("", "env-set", "Inject an environment variable", "VAR=VALUE"),
+        opt::opt("", "lint-config", "Read lint levels and parameters from a file", "PATH"),
     ]);
     opts
 }
@@ -1606,6 +1622,20 @@ pub fn get_cmd_lint_options(
     (lint_opts, describe_lints, lint_cap)
 }
 
+/// Reads the file passed with `--lint-config`.
+fn parse_lint_config(early_dcx: &EarlyDiagCtxt, matches: &getopts::Matches) -> Option<LintConfig> {
+    let path = PathBuf::from(matches.opt_str("lint-config")?);
+    let contents = fs::read_to_string(&path).unwrap_or_else(|err| {
+        early_dcx.early_fatal(format!(
+            "failed to read lint configuration file `{}`: {err}",
+            path.display()
+        ))
+    });
+    Some(LintConfig::parse(path, &contents).unwrap_or_else(|err| {
+        early_dcx.early_fatal(format!("invalid lint configuration file: {err}"))
+    }))
+}
+
 /// Parses the `--color` flag.
 pub fn parse_color(early_dcx: &EarlyDiagCtxt, matches: &getopts::Matches) -> ColorConfig {
     match matches.opt_str("color").as_deref() {
@@ -1719,6 +1749,7 @@ pub fn parse_error_format(
             Some("json") => ErrorOutputType::Json { pretty: false, json_rendered },
             Some("pretty-json") => ErrorOutputType::Json { pretty: true, json_rendered },
             Some("short") => ErrorOutputType::HumanReadable(HumanReadableErrorType::Short(color)),
+            Some("sarif") => ErrorOutputType::Sarif,
 
             Some(arg) => {
                 early_dcx.abort_if_error_and_set_error_format(ErrorOutputType::HumanReadable(
@@ -1790,6 +1821,9 @@ fn check_error_format_stability(
         {
             early_dcx.early_fatal("`--error-format=human-annotate-rs` is unstable");
         }
+        if let ErrorOutputType::Sarif = error_format {
+            early_dcx.early_fatal("`--error-format=sarif` is unstable");
+        }
     }
 }
 
@@ -1905,6 +1939,7 @@ fn collect_print_requests(
         ("deployment-target", PrintKind::DeploymentTarget),
         ("file-names", PrintKind::FileNames),
         ("link-args", PrintKind::LinkArgs),
+        ("lint-levels", PrintKind::LintLevels),
         ("native-static-libs", PrintKind::NativeStaticLibs),
         ("relocation-models", PrintKind::RelocationModels),
         ("split-debuginfo", PrintKind::SplitDebuginfo),
@@ -1949,6 +1984,16 @@ fn collect_print_requests(
                     );
                 }
             }
+            Some((_, PrintKind::LintLevels)) => {
+                if unstable_opts.unstable_options {
+                    PrintKind::LintLevels
+                } else {
+                    early_dcx.early_fatal(
+                        "the `-Z unstable-options` flag must also be passed to \
+                         enable the lint-levels print option",
+                    );
+                }
+            }
             Some((_, PrintKind::CheckCfg)) => {
                 if unstable_opts.unstable_options {
                     PrintKind::CheckCfg
@@ -2339,7 +2384,7 @@ fn parse_remap_path_prefix(
     early_dcx: &EarlyDiagCtxt,
     matches: &getopts::Matches,
     unstable_opts: &UnstableOptions,
-) -> Vec<(PathBuf, PathBuf)> {
+) -> RemapPathPrefixes {
     let mut mapping: Vec<(PathBuf, PathBuf)> = matches
         .opt_strs("remap-path-prefix")
         .into_iter()
@@ -2357,7 +2402,7 @@ fn parse_remap_path_prefix(
         },
         None => (),
     };
-    mapping
+    RemapPathPrefixes(mapping)
 }
 
 fn parse_logical_env(
@@ -2405,6 +2450,7 @@ pub fn build_session_options(early_dcx: &mut EarlyDiagCtxt, matches: &getopts::M
 
     let mut unstable_opts = UnstableOptions::build(early_dcx, matches);
     let (lint_opts, describe_lints, lint_cap) = get_cmd_lint_options(early_dcx, matches);
+    let lint_config = parse_lint_config(early_dcx, matches);
 
     check_error_format_stability(early_dcx, &unstable_opts, error_format);
 
@@ -2662,6 +2708,7 @@ pub fn build_session_options(early_dcx: &mut EarlyDiagCtxt, matches: &getopts::M
         debuginfo_compression,
         lint_opts,
         lint_cap,
+        lint_config,
         describe_lints,
         output_types,
         search_paths,
@@ -2963,11 +3010,11 @@ pub(crate) mod dep_tracking {
     use super::{
         BranchProtection, CFGuard, CFProtection, CollapseMacroDebuginfo, CoverageOptions,
         CrateType, DebugInfo, DebugInfoCompression, ErrorOutputType, FunctionReturn,
-        InliningThreshold, InstrumentCoverage, InstrumentXRay, LinkerPluginLto, LocationDetail,
-        LtoCli, NextSolverConfig, OomStrategy, OptLevel, OutFileName, OutputType, OutputTypes,
-        PatchableFunctionEntry, Polonius, RemapPathScopeComponents, ResolveDocLinks,
-        SourceFileHashAlgorithm, SplitDwarfKind, SwitchWithOptPath, SymbolManglingVersion,
-        WasiExecModel,
+        InliningThreshold, InstrumentCoverage, InstrumentXRay, LinkerPluginLto, LintConfig,
+        LocationDetail, LtoCli, NextSolverConfig, OomStrategy, OptLevel, OutFileName, OutputType,
+        OutputTypes, PatchableFunctionEntry, Polonius, RemapPathPrefixes, RemapPathScopeComponents,
+        ResolveDocLinks, SourceFileHashAlgorithm, SplitDwarfKind, SwitchWithOptPath,
+        SymbolManglingVersion, WasiExecModel,
     };
     use crate::lint;
     use crate::utils::NativeLib;
@@ -3069,6 +3116,7 @@ fn hash(
         OutFileName,
         OutputType,
         RealFileName,
+        LintConfig,
         LocationDetail,
         BranchProtection,
         OomStrategy,
@@ -3150,6 +3198,20 @@ fn hash(
         }
     }
 
+    impl DepTrackingHash for RemapPathPrefixes {
+        fn hash(
+            &self,
+            hasher: &mut DefaultHasher,
+            error_format: ErrorOutputType,
+            for_crate_hash: bool,
+        ) {
+            Hash::hash(&self.0.len(), hasher);
+            for (_from, to) in &self.0 {
+                DepTrackingHash::hash(to, hasher, error_format, for_crate_hash);
+            }
+        }
+    }
+
     impl DepTrackingHash for OutputTypes {
         fn hash(
             &self,
@@ -3213,6 +3275,10 @@ pub enum ProcMacroExecutionStrategy {
 
     /// Run the proc-macro code on a different thread.
     CrossThread,
+
+    /// Run the proc-macro code in a separate process, so that crashes and runaway proc macros
+    /// don't take down the compiler.
+    OutOfProcess,
 }
 
 /// How to perform collapse macros debug info
@@ -3252,6 +3318,45 @@ pub fn extension(self) -> &'static str {
     }
 }
 
+/// Which format to use for `-Z print-type-sizes`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum PrintTypeSizesFormat {
+    /// Human-readable text, one line per field
+    Text,
+    /// One JSON object per type
+    Json,
+}
+
+/// Which format to use for `-Z item-cost-report`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum ItemCostReportFormat {
+    /// Emit structured JSON
+    Json,
+}
+
+impl ItemCostReportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+        }
+    }
+}
+
+/// Which format to use for `-Z dump-stable-mir`
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum StableMirDumpFormat {
+    /// Emit structured JSON
+    Json,
+}
+
+impl StableMirDumpFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+        }
+    }
+}
+
 /// `-Z patchable-function-entry` representation - how many nops to put before and after function
 /// entry.
 #[derive(Clone, Copy, PartialEq, Hash, Debug, Default)]
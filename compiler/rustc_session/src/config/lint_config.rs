@@ -0,0 +1,254 @@
+//! The lint configuration file passed with `--lint-config`.
+//!
+//! The file uses a small subset of TOML:
+//!
+//! ```toml
+//! # Crate-wide levels of lints and lint groups.
+//! [lints]
+//! missing_docs = "deny"
+//! "clippy::pedantic" = "warn"
+//!
+//! # Levels for the source files matching a glob.
+//! [paths."src/generated/**"]
+//! missing_docs = "allow"
+//!
+//! # Lint-specific parameters.
+//! [params.large_assignments]
+//! limit = 4096
+//! ```
+//!
+//! Keys are either bare (letters, digits, `_`, `-` and `:`) or quoted without
+//! escapes, levels are one of `allow`, `warn`, `deny` and `forbid`.
+//!
+//! The parameters of tool lints, such as `[params."clippy::too_many_lines"]`,
+//! are not checked here but left to the tool.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::lint::Level;
+
+#[cfg(test)]
+mod tests;
+
+/// The parameters the lint configuration file may set for rustc lints, by
+/// lint name. Every parameter currently takes an integer.
+const KNOWN_PARAMS: &[(&str, &str)] = &[("large_assignments", "limit")];
+
+#[derive(Clone, Debug, Hash, PartialEq)]
+pub struct LintConfig {
+    /// The path of the file. Path globs are relative to its directory.
+    pub path: PathBuf,
+    /// Crate-wide levels of lints and lint groups, in file order.
+    pub levels: Vec<(String, Level)>,
+    /// Levels for the source files matching a glob, in file order.
+    pub paths: Vec<(String, Vec<(String, Level)>)>,
+    /// Lint parameters, by lint name and parameter name.
+    pub params: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+enum Section {
+    Lints,
+    Path(usize),
+    Params(String),
+}
+
+impl LintConfig {
+    /// Parses the contents of the lint configuration file at `path`. Errors
+    /// are prefixed with the path and line they occur on.
+    pub fn parse(path: PathBuf, contents: &str) -> Result<LintConfig, String> {
+        let display_path = path.display().to_string();
+        let mut config =
+            LintConfig { path, levels: Vec::new(), paths: Vec::new(), params: BTreeMap::new() };
+        let mut section = None;
+
+        for (line_index, line) in contents.lines().enumerate() {
+            let error = |msg: String| format!("{display_path}:{}: {msg}", line_index + 1);
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("expected `]` at the end of the section header".into()))?;
+                section = Some(config.parse_section(header.trim()).map_err(error)?);
+                continue;
+            }
+
+            let (key, value) = parse_key_value(line).map_err(error)?;
+            match &section {
+                None => {
+                    return Err(error(format!("`{key}` is not in a section")));
+                }
+                Some(Section::Lints) => {
+                    config.levels.push((key, parse_level(value).map_err(error)?))
+                }
+                Some(Section::Path(index)) => {
+                    config.paths[*index].1.push((key, parse_level(value).map_err(error)?))
+                }
+                Some(Section::Params(lint_name)) => {
+                    if !is_tool_lint(lint_name)
+                        && !KNOWN_PARAMS.contains(&(lint_name.as_str(), key.as_str()))
+                    {
+                        return Err(error(format!("unknown parameter `{key}` for `{lint_name}`")));
+                    }
+                    let value = value
+                        .parse()
+                        .map_err(|_| error(format!("expected an integer, found `{value}`")))?;
+                    config.params.entry(lint_name.clone()).or_default().insert(key, value);
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn parse_section(&mut self, header: &str) -> Result<Section, String> {
+        if header == "lints" {
+            return Ok(Section::Lints);
+        }
+        if let Some(glob) = header.strip_prefix("paths.") {
+            let (glob, rest) = parse_key(glob.trim())?;
+            if !rest.trim().is_empty() {
+                return Err(format!("unexpected `{}` after the path glob", rest.trim()));
+            }
+            let glob = glob.trim_start_matches("./").to_owned();
+            let index = match self.paths.iter().position(|(g, _)| *g == glob) {
+                Some(index) => index,
+                None => {
+                    self.paths.push((glob, Vec::new()));
+                    self.paths.len() - 1
+                }
+            };
+            return Ok(Section::Path(index));
+        }
+        if let Some(lint_name) = header.strip_prefix("params.") {
+            let (lint_name, rest) = parse_key(lint_name.trim())?;
+            if !rest.trim().is_empty() {
+                return Err(format!("unexpected `{}` after the lint name", rest.trim()));
+            }
+            let lint_name = lint_name.replace('-', "_");
+            if !is_tool_lint(&lint_name) && !KNOWN_PARAMS.iter().any(|&(name, _)| name == lint_name)
+            {
+                return Err(format!("the `{lint_name}` lint has no parameters"));
+            }
+            return Ok(Section::Params(lint_name));
+        }
+        Err(format!(
+            "unknown section `[{header}]`, \
+             expected `[lints]`, `[paths.\"GLOB\"]` or `[params.LINT]`"
+        ))
+    }
+
+    /// The levels set for the source file at `file`, with the glob that
+    /// matched it, in file order.
+    pub fn levels_for_file<'a>(
+        &'a self,
+        file: &Path,
+    ) -> impl Iterator<Item = (&'a str, &'a str, Level)> + 'a {
+        let file = self.path.parent().and_then(|dir| file.strip_prefix(dir).ok()).unwrap_or(file);
+        let file = file.to_string_lossy().replace('\\', "/");
+        let file = file.trim_start_matches("./").to_owned();
+        self.paths
+            .iter()
+            .filter(move |(glob, _)| glob_matches(glob.as_bytes(), file.as_bytes()))
+            .flat_map(|(glob, levels)| {
+                levels.iter().map(move |(lint_name, level)| (&**glob, &**lint_name, *level))
+            })
+    }
+
+    /// The value of the parameter `param` of the lint `lint_name`, if set.
+    pub fn param(&self, lint_name: &str, param: &str) -> Option<u64> {
+        self.params.get(lint_name)?.get(param).copied()
+    }
+}
+
+/// Whether `lint_name` is the name of a lint of a tool, like `clippy::as_conversions`.
+fn is_tool_lint(lint_name: &str) -> bool {
+    lint_name.contains("::")
+}
+
+/// Removes a `#` comment from the end of `line`, unless it is quoted.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses a bare or quoted key at the start of `s`, returning it and the rest
+/// of `s`.
+fn parse_key(s: &str) -> Result<(String, &str), String> {
+    if let Some(quoted) = s.strip_prefix('"') {
+        let end = quoted.find('"').ok_or_else(|| "unterminated string".to_owned())?;
+        return Ok((quoted[..end].to_owned(), &quoted[end + 1..]));
+    }
+    let end = s
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':')))
+        .unwrap_or(s.len());
+    if end == 0 {
+        return Err(format!("expected a key, found `{s}`"));
+    }
+    Ok((s[..end].to_owned(), &s[end..]))
+}
+
+fn parse_key_value(line: &str) -> Result<(String, &str), String> {
+    let (key, rest) = parse_key(line)?;
+    let value = rest
+        .trim_start()
+        .strip_prefix('=')
+        .ok_or_else(|| format!("expected `=` after `{key}`"))?
+        .trim();
+    // Lint names are normalized like on the command line.
+    Ok((key.replace('-', "_"), value))
+}
+
+fn parse_level(value: &str) -> Result<Level, String> {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .and_then(Level::from_str)
+        .ok_or_else(|| {
+            format!(
+                "expected one of `\"allow\"`, `\"warn\"`, `\"deny\"` or `\"forbid\"`, \
+                 found `{value}`"
+            )
+        })
+}
+
+/// Whether `path`, with `/` separated components, matches `glob`. `*` and `?`
+/// match within a component, `**` matches any number of components.
+fn glob_matches(glob: &[u8], path: &[u8]) -> bool {
+    match glob {
+        [] => path.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            let rest = rest.strip_prefix(b"/").unwrap_or(rest);
+            rest.is_empty()
+                || (0..=path.len()).any(|start| {
+                    (start == 0 || path[start - 1] == b'/') && glob_matches(rest, &path[start..])
+                })
+        }
+        [b'*', rest @ ..] => {
+            for start in 0..=path.len() {
+                if glob_matches(rest, &path[start..]) {
+                    return true;
+                }
+                if path.get(start) == Some(&b'/') {
+                    break;
+                }
+            }
+            false
+        }
+        [b'?', rest @ ..] => {
+            matches!(path, [c, path @ ..] if *c != b'/' && glob_matches(rest, path))
+        }
+        [g, rest @ ..] => matches!(path, [c, path @ ..] if c == g && glob_matches(rest, path)),
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+fn parse(contents: &str) -> Result<LintConfig, String> {
+    LintConfig::parse(PathBuf::from("project/lints.toml"), contents)
+}
+
+fn parse_err(contents: &str) -> String {
+    parse(contents).unwrap_err()
+}
+
+#[test]
+fn levels_paths_and_params() {
+    let config = parse(
+        r#"
+# Crate-wide levels.
+[lints]
+missing-docs = "deny" # trailing comment
+"clippy::pedantic" = "warn"
+
+[paths."src/generated/**"]
+unused_variables = "allow"
+
+[params.large_assignments]
+limit = 4096
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.levels,
+        [("missing_docs".to_owned(), Level::Deny), ("clippy::pedantic".to_owned(), Level::Warn)]
+    );
+    assert_eq!(
+        config.paths,
+        [("src/generated/**".to_owned(), vec![("unused_variables".to_owned(), Level::Allow)])]
+    );
+    assert_eq!(config.param("large_assignments", "limit"), Some(4096));
+    assert_eq!(config.param("large_assignments", "size"), None);
+}
+
+#[test]
+fn quoting() {
+    let config = parse(
+        r#"
+[lints]
+"a#b" = "allow" # `#` is only a comment outside of quotes
+[paths."./src/*.rs"]
+"missing_docs" = "forbid"
+[paths."src/*.rs"]
+dead_code = "warn"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.levels, [("a#b".to_owned(), Level::Allow)]);
+    // Sections for the same glob, with or without `./`, are merged.
+    assert_eq!(
+        config.paths,
+        [(
+            "src/*.rs".to_owned(),
+            vec![("missing_docs".to_owned(), Level::Forbid), ("dead_code".to_owned(), Level::Warn)]
+        )]
+    );
+
+    assert_eq!(
+        parse_err("[lints]\n\"missing_docs = \"warn\""),
+        "project/lints.toml:2: expected `=` after `missing_docs = `"
+    );
+    assert_eq!(parse_err("[lints]\n\"missing_docs"), "project/lints.toml:2: unterminated string");
+    assert_eq!(parse_err("[paths.\"src/**]"), "project/lints.toml:1: unterminated string");
+}
+
+#[test]
+fn malformed() {
+    assert_eq!(
+        parse_err("[lints"),
+        "project/lints.toml:1: expected `]` at the end of the section header"
+    );
+    assert_eq!(
+        parse_err("missing_docs = \"warn\""),
+        "project/lints.toml:1: `missing_docs` is not in a section"
+    );
+    assert_eq!(
+        parse_err("[lints]\n\nmissing_docs \"warn\""),
+        "project/lints.toml:3: expected `=` after `missing_docs`"
+    );
+    assert_eq!(
+        parse_err("[lints]\n= \"warn\""),
+        "project/lints.toml:2: expected a key, found `= \"warn\"`"
+    );
+    assert_eq!(
+        parse_err("[lints]\nmissing_docs = warn"),
+        "project/lints.toml:2: expected one of `\"allow\"`, `\"warn\"`, `\"deny\"` or `\"forbid\"`, \
+         found `warn`"
+    );
+    assert_eq!(
+        parse_err("[paths.\"src/**\" extra]"),
+        "project/lints.toml:1: unexpected `extra` after the path glob"
+    );
+    assert_eq!(
+        parse_err("[params.large_assignments]\nlimit = big"),
+        "project/lints.toml:2: expected an integer, found `big`"
+    );
+    assert_eq!(
+        parse_err("[params.large_assignments]\nlimit = -1"),
+        "project/lints.toml:2: expected an integer, found `-1`"
+    );
+}
+
+#[test]
+fn unknown_keys() {
+    assert_eq!(
+        parse_err("[unknown]"),
+        "project/lints.toml:1: unknown section `[unknown]`, \
+         expected `[lints]`, `[paths.\"GLOB\"]` or `[params.LINT]`"
+    );
+    assert_eq!(
+        parse_err("[params.unused_variables]"),
+        "project/lints.toml:1: the `unused_variables` lint has no parameters"
+    );
+    assert_eq!(
+        parse_err("[params.large_assignments]\nsize = 1"),
+        "project/lints.toml:2: unknown parameter `size` for `large_assignments`"
+    );
+
+    // The parameters of tool lints are left to the tool.
+    let config = parse("[params.\"clippy::too_many_lines\"]\nmax-lines = 80").unwrap();
+    assert_eq!(config.param("clippy::too_many_lines", "max_lines"), Some(80));
+}
+
+#[test]
+fn globs() {
+    assert!(glob_matches(b"src/lib.rs", b"src/lib.rs"));
+    assert!(!glob_matches(b"src/lib.rs", b"src/lib.rss"));
+
+    // `*` and `?` match within a component.
+    assert!(glob_matches(b"src/*.rs", b"src/lib.rs"));
+    assert!(glob_matches(b"src/*.rs", b"src/.rs"));
+    assert!(!glob_matches(b"src/*.rs", b"src/a/lib.rs"));
+    assert!(glob_matches(b"src/?.rs", b"src/a.rs"));
+    assert!(!glob_matches(b"src/?.rs", b"src/ab.rs"));
+    assert!(!glob_matches(b"src?a.rs", b"src/a.rs"));
+
+    // `**` matches any number of components.
+    assert!(glob_matches(b"src/**", b"src/lib.rs"));
+    assert!(glob_matches(b"src/**", b"src/a/b/lib.rs"));
+    assert!(!glob_matches(b"src/**", b"tests/lib.rs"));
+    assert!(glob_matches(b"**/*.rs", b"lib.rs"));
+    assert!(glob_matches(b"**/*.rs", b"src/a/lib.rs"));
+    assert!(glob_matches(b"src/**/gen.rs", b"src/gen.rs"));
+    assert!(glob_matches(b"src/**/gen.rs", b"src/a/b/gen.rs"));
+    assert!(!glob_matches(b"src/**/gen.rs", b"src/a/regen.rs"));
+}
+
+#[test]
+fn levels_for_file() {
+    let config = parse(
+        r#"
+[paths."src/generated/**"]
+missing_docs = "allow"
+[paths."**/*.rs"]
+dead_code = "warn"
+"#,
+    )
+    .unwrap();
+
+    let levels = |file: &str| -> Vec<_> { config.levels_for_file(Path::new(file)).collect() };
+    assert_eq!(
+        levels("project/src/generated/a.rs"),
+        [("src/generated/**", "missing_docs", Level::Allow), ("**/*.rs", "dead_code", Level::Warn)]
+    );
+    assert_eq!(levels("project/src/lib.rs"), [("**/*.rs", "dead_code", Level::Warn)]);
+}
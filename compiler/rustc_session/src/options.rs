@@ -140,6 +140,7 @@ pub struct Options {
         debuginfo_compression: DebugInfoCompression [TRACKED],
         lint_opts: Vec<(String, lint::Level)> [TRACKED_NO_CRATE_HASH],
         lint_cap: Option<lint::Level> [TRACKED_NO_CRATE_HASH],
+        lint_config: Option<LintConfig> [TRACKED_NO_CRATE_HASH],
         describe_lints: bool [UNTRACKED],
         output_types: OutputTypes [TRACKED],
         search_paths: Vec<SearchPath> [UNTRACKED],
@@ -193,7 +194,7 @@ pub struct Options {
         cli_forced_local_thinlto_off: bool [UNTRACKED],
 
         /// Remap source path prefixes in all output (messages, object files, debug, etc.).
-        remap_path_prefix: Vec<(PathBuf, PathBuf)> [TRACKED_NO_CRATE_HASH],
+        remap_path_prefix: RemapPathPrefixes [TRACKED_NO_CRATE_HASH],
         /// Base directory containing the `src/` for the Rust standard library, and
         /// potentially `rustc` as well, if we can find it. Right now it's always
         /// `$sysroot/lib/rustlib/src/rust` (i.e. the `rustup` `rust-src` component).
@@ -395,6 +396,9 @@ mod desc {
     pub const parse_linker_flavor: &str = ::rustc_target::spec::LinkerFlavorCli::one_of();
     pub const parse_optimization_fuel: &str = "crate=integer";
     pub const parse_dump_mono_stats: &str = "`markdown` (default) or `json`";
+    pub const parse_dump_stable_mir: &str = "`json`";
+    pub const parse_item_cost_report: &str = "`json`";
+    pub const parse_print_type_sizes_format: &str = "`text` (default) or `json`";
     pub const parse_instrument_coverage: &str = parse_bool;
     pub const parse_coverage_options: &str =
         "`block` | `branch` | `condition` | `mcdc` | `no-mir-spans`";
@@ -436,7 +440,7 @@ mod desc {
     pub const parse_branch_protection: &str =
         "a `,` separated combination of `bti`, `b-key`, `pac-ret`, or `leaf`";
     pub const parse_proc_macro_execution_strategy: &str =
-        "one of supported execution strategies (`same-thread`, or `cross-thread`)";
+        "one of supported execution strategies (`same-thread`, `cross-thread`, or `out-of-process`)";
     pub const parse_remap_path_scope: &str =
         "comma separated list of scopes: `macro`, `diagnostics`, `debuginfo`, `object`, `all`";
     pub const parse_inlining_threshold: &str =
@@ -967,6 +971,49 @@ pub(crate) fn parse_dump_mono_stats(slot: &mut DumpMonoStatsFormat, v: Option<&s
         }
     }
 
+    pub(crate) fn parse_print_type_sizes_format(
+        slot: &mut PrintTypeSizesFormat,
+        v: Option<&str>,
+    ) -> bool {
+        match v {
+            Some("text") => {
+                *slot = PrintTypeSizesFormat::Text;
+                true
+            }
+            Some("json") => {
+                *slot = PrintTypeSizesFormat::Json;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn parse_item_cost_report(
+        slot: &mut Option<ItemCostReportFormat>,
+        v: Option<&str>,
+    ) -> bool {
+        match v {
+            Some("json") => {
+                *slot = Some(ItemCostReportFormat::Json);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn parse_dump_stable_mir(
+        slot: &mut Option<StableMirDumpFormat>,
+        v: Option<&str>,
+    ) -> bool {
+        match v {
+            Some("json") => {
+                *slot = Some(StableMirDumpFormat::Json);
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn parse_instrument_coverage(
         slot: &mut InstrumentCoverage,
         v: Option<&str>,
@@ -1414,6 +1461,7 @@ pub(crate) fn parse_proc_macro_execution_strategy(
         *slot = match v {
             Some("same-thread") => ProcMacroExecutionStrategy::SameThread,
             Some("cross-thread") => ProcMacroExecutionStrategy::CrossThread,
+            Some("out-of-process") => ProcMacroExecutionStrategy::OutOfProcess,
             _ => return false,
         };
         true
@@ -1639,10 +1687,17 @@ pub(crate) fn parse_wasm_c_abi(slot: &mut WasmCAbi, v: Option<&str>) -> bool {
     binary_dep_depinfo: bool = (false, parse_bool, [TRACKED],
         "include artifacts (sysroot, crate dependencies) used during compilation in dep-info \
         (default: no)"),
+    borrowck_explain: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "write a timeline of the loans of the function with this path, with the constraints \
+        that kept them live at each borrow error, to `-Z dump-mir-dir` as text, JSON and \
+        graphviz files"),
     box_noalias: bool = (true, parse_bool, [TRACKED],
         "emit noalias metadata for box (default: yes)"),
     branch_protection: Option<BranchProtection> = (None, parse_branch_protection, [TRACKED],
         "set options for branch target identification and pointer authentication on AArch64"),
+    cache_expansions: bool = (false, parse_bool, [UNTRACKED],
+        "reuse the output of `macro_rules!` macros and deterministic proc macros from the \
+        previous incremental compilation session (default: no)"),
     cf_protection: CFProtection = (CFProtection::None, parse_cfprotection, [TRACKED],
         "instrument control-flow architecture protection"),
     check_cfg_all_expected: bool = (false, parse_bool, [UNTRACKED],
@@ -1651,6 +1706,9 @@ pub(crate) fn parse_wasm_c_abi(slot: &mut WasmCAbi, v: Option<&str>) -> bool {
         "the backend to use"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
         "combine CGUs into a single one"),
+    const_eval_profile: bool = (false, parse_bool, [UNTRACKED],
+        "write the steps taken by compile-time evaluation, by call stack, to \
+        `{crate}.const-eval-profile.folded` (default: no)"),
     coverage_options: CoverageOptions = (CoverageOptions::default(), parse_coverage_options, [TRACKED],
         "control details of coverage instrumentation"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
@@ -1696,6 +1754,9 @@ pub(crate) fn parse_wasm_c_abi(slot: &mut WasmCAbi, v: Option<&str>) -> bool {
         "output statistics about monomorphization collection"),
     dump_mono_stats_format: DumpMonoStatsFormat = (DumpMonoStatsFormat::Markdown, parse_dump_mono_stats, [UNTRACKED],
         "the format to use for -Z dump-mono-stats (`markdown` (default) or `json`)"),
+    dump_stable_mir: Option<StableMirDumpFormat> = (None, parse_dump_stable_mir, [UNTRACKED],
+        "write the StableMIR of every monomorphized item of the crate, with the types, layouts, \
+        allocations and spans it refers to, e.g. `-Z dump-stable-mir=json` (default: no)"),
     dwarf_version: Option<u32> = (None, parse_opt_number, [TRACKED],
         "version of DWARF debug information to emit (default: 2 or 4, depending on platform)"),
     dylib_lto: bool = (false, parse_bool, [UNTRACKED],
@@ -1749,6 +1810,12 @@ pub(crate) fn parse_wasm_c_abi(slot: &mut WasmCAbi, v: Option<&str>) -> bool {
         "display unnamed regions as `'<id>`, using a non-ident unique id (default: no)"),
     ignore_directory_in_diagnostics_source_blocks: Vec<String> = (Vec::new(), parse_string_push, [UNTRACKED],
         "do not display the source code block in diagnostics for files in the directory"),
+    incremental_cache_limit: Option<u64> = (None, parse_opt_number, [UNTRACKED],
+        "delete the least recently used incremental session directories of all crates \
+        while the incremental directory is larger than this many bytes"),
+    incremental_cache_max_age: Option<u64> = (None, parse_opt_number, [UNTRACKED],
+        "delete incremental session directories of all crates that have not been used \
+        for this many days"),
     incremental_ignore_spans: bool = (false, parse_bool, [TRACKED],
         "ignore spans during ICH computation -- used for testing (default: no)"),
     incremental_info: bool = (false, parse_bool, [UNTRACKED],
@@ -1787,6 +1854,9 @@ pub(crate) fn parse_wasm_c_abi(slot: &mut WasmCAbi, v: Option<&str>) -> bool {
          `=skip-entry`
          `=skip-exit`
          Multiple options can be combined with commas."),
+    item_cost_report: Option<ItemCostReportFormat> = (None, parse_item_cost_report, [UNTRACKED],
+        "write a report of the compile time and code size attributed to each item, \
+        e.g. `-Z item-cost-report=json` (default: no)"),
     layout_seed: Option<u64> = (None, parse_opt_number, [TRACKED],
         "seed layout randomization"),
     link_directives: bool = (true, parse_bool, [TRACKED],
@@ -1917,6 +1987,9 @@ pub(crate) fn parse_wasm_c_abi(slot: &mut WasmCAbi, v: Option<&str>) -> bool {
          Note that this overwrites the effect `-Clink-dead-code` has on collection!"),
     print_type_sizes: bool = (false, parse_bool, [UNTRACKED],
         "print layout information for each type encountered (default: no)"),
+    print_type_sizes_format: PrintTypeSizesFormat = (PrintTypeSizesFormat::Text,
+        parse_print_type_sizes_format, [UNTRACKED],
+        "the format to use for -Z print-type-sizes (`text` (default) or `json`)"),
     print_vtable_sizes: bool = (false, parse_bool, [UNTRACKED],
         "print size comparison between old and new vtable layouts (default: no)"),
     proc_macro_backtrace: bool = (false, parse_bool, [UNTRACKED],
@@ -1924,6 +1997,9 @@ pub(crate) fn parse_wasm_c_abi(slot: &mut WasmCAbi, v: Option<&str>) -> bool {
     proc_macro_execution_strategy: ProcMacroExecutionStrategy = (ProcMacroExecutionStrategy::SameThread,
         parse_proc_macro_execution_strategy, [UNTRACKED],
         "how to run proc-macro code (default: same-thread)"),
+    proc_macro_timeout: Option<u64> = (None, parse_opt_number, [UNTRACKED],
+        "kill proc macros that run for longer than this many seconds; only applies to the \
+        `out-of-process` execution strategy (default: no limit)"),
     profile: bool = (false, parse_bool, [TRACKED],
         "insert profiling code (default: no)"),
     profile_closures: bool = (false, parse_no_flag, [UNTRACKED],
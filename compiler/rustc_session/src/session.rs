@@ -1,5 +1,5 @@
 use crate::code_stats::CodeStats;
-pub use crate::code_stats::{DataTypeKind, FieldInfo, FieldKind, SizeKind, VariantInfo};
+pub use crate::code_stats::{DataTypeKind, FieldInfo, FieldKind, NicheInfo, SizeKind, VariantInfo};
 use crate::config::{
     self, CoverageLevel, CrateType, FunctionReturn, InstrumentCoverage, OptLevel, OutFileName,
     OutputType, RemapPathScopeComponents, SwitchWithOptPath,
@@ -21,6 +21,7 @@
 use rustc_errors::emitter::{stderr_destination, DynEmitter, HumanEmitter, HumanReadableErrorType};
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
+use rustc_errors::sarif::{SarifEmitter, SarifLog};
 use rustc_errors::{
     codes::*, fallback_fluent_bundle, Diag, DiagCtxt, DiagCtxtHandle, DiagMessage, Diagnostic,
     ErrorGuaranteed, FatalAbort, FluentBundle, LazyFallbackBundle, TerminalUrl,
@@ -935,6 +936,8 @@ fn default_emitter(
     source_map: Lrc<SourceMap>,
     bundle: Option<Lrc<FluentBundle>>,
     fallback_bundle: LazyFallbackBundle,
+    cfg_version: &'static str,
+    sarif_log: &SarifLog,
 ) -> Box<DynEmitter> {
     let macro_backtrace = sopts.unstable_opts.macro_backtrace;
     let track_diagnostics = sopts.unstable_opts.track_diagnostics;
@@ -999,6 +1002,19 @@ fn default_emitter(
             .track_diagnostics(track_diagnostics)
             .terminal_url(terminal_url),
         ),
+        config::ErrorOutputType::Sarif => Box::new(
+            SarifEmitter::new(
+                Box::new(io::BufWriter::new(io::stderr())),
+                source_map,
+                fallback_bundle,
+                false,
+            )
+            .registry(Some(registry))
+            .fluent_bundle(bundle)
+            .tool_version(cfg_version.to_owned())
+            .emit_empty_log(true)
+            .join_log(sarif_log),
+        ),
     }
 }
 
@@ -1044,7 +1060,15 @@ pub fn build_session(
         sopts.unstable_opts.translate_directionality_markers,
     );
     let source_map = rustc_span::source_map::get_source_map().unwrap();
-    let emitter = default_emitter(&sopts, registry, source_map.clone(), bundle, fallback_bundle);
+    let emitter = default_emitter(
+        &sopts,
+        registry,
+        source_map.clone(),
+        bundle,
+        fallback_bundle,
+        cfg_version,
+        &early_dcx.sarif_log,
+    );
 
     let mut dcx =
         DiagCtxt::new(emitter).with_flags(sopts.unstable_opts.dcx_flags(can_emit_warnings));
@@ -1359,12 +1383,26 @@ enum IncrCompSession {
 /// A wrapper around an [`DiagCtxt`] that is used for early error emissions.
 pub struct EarlyDiagCtxt {
     dcx: DiagCtxt,
+    /// The log that early diagnostics are added to with `--error-format=sarif`, which the
+    /// session's diagnostics are added to as well.
+    sarif_log: SarifLog,
 }
 
 impl EarlyDiagCtxt {
     pub fn new(output: ErrorOutputType) -> Self {
-        let emitter = mk_emitter(output);
-        Self { dcx: DiagCtxt::new(emitter) }
+        Self::with_sarif_log(output, SarifLog::default())
+    }
+
+    /// Creates an `EarlyDiagCtxt` whose SARIF diagnostics go to the same log as those of the
+    /// `EarlyDiagCtxt` that `sarif_log` was obtained from, so that they all end up in a single
+    /// document.
+    pub fn with_sarif_log(output: ErrorOutputType, sarif_log: SarifLog) -> Self {
+        let emitter = mk_emitter(output, &sarif_log);
+        Self { dcx: DiagCtxt::new(emitter), sarif_log }
+    }
+
+    pub fn sarif_log(&self) -> &SarifLog {
+        &self.sarif_log
     }
 
     /// Swap out the underlying dcx once we acquire the user's preference on error emission
@@ -1373,7 +1411,7 @@ pub fn new(output: ErrorOutputType) -> Self {
     pub fn abort_if_error_and_set_error_format(&mut self, output: ErrorOutputType) {
         self.dcx.handle().abort_if_errors();
 
-        let emitter = mk_emitter(output);
+        let emitter = mk_emitter(output, &self.sarif_log);
         self.dcx = DiagCtxt::new(emitter);
     }
 
@@ -1421,7 +1459,7 @@ pub fn early_struct_warn(&self, msg: impl Into<DiagMessage>) -> Diag<'_, ()> {
     }
 }
 
-fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
+fn mk_emitter(output: ErrorOutputType, sarif_log: &SarifLog) -> Box<DynEmitter> {
     // FIXME(#100717): early errors aren't translated at the moment, so this is fine, but it will
     // need to reference every crate that might emit an early error for translation to work.
     let fallback_bundle =
@@ -1441,6 +1479,15 @@ fn mk_emitter(output: ErrorOutputType) -> Box<DynEmitter> {
             pretty,
             json_rendered,
         )),
+        config::ErrorOutputType::Sarif => Box::new(
+            SarifEmitter::new(
+                Box::new(io::BufWriter::new(io::stderr())),
+                Lrc::new(SourceMap::new(FilePathMapping::empty())),
+                fallback_bundle,
+                false,
+            )
+            .join_log(sarif_log),
+        ),
     };
     emitter
 }
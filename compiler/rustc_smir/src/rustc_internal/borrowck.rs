@@ -0,0 +1,243 @@
+//! Collection of the facts computed by the borrow checker, which are then retrieved with
+//! `stable_mir::mir::borrowck::borrowck_facts`.
+//!
+//! The MIR that the borrow checker runs on is stolen by later passes, so the facts cannot be
+//! computed after the fact. Instead, the `mir_borrowck` query is overridden to keep the facts of
+//! the bodies it borrow checks while a StableMIR context is active. The facts are owned by that
+//! context, and dropped with it.
+
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+use rustc_borrowck::borrow_set::{BorrowData, TwoPhaseActivation};
+use rustc_borrowck::consumers::{
+    get_result_and_body_with_borrowck_facts, Borrows, ConsumerOptions, OutlivesConstraint,
+};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_middle::mir::{self, BorrowCheckResult, Location};
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_mir_dataflow::Analysis;
+use rustc_session::Session;
+use rustc_span::def_id::{DefId, LocalDefId};
+use stable_mir::mir::borrowck::{self as stable, BodyLocation, BorrowckFacts};
+use stable_mir::Error;
+
+use super::with_collected_facts;
+use crate::rustc_smir::{Stable, Tables};
+
+type OverrideQueries = fn(&Session, &mut Providers);
+type MirBorrowck = for<'tcx> fn(TyCtxt<'tcx>, LocalDefId) -> &'tcx BorrowCheckResult<'tcx>;
+
+/// The `override_queries` callback that was set before facts collection was requested.
+static COLLECT_BORROWCK_FACTS: OnceLock<Option<OverrideQueries>> = OnceLock::new();
+
+/// The `mir_borrowck` provider that the overriding provider falls back to.
+static MIR_BORROWCK: OnceLock<MirBorrowck> = OnceLock::new();
+
+/// The borrow checker facts collected while a StableMIR context is active.
+pub(crate) struct CollectedFacts<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    bodies: RefCell<FxHashMap<LocalDefId, BodyFacts<'tcx>>>,
+}
+
+impl<'tcx> CollectedFacts<'tcx> {
+    pub(crate) fn new(tcx: TyCtxt<'tcx>) -> Self {
+        CollectedFacts { tcx, bodies: RefCell::default() }
+    }
+
+    /// Whether these facts are collected for the compilation of `tcx`.
+    pub(crate) fn is_of(&self, tcx: TyCtxt<'tcx>) -> bool {
+        std::ptr::eq(*self.tcx, *tcx)
+    }
+}
+
+/// The borrow checker facts of a body, before their conversion to StableMIR.
+struct BodyFacts<'tcx> {
+    body: mir::Body<'tcx>,
+    loans: Vec<(BorrowData<'tcx>, Vec<Location>)>,
+    regions: Vec<(bool, Vec<Location>)>,
+    constraints: Vec<OutlivesConstraint<'tcx>>,
+}
+
+/// Request the borrow checker facts of the local items of the crate compiled with `config` to be
+/// collected, so that they can be retrieved with `stable_mir::mir::borrowck::borrowck_facts`.
+/// This is meant to be called from `Callbacks::config`.
+///
+/// Facts are only collected for the bodies that are borrow checked on the thread of an active
+/// StableMIR context, e.g., by `borrowck_facts` itself. Since the crate is borrow checked by its
+/// analysis, the context has to be entered before it, for instance from
+/// `Callbacks::after_expansion`.
+///
+/// Any `override_queries` callback already set in `config` is still invoked. Only one
+/// compilation per process can request facts.
+pub fn collect_borrowck_facts(config: &mut rustc_interface::Config) {
+    if COLLECT_BORROWCK_FACTS.set(config.override_queries).is_err() {
+        panic!("borrowck facts are already being collected");
+    }
+    config.override_queries = Some(override_queries);
+}
+
+fn override_queries(sess: &Session, providers: &mut Providers) {
+    if let Some(previous) = COLLECT_BORROWCK_FACTS.get().unwrap() {
+        previous(sess, providers);
+    }
+    let mir_borrowck = providers.queries.mir_borrowck;
+    MIR_BORROWCK.get_or_init(|| mir_borrowck);
+    providers.queries.mir_borrowck = collecting_mir_borrowck;
+}
+
+fn collecting_mir_borrowck<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: LocalDefId,
+) -> &'tcx BorrowCheckResult<'tcx> {
+    let collect = {
+        let body = tcx.mir_promoted(def_id).0.borrow();
+        !body.should_skip() && body.tainted_by_errors.is_none() && can_convert(&body)
+    };
+    // The facts are only collected when there is a context to own them.
+    if collect && with_collected_facts(tcx, |_| ()).is_some() {
+        let (result, facts) = body_facts(tcx, def_id);
+        with_collected_facts(tcx, |collected| {
+            collected.bodies.borrow_mut().insert(def_id, facts);
+        });
+        return tcx.arena.alloc(result);
+    }
+    MIR_BORROWCK.get().unwrap()(tcx, def_id)
+}
+
+/// Whether `body` can be converted to StableMIR before it is optimized.
+fn can_convert(body: &mir::Body<'_>) -> bool {
+    body.coroutine.is_none()
+        && body.basic_blocks.iter().all(|block| {
+            !matches!(block.terminator().kind, mir::TerminatorKind::TailCall { .. })
+                && block.statements.iter().all(|statement| {
+                    !matches!(
+                        &statement.kind,
+                        mir::StatementKind::Assign(assign) if matches!(
+                            &assign.1,
+                            mir::Rvalue::Aggregate(kind, _)
+                                if matches!(**kind, mir::AggregateKind::CoroutineClosure(..))
+                        )
+                    )
+                })
+        })
+}
+
+fn body_facts<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: LocalDefId,
+) -> (BorrowCheckResult<'tcx>, BodyFacts<'tcx>) {
+    let (result, facts) = get_result_and_body_with_borrowck_facts(
+        tcx,
+        def_id,
+        ConsumerOptions::RegionInferenceContext,
+    );
+    let body = &facts.body;
+    let regioncx = &*facts.region_inference_context;
+    let borrow_set = &*facts.borrow_set;
+
+    let mut loans: Vec<_> =
+        borrow_set.location_map.values().map(|borrow| (borrow.clone(), Vec::new())).collect();
+    let mut borrows = Borrows::new(tcx, body, regioncx, borrow_set)
+        .into_engine(tcx, body)
+        .iterate_to_fixpoint()
+        .into_results_cursor(body);
+    for (block, data) in body.basic_blocks.iter_enumerated() {
+        for statement_index in 0..=data.statements.len() {
+            let location = Location { block, statement_index };
+            borrows.seek_before_primary_effect(location);
+            for borrow in borrows.get().iter() {
+                loans[borrow.as_usize()].1.push(location);
+            }
+        }
+    }
+
+    let regions = regioncx
+        .regions()
+        .map(|region| {
+            let mut locations: Vec<_> = regioncx.region_locations(region).collect();
+            locations.sort();
+            (regioncx.is_universal_region(region), locations)
+        })
+        .collect();
+    let constraints = regioncx.outlives_constraints().collect();
+
+    let mut body = facts.body;
+    for data in body.basic_blocks_mut() {
+        let terminator = data.terminator_mut();
+        if let mir::TerminatorKind::FalseEdge { real_target, .. }
+        | mir::TerminatorKind::FalseUnwind { real_target, .. } = terminator.kind
+        {
+            terminator.kind = mir::TerminatorKind::Goto { target: real_target };
+        }
+    }
+    (result, BodyFacts { body, loans, regions, constraints })
+}
+
+pub(crate) fn borrowck_facts<'tcx>(
+    tables: &mut Tables<'tcx>,
+    collected: &CollectedFacts<'tcx>,
+    def_id: DefId,
+) -> Result<BorrowckFacts, Error> {
+    let bodies = collected.bodies.borrow();
+    let facts = def_id.as_local().and_then(|def_id| bodies.get(&def_id)).ok_or_else(|| {
+        Error::new(format!(
+            "no borrowck facts were collected for `{def_id:?}`, see \
+             `rustc_internal::collect_borrowck_facts`"
+        ))
+    })?;
+    Ok(facts.stable(tables))
+}
+
+impl<'tcx> BodyFacts<'tcx> {
+    fn stable(&self, tables: &mut Tables<'_>) -> BorrowckFacts {
+        BorrowckFacts {
+            body: self.body.stable(tables),
+            loans: self
+                .loans
+                .iter()
+                .map(|(borrow, live_at)| stable::Loan {
+                    kind: borrow.kind.stable(tables),
+                    borrowed_place: borrow.borrowed_place.stable(tables),
+                    assigned_place: borrow.assigned_place.stable(tables),
+                    region: borrow.region.as_usize(),
+                    reserve_location: body_location(borrow.reserve_location),
+                    activation: match borrow.activation_location {
+                        TwoPhaseActivation::NotTwoPhase => stable::TwoPhaseActivation::NotTwoPhase,
+                        TwoPhaseActivation::NotActivated => {
+                            stable::TwoPhaseActivation::NotActivated
+                        }
+                        TwoPhaseActivation::ActivatedAt(location) => {
+                            stable::TwoPhaseActivation::ActivatedAt(body_location(location))
+                        }
+                    },
+                    live_at: live_at.iter().copied().map(body_location).collect(),
+                })
+                .collect(),
+            regions: self
+                .regions
+                .iter()
+                .map(|(universal, live_at)| stable::RegionInfo {
+                    universal: *universal,
+                    live_at: live_at.iter().copied().map(body_location).collect(),
+                })
+                .collect(),
+            outlives_constraints: self
+                .constraints
+                .iter()
+                .map(|constraint| stable::OutlivesConstraint {
+                    sup: constraint.sup.as_usize(),
+                    sub: constraint.sub.as_usize(),
+                    location: constraint.locations.from_location().map(body_location),
+                    span: constraint.span.stable(tables),
+                    category: stable_mir::opaque(&constraint.category),
+                })
+                .collect(),
+        }
+    }
+}
+
+fn body_location(location: Location) -> BodyLocation {
+    BodyLocation { block: location.block.as_usize(), statement_index: location.statement_index }
+}
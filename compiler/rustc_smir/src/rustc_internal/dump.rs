@@ -0,0 +1,243 @@
+//! `-Z dump-stable-mir`: a machine-readable dump of the StableMIR of a crate.
+//!
+//! The dump contains the body of every function instance and the initializer of every static
+//! that is monomorphized in the crate. Types, layouts, allocations and spans are only referred to
+//! by their ids in those bodies, so the dump also contains a table for each of them with the
+//! entries that can be reached from the items.
+
+use std::io;
+use std::ops::ControlFlow;
+
+use rustc_data_structures::fx::{FxIndexMap, FxIndexSet};
+use rustc_middle::ty::TyCtxt;
+use rustc_session::config::StableMirDumpFormat;
+use serde::Serialize;
+use stable_mir::abi::{Layout, LayoutShape};
+use stable_mir::mir::alloc::{AllocId, GlobalAlloc};
+use stable_mir::mir::mono::{Instance, MonoItem, StaticDef};
+use stable_mir::mir::visit::Location;
+use stable_mir::mir::{Body, MirVisitor};
+use stable_mir::target::MachineInfo;
+use stable_mir::ty::{
+    Allocation, ConstantKind, LineInfo, MirConst, Span, Ty, TyConst, TyConstKind, TyKind,
+};
+use stable_mir::visitor::{Visitable, Visitor};
+use stable_mir::{CrateDef, Filename, Symbol};
+
+use super::{run, stable};
+
+/// The version of the dump format. It is bumped whenever the shape of the dump changes, which
+/// includes changes to the StableMIR data structures themselves.
+pub const FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Dump {
+    format_version: u32,
+    crate_name: Symbol,
+    target: MachineInfo,
+    items: Vec<Item>,
+    types: Vec<TyEntry>,
+    layouts: Vec<LayoutEntry>,
+    allocations: Vec<AllocEntry>,
+    spans: Vec<SpanEntry>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind")]
+enum Item {
+    Fn { name: Symbol, mangled_name: Symbol, instance: Instance, body: Option<Body> },
+    Static { name: Symbol, def: StaticDef, initializer: Option<Allocation> },
+}
+
+#[derive(Serialize)]
+struct TyEntry {
+    id: Ty,
+    pretty: String,
+    kind: TyKind,
+    /// Only computed for the types of locals, which are known to be monomorphic.
+    layout: Option<Layout>,
+}
+
+#[derive(Serialize)]
+struct LayoutEntry {
+    id: Layout,
+    shape: LayoutShape,
+}
+
+#[derive(Serialize)]
+struct AllocEntry {
+    id: AllocId,
+    alloc: GlobalAlloc,
+}
+
+#[derive(Serialize)]
+struct SpanEntry {
+    id: Span,
+    file: Filename,
+    lines: LineInfo,
+}
+
+/// Write the StableMIR of the monomorphized items of the local crate to `w`.
+pub fn write_smir_dump<W: io::Write>(
+    tcx: TyCtxt<'_>,
+    format: StableMirDumpFormat,
+    w: &mut W,
+) -> io::Result<()> {
+    let mono_items: FxIndexSet<_> = tcx
+        .collect_and_partition_mono_items(())
+        .1
+        .iter()
+        .flat_map(|cgu| cgu.items().keys().copied())
+        .collect();
+    let mut mono_items: Vec<_> = mono_items.into_iter().collect();
+    mono_items.sort_by_cached_key(|item| item.symbol_name(tcx).name);
+
+    let dump = run(tcx, || {
+        let mut collector = Collector::default();
+        let items = mono_items
+            .into_iter()
+            .filter_map(|item| match stable(item) {
+                MonoItem::Fn(instance) => {
+                    let body = instance.body();
+                    if let Some(body) = &body {
+                        collector.collect_body(body);
+                    }
+                    Some(Item::Fn {
+                        name: instance.name(),
+                        mangled_name: instance.mangled_name(),
+                        instance,
+                        body,
+                    })
+                }
+                MonoItem::Static(def) => {
+                    let initializer = def.eval_initializer().ok();
+                    if let Some(initializer) = &initializer {
+                        collector.collect_allocation(initializer);
+                    }
+                    Some(Item::Static { name: def.name(), def, initializer })
+                }
+                MonoItem::GlobalAsm(_) => None,
+            })
+            .collect();
+        collector.finish(items)
+    })
+    .map_err(|error| io::Error::other(error.to_string()))?;
+
+    match format {
+        StableMirDumpFormat::Json => serde_json::to_writer(&mut *w, &dump)?,
+    }
+    writeln!(w)
+}
+
+/// Collects the types, layouts, allocations and spans referred to by the dumped items.
+#[derive(Default)]
+struct Collector {
+    tys: FxIndexMap<Ty, Option<Layout>>,
+    allocs: FxIndexSet<AllocId>,
+    spans: FxIndexSet<Span>,
+}
+
+impl Collector {
+    fn collect_body(&mut self, body: &Body) {
+        for decl in body.locals() {
+            self.spans.insert(decl.span);
+            let layout = decl.ty.layout().ok();
+            self.tys.insert(decl.ty, layout);
+        }
+        self.visit_body(body);
+    }
+
+    fn collect_allocation(&mut self, allocation: &Allocation) {
+        for (_, prov) in &allocation.provenance.ptrs {
+            self.allocs.insert(prov.0);
+        }
+    }
+
+    fn finish(mut self, items: Vec<Item>) -> Dump {
+        // Allocations can point to other allocations, and vtables refer to types.
+        let mut allocations = Vec::new();
+        let mut i = 0;
+        while let Some(&id) = self.allocs.get_index(i) {
+            let alloc = GlobalAlloc::from(id);
+            match &alloc {
+                GlobalAlloc::Memory(allocation) => self.collect_allocation(allocation),
+                GlobalAlloc::VTable(ty, _) => {
+                    self.tys.entry(*ty).or_insert(None);
+                }
+                GlobalAlloc::Function(_) | GlobalAlloc::Static(_) => {}
+            }
+            allocations.push(AllocEntry { id, alloc });
+            i += 1;
+        }
+
+        // Add the types that the collected types are built from.
+        let mut expander = TyExpander { tys: FxIndexSet::default() };
+        for ty in self.tys.keys() {
+            let _ = ty.visit(&mut expander);
+        }
+        for ty in expander.tys {
+            self.tys.entry(ty).or_insert(None);
+        }
+
+        let layouts: FxIndexSet<_> = self.tys.values().flatten().copied().collect();
+        Dump {
+            format_version: FORMAT_VERSION,
+            crate_name: stable_mir::local_crate().name,
+            target: MachineInfo::target(),
+            items,
+            types: self
+                .tys
+                .into_iter()
+                .map(|(id, layout)| TyEntry { id, pretty: id.to_string(), kind: id.kind(), layout })
+                .collect(),
+            layouts: layouts.into_iter().map(|id| LayoutEntry { id, shape: id.shape() }).collect(),
+            allocations,
+            spans: self
+                .spans
+                .into_iter()
+                .map(|id| SpanEntry { id, file: id.get_filename(), lines: id.get_lines() })
+                .collect(),
+        }
+    }
+}
+
+impl MirVisitor for Collector {
+    fn visit_ty(&mut self, ty: &Ty, _location: Location) {
+        self.tys.entry(*ty).or_insert(None);
+    }
+
+    fn visit_span(&mut self, span: &Span) {
+        self.spans.insert(*span);
+    }
+
+    fn visit_mir_const(&mut self, constant: &MirConst, location: Location) {
+        if let ConstantKind::Allocated(allocation) = constant.kind() {
+            self.collect_allocation(allocation);
+        }
+        self.super_mir_const(constant, location);
+    }
+
+    fn visit_ty_const(&mut self, constant: &TyConst, location: Location) {
+        let _ = location;
+        if let TyConstKind::Value(_, allocation) = constant.kind() {
+            self.collect_allocation(allocation);
+        }
+        self.super_ty_const(constant);
+    }
+}
+
+struct TyExpander {
+    tys: FxIndexSet<Ty>,
+}
+
+impl Visitor for TyExpander {
+    type Break = ();
+
+    fn visit_ty(&mut self, ty: &Ty) -> ControlFlow<()> {
+        if self.tys.insert(*ty) {
+            ty.super_visit(self)
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
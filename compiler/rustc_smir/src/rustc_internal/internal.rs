@@ -10,7 +10,12 @@
 use stable_mir::abi::Layout;
 use stable_mir::mir::alloc::AllocId;
 use stable_mir::mir::mono::{Instance, MonoItem, StaticDef};
-use stable_mir::mir::{BinOp, Mutability, Place, ProjectionElem, Safety, UnOp};
+use stable_mir::mir::{
+    AggregateKind, AssertMessage, BinOp, BorrowKind, CastKind, ConstOperand, CopyNonOverlapping,
+    CoroutineDesugaring, CoroutineKind, CoroutineSource, FakeBorrowKind, MutBorrowKind, Mutability,
+    NonDivergingIntrinsic, NullOp, Operand, Place, PointerCoercion, ProjectionElem, RetagKind,
+    Rvalue, Safety, StatementKind, SwitchTargets, UnOp, VarDebugInfo, VarDebugInfoContents,
+};
 use stable_mir::ty::{
     Abi, AdtDef, Binder, BoundRegionKind, BoundTyKind, BoundVariableKind, ClosureKind, DynKind,
     ExistentialPredicate, ExistentialProjection, ExistentialTraitRef, FloatTy, FnSig,
@@ -592,6 +597,395 @@ fn internal<'tcx>(&self, _tables: &mut Tables<'_>, _tcx: TyCtxt<'tcx>) -> Self::
     }
 }
 
+impl RustcInternal for Operand {
+    type T<'tcx> = rustc_middle::mir::Operand<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        match self {
+            Operand::Copy(place) => rustc_middle::mir::Operand::Copy(place.internal(tables, tcx)),
+            Operand::Move(place) => rustc_middle::mir::Operand::Move(place.internal(tables, tcx)),
+            Operand::Constant(constant) => {
+                rustc_middle::mir::Operand::Constant(Box::new(constant.internal(tables, tcx)))
+            }
+        }
+    }
+}
+
+impl RustcInternal for ConstOperand {
+    type T<'tcx> = rustc_middle::mir::ConstOperand<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        rustc_middle::mir::ConstOperand {
+            span: self.span.internal(tables, tcx),
+            user_ty: self.user_ty.map(rustc_ty::UserTypeAnnotationIndex::from_usize),
+            const_: self.const_.internal(tables, tcx),
+        }
+    }
+}
+
+impl RustcInternal for Rvalue {
+    type T<'tcx> = rustc_middle::mir::Rvalue<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        use rustc_middle::mir::Rvalue as InternalRvalue;
+        match self {
+            Rvalue::AddressOf(mutability, place) => InternalRvalue::AddressOf(
+                mutability.internal(tables, tcx),
+                place.internal(tables, tcx),
+            ),
+            Rvalue::Aggregate(kind, operands) => InternalRvalue::Aggregate(
+                Box::new(kind.internal(tables, tcx)),
+                operands.iter().map(|op| op.internal(tables, tcx)).collect(),
+            ),
+            Rvalue::BinaryOp(bin_op, lhs, rhs) => InternalRvalue::BinaryOp(
+                bin_op.internal(tables, tcx),
+                Box::new((lhs.internal(tables, tcx), rhs.internal(tables, tcx))),
+            ),
+            Rvalue::CheckedBinaryOp(bin_op, lhs, rhs) => {
+                let bin_op = bin_op.internal(tables, tcx);
+                InternalRvalue::BinaryOp(
+                    bin_op.wrapping_to_overflowing().unwrap_or(bin_op),
+                    Box::new((lhs.internal(tables, tcx), rhs.internal(tables, tcx))),
+                )
+            }
+            Rvalue::Cast(kind, op, ty) => InternalRvalue::Cast(
+                kind.internal(tables, tcx),
+                op.internal(tables, tcx),
+                ty.internal(tables, tcx),
+            ),
+            Rvalue::CopyForDeref(place) => {
+                InternalRvalue::CopyForDeref(place.internal(tables, tcx))
+            }
+            Rvalue::Discriminant(place) => {
+                InternalRvalue::Discriminant(place.internal(tables, tcx))
+            }
+            Rvalue::Len(place) => InternalRvalue::Len(place.internal(tables, tcx)),
+            Rvalue::Ref(region, kind, place) => InternalRvalue::Ref(
+                region.internal(tables, tcx),
+                kind.internal(tables, tcx),
+                place.internal(tables, tcx),
+            ),
+            Rvalue::Repeat(op, count) => {
+                InternalRvalue::Repeat(op.internal(tables, tcx), count.internal(tables, tcx))
+            }
+            Rvalue::ShallowInitBox(op, ty) => {
+                InternalRvalue::ShallowInitBox(op.internal(tables, tcx), ty.internal(tables, tcx))
+            }
+            Rvalue::ThreadLocalRef(item) => {
+                InternalRvalue::ThreadLocalRef(item.internal(tables, tcx))
+            }
+            Rvalue::NullaryOp(null_op, ty) => {
+                InternalRvalue::NullaryOp(null_op.internal(tables, tcx), ty.internal(tables, tcx))
+            }
+            Rvalue::UnaryOp(un_op, op) => {
+                InternalRvalue::UnaryOp(un_op.internal(tables, tcx), op.internal(tables, tcx))
+            }
+            Rvalue::Use(op) => InternalRvalue::Use(op.internal(tables, tcx)),
+        }
+    }
+}
+
+impl RustcInternal for StatementKind {
+    type T<'tcx> = rustc_middle::mir::StatementKind<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        use rustc_middle::mir::StatementKind as InternalStatementKind;
+        match self {
+            StatementKind::Assign(place, rvalue) => InternalStatementKind::Assign(Box::new((
+                place.internal(tables, tcx),
+                rvalue.internal(tables, tcx),
+            ))),
+            StatementKind::SetDiscriminant { place, variant_index } => {
+                InternalStatementKind::SetDiscriminant {
+                    place: Box::new(place.internal(tables, tcx)),
+                    variant_index: variant_index.internal(tables, tcx),
+                }
+            }
+            StatementKind::Deinit(place) => {
+                InternalStatementKind::Deinit(Box::new(place.internal(tables, tcx)))
+            }
+            StatementKind::StorageLive(local) => {
+                InternalStatementKind::StorageLive(rustc_middle::mir::Local::from_usize(*local))
+            }
+            StatementKind::StorageDead(local) => {
+                InternalStatementKind::StorageDead(rustc_middle::mir::Local::from_usize(*local))
+            }
+            StatementKind::Retag(kind, place) => InternalStatementKind::Retag(
+                kind.internal(tables, tcx),
+                Box::new(place.internal(tables, tcx)),
+            ),
+            StatementKind::PlaceMention(place) => {
+                InternalStatementKind::PlaceMention(Box::new(place.internal(tables, tcx)))
+            }
+            StatementKind::Intrinsic(intrinsic) => {
+                InternalStatementKind::Intrinsic(Box::new(intrinsic.internal(tables, tcx)))
+            }
+            StatementKind::ConstEvalCounter => InternalStatementKind::ConstEvalCounter,
+            StatementKind::Nop => InternalStatementKind::Nop,
+            StatementKind::FakeRead(..)
+            | StatementKind::AscribeUserType { .. }
+            | StatementKind::Coverage(..) => {
+                unimplemented!("`{self:?}` cannot be converted back to an internal statement")
+            }
+        }
+    }
+}
+
+impl RustcInternal for AggregateKind {
+    type T<'tcx> = rustc_middle::mir::AggregateKind<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        use rustc_middle::mir::AggregateKind as InternalAggregateKind;
+        match self {
+            AggregateKind::Array(ty) => InternalAggregateKind::Array(ty.internal(tables, tcx)),
+            AggregateKind::Tuple => InternalAggregateKind::Tuple,
+            AggregateKind::Adt(def, variant, args, user_ty, field) => InternalAggregateKind::Adt(
+                def.0.internal(tables, tcx),
+                variant.internal(tables, tcx),
+                args.internal(tables, tcx),
+                user_ty.map(rustc_ty::UserTypeAnnotationIndex::from_usize),
+                field.map(rustc_target::abi::FieldIdx::from_usize),
+            ),
+            AggregateKind::Closure(def, args) => InternalAggregateKind::Closure(
+                def.0.internal(tables, tcx),
+                args.internal(tables, tcx),
+            ),
+            AggregateKind::Coroutine(def, args, _) => InternalAggregateKind::Coroutine(
+                def.0.internal(tables, tcx),
+                args.internal(tables, tcx),
+            ),
+            AggregateKind::RawPtr(ty, mutability) => InternalAggregateKind::RawPtr(
+                ty.internal(tables, tcx),
+                mutability.internal(tables, tcx),
+            ),
+        }
+    }
+}
+
+impl RustcInternal for CastKind {
+    type T<'tcx> = rustc_middle::mir::CastKind;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        use rustc_middle::mir::CastKind as InternalCastKind;
+        match self {
+            CastKind::PointerExposeAddress => InternalCastKind::PointerExposeProvenance,
+            CastKind::PointerWithExposedProvenance => {
+                InternalCastKind::PointerWithExposedProvenance
+            }
+            CastKind::PointerCoercion(coercion) => {
+                InternalCastKind::PointerCoercion(coercion.internal(tables, tcx))
+            }
+            CastKind::DynStar => InternalCastKind::DynStar,
+            CastKind::IntToInt => InternalCastKind::IntToInt,
+            CastKind::FloatToInt => InternalCastKind::FloatToInt,
+            CastKind::FloatToFloat => InternalCastKind::FloatToFloat,
+            CastKind::IntToFloat => InternalCastKind::IntToFloat,
+            CastKind::PtrToPtr => InternalCastKind::PtrToPtr,
+            CastKind::FnPtrToPtr => InternalCastKind::FnPtrToPtr,
+            CastKind::Transmute => InternalCastKind::Transmute,
+        }
+    }
+}
+
+impl RustcInternal for PointerCoercion {
+    type T<'tcx> = rustc_ty::adjustment::PointerCoercion;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        use rustc_ty::adjustment::PointerCoercion as InternalPointerCoercion;
+        match self {
+            PointerCoercion::ReifyFnPointer => InternalPointerCoercion::ReifyFnPointer,
+            PointerCoercion::UnsafeFnPointer => InternalPointerCoercion::UnsafeFnPointer,
+            PointerCoercion::ClosureFnPointer(safety) => {
+                InternalPointerCoercion::ClosureFnPointer(safety.internal(tables, tcx))
+            }
+            PointerCoercion::MutToConstPointer => InternalPointerCoercion::MutToConstPointer,
+            PointerCoercion::ArrayToPointer => InternalPointerCoercion::ArrayToPointer,
+            PointerCoercion::Unsize => InternalPointerCoercion::Unsize,
+        }
+    }
+}
+
+impl RustcInternal for NullOp {
+    type T<'tcx> = rustc_middle::mir::NullOp<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        match self {
+            NullOp::SizeOf => rustc_middle::mir::NullOp::SizeOf,
+            NullOp::AlignOf => rustc_middle::mir::NullOp::AlignOf,
+            NullOp::OffsetOf(indices) => rustc_middle::mir::NullOp::OffsetOf(
+                tcx.mk_offset_of_from_iter(indices.iter().map(|(variant, field)| {
+                    (variant.internal(tables, tcx), rustc_target::abi::FieldIdx::from_usize(*field))
+                })),
+            ),
+            NullOp::UbChecks => rustc_middle::mir::NullOp::UbChecks,
+        }
+    }
+}
+
+impl RustcInternal for BorrowKind {
+    type T<'tcx> = rustc_middle::mir::BorrowKind;
+
+    fn internal<'tcx>(&self, _tables: &mut Tables<'_>, _tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        use rustc_middle::mir::{FakeBorrowKind as InternalFake, MutBorrowKind as InternalMut};
+        match self {
+            BorrowKind::Shared => rustc_middle::mir::BorrowKind::Shared,
+            BorrowKind::Fake(FakeBorrowKind::Deep) => {
+                rustc_middle::mir::BorrowKind::Fake(InternalFake::Deep)
+            }
+            BorrowKind::Fake(FakeBorrowKind::Shallow) => {
+                rustc_middle::mir::BorrowKind::Fake(InternalFake::Shallow)
+            }
+            BorrowKind::Mut { kind } => rustc_middle::mir::BorrowKind::Mut {
+                kind: match kind {
+                    MutBorrowKind::Default => InternalMut::Default,
+                    MutBorrowKind::TwoPhaseBorrow => InternalMut::TwoPhaseBorrow,
+                    MutBorrowKind::ClosureCapture => InternalMut::ClosureCapture,
+                },
+            },
+        }
+    }
+}
+
+impl RustcInternal for AssertMessage {
+    type T<'tcx> = rustc_middle::mir::AssertMessage<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        use rustc_middle::mir::AssertKind;
+        match self {
+            AssertMessage::BoundsCheck { len, index } => AssertKind::BoundsCheck {
+                len: len.internal(tables, tcx),
+                index: index.internal(tables, tcx),
+            },
+            AssertMessage::Overflow(bin_op, lhs, rhs) => AssertKind::Overflow(
+                bin_op.internal(tables, tcx),
+                lhs.internal(tables, tcx),
+                rhs.internal(tables, tcx),
+            ),
+            AssertMessage::OverflowNeg(op) => AssertKind::OverflowNeg(op.internal(tables, tcx)),
+            AssertMessage::DivisionByZero(op) => {
+                AssertKind::DivisionByZero(op.internal(tables, tcx))
+            }
+            AssertMessage::RemainderByZero(op) => {
+                AssertKind::RemainderByZero(op.internal(tables, tcx))
+            }
+            AssertMessage::ResumedAfterReturn(coroutine) => {
+                AssertKind::ResumedAfterReturn(coroutine.internal(tables, tcx))
+            }
+            AssertMessage::ResumedAfterPanic(coroutine) => {
+                AssertKind::ResumedAfterPanic(coroutine.internal(tables, tcx))
+            }
+            AssertMessage::MisalignedPointerDereference { required, found } => {
+                AssertKind::MisalignedPointerDereference {
+                    required: required.internal(tables, tcx),
+                    found: found.internal(tables, tcx),
+                }
+            }
+        }
+    }
+}
+
+impl RustcInternal for CoroutineKind {
+    type T<'tcx> = rustc_hir::CoroutineKind;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        match self {
+            CoroutineKind::Desugared(desugaring, source) => {
+                let desugaring = match desugaring {
+                    CoroutineDesugaring::Async => rustc_hir::CoroutineDesugaring::Async,
+                    CoroutineDesugaring::Gen => rustc_hir::CoroutineDesugaring::Gen,
+                    CoroutineDesugaring::AsyncGen => rustc_hir::CoroutineDesugaring::AsyncGen,
+                };
+                let source = match source {
+                    CoroutineSource::Block => rustc_hir::CoroutineSource::Block,
+                    CoroutineSource::Closure => rustc_hir::CoroutineSource::Closure,
+                    CoroutineSource::Fn => rustc_hir::CoroutineSource::Fn,
+                };
+                rustc_hir::CoroutineKind::Desugared(desugaring, source)
+            }
+            CoroutineKind::Coroutine(movability) => {
+                rustc_hir::CoroutineKind::Coroutine(movability.internal(tables, tcx))
+            }
+        }
+    }
+}
+
+impl RustcInternal for RetagKind {
+    type T<'tcx> = rustc_middle::mir::RetagKind;
+
+    fn internal<'tcx>(&self, _tables: &mut Tables<'_>, _tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        match self {
+            RetagKind::FnEntry => rustc_middle::mir::RetagKind::FnEntry,
+            RetagKind::TwoPhase => rustc_middle::mir::RetagKind::TwoPhase,
+            RetagKind::Raw => rustc_middle::mir::RetagKind::Raw,
+            RetagKind::Default => rustc_middle::mir::RetagKind::Default,
+        }
+    }
+}
+
+impl RustcInternal for NonDivergingIntrinsic {
+    type T<'tcx> = rustc_middle::mir::NonDivergingIntrinsic<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        match self {
+            NonDivergingIntrinsic::Assume(op) => {
+                rustc_middle::mir::NonDivergingIntrinsic::Assume(op.internal(tables, tcx))
+            }
+            NonDivergingIntrinsic::CopyNonOverlapping(CopyNonOverlapping { src, dst, count }) => {
+                rustc_middle::mir::NonDivergingIntrinsic::CopyNonOverlapping(
+                    rustc_middle::mir::CopyNonOverlapping {
+                        src: src.internal(tables, tcx),
+                        dst: dst.internal(tables, tcx),
+                        count: count.internal(tables, tcx),
+                    },
+                )
+            }
+        }
+    }
+}
+
+impl RustcInternal for SwitchTargets {
+    type T<'tcx> = rustc_middle::mir::SwitchTargets;
+
+    fn internal<'tcx>(&self, _tables: &mut Tables<'_>, _tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        rustc_middle::mir::SwitchTargets::new(
+            self.branches()
+                .map(|(value, target)| (value, rustc_middle::mir::BasicBlock::from_usize(target))),
+            rustc_middle::mir::BasicBlock::from_usize(self.otherwise()),
+        )
+    }
+}
+
+impl RustcInternal for VarDebugInfo {
+    type T<'tcx> = rustc_middle::mir::VarDebugInfo<'tcx>;
+
+    fn internal<'tcx>(&self, tables: &mut Tables<'_>, tcx: TyCtxt<'tcx>) -> Self::T<'tcx> {
+        let VarDebugInfo { name, source_info, composite, value, argument_index } = self;
+        let value = match value {
+            VarDebugInfoContents::Place(place) => {
+                rustc_middle::mir::VarDebugInfoContents::Place(place.internal(tables, tcx))
+            }
+            VarDebugInfoContents::Const(constant) => {
+                rustc_middle::mir::VarDebugInfoContents::Const(constant.internal(tables, tcx))
+            }
+        };
+        rustc_middle::mir::VarDebugInfo {
+            name: Symbol::intern(name),
+            source_info: rustc_middle::mir::SourceInfo {
+                span: source_info.span.internal(tables, tcx),
+                scope: rustc_middle::mir::SourceScope::from_u32(source_info.scope),
+            },
+            composite: composite.as_ref().map(|composite| {
+                Box::new(rustc_middle::mir::VarDebugInfoFragment {
+                    ty: composite.ty.internal(tables, tcx),
+                    projection: composite.projection.internal(tables, tcx),
+                })
+            }),
+            value,
+            argument_index: *argument_index,
+        }
+    }
+}
+
 impl<T> RustcInternal for &T
 where
     T: RustcInternal,
@@ -21,8 +21,15 @@
 use std::hash::Hash;
 use std::ops::Index;
 
+mod borrowck;
+pub mod dump;
 mod internal;
 pub mod pretty;
+mod transform;
+
+pub use borrowck::collect_borrowck_facts;
+pub(crate) use borrowck::{borrowck_facts, CollectedFacts};
+pub use transform::{register_mir_transform, MirTransform};
 
 /// Convert an internal Rust compiler item into its stable counterpart, if one exists.
 ///
@@ -203,21 +210,44 @@ pub(crate) fn with_tables<R>(f: impl for<'tcx> FnOnce(&mut Tables<'tcx>) -> R) -
     })
 }
 
+/// Loads the current context and calls a function with the borrowck facts it collected, if it
+/// is a context of the compilation of `tcx`.
+pub(crate) fn with_collected_facts<'tcx, R>(
+    tcx: TyCtxt<'tcx>,
+    f: impl FnOnce(&CollectedFacts<'tcx>) -> R,
+) -> Option<R> {
+    if !TLV.is_set() {
+        return None;
+    }
+    TLV.with(|tlv| {
+        let ptr = tlv.get();
+        assert!(!ptr.is_null());
+        let wrapper = ptr as *const TablesWrapper<'tcx>;
+        // SAFETY: the wrapper outlives the scope of `TLV`, and its lifetime is only relied upon
+        // once its type context is known to be `tcx`.
+        let collected = unsafe { &(*wrapper).1 };
+        collected.is_of(tcx).then(|| f(collected))
+    })
+}
+
 pub fn run<F, T>(tcx: TyCtxt<'_>, f: F) -> Result<T, Error>
 where
     F: FnOnce() -> T,
 {
-    let tables = TablesWrapper(RefCell::new(Tables {
-        tcx,
-        def_ids: IndexMap::default(),
-        alloc_ids: IndexMap::default(),
-        spans: IndexMap::default(),
-        types: IndexMap::default(),
-        instances: IndexMap::default(),
-        ty_consts: IndexMap::default(),
-        mir_consts: IndexMap::default(),
-        layouts: IndexMap::default(),
-    }));
+    let tables = TablesWrapper(
+        RefCell::new(Tables {
+            tcx,
+            def_ids: IndexMap::default(),
+            alloc_ids: IndexMap::default(),
+            spans: IndexMap::default(),
+            types: IndexMap::default(),
+            instances: IndexMap::default(),
+            ty_consts: IndexMap::default(),
+            mir_consts: IndexMap::default(),
+            layouts: IndexMap::default(),
+        }),
+        CollectedFacts::new(tcx),
+    );
     stable_mir::compiler_interface::run(&tables, || init(&tables, f))
 }
 
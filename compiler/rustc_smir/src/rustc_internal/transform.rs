@@ -0,0 +1,282 @@
+//! Replacement of the optimized MIR of local items by a StableMIR callback.
+//!
+//! The callback is applied by overriding the `optimized_mir` query, so the new body is the one
+//! used for monomorphization, codegen, and the MIR encoded in the crate metadata.
+
+use std::sync::OnceLock;
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_middle::mir::{self, SourceInfo, UnwindTerminateReason};
+use rustc_middle::ty::TyCtxt;
+use rustc_middle::util::Providers;
+use rustc_session::Session;
+use rustc_span::def_id::LocalDefId;
+use rustc_span::source_map::Spanned;
+use stable_mir::mir::{Body, StatementKind, Terminator, TerminatorKind, UnwindAction};
+use stable_mir::CrateItem;
+
+use super::{run, with_tables, RustcInternal, TLV};
+use crate::rustc_smir::{Stable, Tables};
+
+/// A callback that can replace the optimized MIR of a local item.
+///
+/// It is given the item and its optimized MIR, and returns the body to use instead, or `None` to
+/// keep the original one. The new body must keep the arguments of the original one.
+pub type MirTransform = fn(CrateItem, Body) -> Option<Body>;
+
+type OverrideQueries = fn(&Session, &mut Providers);
+type OptimizedMir = for<'tcx> fn(TyCtxt<'tcx>, LocalDefId) -> &'tcx mir::Body<'tcx>;
+
+/// The registered transform, and the `override_queries` callback that was set before it.
+static MIR_TRANSFORM: OnceLock<(MirTransform, Option<OverrideQueries>)> = OnceLock::new();
+
+/// The `optimized_mir` provider whose result is given to the transform.
+static OPTIMIZED_MIR: OnceLock<OptimizedMir> = OnceLock::new();
+
+/// Register a callback that can replace the optimized MIR of the local items of the crate
+/// compiled with `config`. This is meant to be called from `Callbacks::config`.
+///
+/// Any `override_queries` callback already set in `config` is still invoked. Only one transform
+/// can be registered per process.
+///
+/// Bodies that contain inline assembly, coverage statements or tail calls, coroutine bodies, and
+/// bodies that are first requested while StableMIR is already running, e.g., by the transform
+/// itself, keep their original MIR. So do bodies for which the transform returns inline assembly,
+/// or `FakeRead`, `AscribeUserType` or `Coverage` statements, which cannot be converted back.
+///
+/// The new body is validated, and compilation aborts with an internal error if it is invalid.
+pub fn register_mir_transform(config: &mut rustc_interface::Config, transform: MirTransform) {
+    if MIR_TRANSFORM.set((transform, config.override_queries)).is_err() {
+        panic!("a MIR transform has already been registered");
+    }
+    config.override_queries = Some(override_queries);
+}
+
+fn override_queries(sess: &Session, providers: &mut Providers) {
+    let (_, previous) = MIR_TRANSFORM.get().unwrap();
+    if let Some(previous) = previous {
+        previous(sess, providers);
+    }
+    let optimized_mir = providers.queries.optimized_mir;
+    OPTIMIZED_MIR.get_or_init(|| optimized_mir);
+    providers.queries.optimized_mir = transformed_optimized_mir;
+}
+
+fn transformed_optimized_mir<'tcx>(tcx: TyCtxt<'tcx>, def_id: LocalDefId) -> &'tcx mir::Body<'tcx> {
+    let body = OPTIMIZED_MIR.get().unwrap()(tcx, def_id);
+    if TLV.is_set() || !can_transform(body) {
+        return body;
+    }
+    let (transform, _) = MIR_TRANSFORM.get().unwrap();
+    let new_body = run(tcx, || {
+        let (item, stable_body) =
+            with_tables(|tables| (tables.crate_item(def_id.to_def_id()), body.stable(tables)));
+        let new_body = transform(item, stable_body)?;
+        if !can_convert(&new_body) {
+            return None;
+        }
+        Some(with_tables(|tables| internal_body(tables, tcx, body, &new_body)))
+    })
+    .unwrap();
+    match new_body {
+        Some(mut new_body) => {
+            rustc_mir_transform::finish_rewritten_optimized_mir(
+                tcx,
+                &mut new_body,
+                "after the StableMIR transform".to_owned(),
+            );
+            tcx.arena.alloc(new_body)
+        }
+        None => body,
+    }
+}
+
+/// Whether the body returned by the transform can be converted back to internal MIR.
+fn can_convert(body: &Body) -> bool {
+    body.blocks.iter().all(|block| {
+        !matches!(block.terminator.kind, TerminatorKind::InlineAsm { .. })
+            && block.statements.iter().all(|statement| {
+                !matches!(
+                    statement.kind,
+                    StatementKind::FakeRead(..)
+                        | StatementKind::AscribeUserType { .. }
+                        | StatementKind::Coverage(..)
+                )
+            })
+    })
+}
+
+/// Whether `body` can be converted to StableMIR and back.
+fn can_transform(body: &mir::Body<'_>) -> bool {
+    body.coroutine.is_none()
+        && body.basic_blocks.iter().all(|block| {
+            !matches!(
+                block.terminator().kind,
+                mir::TerminatorKind::InlineAsm { .. } | mir::TerminatorKind::TailCall { .. }
+            ) && block.statements.iter().all(|statement| match &statement.kind {
+                mir::StatementKind::Coverage(..) => false,
+                mir::StatementKind::Assign(assign) => !matches!(
+                    &assign.1,
+                    mir::Rvalue::Aggregate(kind, _)
+                        if matches!(**kind, mir::AggregateKind::CoroutineClosure(..))
+                ),
+                _ => true,
+            })
+        })
+}
+
+/// Build the internal MIR of `new_body`, which was produced from the original `body`.
+///
+/// StableMIR does not have source scopes nor cleanup markers, so statements and terminators take
+/// the scope of the original ones with the same span, and a block is a cleanup block if it can
+/// be reached from an unwind edge.
+fn internal_body<'tcx>(
+    tables: &mut Tables<'_>,
+    tcx: TyCtxt<'tcx>,
+    body: &mir::Body<'tcx>,
+    new_body: &Body,
+) -> mir::Body<'tcx> {
+    assert_eq!(
+        new_body.arg_locals().len(),
+        body.arg_count,
+        "a MIR transform must not change the arguments of a body"
+    );
+
+    let mut scopes = FxHashMap::default();
+    for block in body.basic_blocks.iter() {
+        for statement in &block.statements {
+            scopes.entry(statement.source_info.span).or_insert(statement.source_info.scope);
+        }
+        let source_info = block.terminator().source_info;
+        scopes.entry(source_info.span).or_insert(source_info.scope);
+    }
+    let source_info = |span: stable_mir::ty::Span, tables: &mut Tables<'_>| {
+        let span = span.internal(tables, tcx);
+        SourceInfo {
+            span,
+            scope: scopes.get(&span).copied().unwrap_or(mir::OUTERMOST_SOURCE_SCOPE),
+        }
+    };
+
+    let mut is_cleanup = vec![false; new_body.blocks.len()];
+    let mut worklist: Vec<_> = new_body
+        .blocks
+        .iter()
+        .filter_map(|block| match block.terminator.kind.unwind() {
+            Some(UnwindAction::Cleanup(target)) => Some(*target),
+            _ => None,
+        })
+        .collect();
+    while let Some(bb) = worklist.pop() {
+        if !std::mem::replace(&mut is_cleanup[bb], true) {
+            worklist.extend(new_body.blocks[bb].terminator.successors());
+        }
+    }
+
+    let mut internal = body.clone();
+    internal.local_decls = new_body
+        .local_decls()
+        .map(|(local, decl)| {
+            let ty = decl.ty.internal(tables, tcx);
+            let mut internal_decl = match body.local_decls.get(mir::Local::from_usize(local)) {
+                Some(original) => original.clone(),
+                None => {
+                    let source_info = source_info(decl.span, tables);
+                    let mut new_decl = mir::LocalDecl::new(ty, source_info.span);
+                    new_decl.source_info = source_info;
+                    new_decl
+                }
+            };
+            internal_decl.ty = ty;
+            internal_decl.mutability = decl.mutability.internal(tables, tcx);
+            internal_decl
+        })
+        .collect();
+    *internal.basic_blocks_mut() = new_body
+        .blocks
+        .iter()
+        .zip(is_cleanup)
+        .map(|(block, is_cleanup)| mir::BasicBlockData {
+            statements: block
+                .statements
+                .iter()
+                .map(|statement| mir::Statement {
+                    source_info: source_info(statement.span, tables),
+                    kind: statement.kind.internal(tables, tcx),
+                })
+                .collect(),
+            terminator: Some(mir::Terminator {
+                source_info: source_info(block.terminator.span, tables),
+                kind: internal_terminator(tables, tcx, &block.terminator, is_cleanup),
+            }),
+            is_cleanup,
+        })
+        .collect();
+    internal.var_debug_info =
+        new_body.var_debug_info.iter().map(|info| info.internal(tables, tcx)).collect();
+    internal
+}
+
+fn internal_terminator<'tcx>(
+    tables: &mut Tables<'_>,
+    tcx: TyCtxt<'tcx>,
+    terminator: &Terminator,
+    is_cleanup: bool,
+) -> mir::TerminatorKind<'tcx> {
+    let reason =
+        if is_cleanup { UnwindTerminateReason::InCleanup } else { UnwindTerminateReason::Abi };
+    let unwind = |unwind: &UnwindAction| match unwind {
+        UnwindAction::Continue => mir::UnwindAction::Continue,
+        UnwindAction::Unreachable => mir::UnwindAction::Unreachable,
+        UnwindAction::Terminate => mir::UnwindAction::Terminate(reason),
+        UnwindAction::Cleanup(target) => mir::UnwindAction::Cleanup(block(*target)),
+    };
+    match &terminator.kind {
+        TerminatorKind::Goto { target } => mir::TerminatorKind::Goto { target: block(*target) },
+        TerminatorKind::SwitchInt { discr, targets } => mir::TerminatorKind::SwitchInt {
+            discr: discr.internal(tables, tcx),
+            targets: targets.internal(tables, tcx),
+        },
+        TerminatorKind::Resume => mir::TerminatorKind::UnwindResume,
+        TerminatorKind::Abort => mir::TerminatorKind::UnwindTerminate(reason),
+        TerminatorKind::Return => mir::TerminatorKind::Return,
+        TerminatorKind::Unreachable => mir::TerminatorKind::Unreachable,
+        TerminatorKind::Drop { place, target, unwind: drop_unwind } => mir::TerminatorKind::Drop {
+            place: place.internal(tables, tcx),
+            target: block(*target),
+            unwind: unwind(drop_unwind),
+            replace: false,
+        },
+        TerminatorKind::Call { func, args, destination, target, unwind: call_unwind } => {
+            let span = terminator.span.internal(tables, tcx);
+            mir::TerminatorKind::Call {
+                func: func.internal(tables, tcx),
+                args: args
+                    .iter()
+                    .map(|arg| Spanned { node: arg.internal(tables, tcx), span })
+                    .collect(),
+                destination: destination.internal(tables, tcx),
+                target: target.map(block),
+                unwind: unwind(call_unwind),
+                call_source: mir::CallSource::Normal,
+                fn_span: span,
+            }
+        }
+        TerminatorKind::Assert { cond, expected, msg, target, unwind: assert_unwind } => {
+            mir::TerminatorKind::Assert {
+                cond: cond.internal(tables, tcx),
+                expected: *expected,
+                msg: Box::new(msg.internal(tables, tcx)),
+                target: block(*target),
+                unwind: unwind(assert_unwind),
+            }
+        }
+        TerminatorKind::InlineAsm { .. } => {
+            unreachable!("bodies with inline assembly are rejected by `can_convert`")
+        }
+    }
+}
+
+fn block(bb: stable_mir::mir::BasicBlockIdx) -> mir::BasicBlock {
+    mir::BasicBlock::from_usize(bb)
+}
@@ -7,6 +7,7 @@
 
 use rustc_abi::HasDataLayout;
 use rustc_hir::LangItem;
+use rustc_middle::traits::ImplSource;
 use rustc_middle::ty::layout::{
     FnAbiOf, FnAbiOfHelpers, HasParamEnv, HasTyCtxt, LayoutOf, LayoutOfHelpers,
 };
@@ -16,9 +17,12 @@
 };
 use rustc_middle::{mir, ty};
 use rustc_span::def_id::LOCAL_CRATE;
+use rustc_trait_selection::infer::{InferCtxtExt, TyCtxtInferExt};
+use rustc_trait_selection::traits::{Obligation, ObligationCause, SelectionContext};
 use stable_mir::abi::{FnAbi, Layout, LayoutShape};
 use stable_mir::compiler_interface::Context;
 use stable_mir::mir::alloc::GlobalAlloc;
+use stable_mir::mir::borrowck::BorrowckFacts;
 use stable_mir::mir::mono::{InstanceDef, StaticDef};
 use stable_mir::mir::{BinOp, Body, Place, UnOp};
 use stable_mir::target::{MachineInfo, MachineSize};
@@ -31,7 +35,7 @@
 use std::cell::RefCell;
 use std::iter;
 
-use crate::rustc_internal::RustcInternal;
+use crate::rustc_internal::{borrowck_facts, CollectedFacts, RustcInternal};
 use crate::rustc_smir::builder::BodyBuilder;
 use crate::rustc_smir::{alloc, new_item_kind, smir_crate, Stable, Tables};
 
@@ -673,6 +677,72 @@ fn resolve_closure(
         )
     }
 
+    fn implements_trait(
+        &self,
+        ty: stable_mir::ty::Ty,
+        trait_def: stable_mir::ty::TraitDef,
+        args: &GenericArgs,
+        item: Option<DefId>,
+    ) -> bool {
+        let mut tables = self.0.borrow_mut();
+        let tcx = tables.tcx;
+        let param_env = param_env_of(&tables, item);
+        let trait_ref =
+            stable_mir::ty::TraitRef::new(trait_def, ty, args).internal(&mut *tables, tcx);
+        let infcx = tcx.infer_ctxt().build();
+        infcx
+            .type_implements_trait(trait_ref.def_id, trait_ref.args, param_env)
+            .must_apply_modulo_regions()
+    }
+
+    fn normalize(
+        &self,
+        ty: stable_mir::ty::Ty,
+        item: Option<DefId>,
+    ) -> Result<stable_mir::ty::Ty, Error> {
+        let mut tables = self.0.borrow_mut();
+        let tcx = tables.tcx;
+        let param_env = param_env_of(&tables, item);
+        let internal_ty = ty.internal(&mut *tables, tcx);
+        match tcx.try_normalize_erasing_regions(param_env, internal_ty) {
+            Ok(normalized) => Ok(normalized.stable(&mut *tables)),
+            Err(_) => Err(Error::new(format!("Failed to normalize `{internal_ty}`"))),
+        }
+    }
+
+    fn impl_for(
+        &self,
+        trait_ref: &stable_mir::ty::TraitRef,
+        item: Option<DefId>,
+    ) -> Option<stable_mir::ty::ImplDef> {
+        let mut tables = self.0.borrow_mut();
+        let tcx = tables.tcx;
+        let param_env = param_env_of(&tables, item);
+        let trait_ref = trait_ref.internal(&mut *tables, tcx);
+        let infcx = tcx.infer_ctxt().build();
+        let mut selcx = SelectionContext::new(&infcx);
+        let obligation = Obligation::new(tcx, ObligationCause::dummy(), param_env, trait_ref);
+        match selcx.select(&obligation) {
+            Ok(Some(ImplSource::UserDefined(data))) => Some(tables.impl_def(data.impl_def_id)),
+            Ok(Some(_) | None) | Err(_) => None,
+        }
+    }
+
+    fn borrowck_facts(&self, item: CrateItem) -> Result<BorrowckFacts, Error> {
+        let (tcx, def_id) = {
+            let tables = self.0.borrow();
+            (tables.tcx, tables[item.0])
+        };
+        // Borrow check the body now if it has not been yet, so that its facts are collected.
+        if let Some(local) = def_id.as_local() {
+            if tcx.is_mir_available(def_id) {
+                tcx.ensure().mir_borrowck(local);
+            }
+        }
+        let mut tables = self.0.borrow_mut();
+        borrowck_facts(&mut *tables, &self.1, def_id)
+    }
+
     fn eval_instance(&self, def: InstanceDef, const_ty: Ty) -> Result<Allocation, Error> {
         let mut tables = self.0.borrow_mut();
         let instance = tables.instances[def];
@@ -783,7 +853,7 @@ fn unop_ty(&self, un_op: UnOp, arg: Ty) -> Ty {
     }
 }
 
-pub struct TablesWrapper<'tcx>(pub RefCell<Tables<'tcx>>);
+pub struct TablesWrapper<'tcx>(pub RefCell<Tables<'tcx>>, pub(crate) CollectedFacts<'tcx>);
 
 /// Implement error handling for extracting function ABI information.
 impl<'tcx> FnAbiOfHelpers<'tcx> for Tables<'tcx> {
@@ -831,3 +901,12 @@ fn data_layout(&self) -> &rustc_abi::TargetDataLayout {
         self.tcx.data_layout()
     }
 }
+
+/// The environment of the trait queries: the one of `item` with opaque types revealed, like in
+/// its optimized MIR, or an empty one if `item` is `None`.
+fn param_env_of<'tcx>(tables: &Tables<'tcx>, item: Option<DefId>) -> ParamEnv<'tcx> {
+    match item {
+        Some(item) => tables.tcx.param_env_reveal_all_normalized(tables[item]),
+        None => ParamEnv::reveal_all(),
+    }
+}
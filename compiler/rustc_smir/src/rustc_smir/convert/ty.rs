@@ -828,6 +828,7 @@ fn stable(&self, tables: &mut Tables<'_>) -> Self::T {
                 })
             }
             ty::ReErased => RegionKind::ReErased,
+            ty::ReVar(vid) => RegionKind::ReVar(vid.as_usize()),
             _ => unreachable!("{self:?}"),
         }
     }
@@ -725,6 +725,9 @@
         destructuring_assignment,
         diagnostic,
         diagnostic_namespace,
+        diagnostic_on_missing_method,
+        diagnostic_on_move,
+        diagnostic_on_type_mismatch,
         direct,
         discriminant_kind,
         discriminant_type,
@@ -1328,6 +1331,9 @@
         ok_or_else,
         omit_gdb_pretty_printer_section,
         on,
+        on_missing_method,
+        on_move,
+        on_type_mismatch,
         on_unimplemented,
         opaque,
         ops,
@@ -39,10 +39,17 @@ trait_selection_invalid_format_specifier = invalid format specifier
 trait_selection_invalid_on_clause_in_rustc_on_unimplemented = invalid `on`-clause in `#[rustc_on_unimplemented]`
     .label = invalid on-clause here
 
+trait_selection_malformed_on_type_attr = malformed `{$attr_name}` attribute
+    .help = only `message`, `note` and `label` are allowed as options
+    .label = invalid option found here
+
 trait_selection_malformed_on_unimplemented_attr = malformed `on_unimplemented` attribute
     .help = only `message`, `note` and `label` are allowed as options
     .label = invalid option found here
 
+trait_selection_missing_options_for_on_type_attr = missing options for `{$attr_name}` attribute
+    .help = at least one of the `message`, `note` and `label` options are expected
+
 trait_selection_missing_options_for_on_unimplemented_attr = missing options for `on_unimplemented` attribute
     .help = at least one of the `message`, `note` and `label` options are expected
 
@@ -64,6 +71,9 @@ trait_selection_trait_has_no_impls = this trait has no implementations, consider
 trait_selection_ty_alias_overflow = in case this is a recursive type alias, consider using a struct, enum, or union instead
 trait_selection_unable_to_construct_constant_value = unable to construct a constant value for the unevaluated constant {$unevaluated}
 
+trait_selection_unknown_format_parameter_for_on_type_attr = there is no parameter `{$argument_name}` on type `{$type_name}`
+    .help = expect either a generic argument name or {"`{Self}`"} as format argument
+
 trait_selection_unknown_format_parameter_for_on_unimplemented_attr = there is no parameter `{$argument_name}` on trait `{$trait_name}`
     .help = expect either a generic argument name or {"`{Self}`"} as format argument
 
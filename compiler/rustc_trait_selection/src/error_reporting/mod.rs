@@ -1 +1,2 @@
+pub mod on_type;
 pub mod traits;
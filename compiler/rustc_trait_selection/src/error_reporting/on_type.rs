@@ -0,0 +1,316 @@
+//! The `#[diagnostic::on_move]`, `#[diagnostic::on_type_mismatch]` and
+//! `#[diagnostic::on_missing_method]` attributes.
+//!
+//! They are placed on struct, enum and union definitions, and customize the
+//! errors about values of that type: moved values in `rustc_borrowck`,
+//! mismatched types and missing methods in `rustc_hir_typeck`. Like
+//! `#[diagnostic::on_unimplemented]`, they accept the `message`, `label` and
+//! `note` options, whose format strings may refer to `{Self}` and to the
+//! generic parameters of the type. Malformed attributes are linted by
+//! `unknown_or_malformed_diagnostic_attributes` and ignored.
+//!
+//! Each attribute is behind its own feature gate, which is checked in the crate
+//! that defines the type. Without it, the attribute is linted as unknown during
+//! name resolution and otherwise ignored. The attributes of types from other
+//! crates are applied whether or not the current crate enables the feature.
+
+use std::path::PathBuf;
+
+use rustc_ast::{AttrArgs, AttrArgsEq, AttrKind, Attribute};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_errors::LintDiagnostic;
+use rustc_hir::def_id::DefId;
+use rustc_macros::LintDiagnostic;
+use rustc_middle::ty::{self, GenericParamDefKind, Ty, TyCtxt};
+use rustc_parse_format::{ParseMode, Parser, Piece, Position};
+use rustc_session::lint::builtin::UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES;
+use rustc_span::symbol::{kw, sym, Symbol};
+use rustc_span::Span;
+
+use crate::error_reporting::traits::on_unimplemented::{
+    DisallowedPositionalArgument, IgnoredDiagnosticOption, InvalidFormatSpecifier,
+    WrappedParserError,
+};
+
+#[derive(LintDiagnostic)]
+#[diag(trait_selection_malformed_on_type_attr)]
+#[help]
+pub struct MalformedOnTypeAttrLint {
+    pub attr_name: Symbol,
+    #[label]
+    pub span: Span,
+}
+
+#[derive(LintDiagnostic)]
+#[diag(trait_selection_missing_options_for_on_type_attr)]
+#[help]
+pub struct MissingOptionsForOnTypeAttr {
+    pub attr_name: Symbol,
+}
+
+#[derive(LintDiagnostic)]
+#[diag(trait_selection_unknown_format_parameter_for_on_type_attr)]
+#[help]
+pub struct UnknownFormatParameterForOnTypeAttr {
+    pub argument_name: Symbol,
+    pub type_name: Symbol,
+}
+
+#[derive(Clone, Debug)]
+pub struct OnTypeFormatString {
+    symbol: Symbol,
+    span: Span,
+}
+
+/// A `#[diagnostic::on_move]`, `#[diagnostic::on_type_mismatch]` or
+/// `#[diagnostic::on_missing_method]` attribute, merged over all of its
+/// occurrences on the type.
+#[derive(Debug)]
+pub struct OnTypeDirective {
+    pub message: Option<OnTypeFormatString>,
+    pub label: Option<OnTypeFormatString>,
+    pub notes: Vec<OnTypeFormatString>,
+}
+
+/// The formatted options of an [`OnTypeDirective`].
+#[derive(Debug, Default)]
+pub struct OnTypeNote {
+    pub message: Option<String>,
+    pub label: Option<String>,
+    pub notes: Vec<String>,
+}
+
+impl<'tcx> OnTypeDirective {
+    /// Parses the `#[diagnostic::{attr_name}]` attributes of the type
+    /// `item_def_id`. Later occurrences add notes, but their message and label
+    /// are ignored if an earlier occurrence already has one.
+    pub fn of_item(tcx: TyCtxt<'tcx>, item_def_id: DefId, attr_name: Symbol) -> Option<Self> {
+        tcx.get_attrs_by_path(item_def_id, &[sym::diagnostic, attr_name])
+            .filter_map(|attr| Self::parse_attribute(tcx, item_def_id, attr_name, attr))
+            .reduce(|aggr, directive| {
+                IgnoredDiagnosticOption::maybe_emit_warning(
+                    tcx,
+                    item_def_id,
+                    directive.message.as_ref().map(|f| f.span),
+                    aggr.message.as_ref().map(|f| f.span),
+                    "message",
+                );
+                IgnoredDiagnosticOption::maybe_emit_warning(
+                    tcx,
+                    item_def_id,
+                    directive.label.as_ref().map(|f| f.span),
+                    aggr.label.as_ref().map(|f| f.span),
+                    "label",
+                );
+                let mut notes = aggr.notes;
+                notes.extend(directive.notes);
+                Self {
+                    message: aggr.message.or(directive.message),
+                    label: aggr.label.or(directive.label),
+                    notes,
+                }
+            })
+    }
+
+    fn parse_attribute(
+        tcx: TyCtxt<'tcx>,
+        item_def_id: DefId,
+        attr_name: Symbol,
+        attr: &Attribute,
+    ) -> Option<Self> {
+        let Some(items) = attr.meta_item_list() else {
+            match &attr.kind {
+                AttrKind::Normal(p) if !matches!(p.item.args, AttrArgs::Empty) => {
+                    let report_span = match &p.item.args {
+                        AttrArgs::Eq(eq_span, AttrArgsEq::Ast(expr)) => eq_span.to(expr.span),
+                        AttrArgs::Eq(span, AttrArgsEq::Hir(expr)) => span.to(expr.span),
+                        _ => attr.span,
+                    };
+                    let lint = MalformedOnTypeAttrLint { attr_name, span: report_span };
+                    emit_lint(tcx, item_def_id, report_span, lint)
+                }
+                _ => {
+                    let lint = MissingOptionsForOnTypeAttr { attr_name };
+                    emit_lint(tcx, item_def_id, attr.span, lint)
+                }
+            }
+            return None;
+        };
+
+        let mut message = None;
+        let mut label = None;
+        let mut notes = Vec::new();
+        for item in &items {
+            let parse = |value| OnTypeFormatString::parse(tcx, item_def_id, value, item.span());
+            match item.value_str() {
+                Some(value) if item.has_name(sym::message) && message.is_none() => {
+                    message = Some(parse(value))
+                }
+                Some(value) if item.has_name(sym::label) && label.is_none() => {
+                    label = Some(parse(value))
+                }
+                Some(value) if item.has_name(sym::note) => notes.push(parse(value)),
+                _ => {
+                    let lint = MalformedOnTypeAttrLint { attr_name, span: item.span() };
+                    emit_lint(tcx, item_def_id, item.span(), lint)
+                }
+            }
+        }
+
+        let directive = OnTypeDirective { message, label, notes };
+        debug!("parse_attribute({:?}, {:?}) = {:?}", item_def_id, attr_name, directive);
+        Some(directive)
+    }
+
+    /// Formats the options for a value of type `ty`, which must be an
+    /// instance of the type the attribute was placed on.
+    pub fn evaluate(
+        &self,
+        tcx: TyCtxt<'tcx>,
+        ty: Ty<'tcx>,
+        long_ty_file: &mut Option<PathBuf>,
+    ) -> OnTypeNote {
+        OnTypeNote {
+            message: self.message.as_ref().map(|m| m.format(tcx, ty, long_ty_file)),
+            label: self.label.as_ref().map(|l| l.format(tcx, ty, long_ty_file)),
+            notes: self.notes.iter().map(|n| n.format(tcx, ty, long_ty_file)).collect(),
+        }
+    }
+}
+
+fn emit_lint<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    item_def_id: DefId,
+    span: Span,
+    decorator: impl for<'a> LintDiagnostic<'a, ()>,
+) {
+    if let Some(item_def_id) = item_def_id.as_local() {
+        tcx.emit_node_span_lint(
+            UNKNOWN_OR_MALFORMED_DIAGNOSTIC_ATTRIBUTES,
+            tcx.local_def_id_to_hir_id(item_def_id),
+            span,
+            decorator,
+        );
+    }
+}
+
+/// Whether the feature gate of `#[diagnostic::{attr_name}]` is enabled in the
+/// current crate.
+pub fn on_type_attr_enabled(tcx: TyCtxt<'_>, attr_name: Symbol) -> bool {
+    let features = tcx.features();
+    match attr_name {
+        sym::on_move => features.diagnostic_on_move,
+        sym::on_type_mismatch => features.diagnostic_on_type_mismatch,
+        sym::on_missing_method => features.diagnostic_on_missing_method,
+        _ => false,
+    }
+}
+
+/// Returns the formatted `#[diagnostic::{attr_name}]` options for a value of
+/// type `ty`, if it is a struct, enum or union with that attribute, and the
+/// attribute is enabled in the crate that defines the type.
+pub fn on_type_note<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    attr_name: Symbol,
+    long_ty_file: &mut Option<PathBuf>,
+) -> Option<OnTypeNote> {
+    let ty::Adt(adt_def, _) = ty.kind() else {
+        return None;
+    };
+    // Types from other crates only have the attribute if it was enabled there.
+    if adt_def.did().is_local() && !on_type_attr_enabled(tcx, attr_name) {
+        return None;
+    }
+    let directive = OnTypeDirective::of_item(tcx, adt_def.did(), attr_name)?;
+    Some(directive.evaluate(tcx, ty, long_ty_file))
+}
+
+impl<'tcx> OnTypeFormatString {
+    fn parse(tcx: TyCtxt<'tcx>, item_def_id: DefId, symbol: Symbol, span: Span) -> Self {
+        let result = OnTypeFormatString { symbol, span };
+        result.verify(tcx, item_def_id);
+        result
+    }
+
+    /// Lints about the format arguments that are not `{Self}` or a generic
+    /// parameter of the type, and about format specifiers and parse errors.
+    fn verify(&self, tcx: TyCtxt<'tcx>, item_def_id: DefId) {
+        let generics = tcx.generics_of(item_def_id);
+        let mut parser = Parser::new(self.symbol.as_str(), None, None, false, ParseMode::Format);
+        for token in &mut parser {
+            let Piece::NextArgument(a) = token else { continue };
+            let format_spec = a.format;
+            if format_spec.ty_span.is_some()
+                || format_spec.width_span.is_some()
+                || format_spec.precision_span.is_some()
+                || format_spec.fill_span.is_some()
+            {
+                emit_lint(tcx, item_def_id, self.span, InvalidFormatSpecifier);
+            }
+            match a.position {
+                Position::ArgumentNamed(s) => {
+                    let s = Symbol::intern(s);
+                    if s != kw::SelfUpper && !generics.own_params.iter().any(|p| p.name == s) {
+                        let lint = UnknownFormatParameterForOnTypeAttr {
+                            argument_name: s,
+                            type_name: tcx.item_name(item_def_id),
+                        };
+                        emit_lint(tcx, item_def_id, self.span, lint);
+                    }
+                }
+                Position::ArgumentIs(..) | Position::ArgumentImplicitlyIs(_) => {
+                    emit_lint(tcx, item_def_id, self.span, DisallowedPositionalArgument);
+                }
+            }
+        }
+        for e in parser.errors {
+            let lint = WrappedParserError { description: e.description, label: e.label };
+            emit_lint(tcx, item_def_id, self.span, lint);
+        }
+    }
+
+    fn format(
+        &self,
+        tcx: TyCtxt<'tcx>,
+        ty: Ty<'tcx>,
+        long_ty_file: &mut Option<PathBuf>,
+    ) -> String {
+        let mut generic_map = FxHashMap::default();
+        generic_map.insert(kw::SelfUpper, tcx.short_ty_string(ty, long_ty_file));
+        if let ty::Adt(adt_def, args) = ty.kind() {
+            for param in &tcx.generics_of(adt_def.did()).own_params {
+                let arg = args[param.index as usize];
+                let value = match param.kind {
+                    GenericParamDefKind::Type { .. } => match arg.as_type() {
+                        Some(ty) => tcx.short_ty_string(ty, long_ty_file),
+                        None => arg.to_string(),
+                    },
+                    GenericParamDefKind::Const { .. } => arg.to_string(),
+                    GenericParamDefKind::Lifetime => continue,
+                };
+                generic_map.insert(param.name, value);
+            }
+        }
+
+        let s = self.symbol.as_str();
+        let mut parser = Parser::new(s, None, None, false, ParseMode::Format);
+        let constructed_message = (&mut parser)
+            .map(|p| match p {
+                Piece::String(s) => s.to_owned(),
+                Piece::NextArgument(a) => match a.position {
+                    // Unknown arguments have been linted, and are kept as is.
+                    Position::ArgumentNamed(arg) => match generic_map.get(&Symbol::intern(arg)) {
+                        Some(val) => val.clone(),
+                        None => format!("{{{arg}}}"),
+                    },
+                    Position::ArgumentImplicitlyIs(_) => String::from("{}"),
+                    Position::ArgumentIs(idx) => format!("{{{idx}}}"),
+                },
+            })
+            .collect();
+        // Like for `#[diagnostic::on_unimplemented]`, the input string is shown
+        // as is if it could not be parsed, the errors have been linted.
+        if !parser.errors.is_empty() { String::from(s) } else { constructed_message }
+    }
+}
@@ -13,7 +13,7 @@
     self, AdtDef, CoroutineArgsExt, EarlyBinder, FieldDef, GenericArgsRef, Ty, TyCtxt,
     TypeVisitableExt,
 };
-use rustc_session::{DataTypeKind, FieldInfo, FieldKind, SizeKind, VariantInfo};
+use rustc_session::{DataTypeKind, FieldInfo, FieldKind, NicheInfo, SizeKind, VariantInfo};
 use rustc_span::sym;
 use rustc_span::symbol::Symbol;
 use rustc_target::abi::*;
@@ -1020,6 +1020,13 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
     // (delay format until we actually need it)
     let record = |kind, packed, opt_discr_size, variants| {
         let type_desc = with_no_trimmed_paths!(format!("{}", layout.ty));
+        let niche = layout.largest_niche.map(|niche| NicheInfo {
+            offset: niche.offset.bytes(),
+            size: niche.value.size(cx).bytes(),
+            valid_range_start: niche.valid_range.start,
+            valid_range_end: niche.valid_range.end,
+            available: niche.available(cx),
+        });
         cx.tcx.sess.code_stats.record_type_size(
             kind,
             type_desc,
@@ -1027,6 +1034,7 @@ fn record_layout_for_printing<'tcx>(cx: &LayoutCx<'tcx, TyCtxt<'tcx>>, layout: T
             layout.size,
             packed,
             opt_discr_size,
+            niche,
             variants,
         );
     };
@@ -1079,6 +1087,7 @@ fn variant_info_for_adt<'tcx>(
                     offset: offset.bytes(),
                     size: field_layout.size.bytes(),
                     align: field_layout.align.abi.bytes(),
+                    ty: Symbol::intern(&with_no_trimmed_paths!(field_layout.ty.to_string())),
                     type_name: None,
                 }
             })
@@ -1163,6 +1172,7 @@ fn variant_info_for_coroutine<'tcx>(
                 offset: offset.bytes(),
                 size: field_layout.size.bytes(),
                 align: field_layout.align.abi.bytes(),
+                ty: Symbol::intern(&with_no_trimmed_paths!(field_layout.ty.to_string())),
                 type_name: None,
             }
         })
@@ -1192,6 +1202,7 @@ fn variant_info_for_coroutine<'tcx>(
                         offset: offset.bytes(),
                         size: field_layout.size.bytes(),
                         align: field_layout.align.abi.bytes(),
+                        ty: Symbol::intern(&with_no_trimmed_paths!(field_layout.ty.to_string())),
                         // Include the type name if there is no field name, or if the name is the
                         // __awaitee placeholder symbol which means a child future being `.await`ed.
                         type_name: (field_name.is_none() || field_name == Some(sym::__awaitee))
@@ -11,6 +11,7 @@
 
 /// A function ABI definition.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FnAbi {
     /// The types of each argument.
     pub args: Vec<ArgAbi>,
@@ -32,6 +33,7 @@ pub struct FnAbi {
 
 /// Information about the ABI of a function's argument, or return value.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ArgAbi {
     pub ty: Ty,
     pub layout: Layout,
@@ -40,6 +42,7 @@ pub struct ArgAbi {
 
 /// How a function argument should be passed in to the target function.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PassMode {
     /// Ignore the argument.
     ///
@@ -61,6 +64,7 @@ pub enum PassMode {
 
 /// The layout of a type, alongside the type itself.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TyAndLayout {
     pub ty: Ty,
     pub layout: Layout,
@@ -68,6 +72,7 @@ pub struct TyAndLayout {
 
 /// The layout of a type in memory.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LayoutShape {
     /// The fields location withing the layout
     pub fields: FieldsShape,
@@ -109,6 +114,7 @@ pub fn is_1zst(&self) -> bool {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Layout(usize);
 
 impl Layout {
@@ -128,6 +134,7 @@ fn to_index(&self) -> usize {
 
 /// Describes how the fields of a type are shaped in memory.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FieldsShape {
     /// Scalar primitives and `!`, which never have fields.
     Primitive,
@@ -178,6 +185,7 @@ pub fn count(&self) -> usize {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VariantsShape {
     /// Single enum variants, structs/tuples, unions, and all non-ADTs.
     Single { index: VariantIdx },
@@ -197,6 +205,7 @@ pub enum VariantsShape {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TagEncoding {
     /// The tag directly stores the discriminant, but possibly with a smaller layout
     /// (so converting the tag to the discriminant can require sign extension).
@@ -222,6 +231,7 @@ pub enum TagEncoding {
 /// Describes how values of the type are passed by target ABIs,
 /// in terms of categories of C types there are ABI rules for.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ValueAbi {
     Uninhabited,
     Scalar(Scalar),
@@ -251,6 +261,7 @@ pub fn is_unsized(&self) -> bool {
 
 /// Information about one scalar component of a Rust type.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Scalar {
     Initialized {
         /// The primitive type used to represent this value.
@@ -281,6 +292,7 @@ pub fn has_niche(&self, target: &MachineInfo) -> bool {
 
 /// Fundamental unit of memory access and layout.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Primitive {
     /// The `bool` is the signedness of the `Integer` type.
     ///
@@ -311,6 +323,7 @@ pub fn size(self, target: &MachineInfo) -> Size {
 
 /// Enum representing the existing integer lengths.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum IntegerLength {
     I8,
     I16,
@@ -321,6 +334,7 @@ pub enum IntegerLength {
 
 /// Enum representing the existing float lengths.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FloatLength {
     F16,
     F32,
@@ -355,6 +369,7 @@ pub fn bits(self) -> usize {
 /// should operate on. Special address spaces have an effect on code generation,
 /// depending on the target and the address spaces it implements.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AddressSpace(pub u32);
 
 impl AddressSpace {
@@ -370,6 +385,7 @@ impl AddressSpace {
 ///
 ///    254 (-2), 255 (-1), 0, 1, 2
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct WrappingRange {
     pub start: u128,
     pub end: u128,
@@ -421,6 +437,7 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
 
 /// General language calling conventions.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CallConvention {
     C,
     Rust,
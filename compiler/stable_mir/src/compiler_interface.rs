@@ -8,6 +8,7 @@
 use crate::abi::{FnAbi, Layout, LayoutShape};
 use crate::crate_def::Attribute;
 use crate::mir::alloc::{AllocId, GlobalAlloc};
+use crate::mir::borrowck::BorrowckFacts;
 use crate::mir::mono::{Instance, InstanceDef, StaticDef};
 use crate::mir::{BinOp, Body, Place, UnOp};
 use crate::target::MachineInfo;
@@ -15,7 +16,7 @@
     AdtDef, AdtKind, Allocation, ClosureDef, ClosureKind, FieldDef, FnDef, ForeignDef,
     ForeignItemKind, ForeignModule, ForeignModuleDef, GenericArgs, GenericPredicates, Generics,
     ImplDef, ImplTrait, IntrinsicDef, LineInfo, MirConst, PolyFnSig, RigidTy, Span, TraitDecl,
-    TraitDef, Ty, TyConst, TyConstId, TyKind, UintTy, VariantDef,
+    TraitDef, TraitRef, Ty, TyConst, TyConstId, TyKind, UintTy, VariantDef,
 };
 use crate::{
     mir, Crate, CrateItem, CrateItems, CrateNum, DefId, Error, Filename, ImplTraitDecls, ItemKind,
@@ -205,6 +206,28 @@ fn resolve_closure(
         kind: ClosureKind,
     ) -> Option<Instance>;
 
+    /// Check whether `ty` implements the trait `trait_def` with the given generic arguments,
+    /// excluding `Self`, in the environment of `item`, or in an empty environment if `item` is
+    /// `None`.
+    fn implements_trait(
+        &self,
+        ty: Ty,
+        trait_def: TraitDef,
+        args: &GenericArgs,
+        item: Option<DefId>,
+    ) -> bool;
+
+    /// Normalize the associated type projections in `ty`, in the environment of `item`, or in an
+    /// empty environment if `item` is `None`.
+    fn normalize(&self, ty: Ty, item: Option<DefId>) -> Result<Ty, Error>;
+
+    /// Find the user-defined impl that satisfies the trait reference, if any, in the environment
+    /// of `item`, or in an empty environment if `item` is `None`.
+    fn impl_for(&self, trait_ref: &TraitRef, item: Option<DefId>) -> Option<ImplDef>;
+
+    /// Retrieve the facts computed by the borrow checker for a local item.
+    fn borrowck_facts(&self, item: CrateItem) -> Result<BorrowckFacts, Error>;
+
     /// Evaluate a static's initializer.
     fn eval_static_initializer(&self, def: StaticDef) -> Result<Allocation, Error>;
 
@@ -6,6 +6,7 @@
 
 /// A unique identification number for each item accessible for the current compilation unit.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DefId(pub(crate) usize);
 
 /// A trait for retrieving information about a particular definition.
@@ -85,6 +86,7 @@ fn ty_with_args(&self, args: &GenericArgs) -> Ty {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Attribute {
     value: String,
     span: Span,
@@ -112,6 +114,7 @@ macro_rules! crate_def {
     ) => {
         $(#[$attr])*
         #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
         $vis struct $name(pub DefId);
 
         impl CrateDef for $name {
@@ -128,6 +131,7 @@ macro_rules! crate_def_with_ty {
     ) => {
         $(#[$attr])*
         #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize))]
         $vis struct $name(pub DefId);
 
         impl CrateDef for $name {
@@ -75,6 +75,7 @@ fn to_index(&self) -> usize {
 
 /// Holds information about a crate.
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Crate {
     pub id: CrateNum,
     pub name: Symbol,
@@ -99,6 +100,7 @@ pub fn trait_impls(&self) -> ImplTraitDecls {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ItemKind {
     Fn,
     Static,
@@ -107,6 +109,7 @@ pub enum ItemKind {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CtorKind {
     Const,
     Fn,
@@ -189,6 +192,7 @@ pub fn all_trait_impls() -> ImplTraitDecls {
 
 /// A type that provides internal information but that can still be used for debug purpose.
 #[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Opaque(String);
 
 impl std::fmt::Display for Opaque {
@@ -9,6 +9,7 @@
 
 /// The SMIR representation of a single function.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Body {
     pub blocks: Vec<BasicBlock>,
 
@@ -100,11 +101,60 @@ pub fn dump<W: io::Write>(&self, w: &mut W, fn_name: &str) -> io::Result<()> {
     pub fn spread_arg(&self) -> Option<Local> {
         self.spread_arg
     }
+
+    /// Add a new local of type `ty` after all the existing locals, and return it.
+    pub fn new_local(&mut self, ty: Ty, span: Span, mutability: Mutability) -> Local {
+        self.locals.push(LocalDecl { ty, span, mutability });
+        self.locals.len() - 1
+    }
+
+    /// Add a new basic block after all the existing blocks, and return its index.
+    pub fn new_block(&mut self, block: BasicBlock) -> BasicBlockIdx {
+        self.blocks.push(block);
+        self.blocks.len() - 1
+    }
+
+    /// Split the block `bb` before its statement at `statement_index`.
+    ///
+    /// The statements starting at `statement_index` and the terminator of `bb` are moved to a
+    /// new block, whose index is returned. `bb` then ends with a `Goto` to the new block, which
+    /// can be replaced to insert control flow at that point, e.g. by [Body::insert_call].
+    pub fn split_block(&mut self, bb: BasicBlockIdx, statement_index: usize) -> BasicBlockIdx {
+        let next = self.blocks.len();
+        let block = &mut self.blocks[bb];
+        let statements = block.statements.split_off(statement_index);
+        let span = statements.first().map_or(block.terminator.span, |stmt| stmt.span);
+        let goto = Terminator { kind: TerminatorKind::Goto { target: next }, span };
+        let terminator = std::mem::replace(&mut block.terminator, goto);
+        self.new_block(BasicBlock { statements, terminator })
+    }
+
+    /// Insert a call to `func` before the statement at `statement_index` of the block `bb`,
+    /// storing its result in `destination`.
+    ///
+    /// Return the index of the block that the call returns to, which contains the rest of `bb`.
+    /// `unwind` must be valid for `bb`, e.g., a cleanup block cannot unwind to another one.
+    pub fn insert_call(
+        &mut self,
+        bb: BasicBlockIdx,
+        statement_index: usize,
+        func: Operand,
+        args: Vec<Operand>,
+        destination: Place,
+        unwind: UnwindAction,
+        span: Span,
+    ) -> BasicBlockIdx {
+        let next = self.split_block(bb, statement_index);
+        let kind = TerminatorKind::Call { func, args, destination, target: Some(next), unwind };
+        self.blocks[bb].terminator = Terminator { kind, span };
+        next
+    }
 }
 
 type LocalDecls = Vec<LocalDecl>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LocalDecl {
     pub ty: Ty,
     pub span: Span,
@@ -112,12 +162,14 @@ pub struct LocalDecl {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BasicBlock {
     pub statements: Vec<Statement>,
     pub terminator: Terminator,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Terminator {
     pub kind: TerminatorKind,
     pub span: Span,
@@ -132,6 +184,7 @@ pub fn successors(&self) -> Successors {
 pub type Successors = Vec<BasicBlockIdx>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TerminatorKind {
     Goto {
         target: BasicBlockIdx,
@@ -222,6 +275,7 @@ pub fn unwind(&self) -> Option<&UnwindAction> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct InlineAsmOperand {
     pub in_value: Option<Operand>,
     pub out_place: Option<Place>,
@@ -231,6 +285,7 @@ pub struct InlineAsmOperand {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UnwindAction {
     Continue,
     Unreachable,
@@ -239,6 +294,7 @@ pub enum UnwindAction {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AssertMessage {
     BoundsCheck { len: Operand, index: Operand },
     Overflow(BinOp, Operand, Operand),
@@ -308,6 +364,7 @@ pub fn description(&self) -> Result<&'static str, Error> {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BinOp {
     Add,
     AddUnchecked,
@@ -343,6 +400,7 @@ pub fn ty(&self, lhs_ty: Ty, rhs_ty: Ty) -> Ty {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UnOp {
     Not,
     Neg,
@@ -358,12 +416,14 @@ pub fn ty(&self, arg_ty: Ty) -> Ty {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CoroutineKind {
     Desugared(CoroutineDesugaring, CoroutineSource),
     Coroutine(Movability),
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CoroutineSource {
     Block,
     Closure,
@@ -371,6 +431,7 @@ pub enum CoroutineSource {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CoroutineDesugaring {
     Async,
 
@@ -387,6 +448,7 @@ pub enum CoroutineDesugaring {
 
 /// The FakeReadCause describes the type of pattern why a FakeRead statement exists.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FakeReadCause {
     ForMatchGuard,
     ForMatchedPlace(LocalDefId),
@@ -397,6 +459,7 @@ pub enum FakeReadCause {
 
 /// Describes what kind of retag is to be performed
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RetagKind {
     FnEntry,
     TwoPhase,
@@ -405,6 +468,7 @@ pub enum RetagKind {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Variance {
     Covariant,
     Invariant,
@@ -413,6 +477,7 @@ pub enum Variance {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CopyNonOverlapping {
     pub src: Operand,
     pub dst: Operand,
@@ -420,18 +485,21 @@ pub struct CopyNonOverlapping {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum NonDivergingIntrinsic {
     Assume(Operand),
     CopyNonOverlapping(CopyNonOverlapping),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Statement {
     pub kind: StatementKind,
     pub span: Span,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StatementKind {
     Assign(Place, Rvalue),
     FakeRead(FakeReadCause, Place),
@@ -449,6 +517,7 @@ pub enum StatementKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Rvalue {
     /// Creates a pointer with the indicated mutability to the place.
     ///
@@ -623,6 +692,7 @@ pub fn ty(&self, locals: &[LocalDecl]) -> Result<Ty, Error> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AggregateKind {
     Array(Ty),
     Tuple,
@@ -634,6 +704,7 @@ pub enum AggregateKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Operand {
     Copy(Place),
     Move(Place),
@@ -641,6 +712,7 @@ pub enum Operand {
 }
 
 #[derive(Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Place {
     pub local: Local,
     /// projection out of a place (access a field, deref a pointer, etc)
@@ -654,6 +726,7 @@ fn from(local: Local) -> Self {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ConstOperand {
     pub span: Span,
     pub user_ty: Option<UserTypeAnnotationIndex>,
@@ -662,6 +735,7 @@ pub struct ConstOperand {
 
 /// Debug information pertaining to a user variable.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct VarDebugInfo {
     /// The variable name.
     pub name: Symbol,
@@ -704,18 +778,21 @@ pub fn constant(&self) -> Option<&ConstOperand> {
 pub type SourceScope = u32;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SourceInfo {
     pub span: Span,
     pub scope: SourceScope,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct VarDebugInfoFragment {
     pub ty: Ty,
     pub projection: Vec<ProjectionElem>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum VarDebugInfoContents {
     Place(Place),
     Const(ConstOperand),
@@ -727,6 +804,7 @@ pub enum VarDebugInfoContents {
 // are of type ProjectionElem<(), ()>). In SMIR we don't need this generality, so we just use
 // ProjectionElem for Places.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ProjectionElem {
     /// Dereference projections (e.g. `*_1`) project to the address referenced by the base place.
     Deref,
@@ -801,6 +879,7 @@ pub enum ProjectionElem {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserTypeProjection {
     pub base: UserTypeAnnotationIndex,
 
@@ -831,6 +910,7 @@ pub struct UserTypeProjection {
 
 /// The possible branch sites of a [TerminatorKind::SwitchInt].
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SwitchTargets {
     /// The conditional branches where the first element represents the value that guards this
     /// branch, and the second element is the branch target.
@@ -868,6 +948,7 @@ pub fn new(branches: Vec<(u128, BasicBlockIdx)>, otherwise: BasicBlockIdx) -> Sw
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BorrowKind {
     /// Data must be immutable and is aliasable.
     Shared,
@@ -895,6 +976,7 @@ pub fn to_mutable_lossy(self) -> Mutability {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MutBorrowKind {
     Default,
     TwoPhaseBorrow,
@@ -902,6 +984,7 @@ pub enum MutBorrowKind {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FakeBorrowKind {
     /// A shared (deep) borrow. Data must be immutable and is aliasable.
     Deep,
@@ -913,18 +996,21 @@ pub enum FakeBorrowKind {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Mutability {
     Not,
     Mut,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Safety {
     Safe,
     Unsafe,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PointerCoercion {
     /// Go from a fn-item type to a fn-pointer type.
     ReifyFnPointer,
@@ -952,6 +1038,7 @@ pub enum PointerCoercion {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum CastKind {
     // FIXME(smir-rename): rename this to PointerExposeProvenance
     PointerExposeAddress,
@@ -968,6 +1055,7 @@ pub enum CastKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum NullOp {
     /// Returns the size of a value of that type.
     SizeOf,
@@ -0,0 +1,101 @@
+//! Facts computed by the borrow checker for the body of an item.
+//!
+//! The borrow checker runs on the MIR of an item before it is optimized, so the facts refer to
+//! their own copy of that MIR, [`BorrowckFacts::body`], in which every region is a
+//! [`RegionKind::ReVar`](crate::ty::RegionKind::ReVar). Locations in the facts are locations in
+//! that body.
+
+use crate::mir::{BasicBlockIdx, Body, BorrowKind, Place};
+use crate::ty::{RegionVid, Span};
+use crate::{with, CrateItem, Error, Opaque};
+
+/// Retrieve the facts computed by the borrow checker for `item`.
+///
+/// The facts are only available for the local items of a compilation that requested them with
+/// `rustc_internal::collect_borrowck_facts`, and not for coroutines. `item` is borrow checked if
+/// it has not been yet, but an item borrow checked outside of the current context has no facts.
+pub fn borrowck_facts(item: CrateItem) -> Result<BorrowckFacts, Error> {
+    with(|cx| cx.borrowck_facts(item))
+}
+
+/// The result of borrow checking the body of an item.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BorrowckFacts {
+    /// The body that was borrow checked.
+    ///
+    /// The false edges the borrow checker uses to approximate the control flow of `match`
+    /// and of loops are replaced by gotos to their real targets.
+    pub body: Body,
+    /// The loans of the body, i.e., its borrow expressions, indexed by [`LoanIdx`].
+    pub loans: Vec<Loan>,
+    /// The regions of the body, indexed by [`RegionVid`].
+    pub regions: Vec<RegionInfo>,
+    /// The outlives constraints between the regions of the body.
+    pub outlives_constraints: Vec<OutlivesConstraint>,
+}
+
+pub type LoanIdx = usize;
+
+/// A location in [`BorrowckFacts::body`]: the statement with index `statement_index` in `block`,
+/// or its terminator if `statement_index` is the number of statements of the block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BodyLocation {
+    pub block: BasicBlockIdx,
+    pub statement_index: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Loan {
+    pub kind: BorrowKind,
+    /// The place that is borrowed.
+    pub borrowed_place: Place,
+    /// The place the reference is assigned to.
+    pub assigned_place: Place,
+    /// The region of the reference.
+    pub region: RegionVid,
+    /// The location of the borrow expression.
+    pub reserve_location: BodyLocation,
+    pub activation: TwoPhaseActivation,
+    /// The locations where the loan is in scope, in order. An access to the borrowed place at
+    /// one of these locations that conflicts with the loan is a borrow error.
+    pub live_at: Vec<BodyLocation>,
+}
+
+/// Where a two-phase borrow, e.g., the auto-ref of `vec.push(vec.len())`, is activated.
+///
+/// Between its reservation and its activation, a two-phase borrow only conflicts with
+/// mutations of the borrowed place.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TwoPhaseActivation {
+    NotTwoPhase,
+    NotActivated,
+    ActivatedAt(BodyLocation),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RegionInfo {
+    /// Whether this region is in scope for the whole body, e.g., a lifetime parameter, a region
+    /// of the signature, or `'static`, as opposed to a region inferred by the borrow checker.
+    pub universal: bool,
+    /// The locations contained in the inferred value of the region, in order.
+    pub live_at: Vec<BodyLocation>,
+}
+
+/// A constraint that region `sup` outlives region `sub`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OutlivesConstraint {
+    pub sup: RegionVid,
+    pub sub: RegionVid,
+    /// The location where the constraint must hold, or `None` if it must hold at every point of
+    /// the body.
+    pub location: Option<BodyLocation>,
+    pub span: Span,
+    /// What caused this constraint, e.g., an assignment or a call.
+    pub category: Opaque,
+}
@@ -7,6 +7,7 @@
 use std::io;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MonoItem {
     Fn(Instance),
     Static(StaticDef),
@@ -14,6 +15,7 @@ pub enum MonoItem {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Instance {
     /// The type of instance.
     pub kind: InstanceKind,
@@ -23,6 +25,7 @@ pub struct Instance {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum InstanceKind {
     /// A user defined item.
     Item,
@@ -241,6 +244,7 @@ fn from(value: StaticDef) -> Self {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct InstanceDef(usize);
 
 impl CrateDef for InstanceDef {
@@ -4,6 +4,7 @@
 
 /// The properties of the target machine being compiled into.
 #[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MachineInfo {
     pub endian: Endian,
     pub pointer_width: MachineSize,
@@ -24,6 +25,7 @@ pub fn target_pointer_width() -> MachineSize {
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Endian {
     Little,
     Big,
@@ -31,6 +33,7 @@ pub enum Endian {
 
 /// Represent the size of a component.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MachineSize {
     num_bits: usize,
 }
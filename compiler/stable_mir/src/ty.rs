@@ -12,6 +12,7 @@
 use std::ops::Range;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Ty(usize);
 
 impl Debug for Ty {
@@ -91,6 +92,39 @@ pub fn unsigned_ty(inner: UintTy) -> Ty {
     pub fn layout(self) -> Result<Layout, Error> {
         with(|cx| cx.ty_layout(self))
     }
+
+    /// Check whether this type implements the trait `trait_def` with the given generic
+    /// arguments, excluding `Self`.
+    ///
+    /// The check is done in an empty environment where opaque types are revealed, which suits
+    /// monomorphic types, e.g., those of an instance. Use [`Ty::implements_trait_in`] for types
+    /// that refer to the generic parameters of an item.
+    pub fn implements_trait(self, trait_def: TraitDef, args: &GenericArgs) -> bool {
+        with(|cx| cx.implements_trait(self, trait_def, args, None))
+    }
+
+    /// Check whether this type implements the trait `trait_def` with the given generic
+    /// arguments, excluding `Self`, assuming the where clauses of `item` hold.
+    ///
+    /// `item` is usually the function whose body this type comes from.
+    pub fn implements_trait_in(self, trait_def: TraitDef, args: &GenericArgs, item: DefId) -> bool {
+        with(|cx| cx.implements_trait(self, trait_def, args, Some(item)))
+    }
+
+    /// Normalize the associated type projections in this type, e.g., `<u8 as Trait>::Assoc`
+    /// to the type given by the impl of `Trait` for `u8`.
+    ///
+    /// Like [`Ty::implements_trait`], this is done in an empty environment. Use
+    /// [`Ty::normalize_in`] for types that refer to the generic parameters of an item.
+    pub fn normalize(self) -> Result<Ty, Error> {
+        with(|cx| cx.normalize(self, None))
+    }
+
+    /// Normalize the associated type projections in this type, assuming the where clauses of
+    /// `item` hold, e.g., `<T as Trait>::Assoc` to `u8` in an item with `T: Trait<Assoc = u8>`.
+    pub fn normalize_in(self, item: DefId) -> Result<Ty, Error> {
+        with(|cx| cx.normalize(self, Some(item)))
+    }
 }
 
 impl Ty {
@@ -101,12 +135,14 @@ pub fn kind(&self) -> TyKind {
 
 /// Represents a pattern in the type system
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Pattern {
     Range { start: Option<TyConst>, end: Option<TyConst>, include_end: bool },
 }
 
 /// Represents a constant in the type system
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TyConst {
     pub(crate) kind: TyConstKind,
     pub id: TyConstId,
@@ -134,6 +170,7 @@ pub fn eval_target_usize(&self) -> Result<u64, Error> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TyConstKind {
     Param(ParamConst),
     Bound(DebruijnIndex, BoundVar),
@@ -145,10 +182,12 @@ pub enum TyConstKind {
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TyConstId(usize);
 
 /// Represents a constant in MIR
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MirConst {
     /// The constant kind.
     pub(crate) kind: ConstantKind,
@@ -206,27 +245,35 @@ pub fn try_from_uint(value: u128, uint_ty: UintTy) -> Result<MirConst, Error> {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MirConstId(usize);
 
 type Ident = Opaque;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Region {
     pub kind: RegionKind,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RegionKind {
     ReEarlyParam(EarlyParamRegion),
     ReBound(DebruijnIndex, BoundRegion),
     ReStatic,
     RePlaceholder(Placeholder<BoundRegion>),
     ReErased,
+    /// A region inference variable, only found in the bodies of borrow checker facts.
+    ReVar(RegionVid),
 }
 
+pub type RegionVid = usize;
+
 pub(crate) type DebruijnIndex = u32;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EarlyParamRegion {
     pub index: u32,
     pub name: Symbol,
@@ -235,6 +282,7 @@ pub struct EarlyParamRegion {
 pub(crate) type BoundVar = u32;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BoundRegion {
     pub var: BoundVar,
     pub kind: BoundRegionKind,
@@ -243,12 +291,14 @@ pub struct BoundRegion {
 pub(crate) type UniverseIndex = u32;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Placeholder<T> {
     pub universe: UniverseIndex,
     pub bound: T,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Span(usize);
 
 impl Debug for Span {
@@ -273,6 +323,7 @@ pub fn get_lines(&self) -> LineInfo {
 }
 
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// Information you get from `Span` in a struct form.
 /// Line and col start from 1.
 pub struct LineInfo {
@@ -283,6 +334,7 @@ pub struct LineInfo {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TyKind {
     RigidTy(RigidTy),
     Alias(AliasKind, AliasTy),
@@ -522,6 +574,7 @@ pub struct TypeAndMut {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RigidTy {
     Bool,
     Char,
@@ -561,6 +614,7 @@ fn from(value: RigidTy) -> Self {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum IntTy {
     Isize,
     I8,
@@ -584,6 +638,7 @@ pub fn num_bytes(self) -> usize {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum UintTy {
     Usize,
     U8,
@@ -607,6 +662,7 @@ pub fn num_bytes(self) -> usize {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FloatTy {
     F16,
     F32,
@@ -615,6 +671,7 @@ pub enum FloatTy {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Movability {
     Static,
     Movable,
@@ -653,6 +710,7 @@ pub fn kind(&self) -> ForeignItemKind {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ForeignItemKind {
     Fn(FnDef),
     Static(StaticDef),
@@ -738,6 +796,7 @@ fn from(def: IntrinsicDef) -> Self {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AdtKind {
     Enum,
     Union,
@@ -792,6 +851,7 @@ pub fn variant(&self, idx: VariantIdx) -> Option<VariantDef> {
 
 /// Definition of a variant, which can be either a struct / union field or an enum variant.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct VariantDef {
     /// The variant index.
     ///
@@ -821,6 +881,7 @@ pub fn fields(&self) -> Vec<FieldDef> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FieldDef {
     /// The field definition.
     ///
@@ -915,6 +976,7 @@ pub fn trait_impl(&self) -> ImplTrait {
 
 /// A list of generic arguments.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GenericArgs(pub Vec<GenericArgKind>);
 
 impl std::ops::Index<ParamTy> for GenericArgs {
@@ -934,6 +996,7 @@ fn index(&self, index: ParamConst) -> &Self::Output {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum GenericArgKind {
     Lifetime(Region),
     Type(Ty),
@@ -971,12 +1034,14 @@ pub fn ty(&self) -> Option<&Ty> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TermKind {
     Type(Ty),
     Const(TyConst),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AliasKind {
     Projection,
     Inherent,
@@ -985,12 +1050,14 @@ pub enum AliasKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AliasTy {
     pub def_id: AliasDef,
     pub args: GenericArgs,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct AliasTerm {
     pub def_id: AliasDef,
     pub args: GenericArgs,
@@ -1009,6 +1076,7 @@ pub fn fn_ptr_abi(self) -> Result<FnAbi, Error> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FnSig {
     pub inputs_and_output: Vec<Ty>,
     pub c_variadic: bool,
@@ -1027,6 +1095,7 @@ pub fn inputs(&self) -> &[Ty] {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Abi {
     Rust,
     C { unwind: bool },
@@ -1057,6 +1126,7 @@ pub enum Abi {
 
 /// A binder represents a possibly generic type and its bound vars.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Binder<T> {
     pub value: T,
     pub bound_vars: Vec<BoundVariableKind>,
@@ -1097,11 +1167,13 @@ pub fn map_bound<F, U>(self, f: F) -> Binder<U>
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EarlyBinder<T> {
     pub value: T,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BoundVariableKind {
     Ty(BoundTyKind),
     Region(BoundRegionKind),
@@ -1109,12 +1181,14 @@ pub enum BoundVariableKind {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BoundTyKind {
     Anon,
     Param(ParamDef, String),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum BoundRegionKind {
     BrAnon,
     BrNamed(BrNamedDef, String),
@@ -1122,12 +1196,14 @@ pub enum BoundRegionKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum DynKind {
     Dyn,
     DynStar,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ExistentialPredicate {
     Trait(ExistentialTraitRef),
     Projection(ExistentialProjection),
@@ -1138,6 +1214,7 @@ pub enum ExistentialPredicate {
 ///
 /// The `generic_args` will include any other known argument.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ExistentialTraitRef {
     pub def_id: TraitDef,
     pub generic_args: GenericArgs,
@@ -1156,6 +1233,7 @@ pub fn with_self_ty(&self, self_ty: Ty) -> TraitRef {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ExistentialProjection {
     pub def_id: TraitDef,
     pub generic_args: GenericArgs,
@@ -1163,12 +1241,14 @@ pub struct ExistentialProjection {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ParamTy {
     pub index: u32,
     pub name: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BoundTy {
     pub var: usize,
     pub kind: BoundTyKind,
@@ -1180,6 +1260,7 @@ pub struct BoundTy {
 pub type Size = usize;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Prov(pub AllocId);
 
 pub type Align = u64;
@@ -1188,6 +1269,7 @@ pub struct BoundTy {
 
 /// Stores the provenance information of pointers stored in memory.
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProvenanceMap {
     /// Provenance in this map applies from the given offset for an entire pointer-size worth of
     /// bytes. Two entries in this map are always at least a pointer size apart.
@@ -1195,6 +1277,7 @@ pub struct ProvenanceMap {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Allocation {
     pub bytes: Bytes,
     pub provenance: ProvenanceMap,
@@ -1271,6 +1354,7 @@ pub fn is_null(&self) -> Result<bool, Error> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ConstantKind {
     Ty(TyConst),
     Allocated(Allocation),
@@ -1282,12 +1366,14 @@ pub enum ConstantKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ParamConst {
     pub index: u32,
     pub name: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UnevaluatedConst {
     pub def: ConstDef,
     pub args: GenericArgs,
@@ -1295,6 +1381,7 @@ pub struct UnevaluatedConst {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TraitSpecializationKind {
     None,
     Marker,
@@ -1302,6 +1389,7 @@ pub enum TraitSpecializationKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TraitDecl {
     pub def_id: TraitDef,
     pub safety: Safety,
@@ -1335,6 +1423,7 @@ pub fn explicit_predicates_of(&self) -> GenericPredicates {
 
 /// A complete reference to a trait, i.e., one where `Self` is known.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TraitRef {
     pub def_id: TraitDef,
     /// The generic arguments for this definition.
@@ -1366,9 +1455,25 @@ pub fn self_ty(&self) -> Ty {
         };
         self_ty
     }
+
+    /// Find the impl that satisfies this trait reference.
+    ///
+    /// Return `None` if the trait is not implemented, or if it is implemented by something other
+    /// than an impl, e.g., a builtin or a where clause. Like [`Ty::implements_trait`], this is
+    /// done in an empty environment.
+    pub fn impl_for(&self) -> Option<ImplDef> {
+        with(|cx| cx.impl_for(self, None))
+    }
+
+    /// Find the impl that satisfies this trait reference, assuming the where clauses of `item`
+    /// hold.
+    pub fn impl_for_in(&self, item: DefId) -> Option<ImplDef> {
+        with(|cx| cx.impl_for(self, Some(item)))
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Generics {
     pub parent: Option<GenericDef>,
     pub parent_count: usize,
@@ -1380,6 +1485,7 @@ pub struct Generics {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum GenericParamDefKind {
     Lifetime,
     Type { has_default: bool, synthetic: bool },
@@ -1387,6 +1493,7 @@ pub enum GenericParamDefKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GenericParamDef {
     pub name: super::Symbol,
     pub def_id: GenericDef,
@@ -1401,6 +1508,7 @@ pub struct GenericPredicates {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PredicateKind {
     Clause(ClauseKind),
     ObjectSafe(TraitDef),
@@ -1412,6 +1520,7 @@ pub enum PredicateKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ClauseKind {
     Trait(TraitPredicate),
     RegionOutlives(RegionOutlivesPredicate),
@@ -1423,6 +1532,7 @@ pub enum ClauseKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ClosureKind {
     Fn,
     FnMut,
@@ -1430,42 +1540,49 @@ pub enum ClosureKind {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SubtypePredicate {
     pub a: Ty,
     pub b: Ty,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CoercePredicate {
     pub a: Ty,
     pub b: Ty,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AliasRelationDirection {
     Equate,
     Subtype,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TraitPredicate {
     pub trait_ref: TraitRef,
     pub polarity: PredicatePolarity,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct OutlivesPredicate<A, B>(pub A, pub B);
 
 pub type RegionOutlivesPredicate = OutlivesPredicate<Region, Region>;
 pub type TypeOutlivesPredicate = OutlivesPredicate<Ty, Region>;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ProjectionPredicate {
     pub projection_term: AliasTerm,
     pub term: TermKind,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ImplPolarity {
     Positive,
     Negative,
@@ -1473,6 +1590,7 @@ pub enum ImplPolarity {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum PredicatePolarity {
     Positive,
     Negative,
@@ -1516,6 +1634,7 @@ fn to_index(&self) -> usize {
 /// `c` is in the variant with the `VariantIdx` of `1`, and
 /// `g` is in the variant with the `VariantIdx` of `0`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct VariantIdx(usize);
 
 index_impl!(VariantIdx);
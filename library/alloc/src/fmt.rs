@@ -578,6 +578,8 @@
 
 #[stable(feature = "fmt_flags_align", since = "1.28.0")]
 pub use core::fmt::Alignment;
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+pub use core::fmt::BufferedWriter;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::Error;
 #[unstable(feature = "debug_closure_helpers", issue = "117729")]
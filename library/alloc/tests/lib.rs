@@ -30,6 +30,7 @@
 #![feature(round_char_boundary)]
 #![feature(slice_partition_dedup)]
 #![feature(string_remove_matches)]
+#![feature(str_graphemes)]
 #![feature(const_btree_len)]
 #![feature(const_trait_impl)]
 #![feature(const_str_from_utf8)]
@@ -1510,6 +1510,50 @@ fn test_split_whitespace() {
     assert_eq!(words, ["Märy", "häd", "ä", "little", "lämb", "Little", "lämb"])
 }
 
+#[test]
+fn test_graphemes() {
+    fn t(data: &str, expected: &[&str]) {
+        let graphemes: Vec<&str> = data.graphemes().collect();
+        assert_eq!(graphemes, expected, "{data:?}");
+    }
+    t("", &[]);
+    t("abc", &["a", "b", "c"]);
+    // Combining marks and CRLF.
+    t("a\u{308}\u{301}b\r\n\n\r", &["a\u{308}\u{301}", "b", "\r\n", "\n", "\r"]);
+    // Control characters never join with combining marks.
+    t("\u{1}\u{308}", &["\u{1}", "\u{308}"]);
+    // Hangul syllable sequences.
+    t(
+        "\u{1100}\u{1161}\u{11a8}\u{ac00}\u{11a8}\u{ac01}\u{1161}",
+        &["\u{1100}\u{1161}\u{11a8}", "\u{ac00}\u{11a8}", "\u{ac01}", "\u{1161}"],
+    );
+    // Prepend and SpacingMark.
+    t("\u{600}a\u{903}b", &["\u{600}a\u{903}", "b"]);
+    // Regional indicators pair up.
+    t("\u{1f1e6}\u{1f1e7}\u{1f1e8}", &["\u{1f1e6}\u{1f1e7}", "\u{1f1e8}"]);
+    t(
+        "a\u{1f1e6}\u{1f1e7}\u{1f1e8}\u{1f1e9}b",
+        &["a", "\u{1f1e6}\u{1f1e7}", "\u{1f1e8}\u{1f1e9}", "b"],
+    );
+    // Emoji ZWJ sequences.
+    t("\u{1f476}\u{1f3ff}\u{200d}\u{1f6d1}", &["\u{1f476}\u{1f3ff}\u{200d}\u{1f6d1}"]);
+    t("a\u{200d}\u{1f6d1}", &["a\u{200d}", "\u{1f6d1}"]);
+    t("\u{1f6d1}\u{200d}\u{200d}\u{1f6d1}", &["\u{1f6d1}\u{200d}\u{200d}", "\u{1f6d1}"]);
+
+    // A single cluster can make up the whole string.
+    let zalgo = "z\u{301}\u{302}\u{303}\u{304}\u{305}\u{306}\u{307}";
+    assert_eq!(zalgo.graphemes().size_hint(), (1, Some(zalgo.len())));
+    assert_eq!(zalgo.graphemes().count(), 1);
+    assert_eq!("".graphemes().size_hint(), (0, Some(0)));
+
+    let mut graphemes = "e\u{301}x".graphemes();
+    assert_eq!(graphemes.next(), Some("e\u{301}"));
+    assert_eq!(graphemes.as_str(), "x");
+    assert_eq!(graphemes.next(), Some("x"));
+    assert_eq!(graphemes.next(), None);
+    assert_eq!(graphemes.next(), None);
+}
+
 #[test]
 fn test_lines() {
     fn t(data: &str, expected: &[&str]) {
@@ -0,0 +1,168 @@
+use crate::fmt::{self, Arguments, Debug, Formatter, Write};
+use crate::str;
+
+/// A [`fmt::Write`] adapter that formats into a fixed-size byte buffer.
+///
+/// `BufferedWriter` never allocates. Output that does not fit into the buffer is
+/// dropped instead of causing an error, but the writer keeps track of how many
+/// bytes were actually written and how many the complete output would have
+/// needed, so callers can detect and report truncation.
+///
+/// Truncation always happens on a `char` boundary, so the written prefix is
+/// always valid UTF-8 and can be retrieved with [`as_str`](BufferedWriter::as_str).
+/// Once any output has been dropped, all subsequent writes are dropped as well,
+/// which guarantees that the written bytes are a prefix of the full output.
+///
+/// See also the [`format_to!`](crate::format_to) macro.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_buffered_writer)]
+/// use std::fmt::{BufferedWriter, Write};
+///
+/// let mut buf = [0u8; 16];
+/// let mut w = BufferedWriter::new(&mut buf);
+/// write!(w, "{} + {} = {}", 40, 2, 42).unwrap();
+/// assert_eq!(w.as_str(), "40 + 2 = 42");
+/// assert!(!w.is_truncated());
+///
+/// let mut buf = [0u8; 8];
+/// let mut w = BufferedWriter::new(&mut buf);
+/// write!(w, "temperature: {}", 21).unwrap();
+/// assert_eq!(w.as_str(), "temperat");
+/// assert!(w.is_truncated());
+/// assert_eq!(w.len(), 8);
+/// assert_eq!(w.required_len(), 15);
+/// ```
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+pub struct BufferedWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+    required: usize,
+}
+
+impl<'a> BufferedWriter<'a> {
+    /// Creates a new writer that formats into `buf`, starting at its beginning.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> BufferedWriter<'a> {
+        BufferedWriter { buf, len: 0, required: 0 }
+    }
+
+    /// Formats `args` into `buf` and returns the written part of the buffer.
+    ///
+    /// If the output does not fit into `buf`, the longest prefix that fits
+    /// without splitting a `char` is returned. This is the function behind the
+    /// [`format_to!`](crate::format_to) macro.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fmt_buffered_writer)]
+    /// use std::fmt::BufferedWriter;
+    ///
+    /// let mut buf = [0u8; 32];
+    /// let s = BufferedWriter::format(&mut buf, format_args!("{:>5}|", "ab"));
+    /// assert_eq!(s, "   ab|");
+    /// ```
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    pub fn format(buf: &'a mut [u8], args: Arguments<'_>) -> &'a str {
+        let mut w = BufferedWriter::new(buf);
+        // Writing into a `BufferedWriter` never fails, so an error can only come from
+        // a formatting trait implementation. The output up to that point is kept.
+        let _ = w.write_fmt(args);
+        w.into_str()
+    }
+
+    /// Returns the output written so far.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only whole `str` prefixes ending on a `char` boundary are ever
+        // copied into `buf[..len]`, so it always contains valid UTF-8.
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Consumes the writer, returning the written output with the lifetime of
+    /// the underlying buffer.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn into_str(self) -> &'a str {
+        let (written, _) = self.buf.split_at_mut(self.len);
+        // SAFETY: see `as_str`.
+        unsafe { str::from_utf8_unchecked_mut(written) }
+    }
+
+    /// Returns the number of bytes written into the buffer.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written into the buffer.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total size of the underlying buffer.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the number of bytes the complete output would have needed.
+    ///
+    /// This is equal to [`len`](BufferedWriter::len) unless the output was truncated.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn required_len(&self) -> usize {
+        self.required
+    }
+
+    /// Returns `true` if some of the output did not fit into the buffer and was
+    /// dropped.
+    #[unstable(feature = "fmt_buffered_writer", issue = "none")]
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.required > self.len
+    }
+}
+
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+impl Write for BufferedWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let already_truncated = self.is_truncated();
+        self.required = self.required.saturating_add(s.len());
+        if already_truncated {
+            return Ok(());
+        }
+
+        let available = self.buf.len() - self.len;
+        let mut n = s.len();
+        if n > available {
+            n = available;
+            while !s.is_char_boundary(n) {
+                n -= 1;
+            }
+        }
+        self.buf[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+impl Debug for BufferedWriter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferedWriter")
+            .field("written", &self.as_str())
+            .field("capacity", &self.capacity())
+            .field("required_len", &self.required)
+            .finish()
+    }
+}
@@ -12,6 +12,7 @@
 use crate::result;
 use crate::str;
 
+mod buffered_writer;
 mod builders;
 #[cfg(not(no_fp_fmt_parse))]
 mod float;
@@ -42,6 +43,9 @@ pub enum Alignment {
 #[unstable(feature = "debug_closure_helpers", issue = "117729")]
 pub use self::builders::FormatterFn;
 
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+pub use self::buffered_writer::BufferedWriter;
+
 /// The type returned by formatter methods.
 ///
 /// # Examples
@@ -1918,11 +1922,11 @@ pub fn sign_aware_zero_pad(&self) -> bool {
 
     // FIXME: Decide what public API we want for these two flags.
     // https://github.com/rust-lang/rust/issues/48584
-    fn debug_lower_hex(&self) -> bool {
+    pub(crate) fn debug_lower_hex(&self) -> bool {
         self.flags & (1 << rt::Flag::DebugLowerHex as u32) != 0
     }
 
-    fn debug_upper_hex(&self) -> bool {
+    pub(crate) fn debug_upper_hex(&self) -> bool {
         self.flags & (1 << rt::Flag::DebugUpperHex as u32) != 0
     }
 
@@ -2738,6 +2738,10 @@ pub const fn ub_checks() -> bool {
 /// Allocates a block of memory at compile time.
 /// At runtime, just returns a null pointer.
 ///
+/// Memory that is still allocated at the end of the evaluation of a `const` or `static`
+/// becomes read-only static memory. Pointers to it that can be used for mutation must be
+/// stored in immutable memory of a `static`, or behind a reference.
+///
 /// # Safety
 ///
 /// - The `align` argument must be a power of two.
@@ -293,6 +293,9 @@ pub mod assert_matches {
 #[unstable(feature = "cfg_match", issue = "115585")]
 pub use crate::macros::cfg_match;
 
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+pub use crate::macros::format_to;
+
 #[macro_use]
 mod internal_macros;
 
@@ -671,6 +671,36 @@ macro_rules! writeln {
     };
 }
 
+/// Formats data into a byte buffer without allocating, returning the written `&str`.
+///
+/// The first argument is a `&mut [u8]` to format into; the remaining arguments are
+/// passed to [`format_args!`]. If the output does not fit, the longest prefix that
+/// fits without splitting a `char` is returned.
+///
+/// To find out whether the output was truncated, or how large the buffer would need
+/// to be, use [`fmt::BufferedWriter`] directly.
+///
+/// [`fmt::BufferedWriter`]: crate::fmt::BufferedWriter
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_buffered_writer)]
+/// use std::format_to;
+///
+/// let mut buf = [0u8; 32];
+/// let line = format_to!(&mut buf, "[{:>4}] {}", 7, "ready");
+/// assert_eq!(line, "[   7] ready");
+///
+/// let mut small = [0u8; 4];
+/// assert_eq!(format_to!(&mut small, "{}", "naïve"), "naï");
+/// ```
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+#[allow_internal_unstable(fmt_buffered_writer)]
+pub macro format_to($buf:expr, $($arg:tt)*) {
+    $crate::fmt::BufferedWriter::format($buf, $crate::format_args!($($arg)*))
+}
+
 /// Indicates unreachable code.
 ///
 /// This is useful any time that the compiler can't determine that some code is unreachable. For
@@ -47,6 +47,7 @@ macro_rules! unlikely {
 mod nonzero;
 mod overflow_panic;
 mod saturating;
+mod wide;
 mod wrapping;
 
 #[stable(feature = "saturating_int_impl", since = "1.74.0")]
@@ -54,6 +55,9 @@ macro_rules! unlikely {
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use wrapping::Wrapping;
 
+#[unstable(feature = "wide_int", issue = "none")]
+pub use wide::{I256, I512, U256, U512};
+
 #[stable(feature = "rust1", since = "1.0.0")]
 #[cfg(not(no_fp_fmt_parse))]
 pub use dec2flt::ParseFloatError;
@@ -157,6 +161,87 @@ pub const fn midpoint(self, rhs: $SelfT) -> $SelfT {
 }
 
 macro_rules! widening_impl {
+    ($SelfT:ty, $HalfT:ty, $BITS:literal, unsigned, split) => {
+        /// Calculates the complete product `self * rhs` without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order.
+        ///
+        /// If you also need to add a carry to the wide result, then you want
+        /// [`Self::carrying_mul`] instead.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".widening_mul(2), (10, 0));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.widening_mul(2), (",
+            stringify!($SelfT), "::MAX - 1, 1));")]
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "const_bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+            self.carrying_mul(rhs, 0)
+        }
+
+        /// Calculates the "full multiplication" `self * rhs + carry`
+        /// without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order.
+        ///
+        /// Performs "long multiplication" which takes in an extra amount to add, and may return an
+        /// additional amount of overflow. This allows for chaining together multiple
+        /// multiplications to create "big integers" which represent larger values.
+        ///
+        /// If you don't need the `carry`, then you can use [`Self::widening_mul`] instead.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".carrying_mul(2, 10), (20, 0));")]
+        #[doc = concat!("assert_eq!(",
+            stringify!($SelfT), "::MAX.carrying_mul(", stringify!($SelfT), "::MAX, ", stringify!($SelfT), "::MAX), ",
+            "(0, ", stringify!($SelfT), "::MAX));"
+        )]
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+            // There is no wider type to do this in, so do schoolbook multiplication on the
+            // two halves of each operand instead. None of the sums below can overflow:
+            // the middle column is at most three half-width values, and the complete
+            // result `self * rhs + carry` always fits in twice the width of `Self`.
+            const HALF: u32 = $BITS / 2;
+            const MASK: $SelfT = <$HalfT>::MAX as $SelfT;
+
+            let (a_lo, a_hi) = (self & MASK, self >> HALF);
+            let (b_lo, b_hi) = (rhs & MASK, rhs >> HALF);
+
+            let ll = a_lo * b_lo;
+            let lh = a_lo * b_hi;
+            let hl = a_hi * b_lo;
+            let hh = a_hi * b_hi;
+
+            let mid = (ll >> HALF) + (lh & MASK) + (hl & MASK);
+            let lo = (ll & MASK) | (mid << HALF);
+            let hi = hh + (lh >> HALF) + (hl >> HALF) + (mid >> HALF);
+
+            let (lo, overflow) = lo.overflowing_add(carry);
+            (lo, hi + overflow as $SelfT)
+        }
+    };
     ($SelfT:ty, $WideT:ty, $BITS:literal, unsigned) => {
         /// Calculates the complete product `self * rhs` without the possibility to overflow.
         ///
@@ -284,6 +369,172 @@ pub const fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
             (wide as $SelfT, (wide >> $BITS) as $SelfT)
         }
     };
+    ($SelfT:ty, $UnsignedT:ty, $BITS:literal, signed, split) => {
+        /// Calculates the complete product `self * rhs` without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order. The low-order bits are
+        /// returned as an unsigned integer, since they do not carry the sign.
+        ///
+        /// If you also need to add a carry to the wide result, then you want
+        /// [`Self::carrying_mul`] instead.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".widening_mul(-2), (",
+            stringify!($UnsignedT), "::MAX - 9, -1));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.widening_mul(2), (0, -1));")]
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "const_bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn widening_mul(self, rhs: Self) -> ($UnsignedT, Self) {
+            self.carrying_mul(rhs, 0)
+        }
+
+        /// Calculates the "full multiplication" `self * rhs + carry`
+        /// without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order. The low-order bits are
+        /// returned as an unsigned integer, since they do not carry the sign.
+        ///
+        /// Performs "long multiplication" which takes in an extra amount to add, and may return an
+        /// additional amount of overflow. This allows for chaining together multiple
+        /// multiplications to create "big integers" which represent larger values.
+        ///
+        /// If you don't need the `carry`, then you can use [`Self::widening_mul`] instead.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        #[doc = concat!("assert_eq!(5", stringify!($SelfT), ".carrying_mul(-2, 10), (0, 0));")]
+        #[doc = concat!("assert_eq!(",
+            stringify!($SelfT), "::MIN.carrying_mul(", stringify!($SelfT), "::MIN, ", stringify!($SelfT), "::MIN), ",
+            "(", stringify!($SelfT), "::MIN as ", stringify!($UnsignedT), ", ", stringify!($SelfT), "::MAX >> 1));"
+        )]
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn carrying_mul(self, rhs: Self, carry: Self) -> ($UnsignedT, Self) {
+            // There is no wider type to do this in, so multiply the bit patterns as unsigned
+            // integers and correct the high half: a negative operand `x` is read as
+            // `x + 2^BITS`, which adds the other operand times `2^BITS` to the product, and a
+            // negative carry is read as `carry + 2^BITS`, which adds one to the high half.
+            let (lo, mut hi) =
+                (self as $UnsignedT).carrying_mul(rhs as $UnsignedT, carry as $UnsignedT);
+            if self < 0 {
+                hi = hi.wrapping_sub(rhs as $UnsignedT);
+            }
+            if rhs < 0 {
+                hi = hi.wrapping_sub(self as $UnsignedT);
+            }
+            if carry < 0 {
+                hi = hi.wrapping_sub(1);
+            }
+            (lo, hi as $SelfT)
+        }
+    };
+    ($SelfT:ty, $UnsignedT:ty, $WideT:ty, $BITS:literal, signed) => {
+        /// Calculates the complete product `self * rhs` without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order. The low-order bits are
+        /// returned as an unsigned integer, since they do not carry the sign.
+        ///
+        /// If you also need to add a carry to the wide result, then you want
+        /// [`Self::carrying_mul`] instead.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// Please note that this example is shared between integer types.
+        /// Which explains why `i32` is used here.
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        /// assert_eq!(5i32.widening_mul(-2), (4294967286, -1));
+        /// assert_eq!(1_000_000_000i32.widening_mul(-10), (2884901888, -3));
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "const_bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn widening_mul(self, rhs: Self) -> ($UnsignedT, Self) {
+            // SAFETY: overflow will be contained within the wider types
+            let wide = unsafe { (self as $WideT).unchecked_mul(rhs as $WideT) };
+            (wide as $UnsignedT, (wide >> $BITS) as $SelfT)
+        }
+
+        /// Calculates the "full multiplication" `self * rhs + carry`
+        /// without the possibility to overflow.
+        ///
+        /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+        /// of the result as two separate values, in that order. The low-order bits are
+        /// returned as an unsigned integer, since they do not carry the sign.
+        ///
+        /// Performs "long multiplication" which takes in an extra amount to add, and may return an
+        /// additional amount of overflow. This allows for chaining together multiple
+        /// multiplications to create "big integers" which represent larger values.
+        ///
+        /// If you don't need the `carry`, then you can use [`Self::widening_mul`] instead.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// Please note that this example is shared between integer types.
+        /// Which explains why `i32` is used here.
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        /// assert_eq!(5i32.carrying_mul(-2, 0), (4294967286, -1));
+        /// assert_eq!(5i32.carrying_mul(-2, 10), (0, 0));
+        /// assert_eq!(1_000_000_000i32.carrying_mul(-10, 0), (2884901888, -3));
+        /// assert_eq!(1_000_000_000i32.carrying_mul(-10, -10), (2884901878, -3));
+        #[doc = concat!("assert_eq!(",
+            stringify!($SelfT), "::MIN.carrying_mul(", stringify!($SelfT), "::MIN, ", stringify!($SelfT), "::MIN), ",
+            "(", stringify!($SelfT), "::MIN as ", stringify!($UnsignedT), ", ", stringify!($SelfT), "::MAX >> 1));"
+        )]
+        /// ```
+        ///
+        /// If `carry` is zero, this is similar to [`overflowing_mul`](Self::overflowing_mul),
+        /// except that it gives the value of the overflow instead of just whether one happened:
+        ///
+        /// ```
+        /// #![feature(bigint_helper_methods)]
+        /// let r = i8::carrying_mul(-7, 13, 0);
+        /// assert_eq!((r.0 as i8, r.1 != (r.0 as i8) >> 7), i8::overflowing_mul(-7, 13));
+        /// let r = i8::carrying_mul(-13, 42, 0);
+        /// assert_eq!((r.0 as i8, r.1 != (r.0 as i8) >> 7), i8::overflowing_mul(-13, 42));
+        /// ```
+        #[unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[rustc_const_unstable(feature = "bigint_helper_methods", issue = "85532")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn carrying_mul(self, rhs: Self, carry: Self) -> ($UnsignedT, Self) {
+            // SAFETY: overflow will be contained within the wider types
+            let wide = unsafe {
+                (self as $WideT).unchecked_mul(rhs as $WideT).unchecked_add(carry as $WideT)
+            };
+            (wide as $UnsignedT, (wide >> $BITS) as $SelfT)
+        }
+    };
 }
 
 impl i8 {
@@ -307,6 +558,7 @@ impl i8 {
         from_xe_bytes_doc = "",
         bound_condition = "",
     }
+    widening_impl! { i8, u8, i16, 8, signed }
 }
 
 impl i16 {
@@ -330,6 +582,7 @@ impl i16 {
         from_xe_bytes_doc = "",
         bound_condition = "",
     }
+    widening_impl! { i16, u16, i32, 16, signed }
 }
 
 impl i32 {
@@ -353,6 +606,7 @@ impl i32 {
         from_xe_bytes_doc = "",
         bound_condition = "",
     }
+    widening_impl! { i32, u32, i64, 32, signed }
 }
 
 impl i64 {
@@ -376,6 +630,7 @@ impl i64 {
         from_xe_bytes_doc = "",
         bound_condition = "",
     }
+    widening_impl! { i64, u64, i128, 64, signed }
 }
 
 impl i128 {
@@ -401,6 +656,7 @@ impl i128 {
         from_xe_bytes_doc = "",
         bound_condition = "",
     }
+    widening_impl! { i128, u128, 128, signed, split }
 }
 
 #[cfg(target_pointer_width = "16")]
@@ -425,6 +681,7 @@ impl isize {
         from_xe_bytes_doc = usize_isize_from_xe_bytes_doc!(),
         bound_condition = " on 16-bit targets",
     }
+    widening_impl! { isize, usize, i32, 16, signed }
 }
 
 #[cfg(target_pointer_width = "32")]
@@ -449,6 +706,7 @@ impl isize {
         from_xe_bytes_doc = usize_isize_from_xe_bytes_doc!(),
         bound_condition = " on 32-bit targets",
     }
+    widening_impl! { isize, usize, i64, 32, signed }
 }
 
 #[cfg(target_pointer_width = "64")]
@@ -473,6 +731,7 @@ impl isize {
         from_xe_bytes_doc = usize_isize_from_xe_bytes_doc!(),
         bound_condition = " on 64-bit targets",
     }
+    widening_impl! { isize, usize, i128, 64, signed }
 }
 
 /// If the 6th bit is set ascii is lower case.
@@ -1207,6 +1466,7 @@ impl u128 {
         from_xe_bytes_doc = "",
         bound_condition = "",
     }
+    widening_impl! { u128, u64, 128, unsigned, split }
     midpoint_impl! { u128, unsigned }
 }
 
@@ -0,0 +1,1834 @@
+/// Methods that behave the same for the signed and the unsigned wide integer types.
+macro_rules! wide_common_impl {
+    (
+        Self = $SelfT:ident,
+        LIMBS = $N:literal,
+        BITS = $BITS:literal,
+        BYTES = $BYTES:literal,
+        SIGNED = $signed:literal,
+    ) => {
+        /// The size of this integer type in bits.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::BITS, ", stringify!($BITS), ");")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        pub const BITS: u32 = $BITS;
+
+        const SIGNED: bool = $signed;
+
+        #[inline]
+        const fn from_limbs(limbs: [u64; $N]) -> Self {
+            Self { limbs }
+        }
+
+        #[inline]
+        const fn is_zero(self) -> bool {
+            limbs::is_zero(&self.limbs)
+        }
+
+        #[inline]
+        const fn not_bits(self) -> Self {
+            Self::from_limbs(limbs::not(&self.limbs))
+        }
+
+        #[inline]
+        const fn and_bits(self, rhs: Self) -> Self {
+            Self::from_limbs(limbs::and(&self.limbs, &rhs.limbs))
+        }
+
+        #[inline]
+        const fn or_bits(self, rhs: Self) -> Self {
+            Self::from_limbs(limbs::or(&self.limbs, &rhs.limbs))
+        }
+
+        #[inline]
+        const fn xor_bits(self, rhs: Self) -> Self {
+            Self::from_limbs(limbs::xor(&self.limbs, &rhs.limbs))
+        }
+
+        #[inline]
+        const fn eq_bits(self, rhs: Self) -> bool {
+            let mut i = 0;
+            while i < $N {
+                if self.limbs[i] != rhs.limbs[i] {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+
+        /// Returns the number of ones in the binary representation of `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0b01001100u8);")]
+        /// assert_eq!(n.count_ones(), 3);
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn count_ones(self) -> u32 {
+            limbs::count_ones(&self.limbs)
+        }
+
+        /// Returns the number of zeros in the binary representation of `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0b01001100u8);")]
+        #[doc = concat!("assert_eq!(n.count_zeros(), ", stringify!($BITS), " - 3);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn count_zeros(self) -> u32 {
+            Self::BITS - self.count_ones()
+        }
+
+        /// Returns the number of leading zeros in the binary representation of `self`.
+        ///
+        /// Depending on what you're doing with the value, you might also be interested in the
+        /// [`ilog2`] function which returns a consistent number, even if the type widens.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x3fu8);")]
+        #[doc = concat!("assert_eq!(n.leading_zeros(), ", stringify!($BITS), " - 6);")]
+        /// ```
+        #[doc = concat!("[`ilog2`]: ", stringify!($SelfT), "::ilog2")]
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn leading_zeros(self) -> u32 {
+            limbs::leading_zeros(&self.limbs)
+        }
+
+        /// Returns the number of trailing zeros in the binary representation of `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0b0101000u8);")]
+        /// assert_eq!(n.trailing_zeros(), 3);
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn trailing_zeros(self) -> u32 {
+            limbs::trailing_zeros(&self.limbs)
+        }
+
+        /// Returns the number of leading ones in the binary representation of `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = !", stringify!($SelfT), "::from(0u8) << (", stringify!($SelfT), "::BITS - 2);")]
+        /// assert_eq!(n.leading_ones(), 2);
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn leading_ones(self) -> u32 {
+            self.not_bits().leading_zeros()
+        }
+
+        /// Returns the number of trailing ones in the binary representation of `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0b1010111u8);")]
+        /// assert_eq!(n.trailing_ones(), 3);
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn trailing_ones(self) -> u32 {
+            self.not_bits().trailing_zeros()
+        }
+
+        /// Shifts the bits to the left by a specified amount, `n`,
+        /// wrapping the truncated bits to the end of the resulting integer.
+        ///
+        /// Please note this isn't the same operation as the `<<` shifting operator!
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("let n = one << (", stringify!($SelfT), "::BITS - 1) | one;")]
+        #[doc = concat!("assert_eq!(n.rotate_left(1), ", stringify!($SelfT), "::from(0b11u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn rotate_left(self, n: u32) -> Self {
+            let n = n % Self::BITS;
+            if n == 0 {
+                return self;
+            }
+            let hi = limbs::shl(&self.limbs, n);
+            let lo = limbs::shr(&self.limbs, Self::BITS - n, false);
+            Self::from_limbs(limbs::or(&hi, &lo))
+        }
+
+        /// Shifts the bits to the right by a specified amount, `n`,
+        /// wrapping the truncated bits to the beginning of the resulting integer.
+        ///
+        /// Please note this isn't the same operation as the `>>` shifting operator!
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0b11u8);")]
+        #[doc = concat!("assert_eq!(n.rotate_right(1), one << (", stringify!($SelfT), "::BITS - 1) | one);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn rotate_right(self, n: u32) -> Self {
+            self.rotate_left(Self::BITS - n % Self::BITS)
+        }
+
+        /// Reverses the byte order of the integer.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x1234u16);")]
+        #[doc = concat!("assert_eq!(n.swap_bytes(), ", stringify!($SelfT), "::from(0x3412u16) << (",
+            stringify!($SelfT), "::BITS - 16));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn swap_bytes(self) -> Self {
+            Self::from_limbs(limbs::swap_bytes(&self.limbs))
+        }
+
+        /// Reverses the order of bits in the integer. The least significant bit becomes the
+        /// most significant bit, second least-significant bit becomes second most-significant
+        /// bit, etc.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0b1011u8);")]
+        #[doc = concat!("assert_eq!(n.reverse_bits(), ", stringify!($SelfT), "::from(0b1101u8) << (",
+            stringify!($SelfT), "::BITS - 4));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn reverse_bits(self) -> Self {
+            Self::from_limbs(limbs::reverse_bits(&self.limbs))
+        }
+
+        /// Converts an integer from big endian to the target's endianness.
+        ///
+        /// On big endian this is a no-op. On little endian the bytes are swapped.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x1Au8);")]
+        ///
+        /// if cfg!(target_endian = "big") {
+        #[doc = concat!("    assert_eq!(", stringify!($SelfT), "::from_be(n), n)")]
+        /// } else {
+        #[doc = concat!("    assert_eq!(", stringify!($SelfT), "::from_be(n), n.swap_bytes())")]
+        /// }
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_be(x: Self) -> Self {
+            #[cfg(target_endian = "big")]
+            {
+                x
+            }
+            #[cfg(not(target_endian = "big"))]
+            {
+                x.swap_bytes()
+            }
+        }
+
+        /// Converts an integer from little endian to the target's endianness.
+        ///
+        /// On little endian this is a no-op. On big endian the bytes are swapped.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x1Au8);")]
+        ///
+        /// if cfg!(target_endian = "little") {
+        #[doc = concat!("    assert_eq!(", stringify!($SelfT), "::from_le(n), n)")]
+        /// } else {
+        #[doc = concat!("    assert_eq!(", stringify!($SelfT), "::from_le(n), n.swap_bytes())")]
+        /// }
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_le(x: Self) -> Self {
+            #[cfg(target_endian = "little")]
+            {
+                x
+            }
+            #[cfg(not(target_endian = "little"))]
+            {
+                x.swap_bytes()
+            }
+        }
+
+        /// Converts `self` to big endian from the target's endianness.
+        ///
+        /// On big endian this is a no-op. On little endian the bytes are swapped.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x1Au8);")]
+        ///
+        /// if cfg!(target_endian = "big") {
+        ///     assert_eq!(n.to_be(), n)
+        /// } else {
+        ///     assert_eq!(n.to_be(), n.swap_bytes())
+        /// }
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn to_be(self) -> Self {
+            Self::from_be(self)
+        }
+
+        /// Converts `self` to little endian from the target's endianness.
+        ///
+        /// On little endian this is a no-op. On big endian the bytes are swapped.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x1Au8);")]
+        ///
+        /// if cfg!(target_endian = "little") {
+        ///     assert_eq!(n.to_le(), n)
+        /// } else {
+        ///     assert_eq!(n.to_le(), n.swap_bytes())
+        /// }
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn to_le(self) -> Self {
+            Self::from_le(self)
+        }
+
+        /// Returns the memory representation of this integer as a byte array in
+        /// little-endian byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let bytes = ", stringify!($SelfT), "::from(0x1234u16).to_le_bytes();")]
+        /// assert_eq!(bytes[..3], [0x34, 0x12, 0]);
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn to_le_bytes(self) -> [u8; $BYTES] {
+            let mut bytes = [0; $BYTES];
+            let mut i = 0;
+            while i < $BYTES {
+                bytes[i] = (self.limbs[i / 8] >> (i % 8 * 8)) as u8;
+                i += 1;
+            }
+            bytes
+        }
+
+        /// Returns the memory representation of this integer as a byte array in
+        /// big-endian (network) byte order.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let bytes = ", stringify!($SelfT), "::from(0x1234u16).to_be_bytes();")]
+        #[doc = concat!("assert_eq!(bytes[", stringify!($BYTES), " - 3..], [0, 0x12, 0x34]);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn to_be_bytes(self) -> [u8; $BYTES] {
+            self.swap_bytes().to_le_bytes()
+        }
+
+        /// Returns the memory representation of this integer as a byte array in
+        /// native byte order.
+        ///
+        /// As the target platform's native endianness is used, portable code
+        /// should use [`to_be_bytes`] or [`to_le_bytes`], as appropriate,
+        /// instead.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x1234u16);")]
+        /// let bytes = n.to_ne_bytes();
+        /// if cfg!(target_endian = "big") {
+        ///     assert_eq!(bytes, n.to_be_bytes());
+        /// } else {
+        ///     assert_eq!(bytes, n.to_le_bytes());
+        /// }
+        /// ```
+        ///
+        #[doc = concat!("[`to_be_bytes`]: ", stringify!($SelfT), "::to_be_bytes")]
+        #[doc = concat!("[`to_le_bytes`]: ", stringify!($SelfT), "::to_le_bytes")]
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn to_ne_bytes(self) -> [u8; $BYTES] {
+            self.to_le().to_le_bytes()
+        }
+
+        /// Creates an integer value from its representation as a byte array in
+        /// little endian.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let mut bytes = [0; ", stringify!($BYTES), "];")]
+        /// bytes[..2].copy_from_slice(&[0x34, 0x12]);
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_le_bytes(bytes), ",
+            stringify!($SelfT), "::from(0x1234u16));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_le_bytes(bytes: [u8; $BYTES]) -> Self {
+            let mut limbs = [0; $N];
+            let mut i = 0;
+            while i < $BYTES {
+                limbs[i / 8] |= (bytes[i] as u64) << (i % 8 * 8);
+                i += 1;
+            }
+            Self::from_limbs(limbs)
+        }
+
+        /// Creates an integer value from its representation as a byte array in
+        /// big endian.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let mut bytes = [0; ", stringify!($BYTES), "];")]
+        #[doc = concat!("bytes[", stringify!($BYTES), " - 2..].copy_from_slice(&[0x12, 0x34]);")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_be_bytes(bytes), ",
+            stringify!($SelfT), "::from(0x1234u16));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_be_bytes(bytes: [u8; $BYTES]) -> Self {
+            Self::from_le_bytes(bytes).swap_bytes()
+        }
+
+        /// Creates an integer value from its memory representation as a byte
+        /// array in native endianness.
+        ///
+        /// As the target platform's native endianness is used, portable code
+        /// likely wants to use [`from_be_bytes`] or [`from_le_bytes`], as
+        /// appropriate instead.
+        ///
+        #[doc = concat!("[`from_be_bytes`]: ", stringify!($SelfT), "::from_be_bytes")]
+        #[doc = concat!("[`from_le_bytes`]: ", stringify!($SelfT), "::from_le_bytes")]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x1234u16);")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_ne_bytes(n.to_ne_bytes()), n);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use]
+        #[inline]
+        pub const fn from_ne_bytes(bytes: [u8; $BYTES]) -> Self {
+            Self::from_le(Self::from_le_bytes(bytes))
+        }
+
+        /// Checked integer addition. Computes `self + rhs`, returning `None`
+        /// if overflow occurred.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!((", stringify!($SelfT), "::MAX - one).checked_add(one), Some(",
+            stringify!($SelfT), "::MAX));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_add(one), None);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+            let (a, b) = self.overflowing_add(rhs);
+            if b { None } else { Some(a) }
+        }
+
+        /// Strict integer addition. Computes `self + rhs`, panicking
+        /// if overflow occurred.
+        ///
+        /// # Panics
+        ///
+        /// ## Overflow behavior
+        ///
+        /// This function will always panic on overflow, regardless of whether overflow
+        /// checks are enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!((", stringify!($SelfT), "::MAX - one).strict_add(one), ",
+            stringify!($SelfT), "::MAX);")]
+        /// ```
+        ///
+        /// The following panics because of overflow:
+        ///
+        /// ```should_panic
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let _ = ", stringify!($SelfT), "::MAX.strict_add(", stringify!($SelfT),
+            "::from(1u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[track_caller]
+        pub const fn strict_add(self, rhs: Self) -> Self {
+            let (a, b) = self.overflowing_add(rhs);
+            if b { overflow_panic::add() } else { a }
+        }
+
+        /// Unchecked integer addition. Computes `self + rhs`, assuming overflow
+        /// cannot occur.
+        ///
+        /// # Safety
+        ///
+        /// This results in undefined behavior when
+        #[doc = concat!("`self + rhs > ", stringify!($SelfT), "::MAX` or `self + rhs < ", stringify!($SelfT), "::MIN`,")]
+        /// i.e. when [`checked_add`] would return `None`.
+        ///
+        #[doc = concat!("[`checked_add`]: ", stringify!($SelfT), "::checked_add")]
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline(always)]
+        #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        pub const unsafe fn unchecked_add(self, rhs: Self) -> Self {
+            assert_unsafe_precondition!(
+                check_library_ub,
+                concat!(stringify!($SelfT), "::unchecked_add cannot overflow"),
+                (
+                    lhs: $SelfT = self,
+                    rhs: $SelfT = rhs,
+                ) => !lhs.overflowing_add(rhs).1,
+            );
+            self.wrapping_add(rhs)
+        }
+
+        /// Checked integer subtraction. Computes `self - rhs`, returning `None`
+        /// if overflow occurred.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!((", stringify!($SelfT), "::MIN + one).checked_sub(one), Some(",
+            stringify!($SelfT), "::MIN));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_sub(one), None);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+            let (a, b) = self.overflowing_sub(rhs);
+            if b { None } else { Some(a) }
+        }
+
+        /// Strict integer subtraction. Computes `self - rhs`, panicking if
+        /// overflow occurred.
+        ///
+        /// # Panics
+        ///
+        /// ## Overflow behavior
+        ///
+        /// This function will always panic on overflow, regardless of whether overflow
+        /// checks are enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!((", stringify!($SelfT), "::MIN + one).strict_sub(one), ",
+            stringify!($SelfT), "::MIN);")]
+        /// ```
+        ///
+        /// The following panics because of overflow:
+        ///
+        /// ```should_panic
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_sub(", stringify!($SelfT),
+            "::from(1u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[track_caller]
+        pub const fn strict_sub(self, rhs: Self) -> Self {
+            let (a, b) = self.overflowing_sub(rhs);
+            if b { overflow_panic::sub() } else { a }
+        }
+
+        /// Unchecked integer subtraction. Computes `self - rhs`, assuming overflow
+        /// cannot occur.
+        ///
+        /// # Safety
+        ///
+        /// This results in undefined behavior when
+        #[doc = concat!("`self - rhs > ", stringify!($SelfT), "::MAX` or `self - rhs < ", stringify!($SelfT), "::MIN`,")]
+        /// i.e. when [`checked_sub`] would return `None`.
+        ///
+        #[doc = concat!("[`checked_sub`]: ", stringify!($SelfT), "::checked_sub")]
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline(always)]
+        #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        pub const unsafe fn unchecked_sub(self, rhs: Self) -> Self {
+            assert_unsafe_precondition!(
+                check_library_ub,
+                concat!(stringify!($SelfT), "::unchecked_sub cannot overflow"),
+                (
+                    lhs: $SelfT = self,
+                    rhs: $SelfT = rhs,
+                ) => !lhs.overflowing_sub(rhs).1,
+            );
+            self.wrapping_sub(rhs)
+        }
+
+        /// Checked integer multiplication. Computes `self * rhs`, returning
+        /// `None` if overflow occurred.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_mul(one), Some(",
+            stringify!($SelfT), "::MAX));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_mul(two), None);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+            let (a, b) = self.overflowing_mul(rhs);
+            if b { None } else { Some(a) }
+        }
+
+        /// Strict integer multiplication. Computes `self * rhs`, panicking if
+        /// overflow occurred.
+        ///
+        /// # Panics
+        ///
+        /// ## Overflow behavior
+        ///
+        /// This function will always panic on overflow, regardless of whether overflow
+        /// checks are enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.strict_mul(one), ",
+            stringify!($SelfT), "::MAX);")]
+        /// ```
+        ///
+        /// The following panics because of overflow:
+        ///
+        /// ```should_panic
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let _ = ", stringify!($SelfT), "::MAX.strict_mul(", stringify!($SelfT),
+            "::from(2u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[track_caller]
+        pub const fn strict_mul(self, rhs: Self) -> Self {
+            let (a, b) = self.overflowing_mul(rhs);
+            if b { overflow_panic::mul() } else { a }
+        }
+
+        /// Unchecked integer multiplication. Computes `self * rhs`, assuming overflow
+        /// cannot occur.
+        ///
+        /// # Safety
+        ///
+        /// This results in undefined behavior when
+        #[doc = concat!("`self * rhs > ", stringify!($SelfT), "::MAX` or `self * rhs < ", stringify!($SelfT), "::MIN`,")]
+        /// i.e. when [`checked_mul`] would return `None`.
+        ///
+        #[doc = concat!("[`checked_mul`]: ", stringify!($SelfT), "::checked_mul")]
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline(always)]
+        #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        pub const unsafe fn unchecked_mul(self, rhs: Self) -> Self {
+            assert_unsafe_precondition!(
+                check_library_ub,
+                concat!(stringify!($SelfT), "::unchecked_mul cannot overflow"),
+                (
+                    lhs: $SelfT = self,
+                    rhs: $SelfT = rhs,
+                ) => !lhs.overflowing_mul(rhs).1,
+            );
+            self.wrapping_mul(rhs)
+        }
+
+        /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if
+        /// overflow occurred.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+        #[doc = concat!("assert_eq!(two.checked_pow(5), Some(", stringify!($SelfT), "::from(32u8)));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_pow(2), None);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+            let (a, b) = self.overflowing_pow(exp);
+            if b { None } else { Some(a) }
+        }
+
+        /// Strict exponentiation. Computes `self.pow(exp)`, panicking if
+        /// overflow occurred.
+        ///
+        /// # Panics
+        ///
+        /// ## Overflow behavior
+        ///
+        /// This function will always panic on overflow, regardless of whether overflow
+        /// checks are enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+        #[doc = concat!("assert_eq!(two.strict_pow(5), ", stringify!($SelfT), "::from(32u8));")]
+        /// ```
+        ///
+        /// The following panics because of overflow:
+        ///
+        /// ```should_panic
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let _ = ", stringify!($SelfT), "::MAX.strict_pow(2);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[track_caller]
+        pub const fn strict_pow(self, exp: u32) -> Self {
+            let (a, b) = self.overflowing_pow(exp);
+            if b { overflow_panic::mul() } else { a }
+        }
+
+        /// Wrapping (modular) exponentiation. Computes `self.pow(exp)`,
+        /// wrapping around at the boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+        #[doc = concat!("assert_eq!(two.wrapping_pow(5), ", stringify!($SelfT), "::from(32u8));")]
+        #[doc = concat!("assert_eq!(two.wrapping_pow(", stringify!($BITS), "), ",
+            stringify!($SelfT), "::from(0u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn wrapping_pow(self, exp: u32) -> Self {
+            self.overflowing_pow(exp).0
+        }
+
+        /// Raises self to the power of `exp`, using exponentiation by squaring.
+        ///
+        /// Returns a tuple of the exponentiation along with a bool indicating
+        /// whether an overflow happened.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+        #[doc = concat!("assert_eq!(two.overflowing_pow(5), (", stringify!($SelfT), "::from(32u8), false));")]
+        #[doc = concat!("assert_eq!(two.overflowing_pow(", stringify!($BITS), "), (",
+            stringify!($SelfT), "::from(0u8), true));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+            let mut acc = Self::from_limbs(limbs::from_u128(1, 0));
+            if exp == 0 {
+                return (acc, false);
+            }
+            let mut base = self;
+            let mut overflown = false;
+            // Scratch space for storing results of overflowing_mul.
+            let mut r;
+
+            while exp > 1 {
+                if (exp & 1) == 1 {
+                    r = acc.overflowing_mul(base);
+                    acc = r.0;
+                    overflown |= r.1;
+                }
+                exp /= 2;
+                r = base.overflowing_mul(base);
+                base = r.0;
+                overflown |= r.1;
+            }
+
+            // since exp!=0, finally the exp must be 1.
+            // Deal with the final bit of the exponent separately, since
+            // squaring the base afterwards is not necessary and may cause a
+            // needless overflow.
+            r = acc.overflowing_mul(base);
+            r.1 |= overflown;
+
+            r
+        }
+
+        /// Raises self to the power of `exp`, using exponentiation by squaring.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+        #[doc = concat!("assert_eq!(two.pow(5), ", stringify!($SelfT), "::from(32u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[track_caller]
+        #[rustc_inherit_overflow_checks]
+        #[allow(arithmetic_overflow)]
+        pub const fn pow(self, exp: u32) -> Self {
+            let (a, b) = self.overflowing_pow(exp);
+            if b {
+                // In debug builds, trigger a panic on overflow.
+                let _ = u8::MAX * 2;
+            }
+            a
+        }
+
+        /// Wrapping (modular) addition. Computes `self + rhs`,
+        /// wrapping around at the boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(200u8);")]
+        #[doc = concat!("assert_eq!(n.wrapping_add(", stringify!($SelfT), "::from(55u8)), ",
+            stringify!($SelfT), "::from(255u8));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.wrapping_add(",
+            stringify!($SelfT), "::from(1u8)), ", stringify!($SelfT), "::MIN);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn wrapping_add(self, rhs: Self) -> Self {
+            Self::from_limbs(limbs::add(&self.limbs, &rhs.limbs, false).0)
+        }
+
+        /// Wrapping (modular) subtraction. Computes `self - rhs`,
+        /// wrapping around at the boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(100u8);")]
+        #[doc = concat!("assert_eq!(n.wrapping_sub(n), ", stringify!($SelfT), "::from(0u8));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.wrapping_sub(",
+            stringify!($SelfT), "::from(1u8)), ", stringify!($SelfT), "::MAX);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn wrapping_sub(self, rhs: Self) -> Self {
+            Self::from_limbs(limbs::sub(&self.limbs, &rhs.limbs, false).0)
+        }
+
+        /// Wrapping (modular) multiplication. Computes `self * rhs`, wrapping around at
+        /// the boundary of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(10u8);")]
+        #[doc = concat!("assert_eq!(n.wrapping_mul(", stringify!($SelfT), "::from(12u8)), ",
+            stringify!($SelfT), "::from(120u8));")]
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.wrapping_mul(",
+            stringify!($SelfT), "::MAX), ", stringify!($SelfT), "::from(1u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn wrapping_mul(self, rhs: Self) -> Self {
+            Self::from_limbs(limbs::mul(&self.limbs, &rhs.limbs).0)
+        }
+
+        /// Wrapping (modular) negation. Computes `-self`, wrapping around at the boundary
+        /// of the type.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let zero = ", stringify!($SelfT), "::from(0u8);")]
+        /// assert_eq!(zero.wrapping_neg(), zero);
+        #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.wrapping_neg(), ",
+            stringify!($SelfT), "::MIN.wrapping_add(", stringify!($SelfT), "::from(1u8)));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn wrapping_neg(self) -> Self {
+            Self::from_limbs(limbs::neg(&self.limbs))
+        }
+
+        /// Checked shift left. Computes `self << rhs`, returning `None`
+        /// if `rhs` is larger than or equal to the number of bits in `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!(n.checked_shl(4), Some(", stringify!($SelfT), "::from(0x10u8)));")]
+        #[doc = concat!("assert_eq!(n.checked_shl(", stringify!($BITS), "), None);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn checked_shl(self, rhs: u32) -> Option<Self> {
+            if rhs < Self::BITS { Some(self.wrapping_shl(rhs)) } else { None }
+        }
+
+        /// Strict shift left. Computes `self << rhs`, panicking if `rhs` is larger
+        /// than or equal to the number of bits in `self`.
+        ///
+        /// # Panics
+        ///
+        /// ## Overflow behavior
+        ///
+        /// This function will always panic on overflow, regardless of whether overflow
+        /// checks are enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!(n.strict_shl(4), ", stringify!($SelfT), "::from(0x10u8));")]
+        /// ```
+        ///
+        /// The following panics because of overflow:
+        ///
+        /// ```should_panic
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let _ = ", stringify!($SelfT), "::from(1u8).strict_shl(",
+            stringify!($BITS), ");")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[track_caller]
+        pub const fn strict_shl(self, rhs: u32) -> Self {
+            let (a, b) = self.overflowing_shl(rhs);
+            if b { overflow_panic::shl() } else { a }
+        }
+
+        /// Unchecked shift left. Computes `self << rhs`, assuming that
+        /// `rhs` is less than the number of bits in `self`.
+        ///
+        /// # Safety
+        ///
+        /// This results in undefined behavior if `rhs` is larger than
+        /// or equal to the number of bits in `self`,
+        /// i.e. when [`checked_shl`] would return `None`.
+        ///
+        #[doc = concat!("[`checked_shl`]: ", stringify!($SelfT), "::checked_shl")]
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline(always)]
+        #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        pub const unsafe fn unchecked_shl(self, rhs: u32) -> Self {
+            assert_unsafe_precondition!(
+                check_library_ub,
+                concat!(stringify!($SelfT), "::unchecked_shl cannot overflow"),
+                (rhs: u32 = rhs) => rhs < $BITS,
+            );
+            self.wrapping_shl(rhs)
+        }
+
+        /// Panic-free bitwise shift-left; yields `self << mask(rhs)`, where `mask` removes
+        /// any high-order bits of `rhs` that would cause the shift to exceed the bitwidth
+        /// of the type.
+        ///
+        /// Note that this is *not* the same as a rotate-left; the RHS of a wrapping
+        /// shift-left is restricted to the range of the type, rather than the bits shifted
+        /// out of the LHS being returned to the other end.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!(n.wrapping_shl(7), ", stringify!($SelfT), "::from(128u8));")]
+        #[doc = concat!("assert_eq!(n.wrapping_shl(", stringify!($BITS), " + 7), ",
+            stringify!($SelfT), "::from(128u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn wrapping_shl(self, rhs: u32) -> Self {
+            Self::from_limbs(limbs::shl(&self.limbs, rhs & (Self::BITS - 1)))
+        }
+
+        /// Shifts self left by `rhs` bits.
+        ///
+        /// Returns a tuple of the shifted version of self along with a boolean
+        /// indicating whether the shift value was larger than or equal to the
+        /// number of bits. If the shift value is too large, then value is
+        /// masked (N-1) where N is the number of bits, and this value is then
+        /// used to perform the shift.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(1u8);")]
+        #[doc = concat!("assert_eq!(n.overflowing_shl(4), (", stringify!($SelfT), "::from(0x10u8), false));")]
+        #[doc = concat!("assert_eq!(n.overflowing_shl(", stringify!($BITS), " + 4), (",
+            stringify!($SelfT), "::from(0x10u8), true));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+            (self.wrapping_shl(rhs), rhs >= Self::BITS)
+        }
+
+        /// Checked shift right. Computes `self >> rhs`, returning `None`
+        /// if `rhs` is larger than or equal to the number of bits in `self`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x10u8);")]
+        #[doc = concat!("assert_eq!(n.checked_shr(4), Some(", stringify!($SelfT), "::from(1u8)));")]
+        #[doc = concat!("assert_eq!(n.checked_shr(", stringify!($BITS), "), None);")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn checked_shr(self, rhs: u32) -> Option<Self> {
+            if rhs < Self::BITS { Some(self.wrapping_shr(rhs)) } else { None }
+        }
+
+        /// Strict shift right. Computes `self >> rhs`, panicking if `rhs` is
+        /// larger than or equal to the number of bits in `self`.
+        ///
+        /// # Panics
+        ///
+        /// ## Overflow behavior
+        ///
+        /// This function will always panic on overflow, regardless of whether overflow
+        /// checks are enabled.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x10u8);")]
+        #[doc = concat!("assert_eq!(n.strict_shr(4), ", stringify!($SelfT), "::from(1u8));")]
+        /// ```
+        ///
+        /// The following panics because of overflow:
+        ///
+        /// ```should_panic
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let _ = ", stringify!($SelfT), "::from(0x10u8).strict_shr(",
+            stringify!($BITS), ");")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        #[track_caller]
+        pub const fn strict_shr(self, rhs: u32) -> Self {
+            let (a, b) = self.overflowing_shr(rhs);
+            if b { overflow_panic::shr() } else { a }
+        }
+
+        /// Unchecked shift right. Computes `self >> rhs`, assuming that
+        /// `rhs` is less than the number of bits in `self`.
+        ///
+        /// # Safety
+        ///
+        /// This results in undefined behavior if `rhs` is larger than
+        /// or equal to the number of bits in `self`,
+        /// i.e. when [`checked_shr`] would return `None`.
+        ///
+        #[doc = concat!("[`checked_shr`]: ", stringify!($SelfT), "::checked_shr")]
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline(always)]
+        #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
+        pub const unsafe fn unchecked_shr(self, rhs: u32) -> Self {
+            assert_unsafe_precondition!(
+                check_library_ub,
+                concat!(stringify!($SelfT), "::unchecked_shr cannot overflow"),
+                (rhs: u32 = rhs) => rhs < $BITS,
+            );
+            self.wrapping_shr(rhs)
+        }
+
+        /// Panic-free bitwise shift-right; yields `self >> mask(rhs)`, where `mask`
+        /// removes any high-order bits of `rhs` that would cause the shift to exceed the
+        /// bitwidth of the type.
+        ///
+        /// Note that this is *not* the same as a rotate-right; the RHS of a wrapping
+        /// shift-right is restricted to the range of the type, rather than the bits shifted
+        /// out of the LHS being returned to the other end.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(128u8);")]
+        #[doc = concat!("assert_eq!(n.wrapping_shr(7), ", stringify!($SelfT), "::from(1u8));")]
+        #[doc = concat!("assert_eq!(n.wrapping_shr(", stringify!($BITS), " + 7), ",
+            stringify!($SelfT), "::from(1u8));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn wrapping_shr(self, rhs: u32) -> Self {
+            Self::from_limbs(limbs::shr(&self.limbs, rhs & (Self::BITS - 1), $signed))
+        }
+
+        /// Shifts self right by `rhs` bits.
+        ///
+        /// Returns a tuple of the shifted version of self along with a boolean
+        /// indicating whether the shift value was larger than or equal to the
+        /// number of bits. If the shift value is too large, then value is
+        /// masked (N-1) where N is the number of bits, and this value is then
+        /// used to perform the shift.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let n = ", stringify!($SelfT), "::from(0x10u8);")]
+        #[doc = concat!("assert_eq!(n.overflowing_shr(4), (", stringify!($SelfT), "::from(1u8), false));")]
+        #[doc = concat!("assert_eq!(n.overflowing_shr(", stringify!($BITS), " + 4), (",
+            stringify!($SelfT), "::from(1u8), true));")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+        #[must_use = "this returns the result of the operation, \
+                      without modifying the original"]
+        #[inline]
+        pub const fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+            (self.wrapping_shr(rhs), rhs >= Self::BITS)
+        }
+    };
+}
+
+/// Trait implementations that are the same for the signed and the unsigned wide integer types.
+macro_rules! wide_traits_impl {
+    (
+        Self = $SelfT:ident,
+        LIMBS = $N:literal,
+        BITS = $BITS:literal,
+        cmp = $cmp:path,
+    ) => {
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl PartialOrd for $SelfT {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Ord for $SelfT {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                $cmp(&self.limbs, &other.limbs)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Hash for $SelfT {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.limbs.hash(state)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Add for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            fn add(self, rhs: Self) -> Self {
+                let (a, b) = self.overflowing_add(rhs);
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = u8::MAX + 1;
+                }
+                a
+            }
+        }
+        forward_ref_binop! { impl Add, add for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Sub for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            fn sub(self, rhs: Self) -> Self {
+                let (a, b) = self.overflowing_sub(rhs);
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = u8::MIN - 1;
+                }
+                a
+            }
+        }
+        forward_ref_binop! { impl Sub, sub for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Mul for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            fn mul(self, rhs: Self) -> Self {
+                let (a, b) = self.overflowing_mul(rhs);
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = u8::MAX * 2;
+                }
+                a
+            }
+        }
+        forward_ref_binop! { impl Mul, mul for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Div for $SelfT {
+            type Output = Self;
+
+            /// This operation rounds towards zero, truncating any
+            /// fractional part of the exact result.
+            ///
+            /// # Panics
+            ///
+            /// This operation will panic if `other == 0`, or if the division
+            /// overflows for a signed type.
+            #[inline]
+            #[track_caller]
+            fn div(self, rhs: Self) -> Self {
+                self.strict_div(rhs)
+            }
+        }
+        forward_ref_binop! { impl Div, div for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Rem for $SelfT {
+            type Output = Self;
+
+            /// This operation satisfies `n % d == n - (n / d) * d`. The
+            /// result has the same sign as the left operand.
+            ///
+            /// # Panics
+            ///
+            /// This operation will panic if `other == 0`, or if the division
+            /// overflows for a signed type.
+            #[inline]
+            #[track_caller]
+            fn rem(self, rhs: Self) -> Self {
+                self.strict_rem(rhs)
+            }
+        }
+        forward_ref_binop! { impl Rem, rem for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Not for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                self.not_bits()
+            }
+        }
+        forward_ref_unop! { impl Not, not for $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl BitAnd for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self {
+                self.and_bits(rhs)
+            }
+        }
+        forward_ref_binop! { impl BitAnd, bitand for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl BitOr for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                self.or_bits(rhs)
+            }
+        }
+        forward_ref_binop! { impl BitOr, bitor for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl BitXor for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            fn bitxor(self, rhs: Self) -> Self {
+                self.xor_bits(rhs)
+            }
+        }
+        forward_ref_binop! { impl BitXor, bitxor for $SelfT, $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Shl<u32> for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            fn shl(self, rhs: u32) -> Self {
+                if rhs >= Self::BITS {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = 1u8 << 8;
+                }
+                self.wrapping_shl(rhs)
+            }
+        }
+        forward_ref_binop! { impl Shl, shl for $SelfT, u32,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Shr<u32> for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            fn shr(self, rhs: u32) -> Self {
+                if rhs >= Self::BITS {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = 1u8 >> 8;
+                }
+                self.wrapping_shr(rhs)
+            }
+        }
+        forward_ref_binop! { impl Shr, shr for $SelfT, u32,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        wide_op_assign! { $SelfT, AddAssign, add_assign, Add, add, $SelfT }
+        wide_op_assign! { $SelfT, SubAssign, sub_assign, Sub, sub, $SelfT }
+        wide_op_assign! { $SelfT, MulAssign, mul_assign, Mul, mul, $SelfT }
+        wide_op_assign! { $SelfT, DivAssign, div_assign, Div, div, $SelfT }
+        wide_op_assign! { $SelfT, RemAssign, rem_assign, Rem, rem, $SelfT }
+        wide_op_assign! { $SelfT, BitAndAssign, bitand_assign, BitAnd, bitand, $SelfT }
+        wide_op_assign! { $SelfT, BitOrAssign, bitor_assign, BitOr, bitor, $SelfT }
+        wide_op_assign! { $SelfT, BitXorAssign, bitxor_assign, BitXor, bitxor, $SelfT }
+        wide_op_assign! { $SelfT, ShlAssign, shl_assign, Shl, shl, u32 }
+        wide_op_assign! { $SelfT, ShrAssign, shr_assign, Shr, shr, u32 }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Sum for $SelfT {
+            #[rustc_inherit_overflow_checks]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(0u8), |a, b| a + b)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl<'a> Sum<&'a $SelfT> for $SelfT {
+            #[rustc_inherit_overflow_checks]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::from(0u8), |a, b| a + b)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Product for $SelfT {
+            #[rustc_inherit_overflow_checks]
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::from(1u8), |a, b| a * b)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl<'a> Product<&'a $SelfT> for $SelfT {
+            #[rustc_inherit_overflow_checks]
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::from(1u8), |a, b| a * b)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl FromStr for $SelfT {
+            type Err = ParseIntError;
+
+            #[inline]
+            fn from_str(src: &str) -> Result<Self, ParseIntError> {
+                Self::from_str_radix(src, 10)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl fmt::Debug for $SelfT {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if f.debug_lower_hex() {
+                    fmt::LowerHex::fmt(self, f)
+                } else if f.debug_upper_hex() {
+                    fmt::UpperHex::fmt(self, f)
+                } else {
+                    fmt::Display::fmt(self, f)
+                }
+            }
+        }
+
+        // Like for the primitive types, the non-decimal formats print the two's complement
+        // bit pattern of signed values.
+        wide_fmt_radix! { $SelfT, $BITS, Binary, 2, false, "0b" }
+        wide_fmt_radix! { $SelfT, $BITS, Octal, 8, false, "0o" }
+        wide_fmt_radix! { $SelfT, $BITS, LowerHex, 16, false, "0x" }
+        wide_fmt_radix! { $SelfT, $BITS, UpperHex, 16, true, "0x" }
+
+        // Every primitive integer converts losslessly into the signed and unsigned wide
+        // types, except for negative values into the unsigned ones.
+        wide_from_primitive! { $SelfT: bool u8 u16 u32 u64 u128 usize }
+        wide_try_into_primitive! {
+            $SelfT: u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize
+        }
+    };
+}
+
+macro_rules! wide_op_assign {
+    ($SelfT:ident, $Imp:ident, $method:ident, $Op:ident, $op:ident, $Rhs:ty) => {
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl $Imp<$Rhs> for $SelfT {
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            fn $method(&mut self, rhs: $Rhs) {
+                *self = $Op::$op(*self, rhs);
+            }
+        }
+        forward_ref_op_assign! { impl $Imp, $method for $SelfT, $Rhs,
+        #[unstable(feature = "wide_int", issue = "none")] }
+    };
+}
+
+macro_rules! wide_fmt_radix {
+    ($SelfT:ident, $BITS:literal, $Trait:ident, $radix:literal, $upper:literal, $prefix:literal) => {
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl fmt::$Trait for $SelfT {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let prefix = if f.alternate() { $prefix } else { "" };
+                let mut buf = [0; $BITS];
+                fmt_radix(self.limbs, true, $radix, $upper, prefix, &mut buf, f)
+            }
+        }
+    };
+}
+
+macro_rules! wide_from_primitive {
+    ($SelfT:ident: $($Prim:ty)*) => {$(
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl From<$Prim> for $SelfT {
+            #[doc = concat!("Converts a [`", stringify!($Prim), "`] into a [`", stringify!($SelfT),
+                "`] losslessly.")]
+            #[inline]
+            fn from(value: $Prim) -> Self {
+                Self::from_limbs(limbs::from_u128(value as u128, 0))
+            }
+        }
+    )*};
+}
+
+macro_rules! wide_from_signed_primitive {
+    ($SelfT:ident: $($Prim:ty)*) => {$(
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl From<$Prim> for $SelfT {
+            #[doc = concat!("Converts a [`", stringify!($Prim), "`] into a [`", stringify!($SelfT),
+                "`] losslessly.")]
+            #[inline]
+            fn from(value: $Prim) -> Self {
+                let fill = if value < 0 { u64::MAX } else { 0 };
+                Self::from_limbs(limbs::from_u128(value as i128 as u128, fill))
+            }
+        }
+    )*};
+}
+
+macro_rules! wide_try_from_signed_primitive {
+    ($SelfT:ident: $($Prim:ty)*) => {$(
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl TryFrom<$Prim> for $SelfT {
+            type Error = TryFromIntError;
+
+            #[doc = concat!("Tries to create the target number type from a source number type. ",
+                "This returns an error if the source value is outside of the range of the ",
+                "target type.")]
+            #[inline]
+            fn try_from(value: $Prim) -> Result<Self, Self::Error> {
+                if value < 0 {
+                    Err(TryFromIntError(()))
+                } else {
+                    Ok(Self::from_limbs(limbs::from_u128(value as u128, 0)))
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! wide_try_into_primitive {
+    ($SelfT:ident: $($Prim:ty)*) => {$(
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl TryFrom<$SelfT> for $Prim {
+            type Error = TryFromIntError;
+
+            #[doc = concat!("Tries to create the target number type from a source number type. ",
+                "This returns an error if the source value is outside of the range of the ",
+                "target type.")]
+            #[inline]
+            fn try_from(value: $SelfT) -> Result<Self, Self::Error> {
+                // Narrow to 128 bits first, then let the primitive conversions do the rest.
+                #[allow(unused_comparisons)]
+                let to_signed = <$Prim>::MIN < 0;
+                let (limbs, fits) =
+                    limbs::convert::<_, 2>(&value.limbs, $SelfT::SIGNED, to_signed);
+                let wide = ((limbs[1] as u128) << 64) | limbs[0] as u128;
+                let value = if !fits {
+                    None
+                } else if to_signed {
+                    <$Prim>::try_from(wide as i128).ok()
+                } else {
+                    <$Prim>::try_from(wide).ok()
+                };
+                value.ok_or(TryFromIntError(()))
+            }
+        }
+    )*};
+}
+
+macro_rules! wide_from {
+    ($From:ident => $($To:ident),+) => {$(
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl From<$From> for $To {
+            #[doc = concat!("Converts a [`", stringify!($From), "`] into a [`", stringify!($To),
+                "`] losslessly.")]
+            #[inline]
+            fn from(value: $From) -> Self {
+                Self::from_limbs(limbs::convert(&value.limbs, $From::SIGNED, $To::SIGNED).0)
+            }
+        }
+    )+};
+}
+
+macro_rules! wide_try_from {
+    ($From:ident => $($To:ident),+) => {$(
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl TryFrom<$From> for $To {
+            type Error = TryFromIntError;
+
+            #[doc = concat!("Tries to create the target number type from a source number type. ",
+                "This returns an error if the source value is outside of the range of the ",
+                "target type.")]
+            #[inline]
+            fn try_from(value: $From) -> Result<Self, Self::Error> {
+                let (limbs, fits) = limbs::convert(&value.limbs, $From::SIGNED, $To::SIGNED);
+                if fits { Ok(Self::from_limbs(limbs)) } else { Err(TryFromIntError(())) }
+            }
+        }
+    )+};
+}
@@ -0,0 +1,2402 @@
+/// Defines a wide signed integer type and its inherent methods and trait implementations.
+macro_rules! wide_int_impl {
+    (
+        Self = $SelfT:ident,
+        UnsignedT = $UnsignedT:ident,
+        LIMBS = $N:literal,
+        BITS = $BITS:literal,
+        BYTES = $BYTES:literal,
+    ) => {
+        #[doc = concat!("The ", stringify!($BITS), "-bit signed integer type.")]
+        ///
+        /// This has the same methods, operators and overflow behavior as the primitive signed
+        /// integer types, and uses the two's complement representation.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let a = ", stringify!($SelfT), "::from(i128::MIN);")]
+        /// let b = a * a;
+        /// assert_eq!(b.to_string(), "28948022309329048855892746252171976963317496166410141009864396001978282409984");
+        /// assert_eq!((-b).to_string(), "-28948022309329048855892746252171976963317496166410141009864396001978282409984");
+        #[doc = concat!("assert_eq!(b, \"28948022309329048855892746252171976963317496166410141009864396001978282409984\".parse::<", stringify!($SelfT), ">().unwrap());")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $SelfT {
+            limbs: [u64; $N],
+        }
+
+        impl $SelfT {
+            #[doc = concat!("The smallest value that can be represented by this integer type, &minus;2<sup>",
+                stringify!($BITS), " &minus; 1</sup>.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.trailing_zeros(), ", stringify!($BITS), " - 1);")]
+            #[doc = concat!("assert!(", stringify!($SelfT), "::MIN.is_negative());")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            pub const MIN: Self = {
+                let mut limbs = [0; $N];
+                limbs[$N - 1] = 1 << 63;
+                Self { limbs }
+            };
+
+            #[doc = concat!("The largest value that can be represented by this integer type, 2<sup>",
+                stringify!($BITS), " &minus; 1</sup> &minus; 1.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.count_ones(), ", stringify!($BITS), " - 1);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX, !", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            pub const MAX: Self = {
+                let mut limbs = [u64::MAX; $N];
+                limbs[$N - 1] = i64::MAX as u64;
+                Self { limbs }
+            };
+
+            wide_common_impl! {
+                Self = $SelfT,
+                LIMBS = $N,
+                BITS = $BITS,
+                BYTES = $BYTES,
+                SIGNED = true,
+            }
+
+            /// Creates a value from an `i128`.
+            ///
+            /// This is a `const` version of the `From<i128>` implementation.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("const N: ", stringify!($SelfT), " = ", stringify!($SelfT), "::from_i128(-42);")]
+            #[doc = concat!("assert_eq!(N, ", stringify!($SelfT), "::from(-42i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn from_i128(value: i128) -> Self {
+                let fill = if value < 0 { u64::MAX } else { 0 };
+                Self::from_limbs(limbs::from_u128(value as u128, fill))
+            }
+
+            /// Converts a string slice in a given base to an integer.
+            ///
+            /// The string is expected to be an optional `+` or `-` sign followed by digits.
+            /// Leading and trailing whitespace represent an error. Digits are a subset of
+            /// these characters, depending on `radix`:
+            ///
+            ///  * `0-9`
+            ///  * `a-z`
+            ///  * `A-Z`
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_radix(\"-A\", 16), Ok(",
+                stringify!($SelfT), "::from(-10i8)));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[inline]
+            pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                match parse(src, radix, true) {
+                    Ok(limbs) => Ok(Self::from_limbs(limbs)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            /// Returns the bit pattern of `self` reinterpreted as an unsigned integer of the same
+            /// size.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-1i8).cast_unsigned(), ",
+                stringify!($UnsignedT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            pub const fn cast_unsigned(self) -> $UnsignedT {
+                $UnsignedT::from_limbs(self.limbs)
+            }
+
+            /// Returns `true` if `self` is positive and `false` if the number is zero or
+            /// negative.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert!(", stringify!($SelfT), "::from(10i8).is_positive());")]
+            #[doc = concat!("assert!(!", stringify!($SelfT), "::from(-10i8).is_positive());")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use]
+            #[inline(always)]
+            pub const fn is_positive(self) -> bool {
+                !self.is_negative() && !self.is_zero()
+            }
+
+            /// Returns `true` if `self` is negative and `false` if the number is zero or
+            /// positive.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert!(", stringify!($SelfT), "::from(-10i8).is_negative());")]
+            #[doc = concat!("assert!(!", stringify!($SelfT), "::from(10i8).is_negative());")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use]
+            #[inline(always)]
+            pub const fn is_negative(self) -> bool {
+                limbs::sign_bit(&self.limbs)
+            }
+
+            /// Returns a number representing sign of `self`.
+            ///
+            ///  - `0` if the number is zero
+            ///  - `1` if the number is positive
+            ///  - `-1` if the number is negative
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10i8).signum(), ", stringify!($SelfT), "::from(1i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(0i8).signum(), ", stringify!($SelfT), "::from(0i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-10i8).signum(), ", stringify!($SelfT), "::from(-1i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn signum(self) -> Self {
+                if self.is_negative() {
+                    Self::from_i128(-1)
+                } else if self.is_zero() {
+                    self
+                } else {
+                    Self::from_i128(1)
+                }
+            }
+
+            /// Calculates `self + rhs`.
+            ///
+            /// Returns a tuple of the addition along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would have occurred then the wrapped value is
+            /// returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(one.overflowing_add(one), (", stringify!($SelfT), "::from(2i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.overflowing_add(one), (",
+                stringify!($SelfT), "::MIN, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let r = self.wrapping_add(rhs);
+                // Overflow happened if both operands have the same sign and the result does not.
+                let overflow = self.is_negative() == rhs.is_negative()
+                    && r.is_negative() != self.is_negative();
+                (r, overflow)
+            }
+
+            /// Calculates `self - rhs`.
+            ///
+            /// Returns a tuple of the subtraction along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would have occurred then the wrapped
+            /// value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(one.overflowing_sub(one), (", stringify!($SelfT), "::from(0i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_sub(one), (",
+                stringify!($SelfT), "::MAX, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let r = self.wrapping_sub(rhs);
+                // Overflow happened if the operands have different signs and the result does
+                // not have the sign of `self`.
+                let overflow = self.is_negative() != rhs.is_negative()
+                    && r.is_negative() != self.is_negative();
+                (r, overflow)
+            }
+
+            /// Calculates the multiplication of `self` and `rhs`.
+            ///
+            /// Returns a tuple of the multiplication along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would have occurred then the wrapped
+            /// value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let two = ", stringify!($SelfT), "::from(2i8);")]
+            #[doc = concat!("assert_eq!(two.overflowing_mul(", stringify!($SelfT), "::from(-3i8)), (",
+                stringify!($SelfT), "::from(-6i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.overflowing_mul(two), (",
+                stringify!($SelfT), "::from(-2i8), true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                // Multiply the magnitudes, then check that the product fits in the range for its
+                // sign: below `2^(BITS - 1)` if positive, at most `2^(BITS - 1)` if negative.
+                let negative = self.is_negative() != rhs.is_negative();
+                let (lo, hi) = self.unsigned_abs().widening_mul(rhs.unsigned_abs());
+                let r = if negative { lo.wrapping_neg() } else { lo };
+                let fits = if negative {
+                    !limbs::sign_bit(&lo.limbs) || lo.eq_bits(Self::MIN.cast_unsigned())
+                } else {
+                    !limbs::sign_bit(&lo.limbs)
+                };
+                let overflow = !hi.is_zero() || !fits;
+                (r.cast_signed(), overflow)
+            }
+
+            /// Negates self, overflowing if this is equal to the minimum value.
+            ///
+            /// Returns a tuple of the negated version of self along with a boolean indicating whether
+            /// an overflow happened. If `self` is the minimum value, then the minimum value will be
+            /// returned again and `true` will be returned for an overflow happening.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(2i8).overflowing_neg(), (",
+                stringify!($SelfT), "::from(-2i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_neg(), (",
+                stringify!($SelfT), "::MIN, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_neg(self) -> (Self, bool) {
+                (self.wrapping_neg(), self.eq_bits(Self::MIN))
+            }
+
+            /// Computes the absolute value of `self`.
+            ///
+            /// Returns a tuple of the absolute version of self along with a boolean indicating whether
+            /// an overflow happened. If self is the minimum value, then the minimum value will be
+            /// returned again and true will be returned for an overflow happening.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-10i8).overflowing_abs(), (",
+                stringify!($SelfT), "::from(10i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_abs(), (",
+                stringify!($SelfT), "::MIN, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_abs(self) -> (Self, bool) {
+                (self.wrapping_abs(), self.eq_bits(Self::MIN))
+            }
+
+            /// Checked negation. Computes `-self`, returning `None` if `self == MIN`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).checked_neg(), Some(",
+                stringify!($SelfT), "::from(-5i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_neg(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_neg(self) -> Option<Self> {
+                let (a, b) = self.overflowing_neg();
+                if b { None } else { Some(a) }
+            }
+
+            /// Strict negation. Computes `-self`, panicking if `self == MIN`.
+            ///
+            /// # Panics
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).strict_neg(), ",
+                stringify!($SelfT), "::from(-5i8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_neg();")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_neg(self) -> Self {
+                let (a, b) = self.overflowing_neg();
+                if b { overflow_panic::neg() } else { a }
+            }
+
+            /// Checked absolute value. Computes `self.abs()`, returning `None` if
+            /// `self == MIN`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-5i8).checked_abs(), Some(",
+                stringify!($SelfT), "::from(5i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_abs(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_abs(self) -> Option<Self> {
+                if self.is_negative() { self.checked_neg() } else { Some(self) }
+            }
+
+            /// Strict absolute value. Computes `self.abs()`, panicking if
+            /// `self == MIN`.
+            ///
+            /// # Panics
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-5i8).strict_abs(), ",
+                stringify!($SelfT), "::from(5i8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_abs();")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_abs(self) -> Self {
+                if self.is_negative() { self.strict_neg() } else { self }
+            }
+
+            /// Computes the absolute value of `self`.
+            ///
+            /// # Overflow behavior
+            ///
+            /// The absolute value of
+            #[doc = concat!("`", stringify!($SelfT), "::MIN`")]
+            /// cannot be represented as an
+            #[doc = concat!("`", stringify!($SelfT), "`,")]
+            /// and attempting to calculate it will cause an overflow. This means
+            /// that code in debug mode will trigger a panic on this case and
+            /// optimized code will return
+            #[doc = concat!("`", stringify!($SelfT), "::MIN`")]
+            /// without a panic.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-10i8).abs(), ",
+                stringify!($SelfT), "::from(10i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            pub const fn abs(self) -> Self {
+                let (a, b) = self.overflowing_abs();
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = -i8::MIN;
+                }
+                a
+            }
+
+            /// Wrapping (modular) absolute value. Computes `self.abs()`, wrapping around at
+            /// the boundary of the type.
+            ///
+            /// The only case where such wrapping can occur is when one takes the absolute value of
+            /// the negative minimal value for the type; this is a positive value that is too large
+            /// to represent in the type. In such a case, this function returns `MIN` itself.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-100i8).wrapping_abs(), ",
+                stringify!($SelfT), "::from(100i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.wrapping_abs(), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn wrapping_abs(self) -> Self {
+                if self.is_negative() { self.wrapping_neg() } else { self }
+            }
+
+            /// Computes the absolute value of `self` without any wrapping
+            /// or panicking.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-100i8).unsigned_abs(), ",
+                stringify!($UnsignedT), "::from(100u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.unsigned_abs(), ",
+                stringify!($UnsignedT), "::from(1u8) << (", stringify!($BITS), " - 1));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn unsigned_abs(self) -> $UnsignedT {
+                self.wrapping_abs().cast_unsigned()
+            }
+
+            /// Saturating absolute value. Computes `self.abs()`, returning `MAX` if `self ==
+            /// MIN` instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-100i8).saturating_abs(), ",
+                stringify!($SelfT), "::from(100i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_abs(), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_abs(self) -> Self {
+                match self.checked_abs() {
+                    Some(x) => x,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer negation. Computes `-self`, returning `MAX` if `self == MIN`
+            /// instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100i8).saturating_neg(), ",
+                stringify!($SelfT), "::from(-100i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_neg(), ", stringify!($SelfT), "::MAX);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_neg(), ",
+                stringify!($SelfT), "::MIN + ", stringify!($SelfT), "::from(1i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_neg(self) -> Self {
+                match self.checked_neg() {
+                    Some(x) => x,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Calculates `self` + `rhs` with an unsigned `rhs`.
+            ///
+            /// Returns a tuple of the addition along with a boolean indicating
+            /// whether an arithmetic overflow would occur. If an overflow would
+            /// have occurred then the wrapped value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($UnsignedT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-1i8).overflowing_add_unsigned(two), (",
+                stringify!($SelfT), "::from(1i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_add_unsigned(", stringify!($UnsignedT),
+                "::MAX), (", stringify!($SelfT), "::MAX, false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.overflowing_add_unsigned(two), (",
+                stringify!($SelfT), "::MIN + ", stringify!($SelfT), "::from(1i8), true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_add_unsigned(self, rhs: $UnsignedT) -> (Self, bool) {
+                let rhs = rhs.cast_signed();
+                let (res, overflowed) = self.overflowing_add(rhs);
+                (res, overflowed ^ rhs.is_negative())
+            }
+
+            /// Calculates `self` - `rhs` with an unsigned `rhs`.
+            ///
+            /// Returns a tuple of the subtraction along with a boolean indicating
+            /// whether an arithmetic overflow would occur. If an overflow would
+            /// have occurred then the wrapped value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($UnsignedT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1i8).overflowing_sub_unsigned(two), (",
+                stringify!($SelfT), "::from(-1i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.overflowing_sub_unsigned(", stringify!($UnsignedT),
+                "::MAX), (", stringify!($SelfT), "::MIN, false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_sub_unsigned(two), (",
+                stringify!($SelfT), "::MAX - ", stringify!($SelfT), "::from(1i8), true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_sub_unsigned(self, rhs: $UnsignedT) -> (Self, bool) {
+                let rhs = rhs.cast_signed();
+                let (res, overflowed) = self.overflowing_sub(rhs);
+                (res, overflowed ^ rhs.is_negative())
+            }
+
+            /// Checked addition with an unsigned integer. Computes `self + rhs`,
+            /// returning `None` if overflow occurred.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($UnsignedT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1i8).checked_add_unsigned(two), Some(",
+                stringify!($SelfT), "::from(3i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_add_unsigned(two), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_add_unsigned(self, rhs: $UnsignedT) -> Option<Self> {
+                let (a, b) = self.overflowing_add_unsigned(rhs);
+                if b { None } else { Some(a) }
+            }
+
+            /// Strict addition with an unsigned integer. Computes `self + rhs`,
+            /// panicking if overflow occurred.
+            ///
+            /// # Panics
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($UnsignedT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1i8).strict_add_unsigned(two), ",
+                stringify!($SelfT), "::from(3i8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MAX.strict_add_unsigned(",
+                stringify!($UnsignedT), "::from(3u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_add_unsigned(self, rhs: $UnsignedT) -> Self {
+                let (a, b) = self.overflowing_add_unsigned(rhs);
+                if b { overflow_panic::add() } else { a }
+            }
+
+            /// Checked subtraction with an unsigned `rhs`. Computes `self - rhs`,
+            /// returning `None` if overflow occurred.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($UnsignedT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1i8).checked_sub_unsigned(two), Some(",
+                stringify!($SelfT), "::from(-1i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_sub_unsigned(two), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_sub_unsigned(self, rhs: $UnsignedT) -> Option<Self> {
+                let (a, b) = self.overflowing_sub_unsigned(rhs);
+                if b { None } else { Some(a) }
+            }
+
+            /// Strict subtraction with an unsigned `rhs`. Computes `self - rhs`,
+            /// panicking if overflow occurred.
+            ///
+            /// # Panics
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($UnsignedT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1i8).strict_sub_unsigned(two), ",
+                stringify!($SelfT), "::from(-1i8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_sub_unsigned(",
+                stringify!($UnsignedT), "::from(3u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_sub_unsigned(self, rhs: $UnsignedT) -> Self {
+                let (a, b) = self.overflowing_sub_unsigned(rhs);
+                if b { overflow_panic::sub() } else { a }
+            }
+
+            /// Wrapping (modular) addition with an unsigned integer. Computes
+            /// `self + rhs`, wrapping around at the boundary of the type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.wrapping_add_unsigned(",
+                stringify!($UnsignedT), "::MAX), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn wrapping_add_unsigned(self, rhs: $UnsignedT) -> Self {
+                self.wrapping_add(rhs.cast_signed())
+            }
+
+            /// Wrapping (modular) subtraction with an unsigned integer. Computes
+            /// `self - rhs`, wrapping around at the boundary of the type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.wrapping_sub_unsigned(",
+                stringify!($UnsignedT), "::MAX), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn wrapping_sub_unsigned(self, rhs: $UnsignedT) -> Self {
+                self.wrapping_sub(rhs.cast_signed())
+            }
+
+            /// Saturating addition with an unsigned integer. Computes `self + rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_add_unsigned(",
+                stringify!($UnsignedT), "::from(100u8)), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_add_unsigned(self, rhs: $UnsignedT) -> Self {
+                // Overflow can only happen at the upper bound
+                match self.checked_add_unsigned(rhs) {
+                    Some(x) => x,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Saturating subtraction with an unsigned integer. Computes `self - rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_sub_unsigned(",
+                stringify!($UnsignedT), "::from(100u8)), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_sub_unsigned(self, rhs: $UnsignedT) -> Self {
+                // Overflow can only happen at the lower bound
+                match self.checked_sub_unsigned(rhs) {
+                    Some(x) => x,
+                    None => Self::MIN,
+                }
+            }
+
+            /// Saturating integer addition. Computes `self + rhs`, saturating at the numeric
+            /// bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_add(one), ", stringify!($SelfT), "::MAX);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_add(-one), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                match self.checked_add(rhs) {
+                    Some(x) => x,
+                    None if rhs.is_negative() => Self::MIN,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer subtraction. Computes `self - rhs`, saturating at the
+            /// numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_sub(one), ", stringify!($SelfT), "::MIN);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_sub(-one), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    Some(x) => x,
+                    None if rhs.is_negative() => Self::MAX,
+                    None => Self::MIN,
+                }
+            }
+
+            /// Saturating integer multiplication. Computes `self * rhs`, saturating at the
+            /// numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let two = ", stringify!($SelfT), "::from(2i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_mul(two), ", stringify!($SelfT), "::MAX);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_mul(two), ", stringify!($SelfT), "::MIN);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_mul(-two), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                match self.checked_mul(rhs) {
+                    Some(x) => x,
+                    None if self.is_negative() == rhs.is_negative() => Self::MAX,
+                    None => Self::MIN,
+                }
+            }
+
+            /// Saturating integer division. Computes `self / rhs`, saturating at the
+            /// numeric bounds instead of overflowing.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_div(", stringify!($SelfT),
+                "::from(-1i8)), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn saturating_div(self, rhs: Self) -> Self {
+                match self.overflowing_div(rhs) {
+                    (result, false) => result,
+                    (_result, true) => Self::MAX, // MIN / -1 is the only possible saturating overflow
+                }
+            }
+
+            /// Saturating integer exponentiation. Computes `self.pow(exp)`,
+            /// saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-4i8).saturating_pow(2), ",
+                stringify!($SelfT), "::from(16i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_pow(2), ", stringify!($SelfT), "::MAX);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.saturating_pow(3), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_pow(self, exp: u32) -> Self {
+                match self.checked_pow(exp) {
+                    Some(x) => x,
+                    None if self.is_negative() && exp % 2 == 1 => Self::MIN,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Computes the quotient and the remainder of `self / rhs`, rounding towards zero and
+            /// wrapping `MIN / -1` around to `MIN`.
+            #[inline]
+            #[track_caller]
+            const fn divrem(self, rhs: Self) -> (Self, Self) {
+                if rhs.is_zero() {
+                    div_by_zero();
+                }
+                let (q, r) = limbs::divrem(&self.unsigned_abs().limbs, &rhs.unsigned_abs().limbs);
+                let (q, r) = (Self::from_limbs(q), Self::from_limbs(r));
+                // The quotient is negative if the signs differ, the remainder has the sign of
+                // `self`.
+                let q = if self.is_negative() != rhs.is_negative() { q.wrapping_neg() } else { q };
+                let r = if self.is_negative() { r.wrapping_neg() } else { r };
+                (q, r)
+            }
+
+            /// Returns `true` if `self / rhs` overflows, which only happens for `MIN / -1`.
+            #[inline]
+            const fn div_overflows(self, rhs: Self) -> bool {
+                self.eq_bits(Self::MIN) && rhs.eq_bits(Self::from_i128(-1))
+            }
+
+            /// Checked integer division. Computes `self / rhs`, returning `None` if `rhs == 0`
+            /// or the division results in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!((", stringify!($SelfT), "::MIN + one).checked_div(-one), Some(",
+                stringify!($SelfT), "::MAX));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_div(-one), None);")]
+            #[doc = concat!("assert_eq!(one.checked_div(", stringify!($SelfT), "::from(0i8)), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() || self.div_overflows(rhs) {
+                    None
+                } else {
+                    Some(self.divrem(rhs).0)
+                }
+            }
+
+            /// Strict integer division. Computes `self / rhs`, panicking
+            /// if overflow occurred.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// The only case where such an overflow can occur is when one divides `MIN / -1` on a
+            /// signed type (where `MIN` is the negative minimal value for the type); this is
+            /// equivalent to `-MIN`, a positive value that is too large to represent in the type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!((", stringify!($SelfT), "::MIN + one).strict_div(-one), ",
+                stringify!($SelfT), "::MAX);")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_div(", stringify!($SelfT), "::from(-1i8));")]
+            /// ```
+            ///
+            /// The following panics because of division by zero:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::from(1i8).strict_div(", stringify!($SelfT), "::from(0i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_div(self, rhs: Self) -> Self {
+                let (a, b) = self.overflowing_div(rhs);
+                if b { overflow_panic::div() } else { a }
+            }
+
+            /// Checked Euclidean division. Computes `self.div_euclid(rhs)`,
+            /// returning `None` if `rhs == 0` or the division results in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-7i8).checked_div_euclid(",
+                stringify!($SelfT), "::from(4i8)), Some(", stringify!($SelfT), "::from(-2i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_div_euclid(-one), None);")]
+            #[doc = concat!("assert_eq!(one.checked_div_euclid(", stringify!($SelfT), "::from(0i8)), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() || self.div_overflows(rhs) {
+                    None
+                } else {
+                    Some(self.wrapping_div_euclid(rhs))
+                }
+            }
+
+            /// Strict Euclidean division. Computes `self.div_euclid(rhs)`, panicking
+            /// if overflow occurred.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// The only case where such an overflow can occur is when one divides `MIN / -1` on a
+            /// signed type (where `MIN` is the negative minimal value for the type); this is
+            /// equivalent to `-MIN`, a positive value that is too large to represent in the type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-7i8).strict_div_euclid(",
+                stringify!($SelfT), "::from(4i8)), ", stringify!($SelfT), "::from(-2i8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_div_euclid(", stringify!($SelfT), "::from(-1i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_div_euclid(self, rhs: Self) -> Self {
+                let (a, b) = self.overflowing_div_euclid(rhs);
+                if b { overflow_panic::div() } else { a }
+            }
+
+            /// Checked integer remainder. Computes `self % rhs`, returning `None` if
+            /// `rhs == 0` or the division results in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).checked_rem(",
+                stringify!($SelfT), "::from(2i8)), Some(one));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).checked_rem(",
+                stringify!($SelfT), "::from(0i8)), None);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_rem(-one), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() || self.div_overflows(rhs) {
+                    None
+                } else {
+                    Some(self.divrem(rhs).1)
+                }
+            }
+
+            /// Strict integer remainder. Computes `self % rhs`, panicking if
+            /// the division results in overflow.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// The only case where such an overflow can occur is `x % y` for `MIN / -1` on a
+            /// signed type (where `MIN` is the negative minimal value), which is invalid due to implementation artifacts.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).strict_rem(",
+                stringify!($SelfT), "::from(2i8)), ", stringify!($SelfT), "::from(1i8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_rem(", stringify!($SelfT), "::from(-1i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_rem(self, rhs: Self) -> Self {
+                let (a, b) = self.overflowing_rem(rhs);
+                if b { overflow_panic::rem() } else { a }
+            }
+
+            /// Checked Euclidean remainder. Computes `self.rem_euclid(rhs)`, returning `None`
+            /// if `rhs == 0` or the division results in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-7i8).checked_rem_euclid(",
+                stringify!($SelfT), "::from(4i8)), Some(", stringify!($SelfT), "::from(1i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_rem_euclid(", stringify!($SelfT),
+                "::from(-1i8)), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() || self.div_overflows(rhs) {
+                    None
+                } else {
+                    Some(self.wrapping_rem_euclid(rhs))
+                }
+            }
+
+            /// Strict Euclidean remainder. Computes `self.rem_euclid(rhs)`, panicking if
+            /// the division results in overflow.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// The only case where such an overflow can occur is `x % y` for `MIN / -1` on a
+            /// signed type (where `MIN` is the negative minimal value), which is invalid due to implementation artifacts.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-7i8).strict_rem_euclid(",
+                stringify!($SelfT), "::from(4i8)), ", stringify!($SelfT), "::from(1i8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::MIN.strict_rem_euclid(", stringify!($SelfT), "::from(-1i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_rem_euclid(self, rhs: Self) -> Self {
+                let (a, b) = self.overflowing_rem_euclid(rhs);
+                if b { overflow_panic::rem() } else { a }
+            }
+
+            /// Wrapping (modular) division. Computes `self / rhs`, wrapping around at the
+            /// boundary of the type.
+            ///
+            /// The only case where such wrapping can occur is when one divides `MIN / -1` on a signed type (where
+            /// `MIN` is the negative minimal value for the type); this is equivalent to `-MIN`, a positive value
+            /// that is too large to represent in the type. In such a case, this function returns `MIN` itself.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100i8).wrapping_div(",
+                stringify!($SelfT), "::from(10i8)), ", stringify!($SelfT), "::from(10i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.wrapping_div(", stringify!($SelfT),
+                "::from(-1i8)), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn wrapping_div(self, rhs: Self) -> Self {
+                self.divrem(rhs).0
+            }
+
+            /// Wrapping Euclidean division. Computes `self.div_euclid(rhs)`,
+            /// wrapping around at the boundary of the type.
+            ///
+            /// Wrapping will only occur in `MIN / -1` on a signed type (where `MIN` is the negative minimal value
+            /// for the type). This is equivalent to `-MIN`, a positive value that is too large to represent in the
+            /// type. In this case, this method returns `MIN` itself.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-7i8).wrapping_div_euclid(",
+                stringify!($SelfT), "::from(4i8)), ", stringify!($SelfT), "::from(-2i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.wrapping_div_euclid(", stringify!($SelfT),
+                "::from(-1i8)), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn wrapping_div_euclid(self, rhs: Self) -> Self {
+                let (q, r) = self.divrem(rhs);
+                if r.is_negative() {
+                    // Round towards negative infinity for positive `rhs`, and towards positive
+                    // infinity for negative `rhs`, so that the remainder is nonnegative.
+                    let one = Self::from_i128(1);
+                    if rhs.is_negative() { q.wrapping_add(one) } else { q.wrapping_sub(one) }
+                } else {
+                    q
+                }
+            }
+
+            /// Wrapping (modular) remainder. Computes `self % rhs`, wrapping around at the
+            /// boundary of the type.
+            ///
+            /// Such wrap-around never actually occurs mathematically; implementation artifacts make `x % y`
+            /// invalid for `MIN / -1` on a signed type (where `MIN` is the negative minimal value). In such a case,
+            /// this function returns `0`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100i8).wrapping_rem(",
+                stringify!($SelfT), "::from(10i8)), ", stringify!($SelfT), "::from(0i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.wrapping_rem(", stringify!($SelfT),
+                "::from(-1i8)), ", stringify!($SelfT), "::from(0i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn wrapping_rem(self, rhs: Self) -> Self {
+                if rhs.is_zero() {
+                    rem_by_zero();
+                }
+                self.divrem(rhs).1
+            }
+
+            /// Wrapping Euclidean remainder. Computes `self.rem_euclid(rhs)`, wrapping around
+            /// at the boundary of the type.
+            ///
+            /// Wrapping will only occur in `MIN % -1` on a signed type (where `MIN` is the negative minimal value
+            /// for the type). In this case, this method returns 0.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-7i8).wrapping_rem_euclid(",
+                stringify!($SelfT), "::from(4i8)), ", stringify!($SelfT), "::from(1i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.wrapping_rem_euclid(", stringify!($SelfT),
+                "::from(-1i8)), ", stringify!($SelfT), "::from(0i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn wrapping_rem_euclid(self, rhs: Self) -> Self {
+                let r = self.wrapping_rem(rhs);
+                if r.is_negative() {
+                    // Semantically equivalent to `if rhs < 0 { r - rhs } else { r + rhs }`.
+                    // If `rhs` is not `Self::MIN`, then `r + abs(rhs)` will not overflow
+                    // and is clearly equivalent, because `r` is negative.
+                    // Otherwise, `rhs` is `Self::MIN`, then we have
+                    // `r.wrapping_add(Self::MIN.wrapping_abs())`, which evaluates
+                    // to `r.wrapping_add(Self::MIN)`, which is equivalent to
+                    // `r - Self::MIN`, which is what we wanted (and will not overflow
+                    // for negative `r`).
+                    r.wrapping_add(rhs.wrapping_abs())
+                } else {
+                    r
+                }
+            }
+
+            /// Calculates the quotient when `self` is divided by `rhs`.
+            ///
+            /// Returns a tuple of the divisor along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would occur then self is returned.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).overflowing_div(",
+                stringify!($SelfT), "::from(2i8)), (", stringify!($SelfT), "::from(2i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_div(", stringify!($SelfT),
+                "::from(-1i8)), (", stringify!($SelfT), "::MIN, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                (self.wrapping_div(rhs), self.div_overflows(rhs))
+            }
+
+            /// Calculates the quotient of Euclidean division `self.div_euclid(rhs)`.
+            ///
+            /// Returns a tuple of the divisor along with a boolean indicating whether an arithmetic
+            /// overflow would occur. If an overflow would occur then `self` is returned.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).overflowing_div_euclid(",
+                stringify!($SelfT), "::from(2i8)), (", stringify!($SelfT), "::from(2i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_div_euclid(", stringify!($SelfT),
+                "::from(-1i8)), (", stringify!($SelfT), "::MIN, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) {
+                (self.wrapping_div_euclid(rhs), self.div_overflows(rhs))
+            }
+
+            /// Calculates the remainder when `self` is divided by `rhs`.
+            ///
+            /// Returns a tuple of the remainder after dividing along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would occur then 0 is returned.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).overflowing_rem(",
+                stringify!($SelfT), "::from(2i8)), (", stringify!($SelfT), "::from(1i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_rem(", stringify!($SelfT),
+                "::from(-1i8)), (", stringify!($SelfT), "::from(0i8), true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+                (self.wrapping_rem(rhs), self.div_overflows(rhs))
+            }
+
+            /// Overflowing Euclidean remainder. Calculates `self.rem_euclid(rhs)`.
+            ///
+            /// Returns a tuple of the remainder after dividing along with a boolean indicating whether an
+            /// arithmetic overflow would occur. If an overflow would occur then 0 is returned.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).overflowing_rem_euclid(",
+                stringify!($SelfT), "::from(2i8)), (", stringify!($SelfT), "::from(1i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_rem_euclid(", stringify!($SelfT),
+                "::from(-1i8)), (", stringify!($SelfT), "::from(0i8), true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn overflowing_rem_euclid(self, rhs: Self) -> (Self, bool) {
+                (self.wrapping_rem_euclid(rhs), self.div_overflows(rhs))
+            }
+
+            /// Calculates the quotient of Euclidean division of `self` by `rhs`.
+            ///
+            /// This computes the integer `q` such that `self = q * rhs + r`, with
+            /// `r = self.rem_euclid(rhs)` and `0 <= r < abs(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero or if `self / rhs` results in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let a = ", stringify!($SelfT), "::from(7i8);")]
+            #[doc = concat!("let b = ", stringify!($SelfT), "::from(4i8);")]
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            ///
+            /// assert_eq!(a.div_euclid(b), one); // 7 >= 4 * 1
+            /// assert_eq!(a.div_euclid(-b), -one); // 7 >= -4 * -1
+            #[doc = concat!("assert_eq!((-a).div_euclid(b), ", stringify!($SelfT), "::from(-2i8)); // -7 >= 4 * -2")]
+            #[doc = concat!("assert_eq!((-a).div_euclid(-b), ", stringify!($SelfT), "::from(2i8)); // -7 >= -4 * 2")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn div_euclid(self, rhs: Self) -> Self {
+                self.strict_div_euclid(rhs)
+            }
+
+            /// Calculates the least nonnegative remainder of `self (mod rhs)`.
+            ///
+            /// This is done as if by the Euclidean division algorithm -- given
+            /// `r = self.rem_euclid(rhs)`, the result satisfies
+            /// `self = rhs * self.div_euclid(rhs) + r` and `0 <= r < abs(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero or if `self.rem_euclid(rhs)` results in
+            /// overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let a = ", stringify!($SelfT), "::from(7i8);")]
+            #[doc = concat!("let b = ", stringify!($SelfT), "::from(4i8);")]
+            #[doc = concat!("let three = ", stringify!($SelfT), "::from(3i8);")]
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            ///
+            /// assert_eq!(a.rem_euclid(b), three);
+            /// assert_eq!((-a).rem_euclid(b), one);
+            /// assert_eq!(a.rem_euclid(-b), three);
+            /// assert_eq!((-a).rem_euclid(-b), one);
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn rem_euclid(self, rhs: Self) -> Self {
+                self.strict_rem_euclid(rhs)
+            }
+
+            /// Calculates the quotient of `self` and `rhs`, rounding the result towards negative infinity.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero or if `self / rhs` results in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let a = ", stringify!($SelfT), "::from(8i8);")]
+            #[doc = concat!("let b = ", stringify!($SelfT), "::from(3i8);")]
+            ///
+            #[doc = concat!("assert_eq!(a.div_floor(b), ", stringify!($SelfT), "::from(2i8));")]
+            #[doc = concat!("assert_eq!(a.div_floor(-b), ", stringify!($SelfT), "::from(-3i8));")]
+            #[doc = concat!("assert_eq!((-a).div_floor(b), ", stringify!($SelfT), "::from(-3i8));")]
+            #[doc = concat!("assert_eq!((-a).div_floor(-b), ", stringify!($SelfT), "::from(2i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn div_floor(self, rhs: Self) -> Self {
+                let d = self.strict_div(rhs);
+                let r = self.wrapping_rem(rhs);
+                // The quotient was rounded towards zero; round it down if the exact result is
+                // negative, i.e. if the remainder and `rhs` have different signs.
+                if !r.is_zero() && r.is_negative() != rhs.is_negative() {
+                    d.wrapping_sub(Self::from_i128(1))
+                } else {
+                    d
+                }
+            }
+
+            /// Calculates the quotient of `self` and `rhs`, rounding the result towards positive infinity.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero or if `self / rhs` results in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let a = ", stringify!($SelfT), "::from(8i8);")]
+            #[doc = concat!("let b = ", stringify!($SelfT), "::from(3i8);")]
+            ///
+            #[doc = concat!("assert_eq!(a.div_ceil(b), ", stringify!($SelfT), "::from(3i8));")]
+            #[doc = concat!("assert_eq!(a.div_ceil(-b), ", stringify!($SelfT), "::from(-2i8));")]
+            #[doc = concat!("assert_eq!((-a).div_ceil(b), ", stringify!($SelfT), "::from(-2i8));")]
+            #[doc = concat!("assert_eq!((-a).div_ceil(-b), ", stringify!($SelfT), "::from(3i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn div_ceil(self, rhs: Self) -> Self {
+                let d = self.strict_div(rhs);
+                let r = self.wrapping_rem(rhs);
+                // The quotient was rounded towards zero; round it up if the exact result is
+                // positive, i.e. if the remainder and `rhs` have the same sign.
+                if !r.is_zero() && r.is_negative() == rhs.is_negative() {
+                    d.wrapping_add(Self::from_i128(1))
+                } else {
+                    d
+                }
+            }
+
+            /// If `rhs` is positive, calculates the smallest value greater than or
+            /// equal to `self` that is a multiple of `rhs`. If `rhs` is negative,
+            /// calculates the largest value less than or equal to `self` that is a
+            /// multiple of `rhs`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// ## Overflow behavior
+            ///
+            /// On overflow, this function will panic if overflow checks are enabled (default in debug
+            /// mode) and wrap if overflow checks are disabled (default in release mode).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let eight = ", stringify!($SelfT), "::from(8i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(23i8).next_multiple_of(eight), ",
+                stringify!($SelfT), "::from(24i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(23i8).next_multiple_of(-eight), ",
+                stringify!($SelfT), "::from(16i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-23i8).next_multiple_of(eight), ",
+                stringify!($SelfT), "::from(-16i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-23i8).next_multiple_of(-eight), ",
+                stringify!($SelfT), "::from(-24i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            pub const fn next_multiple_of(self, rhs: Self) -> Self {
+                let m = self.distance_to_multiple_of(rhs);
+                let (a, b) = self.overflowing_add(m);
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = u8::MAX + 1;
+                }
+                a
+            }
+
+            /// If `rhs` is positive, calculates the smallest value greater than or
+            /// equal to `self` that is a multiple of `rhs`. If `rhs` is negative,
+            /// calculates the largest value less than or equal to `self` that is a
+            /// multiple of `rhs`. Returns `None` if `rhs` is zero or the operation
+            /// would result in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let eight = ", stringify!($SelfT), "::from(8i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-23i8).checked_next_multiple_of(eight), Some(",
+                stringify!($SelfT), "::from(-16i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1i8).checked_next_multiple_of(",
+                stringify!($SelfT), "::from(0i8)), None);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_next_multiple_of(eight), None);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_next_multiple_of(-eight), Some(",
+                stringify!($SelfT), "::MIN));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() {
+                    return None;
+                }
+                self.checked_add(self.distance_to_multiple_of(rhs))
+            }
+
+            /// Returns the amount to add to `self` to reach the next multiple of `rhs` in the
+            /// direction of the sign of `rhs`. `rhs` must not be zero.
+            #[inline]
+            #[track_caller]
+            const fn distance_to_multiple_of(self, rhs: Self) -> Self {
+                // This would otherwise fail when calculating `r` when self == T::MIN.
+                if rhs.eq_bits(Self::from_i128(-1)) {
+                    return Self::from_i128(0);
+                }
+                let r = self.wrapping_rem(rhs);
+                // `r + rhs` cannot overflow because they have opposite signs.
+                let m = if !r.is_zero() && r.is_negative() != rhs.is_negative() {
+                    r.wrapping_add(rhs)
+                } else {
+                    r
+                };
+                // `rhs - m` cannot overflow because `m` has the same sign as `rhs`.
+                if m.is_zero() { m } else { rhs.wrapping_sub(m) }
+            }
+
+            /// Returns the logarithm of the number with respect to an arbitrary base,
+            /// rounded down.
+            ///
+            /// This method might not be optimized owing to implementation details;
+            /// `ilog2` can produce results more efficiently for base 2, and `ilog10`
+            /// can produce results more efficiently for base 10.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is less than or equal to zero,
+            /// or if `base` is less than 2.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5i8).ilog(", stringify!($SelfT), "::from(5i8)), 1);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn ilog(self, base: Self) -> u32 {
+                assert!(
+                    !base.is_negative() && base.leading_zeros() < Self::BITS - 1,
+                    "base of integer logarithm must be at least 2"
+                );
+                if let Some(log) = self.checked_ilog(base) {
+                    log
+                } else {
+                    int_log10::panic_for_nonpositive_argument()
+                }
+            }
+
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is less than or equal to zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(2i8).ilog2(), 1);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn ilog2(self) -> u32 {
+                if let Some(log) = self.checked_ilog2() {
+                    log
+                } else {
+                    int_log10::panic_for_nonpositive_argument()
+                }
+            }
+
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is less than or equal to zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10i8).ilog10(), 1);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn ilog10(self) -> u32 {
+                if let Some(log) = self.checked_ilog10() {
+                    log
+                } else {
+                    int_log10::panic_for_nonpositive_argument()
+                }
+            }
+
+            /// Returns the logarithm of the number with respect to an arbitrary base,
+            /// rounded down.
+            ///
+            /// Returns `None` if the number is negative or zero, or if the base is not at least 2.
+            ///
+            /// This method might not be optimized owing to implementation details;
+            /// `checked_ilog2` can produce results more efficiently for base 2, and
+            /// `checked_ilog10` can produce results more efficiently for base 10.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let five = ", stringify!($SelfT), "::from(5i8);")]
+            /// assert_eq!(five.checked_ilog(five), Some(1));
+            /// assert_eq!((-five).checked_ilog(five), None);
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_ilog(self, base: Self) -> Option<u32> {
+                if self.is_negative() || base.is_negative() {
+                    None
+                } else {
+                    self.cast_unsigned().checked_ilog(base.cast_unsigned())
+                }
+            }
+
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is negative or zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(2i8).checked_ilog2(), Some(1));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-2i8).checked_ilog2(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_ilog2(self) -> Option<u32> {
+                if self.is_negative() { None } else { self.cast_unsigned().checked_ilog2() }
+            }
+
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is negative or zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10i8).checked_ilog10(), Some(1));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-10i8).checked_ilog10(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_ilog10(self) -> Option<u32> {
+                if self.is_negative() { None } else { self.cast_unsigned().checked_ilog10() }
+            }
+
+            /// Returns the square root of the number, rounded down.
+            ///
+            /// Returns `None` if `self` is negative.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10i8).checked_isqrt(), Some(",
+                stringify!($SelfT), "::from(3i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-1i8).checked_isqrt(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_isqrt(self) -> Option<Self> {
+                if self.is_negative() {
+                    None
+                } else {
+                    // The root of a nonnegative value is less than `2^(BITS / 2)`, so it is
+                    // nonnegative as well.
+                    Some(self.cast_unsigned().isqrt().cast_signed())
+                }
+            }
+
+            /// Returns the square root of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is negative.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10i8).isqrt(), ",
+                stringify!($SelfT), "::from(3i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn isqrt(self) -> Self {
+                match self.checked_isqrt() {
+                    Some(sqrt) => sqrt,
+                    None => panic!("argument of integer square root must be non-negative"),
+                }
+            }
+
+            /// Computes the absolute difference between `self` and `other`.
+            ///
+            /// This function always returns the correct answer without overflow or
+            /// panics by returning an unsigned integer.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100i8).abs_diff(",
+                stringify!($SelfT), "::from(-80i8)), ", stringify!($UnsignedT), "::from(180u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.abs_diff(", stringify!($SelfT),
+                "::MAX), ", stringify!($UnsignedT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn abs_diff(self, other: Self) -> $UnsignedT {
+                if let Ordering::Less = limbs::cmp_signed(&self.limbs, &other.limbs) {
+                    // Converting a non-negative x from signed to unsigned by using
+                    // `x as U` is left unchanged, but a negative x is converted
+                    // to value x + 2^N. Thus if `s` and `o` are binary variables
+                    // respectively indicating whether `self` and `other` are
+                    // negative, we are computing the mathematical value:
+                    //
+                    //    (other + o*2^N) - (self + s*2^N)    mod  2^N
+                    //    other - self + (o-s)*2^N            mod  2^N
+                    //    other - self                        mod  2^N
+                    //
+                    // Finally, taking the mod 2^N of the mathematical value of
+                    // `other - self` does not change it as it already is
+                    // in the range [0, 2^N).
+                    other.cast_unsigned().wrapping_sub(self.cast_unsigned())
+                } else {
+                    self.cast_unsigned().wrapping_sub(other.cast_unsigned())
+                }
+            }
+
+            /// Calculates the middle point of `self` and `rhs`.
+            ///
+            /// `midpoint(a, b)` is `(a + b) >> 1` as if it were performed in a
+            /// sufficiently-large signed integral type. This implies that the result is
+            /// always rounded towards negative infinity and that no overflow will ever occur.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-1i8).midpoint(", stringify!($SelfT),
+                "::from(2i8)), ", stringify!($SelfT), "::from(0i8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-7i8).midpoint(", stringify!($SelfT),
+                "::from(0i8)), ", stringify!($SelfT), "::from(-4i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn midpoint(self, rhs: Self) -> Self {
+                // Map to the unsigned type by flipping the sign bit, which preserves the order,
+                // take the unsigned midpoint and map back.
+                let map = Self::MIN.cast_unsigned();
+                let a = self.cast_unsigned().xor_bits(map);
+                let b = rhs.cast_unsigned().xor_bits(map);
+                a.midpoint(b).xor_bits(map).cast_signed()
+            }
+
+            /// Calculates `self + rhs + carry` and checks for overflow.
+            ///
+            /// Performs "ternary addition" of two integer operands and a carry-in
+            /// bit, and returns a tuple of the sum along with a boolean indicating
+            /// whether an arithmetic overflow would occur. On overflow, the wrapped
+            /// value is returned.
+            ///
+            /// This allows chaining together multiple additions to create a wider
+            /// addition, and can be useful for bignum addition. This method should
+            /// only be used for the most significant word; for the less significant
+            /// words the unsigned method
+            #[doc = concat!("[`", stringify!($UnsignedT), "::carrying_add`]")]
+            /// should be used.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(one.carrying_add(one, true), (", stringify!($SelfT), "::from(3i8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.carrying_add(", stringify!($SelfT),
+                "::from(0i8), true), (", stringify!($SelfT), "::MIN, true));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.carrying_add(-one, true), (",
+                stringify!($SelfT), "::MIN, false));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+                // note: longer-term this should be done via an intrinsic.
+                // note: no intermediate overflow is required (https://github.com/rust-lang/rust/issues/85532#issuecomment-1032214946).
+                let (a, b) = self.overflowing_add(rhs);
+                let (c, d) = a.overflowing_add(Self::from_i128(carry as i128));
+                (c, b != d)
+            }
+
+            /// Calculates `self` &minus; `rhs` &minus; `borrow` and checks for
+            /// overflow.
+            ///
+            /// Performs "ternary subtraction" by subtracting both an integer
+            /// operand and a borrow-in bit from `self`, and returns a tuple of the
+            /// difference along with a boolean indicating whether an arithmetic
+            /// overflow would occur. On overflow, the wrapped value is returned.
+            ///
+            /// This allows chaining together multiple subtractions to create a
+            /// wider subtraction, and can be useful for bignum subtraction. This
+            /// method should only be used for the most significant word; for the
+            /// less significant words the unsigned method
+            #[doc = concat!("[`", stringify!($UnsignedT), "::borrowing_sub`]")]
+            /// should be used.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1i8);")]
+            #[doc = concat!("assert_eq!(one.borrowing_sub(one, true), (-one, false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.borrowing_sub(", stringify!($SelfT),
+                "::from(0i8), true), (", stringify!($SelfT), "::MAX, true));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.borrowing_sub(-one, true), (",
+                stringify!($SelfT), "::MAX, false));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+                // note: longer-term this should be done via an intrinsic.
+                // note: no intermediate overflow is required (https://github.com/rust-lang/rust/issues/85532#issuecomment-1032214946).
+                let (a, b) = self.overflowing_sub(rhs);
+                let (c, d) = a.overflowing_sub(Self::from_i128(borrow as i128));
+                (c, b != d)
+            }
+
+            /// Calculates the complete product `self * rhs` without the possibility to overflow.
+            ///
+            /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+            /// of the result as two separate values, in that order. The low-order bits are
+            /// returned as an unsigned integer, since they do not carry the sign.
+            ///
+            /// If you also need to add a carry to the wide result, then you want
+            /// [`Self::carrying_mul`] instead.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($SelfT), "::from(2i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-5i8).widening_mul(two), (",
+                stringify!($UnsignedT), "::MAX - ", stringify!($UnsignedT), "::from(9u8), ",
+                stringify!($SelfT), "::from(-1i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.widening_mul(two), (",
+                stringify!($UnsignedT), "::MIN, ", stringify!($SelfT), "::from(-1i8)));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn widening_mul(self, rhs: Self) -> ($UnsignedT, Self) {
+                self.carrying_mul(rhs, Self::from_i128(0))
+            }
+
+            /// Calculates the "full multiplication" `self * rhs + carry`
+            /// without the possibility to overflow.
+            ///
+            /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+            /// of the result as two separate values, in that order. The low-order bits are
+            /// returned as an unsigned integer, since they do not carry the sign.
+            ///
+            /// Performs "long multiplication" which takes in an extra amount to add, and may return an
+            /// additional amount of overflow. This allows for chaining together multiple
+            /// multiplications to create "big integers" which represent larger values.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($UnsignedT), "};")]
+            ///
+            #[doc = concat!("let two = ", stringify!($SelfT), "::from(2i8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(-5i8).carrying_mul(two, ",
+                stringify!($SelfT), "::from(10i8)), (", stringify!($UnsignedT), "::MIN, ",
+                stringify!($SelfT), "::from(0i8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.carrying_mul(", stringify!($SelfT),
+                "::MIN, ", stringify!($SelfT), "::MIN), (", stringify!($SelfT), "::MIN.cast_unsigned(), ",
+                stringify!($SelfT), "::MAX >> 1));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn carrying_mul(self, rhs: Self, carry: Self) -> ($UnsignedT, Self) {
+                // Multiply the bit patterns as unsigned integers, then correct the high half: a
+                // negative operand `x` is read as `x + 2^BITS`, which adds the other operand
+                // times `2^BITS` to the product.
+                let (lo, mut hi) = self.cast_unsigned().carrying_mul(
+                    rhs.cast_unsigned(),
+                    carry.cast_unsigned(),
+                );
+                if self.is_negative() {
+                    hi = hi.wrapping_sub(rhs.cast_unsigned());
+                }
+                if rhs.is_negative() {
+                    hi = hi.wrapping_sub(self.cast_unsigned());
+                }
+                // The carry is sign-extended into the high half.
+                if carry.is_negative() {
+                    hi = hi.wrapping_sub($UnsignedT::from_u128(1));
+                }
+                (lo, hi.cast_signed())
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl fmt::Display for $SelfT {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = [0; $BITS];
+                let is_nonnegative = !self.is_negative();
+                fmt_radix(self.unsigned_abs().limbs, is_nonnegative, 10, false, "", &mut buf, f)
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl Neg for $SelfT {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            fn neg(self) -> Self {
+                let (a, b) = self.overflowing_neg();
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = -i8::MIN;
+                }
+                a
+            }
+        }
+        forward_ref_unop! { impl Neg, neg for $SelfT,
+        #[unstable(feature = "wide_int", issue = "none")] }
+
+        wide_traits_impl! {
+            Self = $SelfT,
+            LIMBS = $N,
+            BITS = $BITS,
+            cmp = limbs::cmp_signed,
+        }
+
+        wide_from_signed_primitive! { $SelfT: i8 i16 i32 i64 i128 isize }
+    };
+}
@@ -0,0 +1,481 @@
+//! Arithmetic on little-endian arrays of `u64` limbs.
+//!
+//! These are the building blocks of the wide integer types. Every function works on the raw
+//! two's complement bit pattern; the signed types only add sign handling on top of them.
+
+use crate::cmp::Ordering;
+
+/// Builds a limb array from the low 128 bits, filling the higher limbs with `fill`.
+#[inline]
+pub(super) const fn from_u128<const N: usize>(value: u128, fill: u64) -> [u64; N] {
+    let mut r = [fill; N];
+    r[0] = value as u64;
+    r[1] = (value >> 64) as u64;
+    r
+}
+
+/// Returns `true` if all limbs are zero.
+#[inline]
+pub(super) const fn is_zero<const N: usize>(a: &[u64; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if every limb from index `from` upwards equals `fill`.
+#[inline]
+pub(super) const fn high_limbs_are<const N: usize>(a: &[u64; N], from: usize, fill: u64) -> bool {
+    let mut i = from;
+    while i < N {
+        if a[i] != fill {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if the most significant bit is set.
+#[inline]
+pub(super) const fn sign_bit<const N: usize>(a: &[u64; N]) -> bool {
+    (a[N - 1] as i64) < 0
+}
+
+/// Compares two limb arrays as unsigned integers.
+#[inline]
+pub(super) const fn cmp<const N: usize>(a: &[u64; N], b: &[u64; N]) -> Ordering {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return if a[i] < b[i] { Ordering::Less } else { Ordering::Greater };
+        }
+    }
+    Ordering::Equal
+}
+
+/// Compares two limb arrays as two's complement signed integers.
+#[inline]
+pub(super) const fn cmp_signed<const N: usize>(a: &[u64; N], b: &[u64; N]) -> Ordering {
+    match (sign_bit(a), sign_bit(b)) {
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        // With equal signs, the two's complement bit patterns order like unsigned integers.
+        _ => cmp(a, b),
+    }
+}
+
+/// Converts between widths and signedness, returning the converted limbs and whether
+/// the value is representable in the target type.
+pub(super) const fn convert<const M: usize, const N: usize>(
+    a: &[u64; M],
+    from_signed: bool,
+    to_signed: bool,
+) -> ([u64; N], bool) {
+    let negative = from_signed && sign_bit(a);
+    let fill = if negative { u64::MAX } else { 0 };
+    let mut r = [fill; N];
+    let mut i = 0;
+    while i < M && i < N {
+        r[i] = a[i];
+        i += 1;
+    }
+    // Everything that was cut off must be sign extension, and the sign must survive.
+    let fits = high_limbs_are(a, N, fill)
+        && if negative { to_signed && sign_bit(&r) } else { !to_signed || !sign_bit(&r) };
+    (r, fits)
+}
+
+/// Computes `a + b + carry`, returning the sum and the carry out of the top limb.
+#[inline]
+pub(super) const fn add<const N: usize>(
+    a: &[u64; N],
+    b: &[u64; N],
+    carry: bool,
+) -> ([u64; N], bool) {
+    let mut r = [0; N];
+    let mut carry = carry;
+    let mut i = 0;
+    while i < N {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        r[i] = s;
+        carry = c1 | c2;
+        i += 1;
+    }
+    (r, carry)
+}
+
+/// Computes `a - b - borrow`, returning the difference and the borrow out of the top limb.
+#[inline]
+pub(super) const fn sub<const N: usize>(
+    a: &[u64; N],
+    b: &[u64; N],
+    borrow: bool,
+) -> ([u64; N], bool) {
+    let mut r = [0; N];
+    let mut borrow = borrow;
+    let mut i = 0;
+    while i < N {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        r[i] = d;
+        borrow = b1 | b2;
+        i += 1;
+    }
+    (r, borrow)
+}
+
+/// Computes `a * m`, returning the product and the limb carried out of the top.
+#[inline]
+pub(super) const fn mul_limb<const N: usize>(a: &[u64; N], m: u64) -> ([u64; N], u64) {
+    let mut r = [0; N];
+    let mut carry = 0;
+    let mut i = 0;
+    while i < N {
+        let t = (a[i] as u128) * (m as u128) + carry as u128;
+        r[i] = t as u64;
+        carry = (t >> 64) as u64;
+        i += 1;
+    }
+    (r, carry)
+}
+
+/// Computes `a + d`, returning the sum and the carry out of the top limb.
+#[inline]
+pub(super) const fn add_limb<const N: usize>(a: &[u64; N], d: u64) -> ([u64; N], bool) {
+    let mut r = *a;
+    let mut carry = d;
+    let mut i = 0;
+    while i < N && carry != 0 {
+        let (s, c) = r[i].overflowing_add(carry);
+        r[i] = s;
+        carry = c as u64;
+        i += 1;
+    }
+    (r, carry != 0)
+}
+
+/// Computes the two's complement negation.
+#[inline]
+pub(super) const fn neg<const N: usize>(a: &[u64; N]) -> [u64; N] {
+    sub(&[0; N], a, false).0
+}
+
+/// Computes the full product `a * b + carry` as its low and high halves.
+///
+/// This cannot overflow: the result is at most `(2^B - 1)^2 + 2^B - 1 < 2^(2B)`.
+pub(super) const fn widening_mul<const N: usize>(
+    a: &[u64; N],
+    b: &[u64; N],
+    carry: &[u64; N],
+) -> ([u64; N], [u64; N]) {
+    let mut lo = *carry;
+    let mut hi = [0; N];
+    let mut i = 0;
+    while i < N {
+        // Row `i` adds `a[i] * b` into limbs `i..i + N`. The limb `i + N` has not been
+        // touched by any previous row, so the final carry of the row can just be stored there.
+        let mut c = 0;
+        let mut j = 0;
+        while j < N {
+            let k = i + j;
+            let cur = if k < N { lo[k] } else { hi[k - N] };
+            let t = (a[i] as u128) * (b[j] as u128) + cur as u128 + c as u128;
+            if k < N {
+                lo[k] = t as u64;
+            } else {
+                hi[k - N] = t as u64;
+            }
+            c = (t >> 64) as u64;
+            j += 1;
+        }
+        hi[i] = c;
+        i += 1;
+    }
+    (lo, hi)
+}
+
+/// Computes the wrapping product and whether the full product did not fit.
+#[inline]
+pub(super) const fn mul<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let (lo, hi) = widening_mul(a, b, &[0; N]);
+    (lo, !is_zero(&hi))
+}
+
+/// Shifts left by `bits`, which must be less than `64 * N`.
+pub(super) const fn shl<const N: usize>(a: &[u64; N], bits: u32) -> [u64; N] {
+    let limbs = (bits / 64) as usize;
+    let bits = bits % 64;
+    let mut r = [0; N];
+    let mut i = limbs;
+    while i < N {
+        r[i] = a[i - limbs] << bits;
+        if bits > 0 && i > limbs {
+            r[i] |= a[i - limbs - 1] >> (64 - bits);
+        }
+        i += 1;
+    }
+    r
+}
+
+/// Shifts right by `bits`, which must be less than `64 * N`, shifting in copies of
+/// the sign bit if `arithmetic` is set and zeros otherwise.
+pub(super) const fn shr<const N: usize>(a: &[u64; N], bits: u32, arithmetic: bool) -> [u64; N] {
+    let fill = if arithmetic && sign_bit(a) { u64::MAX } else { 0 };
+    let limbs = (bits / 64) as usize;
+    let bits = bits % 64;
+    let mut r = [fill; N];
+    let mut i = 0;
+    while i + limbs < N {
+        r[i] = a[i + limbs] >> bits;
+        if bits > 0 {
+            let next = if i + limbs + 1 < N { a[i + limbs + 1] } else { fill };
+            r[i] |= next << (64 - bits);
+        }
+        i += 1;
+    }
+    r
+}
+
+/// Returns the number of leading zero bits.
+#[inline]
+pub(super) const fn leading_zeros<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut n = 0;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return n + a[i].leading_zeros();
+        }
+        n += 64;
+    }
+    n
+}
+
+/// Returns the number of trailing zero bits.
+#[inline]
+pub(super) const fn trailing_zeros<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut n = 0;
+    let mut i = 0;
+    while i < N {
+        if a[i] != 0 {
+            return n + a[i].trailing_zeros();
+        }
+        n += 64;
+        i += 1;
+    }
+    n
+}
+
+/// Returns the number of set bits.
+#[inline]
+pub(super) const fn count_ones<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut n = 0;
+    let mut i = 0;
+    while i < N {
+        n += a[i].count_ones();
+        i += 1;
+    }
+    n
+}
+
+/// Inverts every bit.
+#[inline]
+pub(super) const fn not<const N: usize>(a: &[u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < N {
+        r[i] = !a[i];
+        i += 1;
+    }
+    r
+}
+
+/// Computes the bitwise AND.
+#[inline]
+pub(super) const fn and<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < N {
+        r[i] = a[i] & b[i];
+        i += 1;
+    }
+    r
+}
+
+/// Computes the bitwise OR.
+#[inline]
+pub(super) const fn or<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < N {
+        r[i] = a[i] | b[i];
+        i += 1;
+    }
+    r
+}
+
+/// Computes the bitwise XOR.
+#[inline]
+pub(super) const fn xor<const N: usize>(a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < N {
+        r[i] = a[i] ^ b[i];
+        i += 1;
+    }
+    r
+}
+
+/// Reverses the order of the bytes.
+#[inline]
+pub(super) const fn swap_bytes<const N: usize>(a: &[u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < N {
+        r[i] = a[N - 1 - i].swap_bytes();
+        i += 1;
+    }
+    r
+}
+
+/// Reverses the order of the bits.
+#[inline]
+pub(super) const fn reverse_bits<const N: usize>(a: &[u64; N]) -> [u64; N] {
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < N {
+        r[i] = a[N - 1 - i].reverse_bits();
+        i += 1;
+    }
+    r
+}
+
+/// Divides by a single limb, returning the quotient and the remainder.
+pub(super) const fn divrem_limb<const N: usize>(a: &[u64; N], d: u64) -> ([u64; N], u64) {
+    let mut q = [0; N];
+    let mut rem = 0u64;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let num = ((rem as u128) << 64) | a[i] as u128;
+        q[i] = (num / d as u128) as u64;
+        rem = (num % d as u128) as u64;
+    }
+    (q, rem)
+}
+
+/// Returns the number of limbs up to and including the most significant nonzero one.
+#[inline]
+const fn significant_limbs<const N: usize>(a: &[u64; N]) -> usize {
+    let mut n = N;
+    while n > 0 && a[n - 1] == 0 {
+        n -= 1;
+    }
+    n
+}
+
+/// Divides `u` by `v`, returning the quotient and the remainder. `v` must not be zero.
+///
+/// This is Knuth's algorithm D (The Art of Computer Programming, vol. 2, section 4.3.1)
+/// with 64-bit digits.
+pub(super) const fn divrem<const N: usize>(u: &[u64; N], v: &[u64; N]) -> ([u64; N], [u64; N]) {
+    let n = significant_limbs(v);
+    let m = significant_limbs(u);
+    if let Ordering::Less = cmp(u, v) {
+        return ([0; N], *u);
+    }
+    if n == 1 {
+        let (q, r) = divrem_limb(u, v[0]);
+        let mut rem = [0; N];
+        rem[0] = r;
+        return (q, rem);
+    }
+
+    // Normalize so that the top limb of the divisor has its high bit set. This makes the
+    // quotient estimates below off by at most two. The dividend needs one more limb than
+    // `N` to hold the bits shifted out of it, which is kept in `un_top`.
+    let s = v[n - 1].leading_zeros();
+    let vn = shl(v, s);
+    let mut un = shl(u, s);
+    let mut un_top = if s > 0 { u[N - 1] >> (64 - s) } else { 0 };
+
+    let mut q = [0; N];
+    let mut j = m - n + 1;
+    while j > 0 {
+        j -= 1;
+
+        // Estimate the next quotient digit from the top two limbs of the remainder
+        // and refine it with the second limb of the divisor.
+        let top = if j + n < N { un[j + n] } else { un_top };
+        let num = ((top as u128) << 64) | un[j + n - 1] as u128;
+        let mut qhat = num / vn[n - 1] as u128;
+        let mut rhat = num % vn[n - 1] as u128;
+        while qhat > u64::MAX as u128
+            || qhat * vn[n - 2] as u128 > ((rhat << 64) | un[j + n - 2] as u128)
+        {
+            qhat -= 1;
+            rhat += vn[n - 1] as u128;
+            if rhat > u64::MAX as u128 {
+                break;
+            }
+        }
+
+        // Multiply and subtract `qhat * vn` from the current window of the remainder.
+        let mut borrow = false;
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < n {
+            let p = qhat * vn[i] as u128 + carry as u128;
+            carry = (p >> 64) as u64;
+            let (t, b1) = un[i + j].overflowing_sub(p as u64);
+            let (t, b2) = t.overflowing_sub(borrow as u64);
+            un[i + j] = t;
+            borrow = b1 | b2;
+            i += 1;
+        }
+        let (t, b1) = top.overflowing_sub(carry);
+        let (t, b2) = t.overflowing_sub(borrow as u64);
+        let mut top = t;
+        q[j] = qhat as u64;
+
+        // The estimate was one too large: add the divisor back.
+        if b1 | b2 {
+            q[j] -= 1;
+            let mut carry = false;
+            let mut i = 0;
+            while i < n {
+                let (s1, c1) = un[i + j].overflowing_add(vn[i]);
+                let (s1, c2) = s1.overflowing_add(carry as u64);
+                un[i + j] = s1;
+                carry = c1 | c2;
+                i += 1;
+            }
+            top = top.wrapping_add(carry as u64);
+        }
+        if j + n < N {
+            un[j + n] = top;
+        } else {
+            un_top = top;
+        }
+    }
+
+    // Undo the normalization on the remainder, which is in the low `n` limbs.
+    let mut r = [0; N];
+    let mut i = 0;
+    while i < n {
+        r[i] = un[i] >> s;
+        if s > 0 {
+            let next = if i + 1 < N { un[i + 1] } else { un_top };
+            r[i] |= next << (64 - s);
+        }
+        i += 1;
+    }
+    (q, r)
+}
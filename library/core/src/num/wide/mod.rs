@@ -0,0 +1,201 @@
+//! Fixed-width integers wider than the primitive integer types.
+//!
+//! [`U256`], [`I256`], [`U512`] and [`I512`] behave like the primitive integers: they have the
+//! same method set (checked, wrapping, saturating, overflowing and strict arithmetic, bit
+//! manipulation, byte conversion, logarithms and roots), implement the same operator traits
+//! with the same overflow semantics, and format and parse like them.
+//!
+//! The values are stored as arrays of `u64` limbs in little-endian limb order. The signed types
+//! use the two's complement representation.
+
+use super::int_log10;
+use super::overflow_panic;
+use super::{from_str_radix_panic, IntErrorKind, ParseIntError, TryFromIntError};
+use crate::cmp::Ordering;
+use crate::fmt;
+use crate::hash::{Hash, Hasher};
+use crate::iter::{Product, Sum};
+use crate::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div,
+    DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub,
+    SubAssign,
+};
+use crate::str::{self, FromStr};
+use crate::ub_checks::assert_unsafe_precondition;
+
+mod limbs;
+
+#[macro_use]
+mod common_macros; // import wide_common_impl!
+#[macro_use]
+mod int_macros; // import wide_int_impl!
+#[macro_use]
+mod uint_macros; // import wide_uint_impl!
+
+wide_uint_impl! {
+    Self = U256,
+    SignedT = I256,
+    LIMBS = 4,
+    BITS = 256,
+    BYTES = 32,
+}
+
+wide_int_impl! {
+    Self = I256,
+    UnsignedT = U256,
+    LIMBS = 4,
+    BITS = 256,
+    BYTES = 32,
+}
+
+wide_uint_impl! {
+    Self = U512,
+    SignedT = I512,
+    LIMBS = 8,
+    BITS = 512,
+    BYTES = 64,
+}
+
+wide_int_impl! {
+    Self = I512,
+    UnsignedT = U512,
+    LIMBS = 8,
+    BITS = 512,
+    BYTES = 64,
+}
+
+// Lossless conversions between the wide types.
+wide_from! { U256 => U512 }
+wide_from! { U256 => I512 }
+wide_from! { I256 => I512 }
+
+// Conversions between the wide types that can fail.
+wide_try_from! { U256 => I256 }
+wide_try_from! { I256 => U256, U512 }
+wide_try_from! { U512 => U256, I256, I512 }
+wide_try_from! { I512 => U256, I256, U512 }
+
+#[cold]
+#[track_caller]
+const fn div_by_zero() -> ! {
+    panic!("attempt to divide by zero")
+}
+
+#[cold]
+#[track_caller]
+const fn rem_by_zero() -> ! {
+    panic!("attempt to calculate the remainder with a divisor of zero")
+}
+
+/// Parses `src` in base `radix` into a two's complement limb array, accepting an optional
+/// `+` sign, or `-` if `signed` is set.
+///
+/// This reports the same errors as `from_str_radix` on the primitive integer types, in the
+/// same order.
+const fn parse<const N: usize>(
+    src: &str,
+    radix: u32,
+    signed: bool,
+) -> Result<[u64; N], ParseIntError> {
+    use self::IntErrorKind::*;
+    use self::ParseIntError as PIE;
+
+    if 2 > radix || radix > 36 {
+        from_str_radix_panic(radix);
+    }
+
+    if src.is_empty() {
+        return Err(PIE { kind: Empty });
+    }
+
+    let src = src.as_bytes();
+    let (is_positive, mut digits) = match src {
+        [b'+' | b'-'] => {
+            return Err(PIE { kind: InvalidDigit });
+        }
+        [b'+', rest @ ..] => (true, rest),
+        [b'-', rest @ ..] if signed => (false, rest),
+        _ => (true, src),
+    };
+    let overflow = PIE { kind: if is_positive { PosOverflow } else { NegOverflow } };
+
+    // Accumulate the magnitude, which can be one larger than the maximum for negative
+    // signed values.
+    let mut magnitude = [0; N];
+    while let [c, rest @ ..] = digits {
+        let (mul, carry) = limbs::mul_limb(&magnitude, radix as u64);
+        let x = match (*c as char).to_digit(radix) {
+            Some(x) => x,
+            None => return Err(PIE { kind: InvalidDigit }),
+        };
+        if carry != 0 {
+            return Err(overflow);
+        }
+        let (sum, carry) = limbs::add_limb(&mul, x as u64);
+        if carry {
+            return Err(overflow);
+        }
+        if signed && limbs::sign_bit(&sum) {
+            // Only the magnitude of the minimum value has the sign bit set.
+            let mut low = sum;
+            low[N - 1] = 0;
+            if is_positive || sum[N - 1] != 1 << 63 || !limbs::is_zero(&low) {
+                return Err(overflow);
+            }
+        }
+        magnitude = sum;
+        digits = rest;
+    }
+    Ok(if is_positive { magnitude } else { limbs::neg(&magnitude) })
+}
+
+/// Formats a limb array in base `radix`, which must be 10 or a power of two, and pads it
+/// like the primitive integers do. `buf` must have room for one digit per bit.
+fn fmt_radix<const N: usize>(
+    mut value: [u64; N],
+    is_nonnegative: bool,
+    radix: u64,
+    upper: bool,
+    prefix: &str,
+    buf: &mut [u8],
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    let digit = |d: u64| match d {
+        0..=9 => b'0' + d as u8,
+        _ => (if upper { b'A' } else { b'a' }) + (d - 10) as u8,
+    };
+    let mut curr = buf.len();
+    if radix == 10 {
+        // Peel off 19 decimal digits at a time, the most that fit into a single limb.
+        const CHUNK: u64 = 10u64.pow(19);
+        loop {
+            let (q, mut r) = limbs::divrem_limb(&value, CHUNK);
+            value = q;
+            let last = limbs::is_zero(&value);
+            // All chunks but the most significant one are zero-padded to their full width.
+            let mut n = 0;
+            while n < 19 && (!last || r > 0 || n == 0) {
+                curr -= 1;
+                buf[curr] = digit(r % 10);
+                r /= 10;
+                n += 1;
+            }
+            if last {
+                break;
+            }
+        }
+    } else {
+        let shift = radix.trailing_zeros();
+        loop {
+            curr -= 1;
+            buf[curr] = digit(value[0] & (radix - 1));
+            value = limbs::shr(&value, shift, false);
+            if limbs::is_zero(&value) {
+                break;
+            }
+        }
+    }
+    // SAFETY: only ASCII digits have been written to `buf[curr..]`.
+    let digits = unsafe { str::from_utf8_unchecked(&buf[curr..]) };
+    f.pad_integral(is_nonnegative, prefix, digits)
+}
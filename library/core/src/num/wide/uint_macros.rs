@@ -0,0 +1,1794 @@
+/// Defines a wide unsigned integer type and its inherent methods and trait implementations.
+macro_rules! wide_uint_impl {
+    (
+        Self = $SelfT:ident,
+        SignedT = $SignedT:ident,
+        LIMBS = $N:literal,
+        BITS = $BITS:literal,
+        BYTES = $BYTES:literal,
+    ) => {
+        #[doc = concat!("The ", stringify!($BITS), "-bit unsigned integer type.")]
+        ///
+        /// This has the same methods, operators and overflow behavior as the primitive unsigned
+        /// integer types.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// #![feature(wide_int)]
+        #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+        ///
+        #[doc = concat!("let a = ", stringify!($SelfT), "::from(u128::MAX);")]
+        /// let b = a * a;
+        /// assert_eq!(b.to_string(), "115792089237316195423570985008687907852589419931798687112530834793049593217025");
+        #[doc = concat!("assert_eq!(b, \"115792089237316195423570985008687907852589419931798687112530834793049593217025\".parse::<", stringify!($SelfT), ">().unwrap());")]
+        /// ```
+        #[unstable(feature = "wide_int", issue = "none")]
+        #[derive(Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $SelfT {
+            limbs: [u64; $N],
+        }
+
+        impl $SelfT {
+            /// The smallest value that can be represented by this integer type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN, ", stringify!($SelfT), "::from(0u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            pub const MIN: Self = Self { limbs: [0; $N] };
+
+            #[doc = concat!("The largest value that can be represented by this integer type, 2<sup>",
+                stringify!($BITS), "</sup> &minus; 1.")]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.count_ones(), ", stringify!($BITS), ");")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            pub const MAX: Self = Self { limbs: [u64::MAX; $N] };
+
+            wide_common_impl! {
+                Self = $SelfT,
+                LIMBS = $N,
+                BITS = $BITS,
+                BYTES = $BYTES,
+                SIGNED = false,
+            }
+
+            /// Creates a value from a `u128`.
+            ///
+            /// This is a `const` version of the `From<u128>` implementation.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("const N: ", stringify!($SelfT), " = ", stringify!($SelfT), "::from_u128(42);")]
+            #[doc = concat!("assert_eq!(N, ", stringify!($SelfT), "::from(42u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use]
+            #[inline]
+            pub const fn from_u128(value: u128) -> Self {
+                Self::from_limbs(limbs::from_u128(value, 0))
+            }
+
+            /// Converts a string slice in a given base to an integer.
+            ///
+            /// The string is expected to be an optional `+` sign followed by digits.
+            /// Leading and trailing whitespace represent an error. Digits are a subset of
+            /// these characters, depending on `radix`:
+            ///
+            ///  * `0-9`
+            ///  * `a-z`
+            ///  * `A-Z`
+            ///
+            /// # Panics
+            ///
+            /// This function panics if `radix` is not in the range from 2 to 36.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from_str_radix(\"A\", 16), Ok(",
+                stringify!($SelfT), "::from(10u8)));")]
+            #[doc = concat!("assert!(", stringify!($SelfT), "::from_str_radix(\"-1\", 10).is_err());")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[inline]
+            pub const fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                match parse(src, radix, false) {
+                    Ok(limbs) => Ok(Self::from_limbs(limbs)),
+                    Err(e) => Err(e),
+                }
+            }
+
+            /// Returns the bit pattern of `self` reinterpreted as a signed integer of the same
+            /// size.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($SignedT), "};")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.cast_signed(), ",
+                stringify!($SignedT), "::from(-1i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            pub const fn cast_signed(self) -> $SignedT {
+                $SignedT::from_limbs(self.limbs)
+            }
+
+            /// Calculates `self + rhs`.
+            ///
+            /// Returns a tuple of the addition along with a boolean indicating
+            /// whether an arithmetic overflow would occur. If an overflow would
+            /// have occurred then the wrapped value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.overflowing_add(one), (", stringify!($SelfT), "::from(2u8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.overflowing_add(one), (",
+                stringify!($SelfT), "::MIN, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (r, carry) = limbs::add(&self.limbs, &rhs.limbs, false);
+                (Self::from_limbs(r), carry)
+            }
+
+            /// Calculates `self - rhs`.
+            ///
+            /// Returns a tuple of the subtraction along with a boolean indicating
+            /// whether an arithmetic overflow would occur. If an overflow would
+            /// have occurred then the wrapped value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.overflowing_sub(one), (", stringify!($SelfT), "::MIN, false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_sub(one), (",
+                stringify!($SelfT), "::MAX, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (r, borrow) = limbs::sub(&self.limbs, &rhs.limbs, false);
+                (Self::from_limbs(r), borrow)
+            }
+
+            /// Calculates the multiplication of `self` and `rhs`.
+            ///
+            /// Returns a tuple of the multiplication along with a boolean
+            /// indicating whether an arithmetic overflow would occur. If an
+            /// overflow would have occurred then the wrapped value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(two.overflowing_mul(two), (", stringify!($SelfT), "::from(4u8), false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.overflowing_mul(two), (",
+                stringify!($SelfT), "::MAX - ", stringify!($SelfT), "::from(1u8), true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                let (r, overflow) = limbs::mul(&self.limbs, &rhs.limbs);
+                (Self::from_limbs(r), overflow)
+            }
+
+            /// Negates self in an overflowing fashion.
+            ///
+            /// Returns `!self + 1` using wrapping operations to return the value
+            /// that represents the negation of this unsigned value. Note that for
+            /// positive unsigned values overflow always occurs, but negating 0 does
+            /// not overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.overflowing_neg(), (",
+                stringify!($SelfT), "::MIN, false));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1u8).overflowing_neg(), (",
+                stringify!($SelfT), "::MAX, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_neg(self) -> (Self, bool) {
+                (self.wrapping_neg(), !self.is_zero())
+            }
+
+            /// Checked negation. Computes `-self`, returning `None` unless `self ==
+            /// 0`.
+            ///
+            /// Note that negating any positive integer will overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_neg(), Some(",
+                stringify!($SelfT), "::MIN));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1u8).checked_neg(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_neg(self) -> Option<Self> {
+                if self.is_zero() { Some(self) } else { None }
+            }
+
+            /// Strict negation. Computes `-self`, panicking unless `self ==
+            /// 0`.
+            ///
+            /// Note that negating any positive integer will overflow.
+            ///
+            /// # Panics
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.strict_neg(), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::from(1u8).strict_neg();")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_neg(self) -> Self {
+                let (a, b) = self.overflowing_neg();
+                if b { overflow_panic::neg() } else { a }
+            }
+
+            /// Calculates `self` + `rhs` with a signed `rhs`.
+            ///
+            /// Returns a tuple of the addition along with a boolean indicating
+            /// whether an arithmetic overflow would occur. If an overflow would
+            /// have occurred then the wrapped value is returned.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($SignedT), "};")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.overflowing_add_signed(", stringify!($SignedT), "::from(2i8)), (",
+                stringify!($SelfT), "::from(3u8), false));")]
+            #[doc = concat!("assert_eq!(one.overflowing_add_signed(", stringify!($SignedT), "::from(-2i8)), (",
+                stringify!($SelfT), "::MAX, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn overflowing_add_signed(self, rhs: $SignedT) -> (Self, bool) {
+                let (res, overflowed) = self.overflowing_add(rhs.cast_unsigned());
+                (res, overflowed ^ rhs.is_negative())
+            }
+
+            /// Checked addition with a signed integer. Computes `self + rhs`,
+            /// returning `None` if overflow occurred.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($SignedT), "};")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.checked_add_signed(", stringify!($SignedT), "::from(2i8)), Some(",
+                stringify!($SelfT), "::from(3u8)));")]
+            #[doc = concat!("assert_eq!(one.checked_add_signed(", stringify!($SignedT), "::from(-2i8)), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_add_signed(self, rhs: $SignedT) -> Option<Self> {
+                let (a, b) = self.overflowing_add_signed(rhs);
+                if b { None } else { Some(a) }
+            }
+
+            /// Strict addition with a signed integer. Computes `self + rhs`,
+            /// panicking if overflow occurred.
+            ///
+            /// # Panics
+            ///
+            /// ## Overflow behavior
+            ///
+            /// This function will always panic on overflow, regardless of whether overflow
+            /// checks are enabled.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($SignedT), "};")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.strict_add_signed(", stringify!($SignedT), "::from(2i8)), ",
+                stringify!($SelfT), "::from(3u8));")]
+            /// ```
+            ///
+            /// The following panics because of overflow:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($SignedT), "};")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::from(1u8).strict_add_signed(",
+                stringify!($SignedT), "::from(-2i8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn strict_add_signed(self, rhs: $SignedT) -> Self {
+                let (a, b) = self.overflowing_add_signed(rhs);
+                if b { overflow_panic::add() } else { a }
+            }
+
+            /// Wrapping (modular) addition with a signed integer. Computes
+            /// `self + rhs`, wrapping around at the boundary of the type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($SignedT), "};")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.wrapping_add_signed(", stringify!($SignedT), "::from(2i8)), ",
+                stringify!($SelfT), "::from(3u8));")]
+            #[doc = concat!("assert_eq!(one.wrapping_add_signed(", stringify!($SignedT), "::from(-2i8)), ",
+                stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn wrapping_add_signed(self, rhs: $SignedT) -> Self {
+                self.wrapping_add(rhs.cast_unsigned())
+            }
+
+            /// Saturating addition with a signed integer. Computes `self + rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::{", stringify!($SelfT), ", ", stringify!($SignedT), "};")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.saturating_add_signed(", stringify!($SignedT), "::from(2i8)), ",
+                stringify!($SelfT), "::from(3u8));")]
+            #[doc = concat!("assert_eq!(one.saturating_add_signed(", stringify!($SignedT), "::from(-2i8)), ",
+                stringify!($SelfT), "::MIN);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_add_signed(",
+                stringify!($SignedT), "::from(4i8)), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_add_signed(self, rhs: $SignedT) -> Self {
+                let (res, overflow) = self.overflowing_add(rhs.cast_unsigned());
+                if overflow == rhs.is_negative() {
+                    res
+                } else if overflow {
+                    Self::MAX
+                } else {
+                    Self::MIN
+                }
+            }
+
+            /// Saturating integer addition. Computes `self + rhs`, saturating at
+            /// the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(one.saturating_add(one), ", stringify!($SelfT), "::from(2u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_add(one), ",
+                stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_add(self, rhs: Self) -> Self {
+                match self.checked_add(rhs) {
+                    Some(x) => x,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer subtraction. Computes `self - rhs`, saturating
+            /// at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let n = ", stringify!($SelfT), "::from(100u8);")]
+            #[doc = concat!("assert_eq!(n.saturating_sub(", stringify!($SelfT), "::from(27u8)), ",
+                stringify!($SelfT), "::from(73u8));")]
+            #[doc = concat!("assert_eq!(n.saturating_sub(", stringify!($SelfT), "::from(127u8)), ",
+                stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_sub(self, rhs: Self) -> Self {
+                match self.checked_sub(rhs) {
+                    Some(x) => x,
+                    None => Self::MIN,
+                }
+            }
+
+            /// Saturating integer multiplication. Computes `self * rhs`,
+            /// saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(two.saturating_mul(", stringify!($SelfT), "::from(10u8)), ",
+                stringify!($SelfT), "::from(20u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_mul(two), ",
+                stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_mul(self, rhs: Self) -> Self {
+                match self.checked_mul(rhs) {
+                    Some(x) => x,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Saturating integer division. Computes `self / rhs`, saturating at the
+            /// numeric bounds instead of overflowing.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5u8).saturating_div(",
+                stringify!($SelfT), "::from(2u8)), ", stringify!($SelfT), "::from(2u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn saturating_div(self, rhs: Self) -> Self {
+                // on unsigned types, there is no overflow in integer division
+                self.wrapping_div(rhs)
+            }
+
+            /// Saturating integer exponentiation. Computes `self.pow(exp)`,
+            /// saturating at the numeric bounds instead of overflowing.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let four = ", stringify!($SelfT), "::from(4u8);")]
+            #[doc = concat!("assert_eq!(four.saturating_pow(3), ", stringify!($SelfT), "::from(64u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.saturating_pow(2), ",
+                stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn saturating_pow(self, exp: u32) -> Self {
+                match self.checked_pow(exp) {
+                    Some(x) => x,
+                    None => Self::MAX,
+                }
+            }
+
+            /// Computes the quotient and the remainder of `self / rhs`.
+            #[inline]
+            #[track_caller]
+            const fn divrem(self, rhs: Self) -> (Self, Self) {
+                if rhs.is_zero() {
+                    div_by_zero();
+                }
+                let (q, r) = limbs::divrem(&self.limbs, &rhs.limbs);
+                (Self::from_limbs(q), Self::from_limbs(r))
+            }
+
+            /// Checked integer division. Computes `self / rhs`, returning `None`
+            /// if `rhs == 0`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let n = ", stringify!($SelfT), "::from(128u8);")]
+            #[doc = concat!("assert_eq!(n.checked_div(", stringify!($SelfT), "::from(2u8)), Some(",
+                stringify!($SelfT), "::from(64u8)));")]
+            #[doc = concat!("assert_eq!(n.checked_div(", stringify!($SelfT), "::MIN), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() { None } else { Some(self.divrem(rhs).0) }
+            }
+
+            /// Strict integer division. Computes `self / rhs`.
+            ///
+            /// Strict division on unsigned types is just normal division. There's no
+            /// way overflow could ever happen. This function exists so that all
+            /// operations are accounted for in the strict operations.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).strict_div(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::from(10u8));")]
+            /// ```
+            ///
+            /// The following panics because of division by zero:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::from(1u8).strict_div(", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn strict_div(self, rhs: Self) -> Self {
+                self.divrem(rhs).0
+            }
+
+            /// Checked Euclidean division. Computes `self.div_euclid(rhs)`, returning `None`
+            /// if `rhs == 0`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let n = ", stringify!($SelfT), "::from(128u8);")]
+            #[doc = concat!("assert_eq!(n.checked_div_euclid(", stringify!($SelfT), "::from(2u8)), Some(",
+                stringify!($SelfT), "::from(64u8)));")]
+            #[doc = concat!("assert_eq!(n.checked_div_euclid(", stringify!($SelfT), "::MIN), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+                self.checked_div(rhs)
+            }
+
+            /// Strict Euclidean division. Computes `self.div_euclid(rhs)`.
+            ///
+            /// Strict division on unsigned types is just normal division. There's no
+            /// way overflow could ever happen. This function exists so that all
+            /// operations are accounted for in the strict operations. Since, for the
+            /// positive integers, all common definitions of division are equal, this
+            /// is exactly equal to `self.strict_div(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).strict_div_euclid(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::from(10u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn strict_div_euclid(self, rhs: Self) -> Self {
+                self.strict_div(rhs)
+            }
+
+            /// Checked integer remainder. Computes `self % rhs`, returning `None`
+            /// if `rhs == 0`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let n = ", stringify!($SelfT), "::from(5u8);")]
+            #[doc = concat!("assert_eq!(n.checked_rem(", stringify!($SelfT), "::from(2u8)), Some(",
+                stringify!($SelfT), "::from(1u8)));")]
+            #[doc = concat!("assert_eq!(n.checked_rem(", stringify!($SelfT), "::MIN), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() { None } else { Some(self.divrem(rhs).1) }
+            }
+
+            /// Strict integer remainder. Computes `self % rhs`.
+            ///
+            /// Strict remainder calculation on unsigned types is just the regular
+            /// remainder calculation. There's no way overflow could ever happen.
+            /// This function exists so that all operations are accounted for in the
+            /// strict operations.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).strict_rem(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            ///
+            /// The following panics because of division by zero:
+            ///
+            /// ```should_panic
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let _ = ", stringify!($SelfT), "::from(5u8).strict_rem(", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn strict_rem(self, rhs: Self) -> Self {
+                if rhs.is_zero() {
+                    rem_by_zero();
+                }
+                self.divrem(rhs).1
+            }
+
+            /// Checked Euclidean modulo. Computes `self.rem_euclid(rhs)`, returning `None`
+            /// if `rhs == 0`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let n = ", stringify!($SelfT), "::from(5u8);")]
+            #[doc = concat!("assert_eq!(n.checked_rem_euclid(", stringify!($SelfT), "::from(2u8)), Some(",
+                stringify!($SelfT), "::from(1u8)));")]
+            #[doc = concat!("assert_eq!(n.checked_rem_euclid(", stringify!($SelfT), "::MIN), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+                self.checked_rem(rhs)
+            }
+
+            /// Strict Euclidean modulo. Computes `self.rem_euclid(rhs)`.
+            ///
+            /// Strict modulo calculation on unsigned types is just the regular
+            /// remainder calculation. There's no way overflow could ever happen.
+            /// This function exists so that all operations are accounted for in the
+            /// strict operations. Since, for the positive integers, all common
+            /// definitions of division are equal, this is exactly equal to
+            /// `self.strict_rem(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).strict_rem_euclid(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn strict_rem_euclid(self, rhs: Self) -> Self {
+                self.strict_rem(rhs)
+            }
+
+            /// Wrapping (modular) division. Computes `self / rhs`.
+            ///
+            /// Wrapped division on unsigned types is just normal division. There's
+            /// no way wrapping could ever happen. This function exists so that all
+            /// operations are accounted for in the wrapping operations.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).wrapping_div(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::from(10u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn wrapping_div(self, rhs: Self) -> Self {
+                self.strict_div(rhs)
+            }
+
+            /// Wrapping Euclidean division. Computes `self.div_euclid(rhs)`.
+            ///
+            /// Wrapped division on unsigned types is just normal division. There's
+            /// no way wrapping could ever happen. This function exists so that all
+            /// operations are accounted for in the wrapping operations. Since, for
+            /// the positive integers, all common definitions of division are equal,
+            /// this is exactly equal to `self.wrapping_div(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).wrapping_div_euclid(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::from(10u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn wrapping_div_euclid(self, rhs: Self) -> Self {
+                self.strict_div(rhs)
+            }
+
+            /// Wrapping (modular) remainder. Computes `self % rhs`.
+            ///
+            /// Wrapped remainder calculation on unsigned types is just the regular
+            /// remainder calculation. There's no way wrapping could ever happen.
+            /// This function exists so that all operations are accounted for in the
+            /// wrapping operations.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).wrapping_rem(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn wrapping_rem(self, rhs: Self) -> Self {
+                self.strict_rem(rhs)
+            }
+
+            /// Wrapping Euclidean modulo. Computes `self.rem_euclid(rhs)`.
+            ///
+            /// Wrapped modulo calculation on unsigned types is just the regular
+            /// remainder calculation. There's no way wrapping could ever happen.
+            /// This function exists so that all operations are accounted for in the
+            /// wrapping operations. Since, for the positive integers, all common
+            /// definitions of division are equal, this is exactly equal to
+            /// `self.wrapping_rem(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(100u8).wrapping_rem_euclid(",
+                stringify!($SelfT), "::from(10u8)), ", stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn wrapping_rem_euclid(self, rhs: Self) -> Self {
+                self.strict_rem(rhs)
+            }
+
+            /// Calculates the divisor when `self` is divided by `rhs`.
+            ///
+            /// Returns a tuple of the divisor along with a boolean indicating
+            /// whether an arithmetic overflow would occur. Note that for unsigned
+            /// integers overflow never occurs, so the second value is always
+            /// `false`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5u8).overflowing_div(",
+                stringify!($SelfT), "::from(2u8)), (", stringify!($SelfT), "::from(2u8), false));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                (self.strict_div(rhs), false)
+            }
+
+            /// Calculates the quotient of Euclidean division `self.div_euclid(rhs)`.
+            ///
+            /// Returns a tuple of the divisor along with a boolean indicating
+            /// whether an arithmetic overflow would occur. Note that for unsigned
+            /// integers overflow never occurs, so the second value is always
+            /// `false`. Since, for the positive integers, all common definitions of
+            /// division are equal, this is exactly equal to
+            /// `self.overflowing_div(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5u8).overflowing_div_euclid(",
+                stringify!($SelfT), "::from(2u8)), (", stringify!($SelfT), "::from(2u8), false));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) {
+                (self.strict_div(rhs), false)
+            }
+
+            /// Calculates the remainder when `self` is divided by `rhs`.
+            ///
+            /// Returns a tuple of the remainder after dividing along with a boolean
+            /// indicating whether an arithmetic overflow would occur. Note that for
+            /// unsigned integers overflow never occurs, so the second value is
+            /// always `false`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5u8).overflowing_rem(",
+                stringify!($SelfT), "::from(2u8)), (", stringify!($SelfT), "::from(1u8), false));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+                (self.strict_rem(rhs), false)
+            }
+
+            /// Calculates the remainder `self.rem_euclid(rhs)` as if by Euclidean division.
+            ///
+            /// Returns a tuple of the modulo after dividing along with a boolean
+            /// indicating whether an arithmetic overflow would occur. Note that for
+            /// unsigned integers overflow never occurs, so the second value is
+            /// always `false`. Since, for the positive integers, all common
+            /// definitions of division are equal, this operation is exactly equal
+            /// to `self.overflowing_rem(rhs)`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5u8).overflowing_rem_euclid(",
+                stringify!($SelfT), "::from(2u8)), (", stringify!($SelfT), "::from(1u8), false));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn overflowing_rem_euclid(self, rhs: Self) -> (Self, bool) {
+                (self.strict_rem(rhs), false)
+            }
+
+            /// Performs Euclidean division.
+            ///
+            /// Since, for the positive integers, all common
+            /// definitions of division are equal, this
+            /// is exactly equal to `self / rhs`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(7u8).div_euclid(",
+                stringify!($SelfT), "::from(4u8)), ", stringify!($SelfT), "::from(1u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn div_euclid(self, rhs: Self) -> Self {
+                self.strict_div(rhs)
+            }
+
+            /// Calculates the least remainder of `self (mod rhs)`.
+            ///
+            /// Since, for the positive integers, all common
+            /// definitions of division are equal, this
+            /// is exactly equal to `self % rhs`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(7u8).rem_euclid(",
+                stringify!($SelfT), "::from(4u8)), ", stringify!($SelfT), "::from(3u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn rem_euclid(self, rhs: Self) -> Self {
+                self.strict_rem(rhs)
+            }
+
+            /// Calculates the quotient of `self` and `rhs`, rounding the result towards negative infinity.
+            ///
+            /// This is the same as performing `self / rhs` for all unsigned integers.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(7u8).div_floor(",
+                stringify!($SelfT), "::from(4u8)), ", stringify!($SelfT), "::from(1u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline(always)]
+            #[track_caller]
+            pub const fn div_floor(self, rhs: Self) -> Self {
+                self.strict_div(rhs)
+            }
+
+            /// Calculates the quotient of `self` and `rhs`, rounding the result towards positive infinity.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(7u8).div_ceil(",
+                stringify!($SelfT), "::from(4u8)), ", stringify!($SelfT), "::from(2u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn div_ceil(self, rhs: Self) -> Self {
+                let (d, r) = self.divrem(rhs);
+                // `d` is less than `MAX` whenever there is a remainder, since then `rhs > 1`.
+                if r.is_zero() { d } else { d.wrapping_add(Self::from_u128(1)) }
+            }
+
+            /// Calculates the smallest value greater than or equal to `self` that
+            /// is a multiple of `rhs`.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `rhs` is zero.
+            ///
+            /// ## Overflow behavior
+            ///
+            /// On overflow, this function will panic if overflow checks are enabled (default in debug
+            /// mode) and wrap if overflow checks are disabled (default in release mode).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let eight = ", stringify!($SelfT), "::from(8u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(16u8).next_multiple_of(eight), ",
+                stringify!($SelfT), "::from(16u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(23u8).next_multiple_of(eight), ",
+                stringify!($SelfT), "::from(24u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            pub const fn next_multiple_of(self, rhs: Self) -> Self {
+                let r = self.strict_rem(rhs);
+                if r.is_zero() {
+                    return self;
+                }
+                let (a, b) = self.overflowing_add(rhs.wrapping_sub(r));
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = u8::MAX + 1;
+                }
+                a
+            }
+
+            /// Calculates the smallest value greater than or equal to `self` that
+            /// is a multiple of `rhs`. Returns `None` if `rhs` is zero or the
+            /// operation would result in overflow.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let eight = ", stringify!($SelfT), "::from(8u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(23u8).checked_next_multiple_of(eight), Some(",
+                stringify!($SelfT), "::from(24u8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1u8).checked_next_multiple_of(",
+                stringify!($SelfT), "::MIN), None);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_next_multiple_of(eight), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+                let r = try_opt!(self.checked_rem(rhs));
+                if r.is_zero() { Some(self) } else { self.checked_add(rhs.wrapping_sub(r)) }
+            }
+
+            /// Returns `true` if and only if `self == 2^k` for some `k`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert!(", stringify!($SelfT), "::from(16u8).is_power_of_two());")]
+            #[doc = concat!("assert!(!", stringify!($SelfT), "::from(10u8).is_power_of_two());")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use]
+            #[inline(always)]
+            pub const fn is_power_of_two(self) -> bool {
+                self.count_ones() == 1
+            }
+
+            // Returns one less than next power of two.
+            // (For 8u8 next power of two is 8u8 and for 6u8 it is 8u8)
+            #[inline]
+            const fn one_less_than_next_power_of_two(self) -> Self {
+                if self.leading_zeros() >= Self::BITS - 1 {
+                    return Self::MIN;
+                }
+                // Because `self > 1`, `p` is nonzero and so has fewer than `BITS` leading zeros.
+                let p = self.wrapping_sub(Self::from_u128(1));
+                Self::MAX.wrapping_shr(p.leading_zeros())
+            }
+
+            /// Returns the smallest power of two greater than or equal to `self`.
+            ///
+            /// When return value overflows (i.e., `self > (1 << (N-1))` for type
+            /// `uN`), it panics in debug mode and the return value is wrapped to 0 in
+            /// release mode (the only situation in which this method can return 0).
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(2u8).next_power_of_two(), ",
+                stringify!($SelfT), "::from(2u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(3u8).next_power_of_two(), ",
+                stringify!($SelfT), "::from(4u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.next_power_of_two(), ",
+                stringify!($SelfT), "::from(1u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            #[rustc_inherit_overflow_checks]
+            #[allow(arithmetic_overflow)]
+            pub const fn next_power_of_two(self) -> Self {
+                let one = Self::from_u128(1);
+                let (a, b) = self.one_less_than_next_power_of_two().overflowing_add(one);
+                if b {
+                    // In debug builds, trigger a panic on overflow.
+                    let _ = u8::MAX + 1;
+                }
+                a
+            }
+
+            /// Returns the smallest power of two greater than or equal to `self`. If
+            /// the next power of two is greater than the type's maximum value,
+            /// `None` is returned, otherwise the power of two is wrapped in `Some`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(3u8).checked_next_power_of_two(), Some(",
+                stringify!($SelfT), "::from(4u8)));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.checked_next_power_of_two(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_next_power_of_two(self) -> Option<Self> {
+                self.one_less_than_next_power_of_two().checked_add(Self::from_u128(1))
+            }
+
+            /// Returns the smallest power of two greater than or equal to `n`. If
+            /// the next power of two is greater than the type's maximum value,
+            /// the return value is wrapped to `0`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(3u8).wrapping_next_power_of_two(), ",
+                stringify!($SelfT), "::from(4u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.wrapping_next_power_of_two(), ",
+                stringify!($SelfT), "::MIN);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn wrapping_next_power_of_two(self) -> Self {
+                self.one_less_than_next_power_of_two().wrapping_add(Self::from_u128(1))
+            }
+
+            /// Returns the logarithm of the number with respect to an arbitrary base,
+            /// rounded down.
+            ///
+            /// This method might not be optimized owing to implementation details;
+            /// `ilog2` can produce results more efficiently for base 2, and `ilog10`
+            /// can produce results more efficiently for base 10.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is zero, or if `base` is less than 2.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(5u8).ilog(", stringify!($SelfT), "::from(5u8)), 1);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn ilog(self, base: Self) -> u32 {
+                assert!(
+                    base.leading_zeros() < Self::BITS - 1,
+                    "base of integer logarithm must be at least 2"
+                );
+                if let Some(log) = self.checked_ilog(base) {
+                    log
+                } else {
+                    int_log10::panic_for_nonpositive_argument()
+                }
+            }
+
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(2u8).ilog2(), 1);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.ilog2(), ", stringify!($BITS), " - 1);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn ilog2(self) -> u32 {
+                if let Some(log) = self.checked_ilog2() {
+                    log
+                } else {
+                    int_log10::panic_for_nonpositive_argument()
+                }
+            }
+
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if `self` is zero.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10u8).ilog10(), 1);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            #[track_caller]
+            pub const fn ilog10(self) -> u32 {
+                if let Some(log) = self.checked_ilog10() {
+                    log
+                } else {
+                    int_log10::panic_for_nonpositive_argument()
+                }
+            }
+
+            /// Returns the logarithm of the number with respect to an arbitrary base,
+            /// rounded down.
+            ///
+            /// Returns `None` if the number is zero, or if the base is not at least 2.
+            ///
+            /// This method might not be optimized owing to implementation details;
+            /// `checked_ilog2` can produce results more efficiently for base 2, and
+            /// `checked_ilog10` can produce results more efficiently for base 10.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let five = ", stringify!($SelfT), "::from(5u8);")]
+            /// assert_eq!(five.checked_ilog(five), Some(1));
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_ilog(five), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_ilog(self, base: Self) -> Option<u32> {
+                if self.is_zero() || base.leading_zeros() >= Self::BITS - 1 {
+                    return None;
+                }
+                if let Ordering::Less = limbs::cmp(&self.limbs, &base.limbs) {
+                    return Some(0);
+                }
+                // Since `self >= base`, `n` is at least one. `r` stays at most `self`, so it
+                // cannot overflow.
+                let mut n = 1;
+                let mut r = base;
+                let limit = self.divrem(base).0;
+                while let Ordering::Less | Ordering::Equal = limbs::cmp(&r.limbs, &limit.limbs) {
+                    n += 1;
+                    r = r.wrapping_mul(base);
+                }
+                Some(n)
+            }
+
+            /// Returns the base 2 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(2u8).checked_ilog2(), Some(1));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_ilog2(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_ilog2(self) -> Option<u32> {
+                if self.is_zero() { None } else { Some(Self::BITS - 1 - self.leading_zeros()) }
+            }
+
+            /// Returns the base 10 logarithm of the number, rounded down.
+            ///
+            /// Returns `None` if the number is zero.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10u8).checked_ilog10(), Some(1));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.checked_ilog10(), None);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn checked_ilog10(self) -> Option<u32> {
+                self.checked_ilog(Self::from_u128(10))
+            }
+
+            /// Returns the square root of the number, rounded down.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(10u8).isqrt(), ",
+                stringify!($SelfT), "::from(3u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.isqrt().count_ones(), ",
+                stringify!($BITS), " / 2);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn isqrt(self) -> Self {
+                if self.leading_zeros() >= Self::BITS - 1 {
+                    return self;
+                }
+                // Newton's method, starting from a power of two that is at least the root. The
+                // iterates decrease monotonically until they reach the rounded-down root.
+                let mut x = Self::from_u128(1).wrapping_shl(self.ilog2() / 2 + 1);
+                loop {
+                    let y = x.wrapping_add(self.divrem(x).0).wrapping_shr(1);
+                    if let Ordering::Less = limbs::cmp(&y.limbs, &x.limbs) {
+                        x = y;
+                    } else {
+                        return x;
+                    }
+                }
+            }
+
+            /// Computes the absolute difference between `self` and `other`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let a = ", stringify!($SelfT), "::from(100u8);")]
+            #[doc = concat!("let b = ", stringify!($SelfT), "::from(80u8);")]
+            #[doc = concat!("assert_eq!(a.abs_diff(b), ", stringify!($SelfT), "::from(20u8));")]
+            #[doc = concat!("assert_eq!(b.abs_diff(a), ", stringify!($SelfT), "::from(20u8));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn abs_diff(self, other: Self) -> Self {
+                let (d, borrow) = self.overflowing_sub(other);
+                if borrow { d.wrapping_neg() } else { d }
+            }
+
+            /// Calculates the middle point of `self` and `rhs`.
+            ///
+            /// `midpoint(a, b)` is `(a + b) >> 1` as if it were performed in a
+            /// sufficiently-large signed integral type. This implies that the result is
+            /// always rounded towards negative infinity and that no overflow will ever occur.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let four = ", stringify!($SelfT), "::from(4u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::from(1u8).midpoint(four), ",
+                stringify!($SelfT), "::from(2u8));")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.midpoint(", stringify!($SelfT),
+                "::MAX), ", stringify!($SelfT), "::MAX);")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn midpoint(self, rhs: Self) -> Self {
+                // Use the well known branchless algorithm from Hacker's Delight to compute
+                // `(a + b) / 2` without overflowing: `((a ^ b) >> 1) + (a & b)`.
+                self.xor_bits(rhs).wrapping_shr(1).wrapping_add(self.and_bits(rhs))
+            }
+
+            /// Calculates `self + rhs + carry` and returns a tuple containing
+            /// the sum and the output carry.
+            ///
+            /// Performs "ternary addition" of two integer operands and a carry-in
+            /// bit, and returns an output integer and a carry-out bit. This allows
+            /// chaining together multiple additions to create a wider addition, and
+            /// can be useful for bignum addition.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.carrying_add(", stringify!($SelfT),
+                "::MIN, true), (", stringify!($SelfT), "::MIN, true));")]
+            #[doc = concat!("assert_eq!(one.carrying_add(one, true), (", stringify!($SelfT), "::from(3u8), false));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+                let (r, carry) = limbs::add(&self.limbs, &rhs.limbs, carry);
+                (Self::from_limbs(r), carry)
+            }
+
+            /// Calculates `self` &minus; `rhs` &minus; `borrow` and returns a tuple
+            /// containing the difference and the output borrow.
+            ///
+            /// Performs "ternary subtraction" by subtracting both an integer
+            /// operand and a borrow-in bit from `self`, and returns an output
+            /// integer and a borrow-out bit. This allows chaining together multiple
+            /// subtractions to create a wider subtraction, and can be useful for
+            /// bignum subtraction.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($SelfT), "::from(1u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MIN.borrowing_sub(", stringify!($SelfT),
+                "::MIN, true), (", stringify!($SelfT), "::MAX, true));")]
+            #[doc = concat!("assert_eq!(one.borrowing_sub(one, true), (", stringify!($SelfT), "::MAX, true));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+                let (r, borrow) = limbs::sub(&self.limbs, &rhs.limbs, borrow);
+                (Self::from_limbs(r), borrow)
+            }
+
+            /// Calculates the complete product `self * rhs` without the possibility to overflow.
+            ///
+            /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+            /// of the result as two separate values, in that order.
+            ///
+            /// If you also need to add a carry to the wide result, then you want
+            /// [`Self::carrying_mul`] instead.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("let two = ", stringify!($SelfT), "::from(2u8);")]
+            #[doc = concat!("assert_eq!(", stringify!($SelfT), "::MAX.widening_mul(two), (",
+                stringify!($SelfT), "::MAX - ", stringify!($SelfT), "::from(1u8), ",
+                stringify!($SelfT), "::from(1u8)));")]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn widening_mul(self, rhs: Self) -> (Self, Self) {
+                self.carrying_mul(rhs, Self::MIN)
+            }
+
+            /// Calculates the "full multiplication" `self * rhs + carry`
+            /// without the possibility to overflow.
+            ///
+            /// This returns the low-order (wrapping) bits and the high-order (overflow) bits
+            /// of the result as two separate values, in that order.
+            ///
+            /// Performs "long multiplication" which takes in an extra amount to add, and may return an
+            /// additional amount of overflow. This allows for chaining together multiple
+            /// multiplications to create "big integers" which represent larger values.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// #![feature(wide_int)]
+            #[doc = concat!("use std::num::", stringify!($SelfT), ";")]
+            ///
+            #[doc = concat!("assert_eq!(",
+                stringify!($SelfT), "::MAX.carrying_mul(", stringify!($SelfT), "::MAX, ", stringify!($SelfT), "::MAX), ",
+                "(", stringify!($SelfT), "::MIN, ", stringify!($SelfT), "::MAX));"
+            )]
+            /// ```
+            #[unstable(feature = "wide_int", issue = "none")]
+            #[rustc_const_unstable(feature = "wide_int", issue = "none")]
+            #[must_use = "this returns the result of the operation, \
+                          without modifying the original"]
+            #[inline]
+            pub const fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+                let (lo, hi) = limbs::widening_mul(&self.limbs, &rhs.limbs, &carry.limbs);
+                (Self::from_limbs(lo), Self::from_limbs(hi))
+            }
+        }
+
+        #[unstable(feature = "wide_int", issue = "none")]
+        impl fmt::Display for $SelfT {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = [0; $BITS];
+                fmt_radix(self.limbs, true, 10, false, "", &mut buf, f)
+            }
+        }
+
+        wide_traits_impl! {
+            Self = $SelfT,
+            LIMBS = $N,
+            BITS = $BITS,
+            cmp = limbs::cmp,
+        }
+
+        wide_try_from_signed_primitive! { $SelfT: i8 i16 i32 i64 i128 isize }
+    };
+}
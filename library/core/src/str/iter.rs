@@ -9,6 +9,7 @@
 use crate::ops::Try;
 use crate::option;
 use crate::slice::{self, Split as SliceSplit};
+use crate::unicode::{GraphemeClusterBreak, Grapheme_Cluster_Break};
 
 use super::from_utf8_unchecked;
 use super::pattern::Pattern;
@@ -1600,3 +1601,130 @@ impl<'a> FusedIterator for $Name<'a> {}
 }
 
 escape_types_impls!(EscapeDebug, EscapeDefault, EscapeUnicode);
+
+/// An iterator over the extended grapheme clusters of a string slice.
+///
+/// This struct is created by the [`graphemes`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`graphemes`]: str::graphemes
+#[unstable(feature = "str_graphemes", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    pub(super) rest: &'a str,
+}
+
+impl<'a> Graphemes<'a> {
+    /// Views the underlying data as a subslice of the original data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_graphemes)]
+    ///
+    /// let mut graphemes = "e\u{301}a".graphemes();
+    /// graphemes.next();
+    /// assert_eq!(graphemes.as_str(), "a");
+    /// ```
+    #[unstable(feature = "str_graphemes", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        self.rest
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "none")]
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut state = GraphemeState::new(first);
+        let end =
+            chars.find(|&(_, c)| state.is_boundary_before(c)).map_or(self.rest.len(), |(i, _)| i);
+        let (grapheme, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(grapheme)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // A single grapheme cluster can span the whole string, and every grapheme
+        // cluster is at least one byte long.
+        (!self.rest.is_empty() as usize, Some(self.rest.len()))
+    }
+}
+
+#[unstable(feature = "str_graphemes", issue = "none")]
+impl FusedIterator for Graphemes<'_> {}
+
+/// The state needed to find the end of an extended grapheme cluster, scanning forward
+/// from its first `char`, following the rules of [UAX #29].
+///
+/// [UAX #29]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules
+struct GraphemeState {
+    prev: GraphemeClusterBreak,
+    /// Number of consecutive regional indicators up to and including `prev`.
+    regional_indicators: usize,
+    /// Whether `prev` ends an `Extended_Pictographic Extend* ZWJ` sequence (GB11).
+    pictographic_zwj: bool,
+    /// Whether `prev` ends an `Extended_Pictographic Extend*` sequence.
+    pictographic: bool,
+}
+
+impl GraphemeState {
+    fn new(first: char) -> GraphemeState {
+        let mut state = GraphemeState {
+            prev: GraphemeClusterBreak::Other,
+            regional_indicators: 0,
+            pictographic_zwj: false,
+            pictographic: false,
+        };
+        state.advance(Grapheme_Cluster_Break(first));
+        state
+    }
+
+    fn advance(&mut self, next: GraphemeClusterBreak) {
+        use GraphemeClusterBreak::*;
+
+        self.regional_indicators =
+            if next == RegionalIndicator { self.regional_indicators + 1 } else { 0 };
+        self.pictographic_zwj = next == ZWJ && self.pictographic;
+        self.pictographic = next == ExtendedPictographic || (next == Extend && self.pictographic);
+        self.prev = next;
+    }
+
+    /// Returns whether there is a grapheme cluster boundary between the previous
+    /// `char` and `c`. If there isn't, `c` becomes part of the current cluster.
+    fn is_boundary_before(&mut self, c: char) -> bool {
+        use GraphemeClusterBreak::*;
+
+        let next = Grapheme_Cluster_Break(c);
+        // FIXME: GB9c, which keeps Indic conjuncts together, needs the
+        // `Indic_Conjunct_Break` property, which the table generator doesn't emit yet.
+        let is_boundary = match (self.prev, next) {
+            // GB3
+            (CR, LF) => false,
+            // GB4, GB5
+            (Control | CR | LF, _) | (_, Control | CR | LF) => true,
+            // GB6, GB7, GB8
+            (L, L | V | LV | LVT) | (LV | V, V | T) | (LVT | T, T) => false,
+            // GB9, GB9a
+            (_, Extend | ZWJ | SpacingMark) => false,
+            // GB9b
+            (Prepend, _) => false,
+            // GB11
+            (ZWJ, ExtendedPictographic) => !self.pictographic_zwj,
+            // GB12, GB13
+            (RegionalIndicator, RegionalIndicator) => self.regional_indicators % 2 == 0,
+            // GB999
+            _ => true,
+        };
+        if !is_boundary {
+            self.advance(next);
+        }
+        is_boundary
+    }
+}
@@ -73,6 +73,9 @@
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::SplitInclusive;
 
+#[unstable(feature = "str_graphemes", issue = "none")]
+pub use iter::Graphemes;
+
 #[unstable(feature = "str_internals", issue = "none")]
 pub use validations::{next_code_point, utf8_char_width};
 
@@ -911,6 +914,43 @@ pub fn char_indices(&self) -> CharIndices<'_> {
         CharIndices { front_offset: 0, iter: self.chars() }
     }
 
+    /// Returns an iterator over the extended grapheme clusters of a string slice.
+    ///
+    /// A grapheme cluster is what a user would usually consider a single
+    /// "character", such as a letter together with its combining accents, a
+    /// `"\r\n"` line ending, a flag made of two regional indicator symbols, or an
+    /// emoji joined from several emoji with zero width joiners. Splitting a string
+    /// between grapheme clusters never breaks such a user-perceived character
+    /// apart, which makes this the right unit for truncating user-visible text.
+    ///
+    /// Boundaries are determined by the rules for extended grapheme clusters from
+    /// [Unicode Standard Annex #29][uax29], based on the Unicode version given by
+    /// [`char::UNICODE_VERSION`]. Rule GB9c, which keeps Indic conjuncts together,
+    /// is not yet implemented.
+    ///
+    /// [uax29]: https://www.unicode.org/reports/tr29/
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_graphemes)]
+    ///
+    /// let s = "ne\u{301}e\r\n🇨🇦👩‍🔬";
+    /// let graphemes: Vec<&str> = s.graphemes().collect();
+    ///
+    /// assert_eq!(graphemes, ["n", "e\u{301}", "e", "\r\n", "🇨🇦", "👩‍🔬"]);
+    ///
+    /// // Each of these is more than one `char`.
+    /// assert_eq!(s.chars().count(), 11);
+    /// ```
+    #[must_use = "this returns the graphemes as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_graphemes", issue = "none")]
+    #[inline]
+    pub fn graphemes(&self) -> Graphemes<'_> {
+        Graphemes { rest: self }
+    }
+
     /// An iterator over the bytes of a string slice.
     ///
     /// As a string slice consists of a sequence of bytes, we can iterate
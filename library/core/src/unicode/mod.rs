@@ -24,6 +24,9 @@
 
 pub(crate) use unicode_data::alphabetic::lookup as Alphabetic;
 pub(crate) use unicode_data::cc::lookup as Cc;
+pub(crate) use unicode_data::grapheme_cluster_break::{
+    lookup as Grapheme_Cluster_Break, GraphemeClusterBreak,
+};
 pub(crate) use unicode_data::grapheme_extend::lookup as Grapheme_Extend;
 pub(crate) use unicode_data::lowercase::lookup as Lowercase;
 pub(crate) use unicode_data::n::lookup as N;
@@ -0,0 +1,68 @@
+use core::fmt::{BufferedWriter, Write};
+use core::format_to;
+
+#[test]
+fn test_fits() {
+    let mut buf = [0u8; 16];
+    let mut w = BufferedWriter::new(&mut buf);
+    write!(w, "{}-{}", "abc", 12).unwrap();
+    assert_eq!(w.as_str(), "abc-12");
+    assert_eq!(w.len(), 6);
+    assert_eq!(w.required_len(), 6);
+    assert_eq!(w.capacity(), 16);
+    assert!(!w.is_truncated());
+}
+
+#[test]
+fn test_truncated() {
+    let mut buf = [0u8; 5];
+    let mut w = BufferedWriter::new(&mut buf);
+    write!(w, "{}{}", "abcd", "efgh").unwrap();
+    assert_eq!(w.as_str(), "abcde");
+    assert!(w.is_truncated());
+    assert_eq!(w.required_len(), 8);
+
+    // Later writes are dropped even if they would fit.
+    let mut buf = [0u8; 5];
+    let mut w = BufferedWriter::new(&mut buf);
+    w.write_str("abc").unwrap();
+    w.write_str("def").unwrap();
+    w.write_str("").unwrap();
+    w.write_char('g').unwrap();
+    assert_eq!(w.into_str(), "abcde");
+}
+
+#[test]
+fn test_truncated_on_char_boundary() {
+    let mut buf = [0u8; 3];
+    let mut w = BufferedWriter::new(&mut buf);
+    w.write_str("aé€").unwrap();
+    assert_eq!(w.as_str(), "aé");
+    assert_eq!(w.len(), 3);
+    assert_eq!(w.required_len(), 6);
+
+    let mut buf = [0u8; 2];
+    let mut w = BufferedWriter::new(&mut buf);
+    w.write_char('€').unwrap();
+    assert!(w.is_empty());
+    assert!(w.is_truncated());
+}
+
+#[test]
+fn test_empty_buffer() {
+    let mut w = BufferedWriter::new(&mut []);
+    w.write_str("").unwrap();
+    assert!(!w.is_truncated());
+    w.write_str("x").unwrap();
+    assert!(w.is_truncated());
+    assert_eq!(w.as_str(), "");
+}
+
+#[test]
+fn test_format_to() {
+    let mut buf = [0u8; 32];
+    assert_eq!(format_to!(&mut buf, "{:?} {:#x}", "hi", 255), "\"hi\" 0xff");
+
+    let mut buf = [0u8; 4];
+    assert_eq!(format_to!(&mut buf, "{}", 123456), "1234");
+}
@@ -1,3 +1,4 @@
+mod buffered_writer;
 mod builders;
 mod float;
 mod num;
@@ -37,6 +37,7 @@
 #![feature(extern_types)]
 #![feature(freeze)]
 #![feature(flt2dec)]
+#![feature(fmt_buffered_writer)]
 #![feature(fmt_internals)]
 #![feature(float_minimum_maximum)]
 #![feature(future_join)]
@@ -114,6 +115,7 @@
 #![feature(is_ascii_octdigit)]
 #![feature(get_many_mut)]
 #![feature(iter_map_windows)]
+#![feature(wide_int)]
 #![allow(internal_features)]
 #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(fuzzy_provenance_casts)]
@@ -397,6 +397,37 @@ fn test_borrowing_sub() {
                 assert_eq!((0 as $T).borrowing_sub($T::MIN, true), ($T::MAX, false));
             }
 
+            #[test]
+            fn test_widening_mul() {
+                // The low half is unsigned; compare its bit pattern.
+                let widening_mul = |a: $T, b: $T| {
+                    let (lo, hi) = a.widening_mul(b);
+                    (lo as $T, hi)
+                };
+                assert_eq!(widening_mul(5, 7), (35, 0));
+                assert_eq!(widening_mul(5, -7), (-35, -1));
+                assert_eq!(widening_mul(-5, -7), (35, 0));
+                assert_eq!(widening_mul($T::MIN, 2), (0, -1));
+                assert_eq!(widening_mul($T::MIN, -1), ($T::MIN, 0));
+                assert_eq!(widening_mul($T::MIN, $T::MIN), (0, 1 << ($T::BITS - 2)));
+                assert_eq!(widening_mul($T::MAX, $T::MIN), ($T::MIN, -1 << ($T::BITS - 2)));
+            }
+
+            #[test]
+            fn test_carrying_mul() {
+                let carrying_mul = |a: $T, b: $T, c: $T| {
+                    let (lo, hi) = a.carrying_mul(b, c);
+                    (lo as $T, hi)
+                };
+                assert_eq!(carrying_mul(5, 7, 3), (38, 0));
+                assert_eq!(carrying_mul(5, -7, 35), (0, 0));
+                assert_eq!(carrying_mul(5, 7, -36), (-1, -1));
+                assert_eq!(carrying_mul($T::MAX, 1, 1), ($T::MIN, 0));
+                assert_eq!(carrying_mul($T::MIN, 1, -1), ($T::MAX, -1));
+                assert_eq!(carrying_mul($T::MIN, $T::MIN, $T::MIN), ($T::MIN, $T::MAX >> 1));
+                assert_eq!(carrying_mul($T::MIN, $T::MAX, $T::MIN), (0, -1 << ($T::BITS - 2)));
+            }
+
             #[test]
             fn test_midpoint() {
                 assert_eq!(<$T>::midpoint(1, 3), 2);
@@ -28,6 +28,7 @@
 mod flt2dec;
 mod int_log;
 mod ops;
+mod wide;
 mod wrapping;
 
 mod ieee754;
@@ -282,6 +282,22 @@ fn test_borrowing_sub() {
                 assert_eq!($T::MAX.borrowing_sub($T::MAX, true), ($T::MAX, true));
             }
 
+            #[test]
+            fn test_widening_mul() {
+                assert_eq!((5 as $T).widening_mul(7), (35, 0));
+                assert_eq!($T::MAX.widening_mul(0), (0, 0));
+                assert_eq!($T::MAX.widening_mul(2), ($T::MAX - 1, 1));
+                assert_eq!($T::MAX.widening_mul($T::MAX), (1, $T::MAX - 1));
+            }
+
+            #[test]
+            fn test_carrying_mul() {
+                assert_eq!((5 as $T).carrying_mul(7, 3), (38, 0));
+                assert_eq!($T::MAX.carrying_mul(1, 1), (0, 1));
+                assert_eq!($T::MAX.carrying_mul($T::MAX, 0), (1, $T::MAX - 1));
+                assert_eq!($T::MAX.carrying_mul($T::MAX, $T::MAX), (0, $T::MAX));
+            }
+
             #[test]
             fn test_midpoint() {
                 assert_eq!(<$T>::midpoint(1, 3), 2);
@@ -0,0 +1,300 @@
+use core::num::{I256, I512, IntErrorKind, U256, U512};
+
+fn u256(hi: u128, lo: u128) -> U256 {
+    U256::from(hi) << 128 | U256::from(lo)
+}
+
+fn i256(hi: u128, lo: u128) -> I256 {
+    u256(hi, lo).cast_signed()
+}
+
+#[test]
+fn test_constants() {
+    assert_eq!(U256::MIN, U256::from(0u8));
+    assert_eq!(U256::MAX, u256(u128::MAX, u128::MAX));
+    assert_eq!(I256::MIN, i256(1 << 127, 0));
+    assert_eq!(I256::MAX, i256(i128::MAX as u128, u128::MAX));
+    assert_eq!(U256::BITS, 256);
+    assert_eq!(I512::BITS, 512);
+    assert_eq!(U512::MAX.count_ones(), 512);
+    assert_eq!(I512::MIN.leading_zeros(), 0);
+    assert_eq!(I512::MAX.leading_zeros(), 1);
+}
+
+#[test]
+fn test_add_sub() {
+    let one = U256::from(1u8);
+    assert_eq!(u256(0, u128::MAX) + one, u256(1, 0));
+    assert_eq!(u256(1, 0) - one, u256(0, u128::MAX));
+    assert_eq!(U256::MAX.overflowing_add(one), (U256::MIN, true));
+    assert_eq!(U256::MIN.overflowing_sub(one), (U256::MAX, true));
+    assert_eq!(U256::MAX.checked_add(one), None);
+    assert_eq!(U256::MAX.saturating_add(one), U256::MAX);
+    assert_eq!(U256::MIN.saturating_sub(one), U256::MIN);
+    assert_eq!(U256::MAX.carrying_add(U256::MIN, true), (U256::MIN, true));
+
+    let one = I256::from(1i8);
+    assert_eq!(I256::MAX.overflowing_add(one), (I256::MIN, true));
+    assert_eq!(I256::MIN.overflowing_sub(one), (I256::MAX, true));
+    assert_eq!(I256::MAX.checked_add(-one), Some(I256::MAX - one));
+    assert_eq!(I256::MIN.saturating_sub(one), I256::MIN);
+    assert_eq!(I256::MIN.saturating_add(-one), I256::MIN);
+    assert_eq!(I256::MAX.saturating_sub(-one), I256::MAX);
+    assert_eq!(I256::MIN.checked_add_unsigned(U256::MAX), Some(I256::MAX));
+    assert_eq!(I256::MAX.checked_sub_unsigned(U256::MAX), Some(I256::MIN));
+    assert_eq!(U256::from(5u8).checked_add_signed(I256::from(-6i8)), None);
+}
+
+#[test]
+fn test_mul() {
+    let a = U256::from(u128::MAX);
+    assert_eq!(a * a, u256(u128::MAX - 1, 1));
+    assert_eq!(U256::MAX.overflowing_mul(U256::from(2u8)), (U256::MAX - U256::from(1u8), true));
+    assert_eq!(U256::MAX.widening_mul(U256::MAX), (U256::from(1u8), U256::MAX - U256::from(1u8)));
+    assert_eq!(U256::MAX.carrying_mul(U256::MAX, U256::MAX), (U256::MIN, U256::MAX),);
+
+    let two = I256::from(2i8);
+    assert_eq!(I256::MIN.checked_mul(-I256::from(1i8)), None);
+    assert_eq!((I256::MIN / two).checked_mul(two), Some(I256::MIN));
+    assert_eq!((I256::MIN / two).checked_mul(-two), None);
+    assert_eq!(I256::MAX.saturating_mul(-two), I256::MIN);
+    assert_eq!(I256::MIN.saturating_mul(-two), I256::MAX);
+    assert_eq!(I256::MIN.widening_mul(I256::MIN), (U256::MIN, I256::from(1i8) << 254));
+    assert_eq!(I256::from(-1i8).widening_mul(I256::from(-1i8)), (U256::from(1u8), I256::from(0i8)));
+}
+
+#[test]
+fn test_div_rem() {
+    let a =
+        u256(0x1234_5678_9abc_def0_1234_5678_9abc_def0, 0xfedc_ba98_7654_3210_fedc_ba98_7654_3210);
+    for b in [
+        U256::from(1u8),
+        U256::from(7u8),
+        U256::from(u64::MAX),
+        U256::from(u128::MAX),
+        u256(1, 0),
+        u256(0x1234, 0xffff),
+        a,
+        a - U256::from(1u8),
+        U256::MAX,
+    ] {
+        let (q, r) = (a / b, a % b);
+        assert!(r < b);
+        let (lo, hi) = q.carrying_mul(b, r);
+        assert_eq!((lo, hi), (a, U256::MIN));
+    }
+    assert_eq!(U256::MAX / U256::MAX, U256::from(1u8));
+    assert_eq!(U256::from(10u8).checked_div(U256::MIN), None);
+    assert_eq!(U256::from(10u8).checked_rem(U256::MIN), None);
+
+    let seven = I256::from(7i8);
+    let four = I256::from(4i8);
+    assert_eq!(-seven / four, I256::from(-1i8));
+    assert_eq!(-seven % four, I256::from(-3i8));
+    assert_eq!((-seven).div_euclid(four), I256::from(-2i8));
+    assert_eq!((-seven).rem_euclid(four), I256::from(1i8));
+    assert_eq!(seven.rem_euclid(-four), I256::from(3i8));
+    assert_eq!((-seven).div_floor(four), I256::from(-2i8));
+    assert_eq!(seven.div_ceil(-four), I256::from(-1i8));
+    assert_eq!(I256::MIN.checked_div(I256::from(-1i8)), None);
+    assert_eq!(I256::MIN.overflowing_rem(I256::from(-1i8)), (I256::from(0i8), true));
+    assert_eq!(I256::MIN.wrapping_div(I256::from(-1i8)), I256::MIN);
+    assert_eq!(I256::MIN / I256::MAX, I256::from(-1i8));
+    assert_eq!(I256::MIN % I256::MAX, I256::from(-1i8));
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_by_zero() {
+    let _ = U512::MAX / U512::MIN;
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide with overflow")]
+fn test_div_overflow() {
+    let _ = I512::MIN / I512::from(-1i8);
+}
+
+#[test]
+#[should_panic]
+fn test_mul_overflow() {
+    let _ = I256::MAX.strict_mul(I256::from(2i8));
+}
+
+#[test]
+fn test_pow() {
+    let two = U256::from(2u8);
+    assert_eq!(two.pow(255), U256::MAX - U256::MAX / two);
+    assert_eq!(two.checked_pow(256), None);
+    assert_eq!(two.wrapping_pow(256), U256::MIN);
+    assert_eq!(two.saturating_pow(256), U256::MAX);
+    assert_eq!(U256::from(10u8).pow(77).ilog10(), 77);
+    assert_eq!(I256::from(-2i8).pow(255), I256::MIN);
+    assert_eq!(I256::from(-2i8).checked_pow(256), None);
+    assert_eq!(I256::from(-2i8).saturating_pow(257), I256::MIN);
+    assert_eq!(I256::from(-3i8).pow(3), I256::from(-27i8));
+}
+
+#[test]
+fn test_shifts_and_bits() {
+    let one = U256::from(1u8);
+    assert_eq!(one << 200 >> 199, U256::from(2u8));
+    assert_eq!(one.checked_shl(256), None);
+    assert_eq!(one.wrapping_shl(257), U256::from(2u8));
+    assert_eq!((one << 200).trailing_zeros(), 200);
+    assert_eq!((one << 200).leading_zeros(), 55);
+    assert_eq!(I256::MIN >> 255, I256::from(-1i8));
+    assert_eq!(I256::MIN.cast_unsigned() >> 255, one);
+    assert_eq!(u256(1, 2).rotate_left(128), u256(2, 1));
+    assert_eq!(u256(1, 0).swap_bytes(), u256(0, 1 << 120));
+    assert_eq!(one.reverse_bits(), U256::MAX - U256::MAX / U256::from(2u8));
+    assert_eq!(U256::from_be_bytes(u256(1, 2).to_be_bytes()), u256(1, 2));
+    assert_eq!(u256(0, 0x0102).to_le_bytes()[..2], [0x02, 0x01]);
+    assert!((one << 100).is_power_of_two());
+    assert_eq!((one << 100 | one).next_power_of_two(), one << 101);
+    assert_eq!(U256::MAX.checked_next_power_of_two(), None);
+}
+
+#[test]
+fn test_roots_and_logs() {
+    assert_eq!(U512::MAX.isqrt(), (U512::from(1u8) << 256) - U512::from(1u8));
+    assert_eq!(u256(1, 0).isqrt(), U256::from(1u128 << 64));
+    assert_eq!((u256(1, 0) - U256::from(1u8)).isqrt(), U256::from(u64::MAX));
+    assert_eq!(I256::from(-1i8).checked_isqrt(), None);
+    assert_eq!(U256::MAX.ilog2(), 255);
+    assert_eq!(U256::MAX.ilog10(), 77);
+    assert_eq!(U256::MAX.ilog(U256::from(16u8)), 63);
+    assert_eq!(I256::from(-1i8).checked_ilog2(), None);
+    assert_eq!(U256::MIN.checked_ilog10(), None);
+}
+
+#[test]
+fn test_fmt() {
+    assert_eq!(
+        U256::MAX.to_string(),
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+    );
+    assert_eq!(
+        I256::MIN.to_string(),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(U256::from(1234u16).to_string(), "1234");
+    assert_eq!(I256::from(0i8).to_string(), "0");
+    assert_eq!(u256(1, 0).to_string(), "340282366920938463463374607431768211456");
+    assert_eq!(format!("{:x}", u256(0xab, 0xcd)), format!("ab{:032x}", 0xcd));
+    assert_eq!(format!("{:#X}", U256::from(255u8)), "0xFF");
+    assert_eq!(format!("{:o}", U256::from(8u8)), "10");
+    assert_eq!(format!("{:#b}", U256::from(5u8)), "0b101");
+    assert_eq!(format!("{:x}", I256::from(-1i8)), "f".repeat(64));
+    assert_eq!(format!("{:+}", I256::from(5i8)), "+5");
+    assert_eq!(format!("{:>6}", I256::from(-5i8)), "    -5");
+    assert_eq!(format!("{:06}", I256::from(-5i8)), "-00005");
+    assert_eq!(format!("{:?}", I512::from(-5i8)), "-5");
+    assert_eq!(format!("{:#x?}", U512::from(255u8)), "0xff");
+}
+
+#[test]
+fn test_from_str() {
+    for x in [U256::MIN, U256::MAX, u256(1, 0), u256(12345, 67890)] {
+        assert_eq!(x.to_string().parse::<U256>(), Ok(x));
+        assert_eq!(U256::from_str_radix(&format!("{x:x}"), 16), Ok(x));
+        assert_eq!(U256::from_str_radix(&format!("{x:b}"), 2), Ok(x));
+    }
+    for x in [I256::MIN, I256::MAX, I256::from(-1i8), i256(12345, 67890)] {
+        assert_eq!(x.to_string().parse::<I256>(), Ok(x));
+    }
+    assert_eq!(U256::from_str_radix("zz", 36), Ok(U256::from(36u16 * 36 - 1)));
+    assert_eq!("+7".parse::<U256>(), Ok(U256::from(7u8)));
+
+    let kind = |r: Result<I256, core::num::ParseIntError>| r.unwrap_err().kind().clone();
+    assert_eq!(kind("".parse()), IntErrorKind::Empty);
+    assert_eq!(kind("-".parse()), IntErrorKind::InvalidDigit);
+    assert_eq!(kind("1a".parse()), IntErrorKind::InvalidDigit);
+    assert_eq!(
+        kind(
+            "57896044618658097711785492504343953926634992332820282019728792003956564819968".parse()
+        ),
+        IntErrorKind::PosOverflow
+    );
+    assert_eq!(
+        kind(
+            "-57896044618658097711785492504343953926634992332820282019728792003956564819969"
+                .parse()
+        ),
+        IntErrorKind::NegOverflow
+    );
+    assert_eq!("-1".parse::<U256>().unwrap_err().kind(), &IntErrorKind::InvalidDigit);
+    assert_eq!(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            .parse::<U256>()
+            .unwrap_err()
+            .kind(),
+        &IntErrorKind::PosOverflow
+    );
+}
+
+#[test]
+fn test_conversions() {
+    assert_eq!(U256::from(u128::MAX), u256(0, u128::MAX));
+    assert_eq!(I256::from(-1i128), I256::from(-1i8));
+    assert_eq!(I256::from(u128::MAX).cast_unsigned(), u256(0, u128::MAX));
+    assert_eq!(U256::from(true), U256::from(1u8));
+    assert!(u128::try_from(u256(1, 0)).is_err());
+    assert_eq!(u128::try_from(u256(0, 5)), Ok(5));
+    assert_eq!(i8::try_from(I256::from(-128i16)), Ok(-128));
+    assert!(i8::try_from(I256::from(-129i16)).is_err());
+    assert!(u8::try_from(I256::from(-1i8)).is_err());
+    assert!(U256::try_from(-1i8).is_err());
+    assert_eq!(U512::from(U256::MAX), (U512::from(1u8) << 256) - U512::from(1u8));
+    assert_eq!(I512::from(I256::MIN), -(I512::from(1u8) << 255));
+    assert_eq!(I512::from(U256::MAX), I512::try_from(U512::from(U256::MAX)).unwrap());
+    assert!(U256::try_from(I256::from(-1i8)).is_err());
+    assert!(I256::try_from(U256::MAX).is_err());
+    assert_eq!(I256::try_from(U512::from(5u8)), Ok(I256::from(5i8)));
+    assert!(U256::try_from(U512::MAX).is_err());
+}
+
+#[test]
+fn test_signed_methods() {
+    let five = I256::from(5i8);
+    assert_eq!((-five).abs(), five);
+    assert_eq!(I256::MIN.checked_abs(), None);
+    assert_eq!(I256::MIN.wrapping_abs(), I256::MIN);
+    assert_eq!(I256::MIN.unsigned_abs(), U256::from(1u8) << 255);
+    assert_eq!(I256::MIN.saturating_neg(), I256::MAX);
+    assert_eq!((-five).signum(), I256::from(-1i8));
+    assert!(five.is_positive() && (-five).is_negative());
+    assert_eq!(I256::MIN.abs_diff(I256::MAX), U256::MAX);
+    assert_eq!(I256::MIN.midpoint(I256::MAX), I256::from(-1i8));
+    assert_eq!(I256::from(-7i8).midpoint(I256::from(0i8)), I256::from(-4i8));
+    assert_eq!(I256::from(-23i8).next_multiple_of(I256::from(8i8)), I256::from(-16i8));
+    assert_eq!(I256::MAX.checked_next_multiple_of(I256::from(2i8)), None);
+    assert_eq!(U256::MAX.midpoint(U256::MAX - U256::from(2u8)), U256::MAX - U256::from(1u8));
+    assert_eq!(U256::MAX.abs_diff(U256::MIN), U256::MAX);
+}
+
+#[test]
+fn test_ops_by_ref() {
+    let a = U256::from(6u8);
+    let b = U256::from(3u8);
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(&a - b, a - b);
+    assert_eq!(a * &b, a * b);
+    assert_eq!(-&I256::from(6i8), I256::from(-6i8));
+    let mut c = a;
+    c /= &b;
+    c <<= 3;
+    assert_eq!(c, U256::from(16u8));
+    assert_eq!([a, b].iter().sum::<U256>(), U256::from(9u8));
+    assert_eq!([a, b].into_iter().product::<U256>(), U256::from(18u8));
+}
+
+#[test]
+fn test_const() {
+    const A: U256 = U256::from_u128(u128::MAX).wrapping_add(U256::from_u128(1));
+    const B: Option<I256> = I256::from_i128(-2).checked_pow(255);
+    const C: u32 = U256::MAX.count_ones();
+    assert_eq!(A, u256(1, 0));
+    assert_eq!(B, Some(I256::MIN));
+    assert_eq!(C, 256);
+}
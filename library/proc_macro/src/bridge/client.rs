@@ -422,4 +422,29 @@ pub const fn bang(
     ) -> Self {
         ProcMacro::Bang { name, client: Client::expand1(expand) }
     }
+
+    /// Runs this proc macro with an input encoded by a server in another process, forwarding
+    /// the requests the proc macro makes to `dispatch`, and returns its encoded output.
+    ///
+    /// This is the client half of `server::OutOfProcess`.
+    pub fn run_with_dispatch(
+        &self,
+        input: Vec<u8>,
+        mut dispatch: impl FnMut(Vec<u8>) -> Vec<u8>,
+        force_show_panics: bool,
+    ) -> Vec<u8> {
+        let run = match self {
+            ProcMacro::CustomDerive { client, .. } => client.run,
+            ProcMacro::Attr { client, .. } => client.run,
+            ProcMacro::Bang { client, .. } => client.run,
+        };
+        let mut dispatch = |buf: Buffer| Buffer::from(dispatch(buf.to_vec()));
+        run(BridgeConfig {
+            input: Buffer::from(input),
+            dispatch: (&mut dispatch).into(),
+            force_show_panics,
+            _marker: PhantomData,
+        })
+        .to_vec()
+    }
 }
@@ -348,6 +348,76 @@ fn run_bridge_and_client(
     }
 }
 
+/// A message from a client running in another process, see `OutOfProcess`.
+pub enum ClientMessage {
+    /// A request that has to be answered with `ClientConnection::reply`.
+    Request(Vec<u8>),
+    /// The client finished with this output.
+    Done(Vec<u8>),
+    /// The client is gone without finishing, e.g. because its process crashed.
+    Failed(String),
+}
+
+/// A connection to another process that runs proc-macro clients, see `OutOfProcess`.
+pub trait ClientConnection {
+    /// Starts running the client with the encoded `input`.
+    fn start(&self, input: &[u8], force_show_panics: bool);
+
+    /// Sends the reply to the last request of the client.
+    fn reply(&self, reply: &[u8]);
+
+    /// Waits for the next message of the client.
+    fn recv(&self) -> ClientMessage;
+}
+
+/// Runs the client in another process, reached through a `ClientConnection`.
+///
+/// The `run_client` function pointer is meaningless in the other process, so the connection
+/// has to know which proc macro to run. The other process loads it and runs it with
+/// `client::ProcMacro::run_with_dispatch`.
+///
+/// If the client fails without finishing, this returns a panic of the client with the failure
+/// message. The connection can be inspected afterwards to tell the two apart.
+pub struct OutOfProcess<C> {
+    connection: C,
+}
+
+impl<C> OutOfProcess<C> {
+    pub const fn new(connection: C) -> Self {
+        OutOfProcess { connection }
+    }
+
+    pub fn connection(&self) -> &C {
+        &self.connection
+    }
+}
+
+impl<C: ClientConnection> ExecutionStrategy for OutOfProcess<C> {
+    fn run_bridge_and_client(
+        &self,
+        dispatcher: &mut impl DispatcherTrait,
+        input: Buffer,
+        _run_client: extern "C" fn(BridgeConfig<'_>) -> Buffer,
+        force_show_panics: bool,
+    ) -> Buffer {
+        self.connection.start(&input, force_show_panics);
+        loop {
+            match self.connection.recv() {
+                ClientMessage::Request(b) => {
+                    let reply = dispatcher.dispatch(Buffer::from(b));
+                    self.connection.reply(&reply);
+                }
+                ClientMessage::Done(b) => return Buffer::from(b),
+                ClientMessage::Failed(message) => {
+                    let mut buf = Buffer::new();
+                    Err::<(), _>(PanicMessage::String(message)).encode(&mut buf, &mut ());
+                    return buf;
+                }
+            }
+        }
+    }
+}
+
 /// A message pipe used for communicating between server and client threads.
 pub trait MessagePipe<T>: Sized {
     /// Create a new pair of endpoints for the message pipe.
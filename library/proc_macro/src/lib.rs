@@ -1587,3 +1587,28 @@ pub fn path<P: AsRef<str>>(path: P) {
         crate::bridge::client::FreeFunctions::track_path(path);
     }
 }
+
+/// Reuse of proc macro expansions across compilation sessions.
+#[unstable(feature = "proc_macro_expansion_cache", issue = "none")]
+pub mod expansion_cache {
+    /// Declares that the output of the current expansion only depends on its input token
+    /// streams, the environment variables read with [`tracked_env::var`] and the contents of
+    /// the files passed to [`tracked_path::path`].
+    ///
+    /// The compiler may then reuse the output in a later incremental compilation session
+    /// instead of running the proc macro again, as long as none of these changed. The
+    /// declaration only applies to the expansion that is currently running, so a proc macro
+    /// has to make it every time it is invoked.
+    ///
+    /// Expansions that emit diagnostics, expand other macros with
+    /// [`TokenStream::expand_expr`] or inspect the debug representation of spans are never
+    /// reused.
+    ///
+    /// [`tracked_env::var`]: crate::tracked_env::var
+    /// [`tracked_path::path`]: crate::tracked_path::path
+    /// [`TokenStream::expand_expr`]: crate::TokenStream::expand_expr
+    #[unstable(feature = "proc_macro_expansion_cache", issue = "none")]
+    pub fn declare_deterministic() {
+        crate::bridge::client::FreeFunctions::declare_deterministic();
+    }
+}
@@ -14,6 +14,8 @@
 use crate::ffi::OsString;
 use crate::fmt;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
+use crate::net::TcpStream;
+use crate::ops::Range;
 use crate::path::{Path, PathBuf};
 use crate::sealed::Sealed;
 use crate::sync::Arc;
@@ -724,6 +726,36 @@ pub fn set_times(&self, times: FileTimes) -> io::Result<()> {
     pub fn set_modified(&self, time: SystemTime) -> io::Result<()> {
         self.set_times(FileTimes::new().set_modified(time))
     }
+
+    /// Sends a byte range of this file over a TCP stream.
+    ///
+    /// This is a convenience wrapper around [`io::copy_range`], which uses
+    /// `sendfile(2)` on Linux to avoid copying the data through userspace. See
+    /// its documentation for details.
+    ///
+    /// The file's cursor is not used. If the range extends beyond the end of
+    /// the file, only the bytes up to the end of the file are sent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// #![feature(io_copy_range)]
+    /// use std::fs::File;
+    /// use std::net::TcpStream;
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let file = File::open("video.mp4")?;
+    ///     let stream = TcpStream::connect("127.0.0.1:8080")?;
+    ///     let sent = file.send_to(&stream, 4096..8192)?;
+    ///     println!("sent {} bytes", sent.bytes());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[unstable(feature = "io_copy_range", issue = "none")]
+    pub fn send_to(&self, stream: &TcpStream, range: Range<u64>) -> io::Result<io::RangeCopy> {
+        let len = range.end.saturating_sub(range.start);
+        io::copy_range(self, range.start, len, &mut &*stream)
+    }
 }
 
 // In addition to the `impl`s here, `File` also has `impl`s for
@@ -1,9 +1,14 @@
+#[cfg(not(any(unix, windows)))]
+use super::{const_io_error, ErrorKind};
 use super::{BorrowedBuf, BufReader, BufWriter, Read, Result, Write, DEFAULT_BUF_SIZE};
 use crate::alloc::Allocator;
 use crate::cmp;
 use crate::collections::VecDeque;
+use crate::fs::File;
 use crate::io::IoSlice;
 use crate::mem::MaybeUninit;
+#[cfg(any(unix, windows))]
+use crate::sys_common::AsInner;
 
 #[cfg(test)]
 mod tests;
@@ -72,6 +77,191 @@ pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
     }
 }
 
+/// The mechanism that [`copy_range`] used to transfer data.
+///
+/// Which mechanism is used is determined by trial and error and depends on the
+/// kind of file descriptors involved, the kernel version and the file systems the
+/// files are located on, so this is mainly useful for diagnostics and tuning.
+#[unstable(feature = "io_copy_range", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CopyMethod {
+    /// The data was copied with `copy_file_range(2)`, which can share the data
+    /// between both files on copy-on-write file systems. Linux only.
+    #[unstable(feature = "io_copy_range", issue = "none")]
+    CopyFileRange,
+    /// The data was copied with `sendfile(2)`. Linux only.
+    #[unstable(feature = "io_copy_range", issue = "none")]
+    Sendfile,
+    /// The data was copied with `splice(2)` into a pipe. Linux only.
+    #[unstable(feature = "io_copy_range", issue = "none")]
+    Splice,
+    /// The data was read into a userspace buffer and then written, either because
+    /// none of the other mechanisms is available on this platform or because none
+    /// of them supports this combination of file and writer.
+    #[unstable(feature = "io_copy_range", issue = "none")]
+    ReadWrite,
+}
+
+/// The outcome of a successful [`copy_range`] call.
+#[unstable(feature = "io_copy_range", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RangeCopy {
+    bytes: u64,
+    method: CopyMethod,
+}
+
+impl RangeCopy {
+    pub(crate) fn new(bytes: u64, method: CopyMethod) -> RangeCopy {
+        RangeCopy { bytes, method }
+    }
+
+    /// Returns the number of bytes that were copied.
+    ///
+    /// This is less than the requested length if the end of the file was reached.
+    #[unstable(feature = "io_copy_range", issue = "none")]
+    #[must_use]
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the mechanism that was used to copy the data.
+    ///
+    /// If a mechanism could only copy part of the range, this is the mechanism
+    /// that copied the rest. In particular, if the remainder had to be copied
+    /// through a userspace buffer, this is [`CopyMethod::ReadWrite`].
+    #[unstable(feature = "io_copy_range", issue = "none")]
+    #[must_use]
+    pub fn method(&self) -> CopyMethod {
+        self.method
+    }
+}
+
+/// Copies up to `len` bytes of `file`, starting at `offset`, into a writer.
+///
+/// Unlike [`copy`], this reads from an explicit position instead of the file's
+/// cursor, and the file's cursor is left unchanged (except on Windows, where it
+/// is left at an unspecified position). Copying stops early if the end of the
+/// file is reached.
+///
+/// On success, returns the number of bytes copied and the [`CopyMethod`] that
+/// was used.
+///
+/// # Platform-specific behavior
+///
+/// On Linux (including Android), this function tries `copy_file_range(2)` if the
+/// writer is a regular file, then `sendfile(2)`, and `splice(2)` if the writer is a
+/// pipe, before falling back to a read-write loop. These only apply if the writer
+/// is a file descriptor owned by a standard library type, such as [`File`],
+/// [`TcpStream`] or [`UnixStream`], possibly wrapped in a [`BufWriter`], which is
+/// flushed first. On all other platforms, a read-write loop is used.
+///
+/// `sendfile` and `splice` may hand pages of the file to a socket or pipe
+/// without copying them, so if the range is modified after this function
+/// returns, the receiving end may observe the modification. Unlike [`copy`],
+/// which avoids these mechanisms in that situation, this function leaves it to
+/// the caller not to modify files that are being sent.
+///
+/// Positional reads are only supported on Unix and Windows. On other platforms,
+/// this returns an error of kind [`ErrorKind::Unsupported`].
+///
+/// Note that platform-specific behavior [may change in the future][changes].
+///
+/// [changes]: crate::io#platform-specific-behavior
+/// [`File`]: crate::fs::File
+/// [`TcpStream`]: crate::net::TcpStream
+/// [`UnixStream`]: crate::os::unix::net::UnixStream
+/// [`ErrorKind::Unsupported`]: crate::io::ErrorKind::Unsupported
+///
+/// # Errors
+///
+/// This function will return an error immediately if any read, write or system
+/// call returns an error, other than those that indicate that a mechanism is not
+/// applicable. All instances of [`ErrorKind::Interrupted`] are handled by this
+/// function and the underlying operation is retried.
+///
+/// [`ErrorKind::Interrupted`]: crate::io::ErrorKind::Interrupted
+///
+/// # Examples
+///
+/// Sending part of a file over a socket:
+///
+/// ```no_run
+/// #![feature(io_copy_range)]
+/// use std::fs::File;
+/// use std::io;
+/// use std::net::TcpStream;
+///
+/// fn main() -> io::Result<()> {
+///     let file = File::open("index.html")?;
+///     let mut stream = TcpStream::connect("127.0.0.1:8080")?;
+///
+///     let copied = io::copy_range(&file, 1024, 4096, &mut stream)?;
+///     println!("sent {} bytes using {:?}", copied.bytes(), copied.method());
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "io_copy_range", issue = "none")]
+pub fn copy_range<W: ?Sized>(
+    file: &File,
+    offset: u64,
+    len: u64,
+    writer: &mut W,
+) -> Result<RangeCopy>
+where
+    W: Write,
+{
+    cfg_if::cfg_if! {
+        if #[cfg(any(target_os = "linux", target_os = "android"))] {
+            crate::sys::kernel_copy::copy_range_spec(file, offset, len, writer)
+        } else {
+            let bytes = generic_copy_range(file, offset, len, writer)?;
+            Ok(RangeCopy::new(bytes, CopyMethod::ReadWrite))
+        }
+    }
+}
+
+/// The userspace implementation of `io::copy_range`, using positional reads.
+pub(crate) fn generic_copy_range<W: ?Sized>(
+    file: &File,
+    mut offset: u64,
+    len: u64,
+    writer: &mut W,
+) -> Result<u64>
+where
+    W: Write,
+{
+    let mut buf = [0u8; DEFAULT_BUF_SIZE];
+    let mut copied = 0;
+
+    while copied < len {
+        let chunk = cmp::min(len - copied, buf.len() as u64) as usize;
+        let read = match read_at(file, &mut buf[..chunk], offset) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.is_interrupted() => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buf[..read])?;
+        offset += read as u64;
+        copied += read as u64;
+    }
+    Ok(copied)
+}
+
+#[cfg(any(unix, windows))]
+fn read_at(file: &File, buf: &mut [u8], offset: u64) -> Result<usize> {
+    file.as_inner().read_at(buf, offset)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn read_at(_file: &File, _buf: &mut [u8], _offset: u64) -> Result<usize> {
+    Err(const_io_error!(
+        ErrorKind::Unsupported,
+        "positional reads are not supported on this platform"
+    ))
+}
+
 /// The userspace read-write-loop implementation of `io::copy` that is used when
 /// OS-specific specializations for copy offloading are not available or not applicable.
 pub(crate) fn generic_copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
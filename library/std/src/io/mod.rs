@@ -308,6 +308,8 @@
 
 #[stable(feature = "bufwriter_into_parts", since = "1.56.0")]
 pub use self::buffered::WriterPanicked;
+#[unstable(feature = "io_copy_range", issue = "none")]
+pub use self::copy::{copy_range, CopyMethod, RangeCopy};
 #[unstable(feature = "raw_os_error_ty", issue = "107792")]
 pub use self::error::RawOsError;
 pub(crate) use self::stdio::attempt_print_to_stderr;
@@ -394,6 +394,7 @@
 #![feature(log_syntax)]
 #![feature(test)]
 #![feature(trace_macros)]
+#![feature(wide_int)]
 // tidy-alphabetical-end
 //
 // Only used in tests/benchmarks:
@@ -610,6 +611,11 @@ pub mod simd {
     pub use crate::std_float::StdFloat;
     #[doc(inline)]
     pub use core::simd::*;
+
+    mod dispatch;
+
+    #[unstable(feature = "simd_dispatch", issue = "none")]
+    pub use self::dispatch::{multiversion, Dispatcher};
 }
 
 #[stable(feature = "futures_api", since = "1.36.0")]
@@ -694,6 +700,9 @@ pub mod arch {
 #[unstable(feature = "cfg_match", issue = "115585")]
 pub use core::cfg_match;
 
+#[unstable(feature = "fmt_buffered_writer", issue = "none")]
+pub use core::format_to;
+
 #[stable(feature = "core_primitive", since = "1.43.0")]
 pub use core::primitive;
 
@@ -0,0 +1,201 @@
+//! Runtime selection between versions of a function compiled for different
+//! target features.
+
+#[cfg(test)]
+mod tests;
+
+use crate::fmt;
+use crate::sync::OnceLock;
+
+/// A function pointer that is selected once, on first use, and cached.
+///
+/// This is the building block of [`multiversion!`]: `select` is typically a
+/// function that uses feature detection such as [`is_x86_feature_detected!`] to
+/// pick the best of several implementations for the CPU the program is running
+/// on. Selection happens at most once; every later call to [`get`] is a single
+/// atomic load.
+///
+/// `F` is normally a function pointer type, but any `Copy` value works.
+///
+/// [`multiversion!`]: crate::simd::multiversion
+/// [`is_x86_feature_detected!`]: crate::arch::is_x86_feature_detected
+/// [`get`]: Dispatcher::get
+///
+/// # Examples
+///
+/// ```
+/// #![feature(simd_dispatch)]
+/// use std::simd::Dispatcher;
+///
+/// fn sum_scalar(xs: &[u32]) -> u32 {
+///     xs.iter().sum()
+/// }
+///
+/// fn select() -> fn(&[u32]) -> u32 {
+///     // Feature detection would go here.
+///     sum_scalar
+/// }
+///
+/// static SUM: Dispatcher<fn(&[u32]) -> u32> = Dispatcher::new(select);
+///
+/// assert_eq!((SUM.get())(&[1, 2, 3]), 6);
+/// ```
+#[unstable(feature = "simd_dispatch", issue = "none")]
+pub struct Dispatcher<F> {
+    selected: OnceLock<F>,
+    select: fn() -> F,
+}
+
+impl<F: Copy> Dispatcher<F> {
+    /// Creates a new dispatcher that calls `select` on first use.
+    #[unstable(feature = "simd_dispatch", issue = "none")]
+    #[must_use]
+    pub const fn new(select: fn() -> F) -> Dispatcher<F> {
+        Dispatcher { selected: OnceLock::new(), select }
+    }
+
+    /// Returns the selected value, running the selection function if this is
+    /// the first call.
+    ///
+    /// If several threads call this concurrently before a value has been
+    /// selected, only one of them runs the selection function.
+    #[unstable(feature = "simd_dispatch", issue = "none")]
+    #[inline]
+    pub fn get(&self) -> F {
+        *self.selected.get_or_init(self.select)
+    }
+}
+
+#[unstable(feature = "simd_dispatch", issue = "none")]
+impl<F: fmt::Debug> fmt::Debug for Dispatcher<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dispatcher").field("selected", &self.selected.get()).finish_non_exhaustive()
+    }
+}
+
+/// Defines a function that is compiled for several sets of target features, and
+/// dispatches to the best version supported by the running CPU.
+///
+/// The function body is compiled once for each listed feature set, with those
+/// features enabled as if by `#[target_feature(enable = "...")]`, plus once
+/// without any additional features as a fallback. Feature sets are tried in the
+/// order they are listed, so the most capable set should come first. Feature
+/// sets for other architectures than the one being compiled for are ignored.
+///
+/// The selection happens on the first call, using [`is_x86_feature_detected!`]
+/// or [`is_aarch64_feature_detected!`], and is cached in a [`Dispatcher`].
+/// Later calls only pay for an indirect call.
+///
+/// Only functions without generic parameters and with simple identifiers as
+/// argument patterns are supported.
+///
+/// [`is_x86_feature_detected!`]: crate::arch::is_x86_feature_detected
+/// [`is_aarch64_feature_detected!`]: crate::arch::is_aarch64_feature_detected
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd, simd_dispatch)]
+/// use std::simd::prelude::*;
+/// use std::simd::multiversion;
+///
+/// multiversion! {
+///     /// Adds `b` to `a` element-wise.
+///     pub fn add_assign(a: &mut [f32], b: &[f32]) {
+///         let mut a = a.chunks_exact_mut(8);
+///         let mut b = b.chunks_exact(8);
+///         for (x, y) in a.by_ref().zip(b.by_ref()) {
+///             (f32x8::from_slice(x) + f32x8::from_slice(y)).copy_to_slice(x);
+///         }
+///         for (x, y) in a.into_remainder().iter_mut().zip(b.remainder()) {
+///             *x += *y;
+///         }
+///     }
+///     x86: ["avx2", "fma"];
+///     x86: ["sse4.1"];
+///     aarch64: ["neon"];
+/// }
+///
+/// let mut a = [1.0; 11];
+/// add_assign(&mut a, &[2.0; 11]);
+/// assert_eq!(a, [3.0; 11]);
+/// ```
+#[unstable(feature = "simd_dispatch", issue = "none")]
+#[allow_internal_unstable(simd_dispatch)]
+pub macro multiversion {
+    (@fn_ptr ($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty) => {
+        fn($($ty),*) -> $ret
+    },
+    (@call $f:expr, ($($arg:ident: $ty:ty),* $(,)?)) => {
+        ($f)($($arg),*)
+    },
+    (@version x86 [$($feature:tt),+] $params:tt -> $ret:ty $body:block) => {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            #[target_feature($(enable = $feature),+)]
+            unsafe fn version $params -> $ret $body
+
+            if true $(&& $crate::arch::is_x86_feature_detected!($feature))+ {
+                return $crate::simd::multiversion!(@unsafe_wrapper version $params);
+            }
+        }
+    },
+    (@version aarch64 [$($feature:tt),+] $params:tt -> $ret:ty $body:block) => {
+        #[cfg(target_arch = "aarch64")]
+        {
+            #[target_feature($(enable = $feature),+)]
+            unsafe fn version $params -> $ret $body
+
+            if true $(&& $crate::arch::is_aarch64_feature_detected!($feature))+ {
+                return $crate::simd::multiversion!(@unsafe_wrapper version $params);
+            }
+        }
+    },
+    (@version $arch:ident $($rest:tt)*) => {
+        compile_error!(concat!(
+            "unsupported architecture `",
+            stringify!($arch),
+            "`, expected `x86` or `aarch64`",
+        ));
+    },
+    (@unsafe_wrapper $version:ident ($($arg:ident: $ty:ty),* $(,)?)) => {
+        // SAFETY: this is only reached after checking that the running CPU supports
+        // all the features `version` was compiled for.
+        |$($arg),*| unsafe { $version($($arg),*) }
+    },
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident $params:tt $body:block
+        $($versions:tt)*
+    ) => {
+        $crate::simd::multiversion! {
+            $(#[$attr])*
+            $vis fn $name $params -> () $body
+            $($versions)*
+        }
+    },
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident $params:tt -> $ret:ty $body:block
+        $($arch:ident: [$($feature:tt),+ $(,)?];)*
+    ) => {
+        $(#[$attr])*
+        $vis fn $name $params -> $ret {
+            fn select() -> $crate::simd::multiversion!(@fn_ptr $params -> $ret) {
+                $(
+                    $crate::simd::multiversion!(
+                        @version $arch [$($feature),+] $params -> $ret $body
+                    );
+                )*
+
+                fn fallback $params -> $ret $body
+                fallback
+            }
+
+            static SELECTED: $crate::simd::Dispatcher<
+                $crate::simd::multiversion!(@fn_ptr $params -> $ret),
+            > = $crate::simd::Dispatcher::new(select);
+            $crate::simd::multiversion!(@call SELECTED.get(), $params)
+        }
+    },
+}
@@ -0,0 +1,71 @@
+use super::{multiversion, Dispatcher};
+use crate::sync::atomic::{AtomicUsize, Ordering};
+use crate::thread;
+
+#[test]
+fn selects_once() {
+    static SELECTIONS: AtomicUsize = AtomicUsize::new(0);
+
+    fn double(x: u32) -> u32 {
+        x * 2
+    }
+
+    fn select() -> fn(u32) -> u32 {
+        SELECTIONS.fetch_add(1, Ordering::Relaxed);
+        double
+    }
+
+    static DOUBLE: Dispatcher<fn(u32) -> u32> = Dispatcher::new(select);
+
+    let threads: Vec<_> = (0..4).map(|i| thread::spawn(move || (DOUBLE.get())(i))).collect();
+    for (i, t) in threads.into_iter().enumerate() {
+        assert_eq!(t.join().unwrap(), i as u32 * 2);
+    }
+    assert_eq!((DOUBLE.get())(21), 42);
+    assert_eq!(SELECTIONS.load(Ordering::Relaxed), 1);
+}
+
+multiversion! {
+    fn dot(a: &[f32], b: &[f32]) -> f32 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+    x86: ["avx2", "fma"];
+    x86: ["sse4.1"];
+    aarch64: ["neon"];
+}
+
+multiversion! {
+    fn increment(x: &mut u64,) {
+        *x += 1;
+    }
+    x86: ["avx2"];
+}
+
+multiversion! {
+    fn interleaved(x: u32) -> u32 {
+        x.count_ones()
+    }
+    aarch64: ["neon"];
+    x86: ["popcnt"];
+    aarch64: ["sve"];
+}
+
+multiversion! {
+    fn no_versions() -> &'static str {
+        "fallback"
+    }
+}
+
+#[test]
+fn multiversion() {
+    assert_eq!(dot(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), 32.0);
+
+    let mut x = 1;
+    increment(&mut x);
+    increment(&mut x);
+    assert_eq!(x, 3);
+
+    assert_eq!(interleaved(0b1011), 3);
+
+    assert_eq!(no_versions(), "fallback");
+}
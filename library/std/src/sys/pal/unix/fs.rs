@@ -1879,7 +1879,7 @@ pub fn copy(from: &Path, to: &Path) -> io::Result<u64> {
 
     use super::kernel_copy::{copy_regular_files, CopyResult};
 
-    match copy_regular_files(reader.as_raw_fd(), writer.as_raw_fd(), max_len) {
+    match copy_regular_files(reader.as_raw_fd(), writer.as_raw_fd(), max_len, None) {
         CopyResult::Ended(bytes) => Ok(bytes),
         CopyResult::Error(e, _) => Err(e),
         CopyResult::Fallback(written) => match io::copy::generic_copy(&mut reader, &mut writer) {
@@ -44,10 +44,10 @@
 
 use crate::cmp::min;
 use crate::fs::{File, Metadata};
-use crate::io::copy::generic_copy;
+use crate::io::copy::{generic_copy, generic_copy_range};
 use crate::io::{
-    BufRead, BufReader, BufWriter, Error, Read, Result, StderrLock, StdinLock, StdoutLock, Take,
-    Write,
+    BufRead, BufReader, BufWriter, CopyMethod, Error, RangeCopy, Read, Result, StderrLock,
+    StdinLock, StdoutLock, Take, Write,
 };
 use crate::mem::ManuallyDrop;
 use crate::net::TcpStream;
@@ -76,6 +76,16 @@ pub(crate) fn copy_spec<R: Read + ?Sized, W: Write + ?Sized>(
     SpecCopy::copy(copier)
 }
 
+pub(crate) fn copy_range_spec<W: Write + ?Sized>(
+    file: &File,
+    offset: u64,
+    len: u64,
+    write: &mut W,
+) -> Result<RangeCopy> {
+    let copier = RangeCopier { file, offset, len, write };
+    SpecCopyRange::copy_range(copier)
+}
+
 /// This type represents either the inferred `FileType` of a `RawFd` based on the source
 /// type from which it was extracted or the actual metadata
 ///
@@ -208,7 +218,7 @@ fn copy(self) -> Result<u64> {
             if input_meta.copy_file_range_candidate(FdHandle::Input)
                 && output_meta.copy_file_range_candidate(FdHandle::Output)
             {
-                let result = copy_regular_files(readfd, writefd, max_write);
+                let result = copy_regular_files(readfd, writefd, max_write, None);
                 result.update_take(reader);
 
                 match result {
@@ -225,7 +235,8 @@ fn copy(self) -> Result<u64> {
             // fall back to the generic copy loop.
             if input_meta.potential_sendfile_source() && safe_kernel_copy(&input_meta, &output_meta)
             {
-                let result = sendfile_splice(SpliceMode::Sendfile, readfd, writefd, max_write);
+                let result =
+                    sendfile_splice(SpliceMode::Sendfile, readfd, writefd, max_write, None);
                 result.update_take(reader);
 
                 match result {
@@ -238,7 +249,7 @@ fn copy(self) -> Result<u64> {
             if (input_meta.maybe_fifo() || output_meta.maybe_fifo())
                 && safe_kernel_copy(&input_meta, &output_meta)
             {
-                let result = sendfile_splice(SpliceMode::Splice, readfd, writefd, max_write);
+                let result = sendfile_splice(SpliceMode::Splice, readfd, writefd, max_write, None);
                 result.update_take(reader);
 
                 match result {
@@ -260,6 +271,103 @@ fn copy(self) -> Result<u64> {
     }
 }
 
+struct RangeCopier<'a, 'b, W: Write + ?Sized> {
+    file: &'a File,
+    offset: u64,
+    len: u64,
+    write: &'b mut W,
+}
+
+trait SpecCopyRange {
+    fn copy_range(self) -> Result<RangeCopy>;
+}
+
+impl<W: Write + ?Sized> SpecCopyRange for RangeCopier<'_, '_, W> {
+    default fn copy_range(self) -> Result<RangeCopy> {
+        let bytes = generic_copy_range(self.file, self.offset, self.len, self.write)?;
+        Ok(RangeCopy::new(bytes, CopyMethod::ReadWrite))
+    }
+}
+
+impl<W: CopyWrite> SpecCopyRange for RangeCopier<'_, '_, W> {
+    fn copy_range(self) -> Result<RangeCopy> {
+        let RangeCopier { file, offset, len, write: writer } = self;
+
+        // The syscalls take a signed offset. Offsets beyond that can't contain any data anyway,
+        // but let the generic path figure out what the platform makes of them.
+        let (CopyParams(output_meta, Some(writefd)), Ok(mut reader_offset)) =
+            (writer.properties(), i64::try_from(offset))
+        else {
+            let bytes = generic_copy_range(file, offset, len, writer)?;
+            return Ok(RangeCopy::new(bytes, CopyMethod::ReadWrite));
+        };
+        let readfd = file.as_raw_fd();
+        let input_meta = fd_to_meta(file);
+
+        // bypassing the writer requires its buffer to be empty
+        writer.flush()?;
+
+        // Unlike `io::copy`, this does not check `safe_kernel_copy`: callers explicitly ask for
+        // the kernel fast paths and the documentation warns that later modifications of the
+        // file may become visible to the receiving end of a pipe or socket.
+        let mut written = 0u64;
+
+        if input_meta.copy_file_range_candidate(FdHandle::Input)
+            && output_meta.copy_file_range_candidate(FdHandle::Output)
+        {
+            match copy_regular_files(readfd, writefd, len, Some(&mut reader_offset)) {
+                CopyResult::Ended(bytes) => {
+                    return Ok(RangeCopy::new(written + bytes, CopyMethod::CopyFileRange));
+                }
+                CopyResult::Error(e, _) => return Err(e),
+                CopyResult::Fallback(bytes) => written += bytes,
+            }
+        }
+
+        if input_meta.potential_sendfile_source() {
+            let result = sendfile_splice(
+                SpliceMode::Sendfile,
+                readfd,
+                writefd,
+                len - written,
+                Some(&mut reader_offset),
+            );
+            match result {
+                CopyResult::Ended(bytes) => {
+                    return Ok(RangeCopy::new(written + bytes, CopyMethod::Sendfile));
+                }
+                CopyResult::Error(e, _) => return Err(e),
+                CopyResult::Fallback(bytes) => written += bytes,
+            }
+        }
+
+        // splice requires one side to be a pipe, and the reader is a file
+        if output_meta.maybe_fifo() {
+            let result = sendfile_splice(
+                SpliceMode::Splice,
+                readfd,
+                writefd,
+                len - written,
+                Some(&mut reader_offset),
+            );
+            match result {
+                CopyResult::Ended(bytes) => {
+                    return Ok(RangeCopy::new(written + bytes, CopyMethod::Splice));
+                }
+                CopyResult::Error(e, _) => return Err(e),
+                CopyResult::Fallback(0) => { /* use the fallback below */ }
+                CopyResult::Fallback(_) => {
+                    unreachable!("splice should not return > 0 bytes on the fallback path")
+                }
+            }
+        }
+
+        // fallback if none of the more specialized syscalls wants to work with these file descriptors
+        let bytes = generic_copy_range(file, offset + written, len - written, writer)?;
+        Ok(RangeCopy::new(written + bytes, CopyMethod::ReadWrite))
+    }
+}
+
 #[rustc_specialization_trait]
 trait CopyRead: Read {
     /// Implementations that contain buffers (i.e. `BufReader`) must transfer data from their internal
@@ -546,10 +654,18 @@ fn update_take(&self, reader: &mut impl CopyRead) {
 /// Linux-specific implementation that will attempt to use copy_file_range for copy offloading.
 /// As the name says, it only works on regular files.
 ///
+/// If `reader_offset` is given, data is read starting at that offset instead of the reader's
+/// cursor, the cursor is left untouched and the offset is advanced by the number of bytes copied.
+///
 /// Callers must handle fallback to a generic copy loop.
 /// `Fallback` may indicate non-zero number of bytes already written
 /// if one of the files' cursor +`max_len` would exceed u64::MAX (`EOVERFLOW`).
-pub(super) fn copy_regular_files(reader: RawFd, writer: RawFd, max_len: u64) -> CopyResult {
+pub(super) fn copy_regular_files(
+    reader: RawFd,
+    writer: RawFd,
+    max_len: u64,
+    reader_offset: Option<&mut i64>,
+) -> CopyResult {
     use crate::cmp;
 
     const NOT_PROBED: u8 = 0;
@@ -594,6 +710,8 @@ fn probe_copy_file_range_support() -> u8 {
         }
     }
 
+    let off_in: *mut libc::loff_t = reader_offset.map_or(ptr::null_mut(), |off| off as *mut _);
+
     let mut written = 0u64;
     while written < max_len {
         let bytes_to_copy = cmp::min(max_len - written, usize::MAX as u64);
@@ -603,8 +721,8 @@ fn probe_copy_file_range_support() -> u8 {
         let bytes_to_copy = cmp::min(bytes_to_copy as usize, 0x4000_0000usize);
         let copy_result = unsafe {
             // We actually don't have to adjust the offsets,
-            // because copy_file_range adjusts the file offset automatically
-            cvt(copy_file_range(reader, ptr::null_mut(), writer, ptr::null_mut(), bytes_to_copy, 0))
+            // because copy_file_range adjusts the file offset (or `*off_in`) automatically
+            cvt(copy_file_range(reader, off_in, writer, ptr::null_mut(), bytes_to_copy, 0))
         };
 
         if !have_probed && copy_result.is_ok() {
@@ -682,7 +800,16 @@ enum SpliceMode {
 
 /// performs splice or sendfile between file descriptors
 /// Does _not_ fall back to a generic copy loop.
-fn sendfile_splice(mode: SpliceMode, reader: RawFd, writer: RawFd, len: u64) -> CopyResult {
+///
+/// If `reader_offset` is given, the reader must not be a pipe and is read from that offset,
+/// which is advanced by the number of bytes copied, instead of its cursor.
+fn sendfile_splice(
+    mode: SpliceMode,
+    reader: RawFd,
+    writer: RawFd,
+    len: u64,
+    reader_offset: Option<&mut i64>,
+) -> CopyResult {
     static HAS_SENDFILE: AtomicBool = AtomicBool::new(true);
     static HAS_SPLICE: AtomicBool = AtomicBool::new(true);
 
@@ -713,18 +840,37 @@ fn splice(
         _ => (),
     }
 
+    // `sendfile` takes an `off_t`, which is only 32 bits wide on some 32-bit targets
+    // that don't use `sendfile64`.
+    if mode == SpliceMode::Sendfile
+        && reader_offset.as_deref().is_some_and(|&off| off_t_from_i64(off).is_none())
+    {
+        return CopyResult::Fallback(0);
+    }
+    let mut offset = reader_offset;
+
     let mut written = 0u64;
     while written < len {
         // according to its manpage that's the maximum size sendfile() will copy per invocation
         let chunk_size = crate::cmp::min(len - written, 0x7ffff000_u64) as usize;
 
         let result = match mode {
-            SpliceMode::Sendfile => {
-                cvt(unsafe { sendfile64(writer, reader, ptr::null_mut(), chunk_size) })
+            SpliceMode::Sendfile => match offset.as_deref_mut() {
+                None => cvt(unsafe { sendfile64(writer, reader, ptr::null_mut(), chunk_size) }),
+                Some(off) => {
+                    let Some(mut off_t) = off_t_from_i64(*off) else {
+                        return CopyResult::Fallback(written);
+                    };
+                    let result = cvt(unsafe { sendfile64(writer, reader, &mut off_t, chunk_size) });
+                    *off = off_t.into();
+                    result
+                }
+            },
+            SpliceMode::Splice => {
+                let off_in: *mut i64 =
+                    offset.as_deref_mut().map_or(ptr::null_mut(), |off| off as *mut _);
+                cvt(unsafe { splice(reader, off_in, writer, ptr::null_mut(), chunk_size, 0) })
             }
-            SpliceMode::Splice => cvt(unsafe {
-                splice(reader, ptr::null_mut(), writer, ptr::null_mut(), chunk_size, 0)
-            }),
         };
 
         match result {
@@ -757,3 +903,13 @@ fn splice(
     }
     CopyResult::Ended(written)
 }
+
+#[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "hurd"))]
+fn off_t_from_i64(off: i64) -> Option<libc::off64_t> {
+    Some(off)
+}
+
+#[cfg(not(any(all(target_os = "linux", target_env = "gnu"), target_os = "hurd")))]
+fn off_t_from_i64(off: i64) -> Option<libc::off_t> {
+    off.try_into().ok()
+}
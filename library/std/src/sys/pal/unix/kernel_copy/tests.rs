@@ -83,6 +83,48 @@ fn copies_append_mode_sink() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn copy_range_uses_offset() -> Result<()> {
+    use crate::io::{BufWriter, CopyMethod};
+
+    let tmp_path = tmpdir();
+    let source_path = tmp_path.join("copy_range.source");
+    let sink_path = tmp_path.join("copy_range.sink");
+    let mut source =
+        OpenOptions::new().create(true).truncate(true).write(true).read(true).open(&source_path)?;
+    source.write_all(b"abcdefghijklmnopqr")?;
+    source.seek(SeekFrom::Start(2))?;
+    let sink =
+        OpenOptions::new().create(true).truncate(true).write(true).read(true).open(&sink_path)?;
+    let mut sink = BufWriter::with_capacity(8, sink);
+    sink.write_all(b"xy")?;
+
+    let copied = io::copy_range(&source, 4, 5, &mut sink)?;
+    assert_eq!(copied.bytes(), 5);
+    assert_eq!(source.stream_position()?, 2, "file cursor was not moved");
+
+    // only 3 bytes are left before the end of the file
+    let copied = io::copy_range(&source, 15, 10, &mut sink)?;
+    assert_eq!(copied.bytes(), 3);
+
+    let copied = io::copy_range(&source, 100, 10, &mut sink)?;
+    assert_eq!(copied.bytes(), 0);
+
+    let mut sink = sink.into_inner()?;
+    sink.seek(SeekFrom::Start(0))?;
+    let mut contents = Vec::new();
+    sink.read_to_end(&mut contents)?;
+    assert_eq!(contents, b"xyefghipqr");
+
+    let mut sink = Vec::new();
+    let copied = io::copy_range(&source, 1, 3, &mut sink)?;
+    assert_eq!(copied.bytes(), 3);
+    assert_eq!(copied.method(), CopyMethod::ReadWrite);
+    assert_eq!(sink, b"bcd");
+
+    Ok(())
+}
+
 #[test]
 fn dont_splice_pipes_from_files() -> Result<()> {
     // splicing to a pipe and then modifying the source could lead to changes
@@ -277,6 +319,7 @@ fn bench_socket_pipe_socket_copy(b: &mut test::Bencher) {
         local_end.as_raw_fd(),
         write_end.as_raw_fd(),
         1,
+        None,
     );
 
     match probe {
@@ -297,6 +340,7 @@ fn bench_socket_pipe_socket_copy(b: &mut test::Bencher) {
                 local_source.as_raw_fd(),
                 write_end.as_raw_fd(),
                 u64::MAX,
+                None,
             );
         }
     });
@@ -3,6 +3,7 @@
 use rustc_data_structures::unord::UnordSet;
 use rustc_errors::emitter::{stderr_destination, DynEmitter, HumanEmitter};
 use rustc_errors::json::JsonEmitter;
+use rustc_errors::sarif::{SarifEmitter, SarifLog};
 use rustc_errors::{codes::*, DiagCtxtHandle, ErrorGuaranteed, TerminalUrl};
 use rustc_feature::UnstableFeatures;
 use rustc_hir::def::Res;
@@ -126,13 +127,15 @@ pub(crate) fn as_local_hir_id(tcx: TyCtxt<'_>, item_id: ItemId) -> Option<HirId>
 
 /// Creates a new `DiagCtxt` that can be used to emit warnings and errors.
 ///
-/// If the given `error_format` is `ErrorOutputType::Json` and no `SourceMap` is given, a new one
-/// will be created for the `DiagCtxt`.
+/// If the given `error_format` is `ErrorOutputType::Json` or `ErrorOutputType::Sarif` and no
+/// `SourceMap` is given, a new one will be created for the `DiagCtxt`. With
+/// `ErrorOutputType::Sarif`, diagnostics are added to `sarif_log`.
 pub(crate) fn new_dcx(
     error_format: ErrorOutputType,
     source_map: Option<Lrc<source_map::SourceMap>>,
     diagnostic_width: Option<usize>,
     unstable_opts: &UnstableOptions,
+    sarif_log: &SarifLog,
 ) -> rustc_errors::DiagCtxt {
     let fallback_bundle = rustc_errors::fallback_fluent_bundle(
         rustc_driver::DEFAULT_LOCALE_RESOURCES.to_vec(),
@@ -169,6 +172,21 @@ pub(crate) fn new_dcx(
                 .terminal_url(TerminalUrl::No),
             )
         }
+        ErrorOutputType::Sarif => {
+            let source_map = source_map.unwrap_or_else(|| {
+                Lrc::new(source_map::SourceMap::new(source_map::FilePathMapping::empty()))
+            });
+            Box::new(
+                SarifEmitter::new(
+                    Box::new(io::BufWriter::new(io::stderr())),
+                    source_map,
+                    fallback_bundle,
+                    false,
+                )
+                .emit_empty_log(true)
+                .join_log(sarif_log),
+            )
+        }
     };
 
     rustc_errors::DiagCtxt::new(emitter).with_flags(unstable_opts.dcx_flags(true))
@@ -200,6 +218,7 @@ pub(crate) fn create_config(
     }: RustdocOptions,
     RenderOptions { document_private, .. }: &RenderOptions,
     using_internal_features: Arc<AtomicBool>,
+    sarif_log: SarifLog,
 ) -> rustc_interface::Config {
     // Add the doc cfg into the doc build.
     cfgs.push("doc".to_string());
@@ -292,6 +311,7 @@ pub(crate) fn create_config(
         }),
         make_codegen_backend: None,
         registry: rustc_driver::diagnostics_registry(),
+        sarif_log,
         ice_file: None,
         using_internal_features,
         expanded_args,
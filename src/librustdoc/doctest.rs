@@ -126,7 +126,7 @@ pub(crate) fn run(dcx: DiagCtxtHandle<'_>, options: RustdocOptions) -> Result<()
         edition: options.edition,
         target_triple: options.target.clone(),
         crate_name: options.crate_name.clone(),
-        remap_path_prefix: options.remap_path_prefix.clone(),
+        remap_path_prefix: config::RemapPathPrefixes(options.remap_path_prefix.clone()),
         ..config::Options::default()
     };
 
@@ -149,6 +149,7 @@ pub(crate) fn run(dcx: DiagCtxtHandle<'_>, options: RustdocOptions) -> Result<()
         override_queries: None,
         make_codegen_backend: None,
         registry: rustc_driver::diagnostics_registry(),
+        sarif_log: Default::default(),
         ice_file: None,
         using_internal_features: Arc::default(),
         expanded_args: options.expanded_args.clone(),
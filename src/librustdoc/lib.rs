@@ -156,6 +156,8 @@ pub fn main() {
         }
     }
 
+    rustc_driver::maybe_run_proc_macro_server();
+
     let mut early_dcx = EarlyDiagCtxt::new(ErrorOutputType::default());
 
     let using_internal_features = rustc_driver::install_ice_hook(
@@ -732,8 +734,14 @@ fn main_args(
         None => return Ok(()),
     };
 
-    let dcx =
-        core::new_dcx(options.error_format, None, options.diagnostic_width, &options.unstable_opts);
+    let sarif_log = early_dcx.sarif_log().clone();
+    let dcx = core::new_dcx(
+        options.error_format,
+        None,
+        options.diagnostic_width,
+        &options.unstable_opts,
+        &sarif_log,
+    );
     let dcx = dcx.handle();
 
     match (options.should_test, options.markdown_input()) {
@@ -742,7 +750,8 @@ fn main_args(
         (false, Some(input)) => {
             let input = input.to_owned();
             let edition = options.edition;
-            let config = core::create_config(options, &render_options, using_internal_features);
+            let config =
+                core::create_config(options, &render_options, using_internal_features, sarif_log);
 
             // `markdown::render` can invoke `doctest::make_test`, which
             // requires session globals and a thread pool, so we use
@@ -775,7 +784,7 @@ fn main_args(
     let scrape_examples_options = options.scrape_examples_options.clone();
     let bin_crate = options.bin_crate;
 
-    let config = core::create_config(options, &render_options, using_internal_features);
+    let config = core::create_config(options, &render_options, using_internal_features, sarif_log);
 
     interface::run_compiler(config, |compiler| {
         let sess = &compiler.sess;
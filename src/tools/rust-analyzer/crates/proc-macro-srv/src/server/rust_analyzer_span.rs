@@ -65,6 +65,7 @@ fn track_env_var(&mut self, var: &str, value: Option<&str>) {
     fn track_path(&mut self, path: &str) {
         self.tracked_paths.insert(path.into());
     }
+    fn declare_deterministic(&mut self) {}
 
     fn literal_from_str(
         &mut self,
@@ -58,6 +58,7 @@ fn injected_env_var(&mut self, _: &str) -> Option<std::string::String> {
     }
     fn track_env_var(&mut self, _var: &str, _value: Option<&str>) {}
     fn track_path(&mut self, _path: &str) {}
+    fn declare_deterministic(&mut self) {}
     fn literal_from_str(
         &mut self,
         s: &str,
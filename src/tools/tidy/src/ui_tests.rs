@@ -43,6 +43,8 @@
     "tests/ui/proc-macro/auxiliary/included-file.txt", // more include
     "tests/ui/unpretty/auxiliary/data.txt", // more include
     "tests/ui/invalid/foo.natvis.xml", // sample debugger visualizer
+    "tests/ui/lint/lint-config/invalid-lints.toml", // lint configuration file
+    "tests/ui/lint/lint-config/lints.toml", // lint configuration file
     "tests/ui/sanitizer/dataflow-abilist.txt", // dataflow sanitizer ABI list file
     "tests/ui/shell-argfiles/shell-argfiles.args", // passing args via a file
     "tests/ui/shell-argfiles/shell-argfiles-badquotes.args", // passing args via a file
@@ -0,0 +1,127 @@
+use crate::{fmt_list, UnicodeData};
+use std::{
+    fmt::{self, Write},
+    ops::Range,
+};
+
+/// The values of the `Grapheme_Cluster_Break` property, plus `Extended_Pictographic`
+/// (which is a separate binary property, but never overlaps with any other value
+/// and is needed to implement rule GB11).
+static VALUES: &[(&str, &str)] = &[
+    ("CR", "CR"),
+    ("LF", "LF"),
+    ("Control", "Control"),
+    ("Extend", "Extend"),
+    ("ZWJ", "ZWJ"),
+    ("Regional_Indicator", "RegionalIndicator"),
+    ("Prepend", "Prepend"),
+    ("SpacingMark", "SpacingMark"),
+    ("L", "L"),
+    ("V", "V"),
+    ("T", "T"),
+    ("LV", "LV"),
+    ("LVT", "LVT"),
+    ("Extended_Pictographic", "ExtendedPictographic"),
+];
+
+pub(crate) fn variant_name(value: &str) -> &'static str {
+    VALUES
+        .iter()
+        .find(|(ucd, _)| *ucd == value)
+        .map(|(_, variant)| *variant)
+        .unwrap_or_else(|| panic!("unknown Grapheme_Cluster_Break value {value:?}"))
+}
+
+pub(crate) fn generate_grapheme_cluster_break(data: &UnicodeData) -> String {
+    let mut file = String::new();
+
+    file.push_str(HEADER.trim_start());
+    file.push('\n');
+    file.push_str(&generate_table(&data.grapheme_cluster_break));
+    file
+}
+
+fn generate_table(data: &[(Range<u32>, &'static str)]) -> String {
+    let mut entries = Vec::with_capacity(data.len());
+    for (range, variant) in data {
+        // ASCII is handled by the fast path in `lookup`.
+        if range.end <= 0x80 {
+            continue;
+        }
+        // Precomposed Hangul syllables alternate between LV and LVT, which would take
+        // hundreds of entries, so `lookup` computes their value instead.
+        if HANGUL_SYLLABLES.contains(&range.start) {
+            assert!(range.end <= HANGUL_SYLLABLES.end, "{range:?} ({variant})");
+            assert!(*variant == "LV" || *variant == "LVT", "{range:?} ({variant})");
+            continue;
+        }
+        let start = char::from_u32(range.start.max(0x80)).unwrap();
+        let end = char::from_u32(range.end - 1).unwrap();
+        entries.push(Entry(start, end, variant));
+    }
+
+    let mut table = String::new();
+    write!(
+        table,
+        "static GRAPHEME_CLUSTER_BREAK_TABLE: &[(char, char, GraphemeClusterBreak)] = &[{}];",
+        fmt_list(entries)
+    )
+    .unwrap();
+    table
+}
+
+const HANGUL_SYLLABLES: Range<u32> = 0xac00..0xd7a4;
+
+struct Entry(char, char, &'static str);
+
+impl fmt::Debug for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "('{}', '{}', {})", self.0.escape_default(), self.1.escape_default(), self.2)
+    }
+}
+
+static HEADER: &str = r"
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphemeClusterBreak {
+    Other,
+    CR,
+    LF,
+    Control,
+    Extend,
+    ZWJ,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    LV,
+    LVT,
+    ExtendedPictographic,
+}
+
+use GraphemeClusterBreak::*;
+
+pub fn lookup(c: char) -> GraphemeClusterBreak {
+    match c {
+        '\r' => CR,
+        '\n' => LF,
+        '\0'..='\x1f' | '\x7f' => Control,
+        '\0'..='\x7f' => Other,
+        '\u{ac00}'..='\u{d7a3}' if (c as u32 - 0xac00) % 28 == 0 => LV,
+        '\u{ac00}'..='\u{d7a3}' => LVT,
+        _ => GRAPHEME_CLUSTER_BREAK_TABLE
+            .binary_search_by(|&(start, end, _)| {
+                if c < start {
+                    crate::cmp::Ordering::Greater
+                } else if c > end {
+                    crate::cmp::Ordering::Less
+                } else {
+                    crate::cmp::Ordering::Equal
+                }
+            })
+            .map(|i| GRAPHEME_CLUSTER_BREAK_TABLE[i].2)
+            .unwrap_or(Other),
+    }
+}
+";
@@ -77,6 +77,7 @@
 
 mod cascading_map;
 mod case_mapping;
+mod grapheme_cluster_break;
 mod raw_emitter;
 mod skiplist;
 mod unicode_download;
@@ -99,6 +100,7 @@ struct UnicodeData {
     ranges: Vec<(&'static str, Vec<Range<u32>>)>,
     to_upper: BTreeMap<u32, (u32, u32, u32)>,
     to_lower: BTreeMap<u32, (u32, u32, u32)>,
+    grapheme_cluster_break: Vec<(Range<u32>, &'static str)>,
 }
 
 fn to_mapping(origin: u32, codepoints: Vec<ucd_parse::Codepoint>) -> Option<(u32, u32, u32)> {
@@ -186,6 +188,20 @@ fn load_data() -> UnicodeData {
         }
     }
 
+    let mut grapheme_cluster_break = Vec::new();
+    for row in ucd_parse::parse::<_, ucd_parse::GraphemeClusterBreak>(&UNICODE_DIRECTORY).unwrap() {
+        let variant = grapheme_cluster_break::variant_name(&row.value);
+        grapheme_cluster_break.extend(codepoint_ranges(row.codepoints).map(|r| (r, variant)));
+    }
+    for row in ucd_parse::parse::<_, ucd_parse::EmojiProperty>(&UNICODE_DIRECTORY).unwrap() {
+        if row.property == "Extended_Pictographic" {
+            let variant = grapheme_cluster_break::variant_name(&row.property);
+            grapheme_cluster_break.extend(codepoint_ranges(row.codepoints).map(|r| (r, variant)));
+        }
+    }
+    grapheme_cluster_break.sort_by_key(|(range, _)| range.start);
+    merge_valued_ranges(&mut grapheme_cluster_break);
+
     let mut properties: HashMap<&'static str, Vec<Range<u32>>> = properties
         .into_iter()
         .map(|(k, v)| {
@@ -214,7 +230,36 @@ fn load_data() -> UnicodeData {
 
     let mut properties = properties.into_iter().collect::<Vec<_>>();
     properties.sort_by_key(|p| p.0);
-    UnicodeData { ranges: properties, to_lower, to_upper }
+    UnicodeData { ranges: properties, to_lower, to_upper, grapheme_cluster_break }
+}
+
+fn codepoint_ranges(codepoints: Codepoints) -> impl Iterator<Item = Range<u32>> {
+    let (start, end) = match codepoints {
+        Codepoints::Single(c) => (c.value(), c.value()),
+        Codepoints::Range(r) => (r.start.value(), r.end.value()),
+    };
+    (start..=end).filter(|c| char::from_u32(*c).is_some()).map(|c| c..c + 1)
+}
+
+/// Merges adjacent ranges with the same value, and asserts that no two ranges overlap.
+fn merge_valued_ranges(ranges: &mut Vec<(Range<u32>, &'static str)>) {
+    let mut merged: Vec<(Range<u32>, &'static str)> = Vec::with_capacity(ranges.len());
+    for (range, value) in ranges.drain(..) {
+        match merged.last_mut() {
+            Some((last, last_value)) if last.end == range.start && *last_value == value => {
+                last.end = range.end;
+            }
+            Some((last, last_value)) => {
+                assert!(
+                    last.end <= range.start,
+                    "{range:?} ({value}) overlaps with {last:?} ({last_value})"
+                );
+                merged.push((range, value));
+            }
+            None => merged.push((range, value)),
+        }
+    }
+    *ranges = merged;
 }
 
 fn main() {
@@ -280,6 +325,10 @@ fn main() {
     table_file.push('\n');
 
     modules.push((String::from("conversions"), case_mapping::generate_case_mapping(&unicode_data)));
+    modules.push((
+        String::from("grapheme_cluster_break"),
+        grapheme_cluster_break::generate_grapheme_cluster_break(&unicode_data),
+    ));
 
     for (name, contents) in modules {
         table_file.push_str("#[rustfmt::skip]\n");
@@ -0,0 +1,22 @@
+//@ force-host
+//@ no-prefer-dynamic
+
+#![crate_type = "proc-macro"]
+#![feature(proc_macro_diagnostic, proc_macro_expansion_cache)]
+
+extern crate proc_macro;
+
+use proc_macro::expansion_cache::declare_deterministic;
+use proc_macro::{Span, TokenStream, TokenTree};
+
+/// Defines a function called `$name` that returns 42, and warns about it.
+#[proc_macro]
+pub fn make_answer(input: TokenStream) -> TokenStream {
+    declare_deterministic();
+    let Some(TokenTree::Ident(name)) = input.clone().into_iter().next() else { panic!() };
+    name.span()
+        .warning(format!("`{name}` is always 42"))
+        .span_note(Span::call_site(), "defined by this macro")
+        .emit();
+    format!("fn {input}() -> u32 {{ 42 }}").parse().unwrap()
+}
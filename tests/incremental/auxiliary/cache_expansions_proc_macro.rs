@@ -0,0 +1,50 @@
+//@ force-host
+//@ no-prefer-dynamic
+
+#![crate_type = "proc-macro"]
+#![feature(proc_macro_expansion_cache)]
+
+extern crate proc_macro;
+
+use proc_macro::expansion_cache::declare_deterministic;
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// Returns the identifier following the keyword `keyword` in `input`.
+fn name_after(input: &TokenStream, keyword: &str) -> String {
+    let mut tokens = input.clone().into_iter();
+    tokens.by_ref().find(|tt| matches!(tt, TokenTree::Ident(i) if i.to_string() == keyword));
+    tokens.next().unwrap().to_string()
+}
+
+/// Implements `FieldCount` for a struct with named fields.
+#[proc_macro_derive(FieldCount)]
+pub fn derive_field_count(input: TokenStream) -> TokenStream {
+    declare_deterministic();
+    let name = name_after(&input, "struct");
+    let fields = input
+        .into_iter()
+        .find_map(|tt| match tt {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Brace => Some(g.stream()),
+            _ => None,
+        })
+        .unwrap();
+    let count = fields.to_string().split(',').filter(|field| !field.trim().is_empty()).count();
+    format!("impl FieldCount for {name} {{ const COUNT: usize = {count}; }}").parse().unwrap()
+}
+
+/// Defines a function called `$name` that returns 42.
+#[proc_macro]
+pub fn make_answer(input: TokenStream) -> TokenStream {
+    declare_deterministic();
+    format!("fn {input}() -> u32 {{ 42 }}").parse().unwrap()
+}
+
+/// Keeps the annotated function `f` and adds a `double_f` function returning twice its result.
+#[proc_macro_attribute]
+pub fn with_double(_: TokenStream, item: TokenStream) -> TokenStream {
+    declare_deterministic();
+    let name = name_after(&item, "fn");
+    let double: TokenStream =
+        format!("fn double_{name}() -> u32 {{ 2 * {name}() }}").parse().unwrap();
+    item.into_iter().chain(double).collect()
+}
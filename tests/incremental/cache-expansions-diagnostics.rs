@@ -0,0 +1,22 @@
+// Test that the warnings a proc macro emitted are emitted again when its
+// expansion is reused from the previous session with `-Z cache-expansions`.
+
+//@ aux-build:cache_expansions_diagnostics.rs
+//@ revisions: cfail1 cfail2
+//@ compile-flags: -Z cache-expansions
+//@ build-pass
+
+extern crate cache_expansions_diagnostics;
+
+use cache_expansions_diagnostics::make_answer;
+
+#[cfg(cfail2)]
+fn unrelated() {}
+
+make_answer!(answer);
+//~^ WARNING `answer` is always 42
+//~| NOTE defined by this macro
+
+fn main() {
+    assert_eq!(answer(), 42);
+}
@@ -0,0 +1,48 @@
+// Test that function-like, attribute and derive proc macro expansions reused
+// from the previous session with `-Z cache-expansions` behave like fresh ones,
+// and that they are not reused once their input changes.
+
+//@ aux-build:cache_expansions_proc_macro.rs
+//@ revisions: rpass1 rpass2 rpass3
+//@ compile-flags: -Z cache-expansions
+
+extern crate cache_expansions_proc_macro;
+
+use cache_expansions_proc_macro::{make_answer, with_double, FieldCount};
+
+trait FieldCount {
+    const COUNT: usize;
+}
+
+#[cfg(rpass2)]
+fn unrelated() {}
+
+#[derive(FieldCount)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(FieldCount)]
+struct Changing {
+    a: u8,
+    #[cfg(rpass3)]
+    b: u8,
+}
+
+make_answer!(answer);
+
+#[with_double]
+fn three() -> u32 {
+    3
+}
+
+fn main() {
+    assert_eq!(Point::COUNT, 2);
+    #[cfg(not(rpass3))]
+    assert_eq!(Changing::COUNT, 1);
+    #[cfg(rpass3)]
+    assert_eq!(Changing::COUNT, 2);
+    assert_eq!(answer(), 42);
+    assert_eq!(double_three(), 6);
+}
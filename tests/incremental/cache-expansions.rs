@@ -0,0 +1,34 @@
+// Test that `macro_rules!` expansions reused from the previous session with
+// `-Z cache-expansions` behave like fresh ones, including hygiene.
+
+//@ revisions: rpass1 rpass2 rpass3
+//@ compile-flags: -Z cache-expansions
+
+macro_rules! hygienic_add {
+    ($e:expr) => {{
+        let x = 10;
+        $e + x
+    }};
+}
+
+macro_rules! make_fn {
+    ($name:ident, $value:literal) => {
+        fn $name() -> u32 {
+            $value
+        }
+    };
+}
+
+#[cfg(rpass2)]
+fn unrelated() {}
+
+make_fn!(one, 1);
+make_fn!(two, 2);
+
+fn main() {
+    let x = 1;
+    assert_eq!(hygienic_add!(x), 11);
+    assert_eq!(one() + two(), 3);
+    #[cfg(rpass3)]
+    assert_eq!(hygienic_add!(x * 2), 12);
+}
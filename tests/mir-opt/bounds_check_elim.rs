@@ -0,0 +1,52 @@
+//@ test-mir-pass: BoundsCheckElimination
+//@ compile-flags: -Zmir-enable-passes=+LowerSliceLenCalls
+// EMIT_MIR_FOR_EACH_PANIC_STRATEGY
+
+// EMIT_MIR bounds_check_elim.bound.BoundsCheckElimination.diff
+pub fn bound(index: usize, slice: &[u8]) -> u8 {
+    // CHECK-LABEL: fn bound(
+    // CHECK: [[lt:_.*]] = Lt(
+    // CHECK: switchInt(move [[lt]]) -> [0: {{bb.*}}, otherwise: [[bb:bb.*]]];
+    // CHECK: [[bb]]: {
+    // CHECK-NOT: assert(
+    // CHECK: (*_2)[
+    if index < slice.len() { slice[index] } else { 42 }
+}
+
+// EMIT_MIR bounds_check_elim.other_len.BoundsCheckElimination.diff
+pub fn other_len(index: usize, slice: &[u8], other: &[u8]) -> u8 {
+    // CHECK-LABEL: fn other_len(
+    // CHECK: [[lt:_.*]] = Lt(
+    // CHECK: switchInt(move [[lt]]) -> [0: {{bb.*}}, otherwise: [[bb:bb.*]]];
+    // CHECK: [[bb]]: {
+    // CHECK: assert(
+    if index < other.len() { slice[index] } else { 42 }
+}
+
+// EMIT_MIR bounds_check_elim.wrapped_loop.BoundsCheckElimination.diff
+pub fn wrapped_loop(slice: &[u8]) -> u8 {
+    // CHECK-LABEL: fn wrapped_loop(
+    // CHECK: [[lt:_.*]] = Lt(
+    // CHECK: switchInt(move [[lt]]) -> [0: {{bb.*}}, otherwise: [[body:bb.*]]];
+    // CHECK: [[body]]: {
+    // CHECK: [[some:_.*]] = Option::<usize>::Some(
+    // CHECK: = (([[some]] as Some).0: usize);
+    // CHECK-NOT: assert(
+    // CHECK: = (*_1)[
+    let mut acc = 0;
+    let mut i = 0;
+    while i < slice.len() {
+        // Like the index yielded by an inlined `Range::next`.
+        if let Some(index) = Some(i) {
+            acc ^= slice[index];
+        }
+        i += 1;
+    }
+    acc
+}
+
+fn main() {
+    let _ = bound(1, &[1, 2, 3]);
+    let _ = other_len(1, &[1, 2, 3], &[1, 2]);
+    let _ = wrapped_loop(&[1, 2, 3]);
+}
@@ -0,0 +1,49 @@
+//@ test-mir-pass: LoopInvariantCodeMotion
+
+#![feature(core_intrinsics, custom_mir)]
+use std::intrinsics::mir::*;
+
+// EMIT_MIR licm.invariant_arith.LoopInvariantCodeMotion.diff
+#[custom_mir(dialect = "runtime", phase = "post-cleanup")]
+fn invariant_arith(x: u32, n: u32) -> u32 {
+    // CHECK-LABEL: fn invariant_arith(
+    // CHECK: bb0: {
+    // CHECK: [[y:_.*]] = Mul(_1, const 3_u32);
+    // CHECK-NEXT: goto -> bb1;
+    // CHECK: bb2: {
+    // CHECK-NOT: Mul(
+    // CHECK: = Div(_1, _2);
+    // CHECK: _0 = Add(_0, [[y]]);
+    mir! {
+        let i: u32;
+        let cond: bool;
+        let y: u32;
+        let q: u32;
+        {
+            i = 0;
+            RET = 0;
+            Goto(bb1)
+        }
+        bb1 = {
+            cond = i < n;
+            match cond { true => bb2, _ => ret }
+        }
+        bb2 = {
+            // Invariant and side-effect free, moved before the loop.
+            y = x * 3;
+            // Invariant, but dividing by zero is UB so it must stay behind the check.
+            q = x / n;
+            RET = RET + y;
+            RET = RET + q;
+            i = i + 1;
+            Goto(bb1)
+        }
+        ret = {
+            Return()
+        }
+    }
+}
+
+fn main() {
+    invariant_arith(5, 4);
+}
@@ -0,0 +1,12 @@
+pub fn assign_while_borrowed() {
+    let mut x = 1;
+    let r = &x;
+    x += 1;
+    drop(r);
+}
+
+pub fn not_explained() {
+    let mut y = 1;
+    let r = &mut y;
+    *r += 1;
+}
@@ -0,0 +1,41 @@
+// `-Z borrowck-explain` should dump the loans of the named function, and explain
+// why the loan of `x` is still live where `x` is assigned: `r` is used later.
+
+use run_make_support::{assert_contains, fs_wrapper, rustc, shallow_find_files};
+
+fn main() {
+    rustc()
+        .input("lib.rs")
+        .crate_type("lib")
+        .arg("-Zborrowck-explain=assign_while_borrowed")
+        .arg("-Zdump-mir-dir=dump")
+        .run_fail();
+
+    let dumps = shallow_find_files("dump", |path| {
+        path.file_name().unwrap().to_str().unwrap().contains(".borrowck.0.loans.")
+    });
+    assert_eq!(dumps.len(), 3, "{dumps:?}");
+    for dump in &dumps {
+        let name = dump.file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("lib.assign_while_borrowed."), "{name}");
+    }
+
+    let find = |extension: &str| {
+        let dump = dumps.iter().find(|path| path.extension().unwrap() == extension).unwrap();
+        fs_wrapper::read_to_string(dump)
+    };
+    let text = find("txt");
+    assert_contains(&text, "bw0: shared borrow of `x`");
+    assert_contains(&text, "error at");
+    assert_contains(&text, "because `r` is used at");
+
+    let json = find("json");
+    assert_contains(&json, r#""function":"assign_while_borrowed""#);
+    assert_contains(&json, r#""kind":"shared","place":"`x`""#);
+    assert_contains(&json, r#""cause":"`r` is used at"#);
+
+    let dot = find("dot");
+    assert_contains(&dot, "digraph loans {");
+    assert_contains(&dot, "loan0 -> ");
+    assert_contains(&dot, "[shape=octagon");
+}
@@ -0,0 +1,16 @@
+const fn square(x: u64) -> u64 {
+    x * x
+}
+
+const fn sum_of_squares(n: u64) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < n {
+        sum += square(i);
+        i += 1;
+    }
+    sum
+}
+
+pub const SUM: u64 = sum_of_squares(100);
+pub static TABLE: [u64; 4] = [square(1), square(2), square(3), square(4)];
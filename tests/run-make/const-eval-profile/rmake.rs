@@ -0,0 +1,30 @@
+// `-Z const-eval-profile` should write the steps taken by compile-time evaluation
+// as folded stacks, with the evaluated item as the outermost frame.
+
+use run_make_support::{fs_wrapper, rustc};
+
+fn main() {
+    rustc().input("lib.rs").crate_type("lib").arg("-Zconst-eval-profile").run();
+
+    let profile = fs_wrapper::read_to_string("lib.const-eval-profile.folded");
+    let steps = |prefix: &str| -> u64 {
+        profile
+            .lines()
+            .filter(|line| line.starts_with(prefix))
+            .map(|line| line.rsplit_once(' ').unwrap().1.parse::<u64>().unwrap())
+            .sum()
+    };
+
+    // The loop runs 100 times, and calls `square` each time.
+    let sum_of_squares = "SUM;SUM at lib.rs:15:22;sum_of_squares at lib.rs:";
+    assert!(steps(sum_of_squares) >= 200, "{profile}");
+    // Steps are attributed to the call site in the caller.
+    assert!(profile.contains(";sum_of_squares at lib.rs:9:16 "), "{profile}");
+    assert!(steps("TABLE;") >= 4, "{profile}");
+    // Every line is a stack followed by its number of steps.
+    for line in profile.lines() {
+        let (stack, steps) = line.rsplit_once(' ').unwrap();
+        assert!(stack.split(';').count() >= 2, "{line}");
+        assert!(steps.parse::<u64>().unwrap() > 0, "{line}");
+    }
+}
@@ -0,0 +1,10 @@
+pub static GREETING: &str = "hello";
+
+#[inline(never)]
+pub fn generic<T: Default>() -> T {
+    T::default()
+}
+
+pub fn instantiate() -> (u8, u32) {
+    (generic(), generic())
+}
@@ -0,0 +1,20 @@
+// `-Z dump-stable-mir=json` should write the StableMIR of every monomorphized
+// item, together with the tables of the types, allocations and spans that
+// those items refer to.
+
+use run_make_support::{fs_wrapper, rustc};
+
+fn main() {
+    rustc().input("lib.rs").crate_type("lib").arg("-Zdump-stable-mir=json").run();
+
+    let dump = fs_wrapper::read_to_string("lib.stable-mir.json");
+    assert!(dump.starts_with(r#"{"format_version":1,"crate_name":"lib","#), "{dump}");
+    for name in ["instantiate", "generic::<u8>", "generic::<u32>", "GREETING"] {
+        assert!(dump.contains(&format!(r#""name":"{name}""#)), "no item `{name}`: {dump}");
+    }
+    assert!(dump.contains(r#""kind":"Static""#), "{dump}");
+    assert!(dump.contains(r#""pretty":"(u8, u32)""#), "{dump}");
+    // The string pointed to by `GREETING` is in the allocation table.
+    assert!(dump.contains(r#""alloc":{"Memory":{"bytes":[104,101,108,108,111]"#), "{dump}");
+    assert!(dump.contains(r#""file":"lib.rs""#), "{dump}");
+}
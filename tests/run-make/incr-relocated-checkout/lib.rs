@@ -0,0 +1,14 @@
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Point {
+    pub fn sum(&self) -> u32 {
+        add(self.x, self.y)
+    }
+}
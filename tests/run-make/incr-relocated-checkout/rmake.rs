@@ -0,0 +1,44 @@
+// Moving a checkout to another path should not invalidate its incremental
+// cache, as long as `--remap-path-prefix` maps both checkouts to the same
+// virtual path.
+
+use run_make_support::{cwd, fs_wrapper, rustc};
+
+/// Builds `lib.rs` in `checkout` and returns how many nodes of the previous
+/// session's dependency graph were reused, and how many it had.
+fn build(checkout: &str) -> (usize, usize) {
+    fs_wrapper::create_dir_all(checkout);
+    fs_wrapper::copy("lib.rs", format!("{checkout}/lib.rs"));
+    let stderr = rustc()
+        .current_dir(checkout)
+        .input("lib.rs")
+        .crate_type("lib")
+        .incremental(cwd().join("incr"))
+        .remap_path_prefix(cwd().join(checkout), "/src")
+        .arg("-Zincremental-info")
+        .run()
+        .stderr_utf8();
+    assert!(!stderr.contains("completely ignoring cache"), "{stderr}");
+    // [incremental] Reused Node Count: 123 of 456 (27.0%)
+    let Some(counts) = stderr
+        .lines()
+        .find_map(|line| line.strip_prefix("[incremental] Reused Node Count: "))
+        .and_then(|counts| counts.split_once(" ("))
+        .and_then(|(counts, _)| counts.split_once(" of "))
+    else {
+        return (0, 0);
+    };
+    (counts.0.parse().unwrap(), counts.1.parse().unwrap())
+}
+
+fn main() {
+    assert_eq!(build("first"), (0, 0));
+    // The dependency graph of a session that reused the cache can differ from
+    // that of the initial one, so compare against a second rebuild.
+    build("first");
+    // Rebuilding in place reuses everything that can be reused...
+    let (reused, total) = build("first");
+    assert!(reused > 0 && reused <= total, "reused {reused} of {total} nodes");
+    // ...and so does building in another checkout.
+    assert_eq!(build("second"), (reused, total));
+}
@@ -0,0 +1,23 @@
+// `-Z item-cost-report=json` should attribute the frontend and LLVM costs of
+// all instantiations of a generic function to that function, and list the
+// costs of each instantiation.
+
+use run_make_support::{fs_wrapper, rustc};
+
+fn main() {
+    rustc().input("lib.rs").crate_type("lib").opt_level("1").arg("-Zitem-cost-report=json").run();
+
+    let report = fs_wrapper::read_to_string("lib.item-costs.json");
+    let generic = report
+        .split(r#"{"name":"#)
+        .find(|item| item.starts_with(r#""generic""#))
+        .unwrap_or_else(|| panic!("no entry for `generic`: {report}"));
+    assert!(generic.contains(r#""typeck":"#), "{generic}");
+    assert!(generic.contains(r#""instantiations":2,"#), "{generic}");
+    assert!(!generic.contains(r#""llvm_ir_instructions":0,"#), "{generic}");
+    // Each instantiation is listed separately.
+    for name in ["fn generic::<u8>", "fn generic::<u32>"] {
+        let mono_item = format!(r#"{{"name":"{name}","symbol_name":"#);
+        assert!(report.contains(&mono_item), "no mono item `{name}`: {report}");
+    }
+}
@@ -0,0 +1,43 @@
+// `--print=lint-levels` should show where the crate-wide level of each lint
+// comes from, followed by the levels for paths and the lint parameters of the
+// `--lint-config` file.
+
+use run_make_support::{fs_wrapper, rustc};
+
+fn main() {
+    fs_wrapper::write(
+        "lints.toml",
+        r#"
+[lints]
+unused = "deny"
+missing-docs = "warn"
+
+[paths."src/generated/**"]
+unused_variables = "allow"
+
+[params.large_assignments]
+limit = 4096
+"#,
+    );
+
+    let output = rustc()
+        .input("lib.rs")
+        .arg("-Zunstable-options")
+        .arg("--lint-config=lints.toml")
+        .arg("-Adead-code")
+        .arg("--print=lint-levels")
+        .run();
+    let stdout = output.stdout_utf8();
+    let lines: Vec<_> = stdout.lines().collect();
+
+    for expected in [
+        "dead_code allow command-line",
+        "missing_docs warn lint-config",
+        "unused_variables deny lint-config",
+        "while_true warn default",
+        r#"unused_variables allow lint-config:"src/generated/**""#,
+        "large_assignments param limit=4096",
+    ] {
+        assert!(lines.contains(&expected), "missing `{expected}` in:\n{stdout}");
+    }
+}
@@ -0,0 +1,27 @@
+// `--error-format=sarif` should write a single SARIF log to stderr, which contains both the
+// early diagnostics emitted while the command line is processed and the diagnostics of the
+// compilation itself.
+
+use run_make_support::rustc;
+
+fn main() {
+    let output = rustc()
+        .input("main.rs")
+        .arg("--error-format=sarif")
+        .arg("-Zunstable-options")
+        // Emits an early warning, once the error format is known.
+        .arg("-Cremark=all")
+        .run_fail();
+    let stderr = output.stderr_utf8();
+
+    assert_eq!(stderr.lines().count(), 1, "{stderr}");
+    assert_eq!(stderr.matches(r#""$schema":"#).count(), 1, "{stderr}");
+    assert!(stderr.contains(r#""version":"2.1.0""#), "{stderr}");
+    assert!(stderr.contains(r#""-C remark requires \"-C debuginfo=n\" to show"#), "{stderr}");
+    assert!(stderr.contains(r#""ruleId":"unused_variables""#), "{stderr}");
+    assert!(stderr.contains(r#""ruleId":"E0308","ruleIndex":"#), "{stderr}");
+    assert!(stderr.contains(r#""level":"error""#), "{stderr}");
+    assert!(stderr.contains(r#""uri":"main.rs""#), "{stderr}");
+    // The summary ("aborting due to ...") is not a result.
+    assert!(!stderr.contains("aborting"), "{stderr}");
+}
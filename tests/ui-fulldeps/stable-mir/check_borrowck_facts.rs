@@ -0,0 +1,124 @@
+//@ run-pass
+//! Test that users are able to retrieve the loans, regions and outlives constraints computed by
+//! the borrow checker.
+
+//@ ignore-stage1
+//@ ignore-cross-compile
+//@ ignore-remote
+//@ ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate rustc_smir;
+extern crate stable_mir;
+
+use rustc_driver::Compilation;
+use rustc_interface::interface::{Compiler, Config};
+use rustc_interface::Queries;
+use rustc_smir::rustc_internal;
+use stable_mir::mir::borrowck::{borrowck_facts, BorrowckFacts, TwoPhaseActivation};
+use stable_mir::mir::{BorrowKind, MutBorrowKind};
+use stable_mir::{CrateDef, CrateItem};
+use std::io::Write;
+
+const CRATE_NAME: &str = "input";
+
+fn test_borrowck_facts() {
+    let items = stable_mir::all_local_items();
+
+    let simple = facts(&items, "simple");
+    assert_eq!(simple.loans.len(), 1);
+    let loan = &simple.loans[0];
+    assert!(matches!(loan.kind, BorrowKind::Mut { kind: MutBorrowKind::Default }));
+    assert_eq!(loan.activation, TwoPhaseActivation::NotTwoPhase);
+    assert!(!loan.live_at.is_empty());
+    assert!(!loan.live_at.contains(&loan.reserve_location));
+    let region = &simple.regions[loan.region];
+    assert!(!region.universal);
+    assert!(loan.live_at.iter().all(|location| region.live_at.contains(location)));
+
+    // The items are borrow checked on demand, and only once.
+    assert_eq!(facts(&items, "simple").loans, simple.loans);
+
+    let two_phase = facts(&items, "two_phase");
+    assert!(two_phase.loans.iter().any(|loan| matches!(
+        loan.activation,
+        TwoPhaseActivation::ActivatedAt(location) if location != loan.reserve_location
+    )));
+
+    let first = facts(&items, "first");
+    assert!(first.regions.iter().any(|region| region.universal));
+    let loan_region = first.loans[0].region;
+    assert!(first
+        .outlives_constraints
+        .iter()
+        .any(|constraint| constraint.sub == loan_region || constraint.sup == loan_region));
+}
+
+fn facts(items: &[CrateItem], name: &str) -> BorrowckFacts {
+    let item = *items.iter().find(|item| item.name() == name).unwrap();
+    borrowck_facts(item).unwrap()
+}
+
+struct BorrowckCallbacks;
+
+impl rustc_driver::Callbacks for BorrowckCallbacks {
+    fn config(&mut self, config: &mut Config) {
+        rustc_internal::collect_borrowck_facts(config);
+    }
+
+    fn after_expansion<'tcx>(
+        &mut self,
+        _compiler: &Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        queries
+            .global_ctxt()
+            .unwrap()
+            .enter(|tcx| rustc_internal::run(tcx, test_borrowck_facts).unwrap());
+        Compilation::Stop
+    }
+}
+
+/// This test will generate a dummy crate, and check the borrow checker facts of its items with the
+/// StableMIR APIs, before the crate is analyzed.
+fn main() {
+    let path = "borrowck_facts_input.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    rustc_driver::RunCompiler::new(&args, &mut BorrowckCallbacks).run().unwrap();
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+        pub fn simple() -> u32 {{
+            let mut x = 1;
+            let r = &mut x;
+            *r += 1;
+            x
+        }}
+
+        pub fn two_phase(v: &mut Vec<usize>) {{
+            v.push(v.len());
+        }}
+
+        pub fn first<'a>(x: &'a [u8; 4], y: &u8) -> &'a u8 {{
+            let r = &x[0];
+            let _ = y;
+            r
+        }}
+        "#
+    )?;
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//@ run-pass
+//! Test that a StableMIR transform registered with `register_mir_transform` replaces the
+//! optimized MIR of an item, using the mutable visitor and the `Body` builder methods, and that
+//! a body that cannot be converted back to internal MIR is ignored.
+
+//@ ignore-stage1
+//@ ignore-cross-compile
+//@ ignore-remote
+//@ ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate rustc_smir;
+extern crate stable_mir;
+
+use rustc_interface::interface::Config;
+use rustc_smir::rustc_internal;
+use stable_mir::mir::visit::Location;
+use stable_mir::mir::{
+    BinOp, Body, ConstOperand, FakeReadCause, MutMirVisitor, Mutability, Operand, Place, Rvalue,
+    Statement, StatementKind, UnwindAction,
+};
+use stable_mir::ty::{MirConst, Ty};
+use stable_mir::{CrateDef, CrateItem};
+use std::io::Write;
+
+const CRATE_NAME: &str = "input";
+
+/// Replace the additions of `add_one` by subtractions, and call `marker` on entry. Add a
+/// `FakeRead` to `add_two`, which keeps its original body as it cannot be converted back.
+fn transform(item: CrateItem, mut body: Body) -> Option<Body> {
+    match item.name().as_str() {
+        "add_one" => {}
+        "add_two" => {
+            let place = Place { local: 1, projection: vec![] };
+            let kind = StatementKind::FakeRead(FakeReadCause::ForIndex, place);
+            body.blocks[0].statements.push(Statement { kind, span: body.span });
+            return Some(body);
+        }
+        _ => return None,
+    }
+
+    let mut visitor = AddToSub { replaced: 0 };
+    visitor.visit_body(&mut body);
+    assert_eq!(visitor.replaced, 1);
+
+    let marker = stable_mir::all_local_items()
+        .into_iter()
+        .find(|item| item.name() == "marker")
+        .unwrap();
+    let func = Operand::Constant(ConstOperand {
+        span: body.span,
+        user_ty: None,
+        const_: MirConst::try_new_zero_sized(marker.ty()).unwrap(),
+    });
+    let local = body.new_local(Ty::new_tuple(&[]), body.span, Mutability::Not);
+    let destination = Place { local, projection: vec![] };
+    let next = body.insert_call(0, 0, func, vec![], destination, UnwindAction::Continue, body.span);
+    assert_eq!(next, body.blocks.len() - 1);
+    Some(body)
+}
+
+struct AddToSub {
+    replaced: usize,
+}
+
+impl MutMirVisitor for AddToSub {
+    fn visit_rvalue(&mut self, rvalue: &mut Rvalue, location: Location) {
+        if let Rvalue::CheckedBinaryOp(op @ BinOp::Add, ..) = rvalue {
+            *op = BinOp::Sub;
+            self.replaced += 1;
+        }
+        self.super_rvalue(rvalue, location);
+    }
+}
+
+struct TransformCallbacks;
+
+impl rustc_driver::Callbacks for TransformCallbacks {
+    fn config(&mut self, config: &mut Config) {
+        rustc_internal::register_mir_transform(config, transform);
+    }
+}
+
+/// This test will generate a dummy crate, compile it to MIR with a transform registered,
+/// and check that the emitted MIR is the transformed one.
+fn main() {
+    let path = "mir_transform_input.rs";
+    let output = "mir_transform_input.mir";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        "--emit=mir".to_string(),
+        "-o".to_string(),
+        output.to_string(),
+        path.to_string(),
+    ];
+    rustc_driver::RunCompiler::new(&args, &mut TransformCallbacks).run().unwrap();
+
+    let mir = std::fs::read_to_string(output).unwrap();
+    assert!(mir.contains("SubWithOverflow("), "{mir}");
+    assert_eq!(mir.matches("AddWithOverflow(").count(), 1, "{mir}");
+    assert!(!mir.contains("FakeRead"), "{mir}");
+    assert!(mir.contains("= marker()"), "{mir}");
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+        #[inline(never)]
+        pub fn marker() {{}}
+
+        pub fn add_one(x: u32) -> u32 {{
+            x + 1
+        }}
+
+        pub fn add_two(x: u32) -> u32 {{
+            x + 2
+        }}
+        "#
+    )?;
+    Ok(())
+}
@@ -0,0 +1,141 @@
+//@ run-pass
+//! Test that users are able to check trait implementations, find the impl that satisfies a trait
+//! reference, and normalize types, both in an empty environment and in the one of a generic item.
+
+//@ ignore-stage1
+//@ ignore-cross-compile
+//@ ignore-remote
+//@ ignore-windows-gnu mingw has troubles with linking https://github.com/rust-lang/rust/pull/116837
+//@ edition: 2021
+
+#![feature(rustc_private)]
+#![feature(assert_matches)]
+#![feature(control_flow_enum)]
+
+#[macro_use]
+extern crate rustc_smir;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate stable_mir;
+
+use rustc_smir::rustc_internal;
+use stable_mir::ty::{
+    GenericArgKind, GenericArgs, RigidTy, TraitDef, TraitRef, Ty, TyKind, UintTy,
+};
+use stable_mir::CrateDef;
+use std::io::Write;
+use std::ops::ControlFlow;
+
+const CRATE_NAME: &str = "trait_solving";
+
+/// This function uses the Stable MIR APIs to query the trait system about the test crate.
+fn test_trait_solving() -> ControlFlow<()> {
+    let local_crate = stable_mir::local_crate();
+    let shape = find_trait(&local_crate.trait_decls(), "Shape");
+    let convert = find_trait(&local_crate.trait_decls(), "Convert");
+    let copy = find_trait(&stable_mir::all_trait_decls(), "std::marker::Copy");
+
+    let shape_impl = local_crate
+        .trait_impls()
+        .into_iter()
+        .find(|trait_impl| trait_impl.name() == "<Square as Shape>")
+        .unwrap();
+    let square = shape_impl.trait_impl().value.self_ty();
+    let no_args = GenericArgs(vec![]);
+    let u32_ty = Ty::unsigned_ty(UintTy::U32);
+    let u64_ty = Ty::unsigned_ty(UintTy::U64);
+
+    assert!(square.implements_trait(shape, &no_args));
+    assert!(!u32_ty.implements_trait(shape, &no_args));
+    assert!(u32_ty.implements_trait(copy, &no_args));
+    assert!(!square.implements_trait(copy, &no_args));
+    assert!(square.implements_trait(convert, &GenericArgs(vec![GenericArgKind::Type(u64_ty)])));
+    assert!(!square.implements_trait(convert, &GenericArgs(vec![GenericArgKind::Type(u32_ty)])));
+
+    assert_eq!(TraitRef::new(shape, square, &no_args).impl_for(), Some(shape_impl));
+    assert_eq!(TraitRef::new(shape, u32_ty, &no_args).impl_for(), None);
+    // `Copy` for `u32` is builtin, there is no impl for it.
+    assert_eq!(TraitRef::new(copy, u32_ty, &no_args).impl_for(), None);
+    let convert_args = GenericArgs(vec![GenericArgKind::Type(u64_ty)]);
+    let convert_impl = TraitRef::new(convert, square, &convert_args).impl_for().unwrap();
+    assert_eq!(convert_impl.name(), "<Square as Convert<u64>>");
+
+    let area =
+        stable_mir::all_local_items().into_iter().find(|item| item.name() == "AREA").unwrap();
+    assert_ne!(area.ty(), u32_ty);
+    assert_eq!(area.ty().normalize().unwrap(), u32_ty);
+    assert_eq!(u64_ty.normalize().unwrap(), u64_ty);
+
+    // In `area_of`, the where clause `T: Shape<Output = u64>` holds.
+    let area_of =
+        stable_mir::all_local_items().into_iter().find(|item| item.name() == "area_of").unwrap();
+    let sig = area_of.ty().kind().fn_sig().unwrap().skip_binder();
+    let TyKind::RigidTy(RigidTy::Ref(_, param, _)) = sig.inputs()[0].kind() else {
+        panic!("unexpected signature {sig:?}");
+    };
+    assert!(!param.implements_trait(shape, &no_args));
+    assert!(param.implements_trait_in(shape, &no_args, area_of.def_id()));
+    assert_eq!(sig.output().normalize_in(area_of.def_id()).unwrap(), u64_ty);
+    // The trait is implemented by the where clause, not by an impl.
+    assert_eq!(TraitRef::new(shape, param, &no_args).impl_for_in(area_of.def_id()), None);
+    ControlFlow::Continue(())
+}
+
+fn find_trait(traits: &[TraitDef], name: &str) -> TraitDef {
+    *traits
+        .iter()
+        .find(|trait_def| trait_def.name() == name)
+        .unwrap_or_else(|| panic!("Failed to find `{name}`"))
+}
+
+/// This test will generate and analyze a dummy crate using the stable mir.
+/// For that, it will first write the dummy crate into a file.
+/// Then it will create a `StableMir` using custom arguments and then
+/// it will run the compiler.
+fn main() {
+    let path = "trait_solving.rs";
+    generate_input(&path).unwrap();
+    let args = vec![
+        "rustc".to_string(),
+        "--crate-type=lib".to_string(),
+        "--crate-name".to_string(),
+        CRATE_NAME.to_string(),
+        path.to_string(),
+    ];
+    run!(args, test_trait_solving).unwrap();
+}
+
+fn generate_input(path: &str) -> std::io::Result<()> {
+    let mut file = std::fs::File::create(path)?;
+    write!(
+        file,
+        r#"
+        pub trait Shape {{
+            type Output;
+            fn area(&self) -> Self::Output;
+        }}
+
+        pub trait Convert<T> {{
+            fn convert(&self) -> T;
+        }}
+
+        pub struct Square(pub u32);
+
+        impl Shape for Square {{
+            type Output = u32;
+            fn area(&self) -> u32 {{ self.0 * self.0 }}
+        }}
+
+        impl Convert<u64> for Square {{
+            fn convert(&self) -> u64 {{ self.0 as u64 }}
+        }}
+
+        pub const AREA: <Square as Shape>::Output = 4;
+
+        pub fn area_of<T: Shape<Output = u64>>(shape: &T) -> T::Output {{
+            shape.area()
+        }}
+    "#
+    )?;
+    Ok(())
+}
@@ -0,0 +1,54 @@
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+#![feature(const_ptr_write)]
+// Heap allocations are interned as read-only static memory, so anything that could mutate,
+// reallocate or deallocate them at runtime must be rejected.
+
+// Strip out raw byte dumps to make comparison platform-independent:
+//@ normalize-stderr-test "(the raw bytes of the constant) \(size: [0-9]*, align: [0-9]*\)" -> "$1 (size: $$SIZE, align: $$ALIGN)"
+//@ normalize-stderr-test "([0-9a-f][0-9a-f] |╾─*A(LLOC)?[0-9]+(\+[a-z0-9]+)?(<imm>)?─*╼ )+ *│.*" -> "HEX_DUMP"
+//@ normalize-stderr-test "HEX_DUMP\s*\n\s*HEX_DUMP" -> "HEX_DUMP"
+
+use std::cell::Cell;
+use std::intrinsics;
+use std::mem::{align_of, size_of};
+use std::sync::atomic::AtomicPtr;
+
+struct Buf<T> {
+    ptr: *mut T,
+}
+
+unsafe impl<T> Sync for Buf<T> {}
+
+impl<T> Buf<T> {
+    const fn new(value: T) -> Buf<T> {
+        unsafe {
+            let ptr = intrinsics::const_allocate(size_of::<T>(), align_of::<T>()) as *mut T;
+            ptr.write(value);
+            Buf { ptr }
+        }
+    }
+}
+
+// The value of a constant is copied to each use, which could then free the heap memory.
+const OWNED: Buf<u8> = Buf::new(0);
+//~^ ERROR mutable pointer to heap memory in final value of constant
+
+static mut MUTABLE: Buf<u8> = Buf::new(0);
+//~^ ERROR mutable pointer to heap memory in final value of mutable static
+
+static INTERIOR_MUTABLE: AtomicPtr<u8> = AtomicPtr::new(Buf::new(0).ptr);
+//~^ ERROR mutable pointer to heap memory in final value of static
+
+static CELL: Buf<Cell<u8>> = Buf::new(Cell::new(0));
+//~^ ERROR it is undefined behavior to use this value
+
+static FREED: Buf<u8> = unsafe {
+    //~^ ERROR dangling pointer in final value of static
+    let buf = Buf::new(0);
+    intrinsics::const_deallocate(buf.ptr, 1, 1);
+    buf
+};
+
+fn main() {}
@@ -0,0 +1,121 @@
+//@ run-pass
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+// Heap allocations that are still alive at the end of const-evaluation are interned as read-only
+// static memory, even if they are reachable through mutable raw pointers, like the buffer of a
+// `Vec`.
+
+use std::intrinsics;
+use std::mem::{align_of, size_of};
+
+#[derive(Debug, PartialEq)]
+struct Entry {
+    key: u8,
+    square: u32,
+}
+
+struct Table {
+    ptr: *mut Entry,
+    len: usize,
+}
+
+unsafe impl Sync for Table {}
+
+impl Table {
+    const fn build(len: usize) -> Table {
+        unsafe {
+            let ptr = intrinsics::const_allocate(len * size_of::<Entry>(), align_of::<Entry>())
+                as *mut Entry;
+            let mut i = 0;
+            while i < len {
+                *ptr.add(i) = Entry { key: i as u8, square: (i * i) as u32 };
+                i += 1;
+            }
+            Table { ptr, len }
+        }
+    }
+
+    const fn as_slice(&self) -> &[Entry] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+// `Vec` itself cannot be built in a const: `Vec::with_capacity` and `Vec::push` are not
+// `const fn`, since `Vec` allocates through the `Allocator` trait, which has no const
+// implementation. This type grows its buffer the way `Vec` does, so the static below ends up
+// with a buffer larger than its entries, and with the previous buffers deallocated.
+struct Growable {
+    ptr: *mut Entry,
+    cap: usize,
+    len: usize,
+}
+
+unsafe impl Sync for Growable {}
+
+impl Growable {
+    const fn new() -> Growable {
+        Growable { ptr: std::ptr::null_mut(), cap: 0, len: 0 }
+    }
+
+    const fn push(&mut self, entry: Entry) {
+        unsafe {
+            if self.len == self.cap {
+                let cap = if self.cap == 0 { 1 } else { self.cap * 2 };
+                let ptr = intrinsics::const_allocate(cap * size_of::<Entry>(), align_of::<Entry>())
+                    as *mut Entry;
+                let mut i = 0;
+                while i < self.len {
+                    *ptr.add(i) = std::ptr::read(self.ptr.add(i));
+                    i += 1;
+                }
+                if self.cap != 0 {
+                    intrinsics::const_deallocate(
+                        self.ptr as *mut u8,
+                        self.cap * size_of::<Entry>(),
+                        align_of::<Entry>(),
+                    );
+                }
+                self.ptr = ptr;
+                self.cap = cap;
+            }
+            *self.ptr.add(self.len) = entry;
+            self.len += 1;
+        }
+    }
+
+    const fn as_slice(&self) -> &[Entry] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+const fn build_growable(len: usize) -> Growable {
+    let mut table = Growable::new();
+    let mut i = 0;
+    while i < len {
+        table.push(Entry { key: i as u8, square: (i * i) as u32 });
+        i += 1;
+    }
+    table
+}
+
+const fn build_table(len: usize) -> &'static [Entry] {
+    let table = Table::build(len);
+    unsafe { std::slice::from_raw_parts(table.ptr, table.len) }
+}
+
+static TABLE: &[Entry] = build_table(4);
+static OWNED: Table = Table::build(3);
+static BEHIND_REF: &Table = &Table::build(2);
+const CONST_BEHIND_REF: &Table = &Table::build(1);
+static GROWN: &Growable = &build_growable(5);
+
+fn main() {
+    assert_eq!(TABLE.len(), 4);
+    assert_eq!(TABLE[3], Entry { key: 3, square: 9 });
+    assert_eq!(OWNED.as_slice()[2], Entry { key: 2, square: 4 });
+    assert_eq!(BEHIND_REF.as_slice().len(), 2);
+    assert_eq!(CONST_BEHIND_REF.as_slice(), &[Entry { key: 0, square: 0 }]);
+    assert_eq!((GROWN.len, GROWN.cap), (5, 8));
+    assert_eq!(GROWN.as_slice()[4], Entry { key: 4, square: 16 });
+}
@@ -0,0 +1,13 @@
+// Without its feature gate, `#[diagnostic::on_missing_method]` is ignored: the
+// error keeps its default message and label, and has no custom note.
+#![allow(dead_code)]
+
+#[diagnostic::on_missing_method(message = "no such method", label = "none", note = "custom note")]
+//~^ WARN unknown diagnostic attribute
+struct Wrapper<T>(T);
+
+fn main() {
+    let wrapper = Wrapper(1u32);
+    wrapper.frobnicate();
+    //~^ ERROR no method named `frobnicate` found for struct `Wrapper` in the current scope
+}
@@ -0,0 +1,21 @@
+#![feature(diagnostic_on_missing_method)]
+#![allow(dead_code)]
+
+#[diagnostic::on_missing_method(
+    message = "`{Self}` has no such method",
+    label = "see the inherent methods of `Wrapper`",
+    note = "call the methods of `{T}` on `Wrapper::inner` instead"
+)]
+struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
+fn main() {
+    let wrapper = Wrapper(1u32);
+    wrapper.frobnicate();
+    //~^ ERROR `Wrapper<u32>` has no such method
+}
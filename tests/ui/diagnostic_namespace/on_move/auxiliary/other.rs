@@ -0,0 +1,9 @@
+#![feature(diagnostic_on_move)]
+
+#[derive(Clone)]
+#[diagnostic::on_move(
+    message = "`{Self}` used after being moved",
+    label = "this handle was already moved",
+    note = "a `Handle<{T}>` owns a unique resource"
+)]
+pub struct Handle<T>(pub T);
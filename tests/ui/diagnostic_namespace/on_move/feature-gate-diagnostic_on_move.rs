@@ -0,0 +1,15 @@
+// Without its feature gate, `#[diagnostic::on_move]` is ignored: the error
+// keeps its default message and label, and has no custom note.
+#![allow(dead_code)]
+
+#[derive(Clone)]
+#[diagnostic::on_move(message = "moved", label = "moved here", note = "custom note")]
+//~^ WARN unknown diagnostic attribute
+struct Foo;
+
+fn main() {
+    let foo = Foo;
+    let _a = foo;
+    let _b = foo;
+    //~^ ERROR use of moved value: `foo`
+}
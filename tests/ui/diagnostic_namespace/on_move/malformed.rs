@@ -0,0 +1,29 @@
+//@ check-pass
+#![feature(diagnostic_on_move)]
+
+#[diagnostic::on_move(message = "moved", unsupported = "value")]
+//~^ WARN malformed `on_move` attribute
+struct UnsupportedOption;
+
+#[diagnostic::on_move(message = "moved {Unknown}")]
+//~^ WARN there is no parameter `Unknown` on type `UnknownParameter`
+struct UnknownParameter;
+
+#[diagnostic::on_move(message = "moved {}")]
+//~^ WARN positional format arguments are not allowed here
+struct PositionalArgument;
+
+#[diagnostic::on_move]
+//~^ WARN missing options for `on_move` attribute
+struct MissingOptions;
+
+#[diagnostic::on_move(message = "first")]
+#[diagnostic::on_move(message = "second")]
+//~^ WARN `message` is ignored due to previous definition of `message`
+struct DuplicateMessage;
+
+#[diagnostic::on_move(message = "moved")]
+//~^ WARN `#[diagnostic::on_move]` can only be applied to struct, enum and union definitions
+trait NotAType {}
+
+fn main() {}
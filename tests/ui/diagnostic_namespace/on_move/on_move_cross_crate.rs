@@ -0,0 +1,14 @@
+//@ aux-build:other.rs
+// The attribute of a type from another crate applies without the feature gate,
+// which only needs to be enabled in the crate that defines the type.
+
+extern crate other;
+
+use other::Handle;
+
+fn main() {
+    let handle = Handle(1u8);
+    let _a = handle;
+    let _b = handle;
+    //~^ ERROR `Handle<u8>` used after being moved
+}
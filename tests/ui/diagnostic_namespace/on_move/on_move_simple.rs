@@ -0,0 +1,17 @@
+#![feature(diagnostic_on_move)]
+#![allow(dead_code)]
+
+#[derive(Clone)]
+#[diagnostic::on_move(
+    message = "`{Self}` used after being moved",
+    label = "this handle was already moved",
+    note = "a `Handle<{T}>` owns a unique resource"
+)]
+struct Handle<T>(T);
+
+fn main() {
+    let handle = Handle(1u8);
+    let _a = handle;
+    let _b = handle;
+    //~^ ERROR `Handle<u8>` used after being moved
+}
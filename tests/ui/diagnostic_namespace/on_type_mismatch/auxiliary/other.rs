@@ -0,0 +1,8 @@
+#![feature(diagnostic_on_type_mismatch)]
+
+#[diagnostic::on_type_mismatch(
+    message = "expected a `{Self}`",
+    label = "distances must be given as `{Self}`",
+    note = "construct a `{Self}` from a number of meters with `Meters(..)`"
+)]
+pub struct Meters(pub f64);
@@ -0,0 +1,12 @@
+// Without its feature gate, `#[diagnostic::on_type_mismatch]` is ignored: the
+// error keeps its default message and label, and has no custom note.
+#![allow(dead_code)]
+
+#[diagnostic::on_type_mismatch(message = "expected meters", label = "meters", note = "custom note")]
+//~^ WARN unknown diagnostic attribute
+struct Meters(f64);
+
+fn main() {
+    let _distance: Meters = "ten";
+    //~^ ERROR mismatched types
+}
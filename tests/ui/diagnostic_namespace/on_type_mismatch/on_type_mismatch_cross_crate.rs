@@ -0,0 +1,12 @@
+//@ aux-build:other.rs
+// The attribute of a type from another crate applies without the feature gate,
+// which only needs to be enabled in the crate that defines the type.
+
+extern crate other;
+
+use other::Meters;
+
+fn main() {
+    let _distance: Meters = "ten";
+    //~^ ERROR expected a `Meters`
+}
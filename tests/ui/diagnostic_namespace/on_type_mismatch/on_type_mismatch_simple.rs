@@ -0,0 +1,14 @@
+#![feature(diagnostic_on_type_mismatch)]
+#![allow(dead_code)]
+
+#[diagnostic::on_type_mismatch(
+    message = "expected a `{Self}`",
+    label = "distances must be given as `{Self}`",
+    note = "construct a `{Self}` from a number of meters with `Meters(..)`"
+)]
+struct Meters(f64);
+
+fn main() {
+    let _distance: Meters = "ten";
+    //~^ ERROR expected a `Meters`
+}
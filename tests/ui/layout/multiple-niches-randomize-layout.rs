@@ -0,0 +1,47 @@
+//@ run-pass
+//@ revisions: normal randomize-layout
+//@ [randomize-layout]compile-flags: -Zrandomize-layout
+
+// Niche-filling can pick the niche of any field that does not overlap the other variants. With
+// shuffled field orders the chosen niche (or whether one fits at all) changes, but the enum must
+// still round-trip its values and leave a niche for `Option`.
+
+#![allow(dead_code)]
+
+use std::mem::size_of;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct MiddleChar(u32, char, u32);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MultipleNiches {
+    A(MiddleChar, bool, bool, bool, bool),
+    B([u8; 10]),
+}
+
+#[inline(never)]
+fn check(value: MultipleNiches) {
+    let opt = std::hint::black_box(Some(value));
+    assert_eq!(opt, Some(value));
+    match (value, opt.unwrap()) {
+        (MultipleNiches::A(x, a, b, c, d), MultipleNiches::A(y, e, f, g, h)) => {
+            assert_eq!((x, a, b, c, d), (y, e, f, g, h));
+        }
+        (MultipleNiches::B(x), MultipleNiches::B(y)) => assert_eq!(x, y),
+        _ => panic!("variant changed"),
+    }
+}
+
+fn main() {
+    #[cfg(normal)]
+    assert_eq!(size_of::<MultipleNiches>(), 16);
+    assert_eq!(size_of::<Option<MultipleNiches>>(), size_of::<MultipleNiches>());
+
+    check(MultipleNiches::A(MiddleChar(u32::MAX, char::MAX, 0), true, false, true, false));
+    check(MultipleNiches::A(MiddleChar(0, '\0', u32::MAX), false, true, false, true));
+    check(MultipleNiches::B([0xff; 10]));
+    check(MultipleNiches::B([0; 10]));
+    check(MultipleNiches::B([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]));
+    assert_eq!(std::hint::black_box(None::<MultipleNiches>), None);
+}
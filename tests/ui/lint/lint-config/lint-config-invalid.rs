@@ -0,0 +1,9 @@
+// Checks the error for an invalid level in a `--lint-config` file.
+//
+// Path replacement in .stderr files (i.e. `$DIR`) doesn't handle mixed path
+// separators.
+
+//@ ignore-windows
+//@ compile-flags: -Zunstable-options --lint-config {{src-base}}/lint/lint-config/invalid-lints.toml
+
+fn main() {}
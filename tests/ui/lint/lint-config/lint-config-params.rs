@@ -0,0 +1,16 @@
+// Checks that a `--lint-config` file sets the limit of `large_assignments`.
+
+//@ build-fail
+//@ compile-flags: -Zunstable-options --lint-config {{src-base}}/lint/lint-config/lints.toml
+
+#![deny(large_assignments)]
+#![allow(unused)]
+
+#[derive(Copy, Clone)]
+struct Data([u8; 9999]);
+
+fn main() {
+    take(Data([0; 9999])); //~ ERROR moving 9999 bytes
+}
+
+fn take(data: Data) {}
@@ -0,0 +1,14 @@
+// Checks the levels set by a `--lint-config` file, for the whole crate and for
+// the files matching a path glob.
+
+//@ compile-flags: -Zunstable-options --lint-config {{src-base}}/lint/lint-config/lints.toml
+
+mod generated;
+
+#[allow(non_camel_case_types)] //~ ERROR allow(non_camel_case_types) incompatible with previous forbid
+struct S;
+
+fn main() {
+    let unused = S; //~ ERROR unused variable: `unused`
+    generated::f();
+}
@@ -0,0 +1,31 @@
+//@ compile-flags: -Z print-type-sizes -Z print-type-sizes-format=json --crate-type lib
+//@ compile-flags: --remap-path-prefix={{src-base}}=remapped
+//@ ignore-windows path separators are escaped in the output
+//@ build-pass
+//@ ignore-pass
+// ^-- needed because `--pass check` does not emit the output needed.
+//     FIXME: consider using an attribute instead of side-effects.
+
+// This file illustrates the JSON output of `-Z print-type-sizes`, which
+// spells out the padding between fields, the niche and the type of each
+// field, and where values of each type are created.
+
+#![allow(dead_code)]
+
+pub struct Padded {
+    a: u8,
+    b: u32,
+}
+
+pub enum Flag {
+    On(bool),
+    Off,
+}
+
+pub fn padded() -> Padded {
+    Padded { a: 1, b: 2 }
+}
+
+pub fn off() -> Flag {
+    Flag::Off
+}
@@ -0,0 +1,15 @@
+//@ compile-flags: -Z print-type-sizes --crate-type=lib
+//@ build-pass
+
+// This file illustrates that the niche used to encode the variants of an
+// enum is not necessarily the largest one: the `char` in the middle of `A`
+// leaves no room for `B` on either side of it, but the niche of one of the
+// trailing `bool`s does, so no separate discriminant is needed.
+
+#[repr(C)]
+pub struct MiddleChar(u32, char, u32);
+
+pub enum MultipleNiches {
+    A(MiddleChar, bool, bool, bool, bool),
+    B([u8; 10]),
+}
@@ -0,0 +1,20 @@
+//@ force-host
+//@ no-prefer-dynamic
+
+#![crate_type = "proc-macro"]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+#[proc_macro]
+pub fn abort(_: TokenStream) -> TokenStream {
+    std::process::abort()
+}
+
+#[proc_macro]
+pub fn spin(_: TokenStream) -> TokenStream {
+    loop {
+        std::hint::spin_loop();
+    }
+}
@@ -0,0 +1,15 @@
+//@ aux-build:process-killers.rs
+//@ aux-build:bang-macro.rs
+//@ compile-flags: -Z proc-macro-execution-strategy=out-of-process
+//@ normalize-stderr-test: "exited with .*" -> "exited with $$STATUS"
+
+extern crate bang_macro;
+extern crate process_killers;
+
+process_killers::abort!();
+//~^ ERROR proc macro crashed
+
+// Later expansions get a new server.
+const _: &str = bang_macro::rewrite!("Hello, world!");
+
+fn main() {}
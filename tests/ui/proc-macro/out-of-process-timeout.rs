@@ -0,0 +1,9 @@
+//@ aux-build:process-killers.rs
+//@ compile-flags: -Z proc-macro-execution-strategy=out-of-process -Z proc-macro-timeout=1
+
+extern crate process_killers;
+
+process_killers::spin!();
+//~^ ERROR proc macro did not finish within 1 seconds
+
+fn main() {}
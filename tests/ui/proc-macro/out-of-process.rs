@@ -0,0 +1,24 @@
+//@ run-pass
+//@ aux-build:bang-macro.rs
+//@ aux-build:test-macros.rs
+//@ compile-flags: -Z proc-macro-execution-strategy=out-of-process
+
+extern crate bang_macro;
+#[macro_use]
+extern crate test_macros;
+
+use bang_macro::rewrite;
+
+#[derive(Identity)]
+struct Foo;
+
+#[identity_attr]
+fn foo() -> Foo {
+    identity!(Foo)
+}
+
+fn main() {
+    let Foo = foo();
+    assert_eq!(rewrite!("Hello, world!"), "NOT Hello, world!");
+    assert_eq!(rewrite!("Hello, world!"), "NOT Hello, world!");
+}
@@ -208,6 +208,16 @@ struct ReorderEndNiche {
     b: MiddleNiche4,
 }
 
+#[repr(C)]
+struct MiddleChar(u32, char, u32);
+
+// The largest niche, in the `char`, leaves no room for `B` on either side of it,
+// but the niche of one of the `bool`s does.
+enum MultipleNiches {
+    A(MiddleChar, bool, bool, bool, bool),
+    B([u8; 10]),
+}
+
 
 // standins for std types which we want to be laid out in a reasonable way
 struct RawVecDummy {
@@ -333,5 +343,8 @@ pub enum FillPadding { A(NonZero<u8>, u32), B }
     let v = ReorderEndNiche { a: EndNiche8([0; 7], false), b: MiddleNiche4(0, 0, false, 0) };
     assert!(ptr::from_ref(&v.a).addr() > ptr::from_ref(&v.b).addr());
 
+    assert_eq!(size_of::<MultipleNiches>(), 16);
+    assert_eq!(size_of::<Option<MultipleNiches>>(), 16);
+
 
 }